@@ -39,6 +39,10 @@ impl iceoryx2::service::Service for CustomServiceVariant {
             iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator,
         >,
     >;
+    // the data segment for variable-sized payload is also based on a file
+    type BuddySharedMemory = iceoryx2_cal::shared_memory::file::Memory<
+        iceoryx2_cal::shm_allocator::buddy_allocator::BuddyAllocator,
+    >;
     // instead of using a connection based on POSIX shared memory, we use a
     // variant based on a file
     type Connection = iceoryx2_cal::zero_copy_connection::file::Connection;
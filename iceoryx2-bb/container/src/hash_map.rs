@@ -0,0 +1,816 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A HashMap is a data structure to store key-value pairs with a constant average lookup
+//! time. It uses open addressing with linear probing and a [`DeterministicHasher`] so that
+//! every process computes the same slot for a key, which makes it usable in shared memory.
+//! Multiple variations of that container are available.
+//!
+//!  * [`StaticHashMap`](crate::hash_map::StaticHashMap), compile-time fixed-size hash map
+//!    that is self-contained and shared-memory compatible.
+//!  * [`RelocatableHashMap`](crate::hash_map::RelocatableHashMap), run-time fixed-size hash
+//!    map that is shared-memory compatible.
+//!  * [`HashMap`](crate::hash_map::HashMap), run-time fixed-size hash map that is not
+//!    shared-memory compatible since the memory resides in the heap.
+//!
+//! The map allocates at least twice as many slots as its capacity, rounded up to the next
+//! power of two, so that the load factor never exceeds 50% and the probe sequences stay short
+//! even when the map is full.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::hash_map::StaticHashMap;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut map = StaticHashMap::<u64, u8, CAPACITY>::new();
+//! assert_eq!(map.insert(23, 4).is_ok(), true);
+//! assert_eq!(map.get(&23).unwrap(), 4);
+//! ```
+
+use core::alloc::Layout;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::{GenericOwningPointer, OwningPointer};
+use iceoryx2_bb_elementary::relocatable_pointer::{GenericRelocatablePointer, RelocatablePointer};
+use iceoryx2_bb_elementary_traits::allocator::{Allocate, AllocationError};
+use iceoryx2_bb_elementary_traits::pointer::Pointer;
+use iceoryx2_bb_elementary_traits::pointer_family::PointerFamily;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HashMapError {
+    /// The HashMap already contains the key that shall be inserted.
+    KeyAlreadyExists,
+    /// The HashMap is full and cannot hold an additional key-value pair.
+    IsFull,
+}
+
+impl core::fmt::Display for HashMapError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "HashMapError::{self:?}")
+    }
+}
+
+impl core::error::Error for HashMapError {}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// A [`Hasher`] that produces the same hash for the same input in every process. It is
+/// a 64-bit FNV-1a hash with a final avalanche step so that sequential keys are spread over
+/// the whole map. It is not resistant against hash flooding and shall not be used for keys
+/// that are controlled by an untrusted party.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DeterministicHasher {
+    state: u64,
+}
+
+impl Default for DeterministicHasher {
+    fn default() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for DeterministicHasher {
+    fn finish(&self) -> u64 {
+        let mut value = self.state;
+        value ^= value >> 33;
+        value = value.wrapping_mul(0xff51afd7ed558ccd);
+        value ^= value >> 33;
+        value = value.wrapping_mul(0xc4ceb9fe1a85ec53);
+        value ^ (value >> 33)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+impl DeterministicHasher {
+    /// Returns the hash of the provided value.
+    pub fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = Self::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[repr(C)]
+pub(crate) struct Slot<K, V> {
+    key: MaybeUninit<K>,
+    value: MaybeUninit<V>,
+    is_occupied: bool,
+}
+
+impl<K, V> Debug for Slot<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Slot {{ is_occupied: {} }}", self.is_occupied)
+    }
+}
+
+impl<K, V> Slot<K, V> {
+    const fn empty() -> Self {
+        Self {
+            key: MaybeUninit::uninit(),
+            value: MaybeUninit::uninit(),
+            is_occupied: false,
+        }
+    }
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`HashMap`]. The [`HashMap`]s memory
+/// resides in the heap.
+pub type HashMap<K, V> = MetaHashMap<K, V, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableHashMap`].
+pub type RelocatableHashMap<K, V> = MetaHashMap<K, V, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaHashMap<K: Eq + Hash, V, Ptr: PointerFamily> {
+    slots: Ptr::Pointer<Slot<K, V>>,
+    capacity: usize,
+    number_of_slots: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+    _phantom_data: PhantomData<(K, V)>,
+}
+
+unsafe impl<K: Eq + Hash + Send, V: Send, Ptr: PointerFamily> Send for MetaHashMap<K, V, Ptr> {}
+
+impl<K: Eq + Hash + Debug, V: Debug, Ptr: PointerFamily> Debug for MetaHashMap<K, V, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaHashMap<{}, {}, {}> {{ len: {}, capacity: {}, is_initialized: {} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            core::any::type_name::<Ptr>(),
+            self.len,
+            self.capacity,
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+/// Iterator over all key-value pairs of a hash map. The order is unspecified.
+pub struct HashMapIter<'a, K, V> {
+    slots: *const Slot<K, V>,
+    index: usize,
+    number_of_slots: usize,
+    _lifetime: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V> Iterator for HashMapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.number_of_slots {
+            let slot = unsafe { &*self.slots.add(self.index) };
+            self.index += 1;
+            if slot.is_occupied {
+                return Some(unsafe { (slot.key.assume_init_ref(), slot.value.assume_init_ref()) });
+            }
+        }
+
+        None
+    }
+}
+
+impl<K: Eq + Hash, V, Ptr: PointerFamily> MetaHashMap<K, V, Ptr> {
+    const fn number_of_slots(capacity: usize) -> usize {
+        if capacity == 0 {
+            0
+        } else {
+            (capacity * 2).next_power_of_two()
+        }
+    }
+
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaHashMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            source
+        );
+    }
+
+    /// Returns the maximum number of key-value pairs the map can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the map is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    fn home_index(&self, key: &K) -> usize {
+        DeterministicHasher::hash(key) as usize & (self.number_of_slots - 1)
+    }
+
+    fn next_index(&self, index: usize) -> usize {
+        (index + 1) & (self.number_of_slots - 1)
+    }
+
+    fn slot(&self, index: usize) -> &Slot<K, V> {
+        unsafe { &*self.slots.as_ptr().add(index) }
+    }
+
+    fn slot_mut(&mut self, index: usize) -> &mut Slot<K, V> {
+        unsafe { &mut *self.slots.as_mut_ptr().add(index) }
+    }
+
+    unsafe fn initialize_slots(&mut self) {
+        for n in 0..self.number_of_slots {
+            unsafe { self.slots.as_mut_ptr().add(n).write(Slot::empty()) };
+        }
+    }
+
+    fn find_index(&self, key: &K) -> Option<usize> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        let mut index = self.home_index(key);
+        for _ in 0..self.number_of_slots {
+            let slot = self.slot(index);
+            if !slot.is_occupied {
+                return None;
+            }
+
+            if unsafe { slot.key.assume_init_ref() } == key {
+                return Some(index);
+            }
+
+            index = self.next_index(index);
+        }
+
+        None
+    }
+
+    pub(crate) unsafe fn insert_impl(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        self.verify_init("insert()");
+
+        let msg = "Unable to insert key-value pair into HashMap";
+        let origin = "MetaHashMap::insert_impl()";
+
+        if self.find_index(&key).is_some() {
+            fail!(from origin, with HashMapError::KeyAlreadyExists,
+                "{msg} since the passed key already exists.");
+        }
+
+        if self.is_full() {
+            fail!(from origin, with HashMapError::IsFull,
+                "{msg} since the HashMap is full.");
+        }
+
+        let mut index = self.home_index(&key);
+        while self.slot(index).is_occupied {
+            index = self.next_index(index);
+        }
+
+        let slot = self.slot_mut(index);
+        slot.key.write(key);
+        slot.value.write(value);
+        slot.is_occupied = true;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub(crate) unsafe fn get_ref_impl(&self, key: &K) -> Option<&V> {
+        self.verify_init("get_ref()");
+
+        let index = self.find_index(key)?;
+        Some(unsafe { self.slot(index).value.assume_init_ref() })
+    }
+
+    pub(crate) unsafe fn get_mut_ref_impl(&mut self, key: &K) -> Option<&mut V> {
+        self.verify_init("get_mut_ref()");
+
+        let index = self.find_index(key)?;
+        Some(unsafe { self.slot_mut(index).value.assume_init_mut() })
+    }
+
+    pub(crate) unsafe fn contains_impl(&self, key: &K) -> bool {
+        self.verify_init("contains()");
+        self.find_index(key).is_some()
+    }
+
+    pub(crate) unsafe fn remove_entry_impl(&mut self, key: &K) -> Option<(K, V)> {
+        self.verify_init("remove()");
+
+        let mut vacant = self.find_index(key)?;
+        let removed = {
+            let slot = self.slot_mut(vacant);
+            slot.is_occupied = false;
+            unsafe { (slot.key.assume_init_read(), slot.value.assume_init_read()) }
+        };
+        self.len -= 1;
+
+        // backward shift deletion: move all succeeding entries of the probe sequence whose
+        // home slot is not located between the vacant slot and their current position into
+        // the vacant slot so that no lookup is interrupted by the new gap
+        let mut index = vacant;
+        for _ in 1..self.number_of_slots {
+            index = self.next_index(index);
+            if !self.slot(index).is_occupied {
+                break;
+            }
+
+            let home = self.home_index(unsafe { self.slot(index).key.assume_init_ref() });
+            let stays = if vacant <= index {
+                vacant < home && home <= index
+            } else {
+                vacant < home || home <= index
+            };
+
+            if !stays {
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        self.slots.as_ptr().add(index),
+                        self.slots.as_mut_ptr().add(vacant),
+                        1,
+                    )
+                };
+                self.slot_mut(index).is_occupied = false;
+                vacant = index;
+            }
+        }
+
+        Some(removed)
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        for n in 0..self.number_of_slots {
+            let slot = self.slot_mut(n);
+            if slot.is_occupied {
+                slot.is_occupied = false;
+                unsafe {
+                    slot.key.assume_init_drop();
+                    slot.value.assume_init_drop();
+                }
+            }
+        }
+        self.len = 0;
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> HashMapIter<'_, K, V> {
+        self.verify_init("iter()");
+
+        HashMapIter {
+            slots: self.slots.as_ptr(),
+            index: 0,
+            number_of_slots: self.number_of_slots,
+            _lifetime: PhantomData,
+        }
+    }
+
+    pub(crate) unsafe fn list_keys_impl<F: FnMut(&K) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        for (key, _) in unsafe { self.iter_impl() } {
+            if callback(key) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, Ptr: PointerFamily> Drop for MetaHashMap<K, V, Ptr> {
+    fn drop(&mut self) {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> HashMap<K, V> {
+    /// Creates a new runtime-fixed size [`HashMap`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        let mut new_self = Self {
+            slots: OwningPointer::<Slot<K, V>>::new_with_alloc(Self::number_of_slots(capacity)),
+            capacity,
+            number_of_slots: Self::number_of_slots(capacity),
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+            _phantom_data: PhantomData,
+        };
+        unsafe { new_self.initialize_slots() };
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`HashMap`]. On success, the method returns [`Ok`],
+    /// otherwise a [`HashMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`HashMap`], returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_entry_impl(key).map(|(_, value)| value) }
+    }
+
+    /// Returns true if the [`HashMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.contains_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the [`HashMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in an unspecified order.
+    pub fn iter(&self) -> HashMapIter<'_, K, V> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+}
+
+impl<K: Eq + Hash, V> RelocatableContainer for RelocatableHashMap<K, V> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            slots: unsafe { RelocatablePointer::new_uninit() },
+            capacity,
+            number_of_slots: Self::number_of_slots(capacity),
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    unsafe fn init<Allocator: Allocate<NonNull<u8>>>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableHashMap::init()",
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        let memory = fail!(from "RelocatableHashMap::init()",
+            when allocator.allocate(unsafe {
+                Layout::from_size_align_unchecked(
+                    core::mem::size_of::<Slot<K, V>>() * self.number_of_slots,
+                    core::mem::align_of::<Slot<K, V>>(),
+                )
+            }),
+            "Failed to initialize RelocatableHashMap since the allocation of the data memory failed.");
+
+        unsafe {
+            self.slots.init(memory);
+            self.initialize_slots();
+        }
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, V: ZeroCopySend> ZeroCopySend
+    for RelocatableHashMap<K, V>
+{
+}
+
+impl<K: Eq + Hash, V> RelocatableHashMap<K, V> {
+    /// Returns how much memory the [`RelocatableHashMap`] will allocate from the allocator
+    /// in [`RelocatableHashMap::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        unaligned_mem_size::<Slot<K, V>>(Self::number_of_slots(capacity))
+    }
+
+    /// Inserts a new key-value pair into the map. On success, the method returns [`Ok`],
+    /// otherwise a [`HashMapError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get(&self, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the map, returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_entry_impl(key).map(|(_, value)| value) }
+    }
+
+    /// Returns true if the map contains the given key, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, key: &K) -> bool {
+        unsafe { self.contains_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the map.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in an unspecified order.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> HashMapIter<'_, K, V> {
+        unsafe { self.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`StaticHashMap`].
+#[repr(C)]
+pub struct StaticHashMap<K: Eq + Hash, V, const CAPACITY: usize> {
+    map: RelocatableHashMap<K, V>,
+    // the number of slots cannot be computed in a const generic context, therefore the
+    // upper bound of `(2 * CAPACITY).next_power_of_two()` is reserved
+    _data: [[MaybeUninit<Slot<K, V>>; 4]; CAPACITY],
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for StaticHashMap<K, V, CAPACITY>
+{
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> PlacementDefault for StaticHashMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+            map_ptr.write(RelocatableHashMap::new_uninit(CAPACITY));
+
+            // SAFETY: Creating a pointer to an existing member is always not null
+            let data_ptr =
+                core::ptr::NonNull::<u8>::new_unchecked((*ptr)._data.as_mut_ptr().cast());
+
+            let allocator = BumpAllocator::new(
+                data_ptr,
+                size_of::<[[MaybeUninit<Slot<K, V>>; 4]; CAPACITY]>(),
+            );
+            (*ptr)
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> Default for StaticHashMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Debug, V: Debug, const CAPACITY: usize> Debug
+    for StaticHashMap<K, V, CAPACITY>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticHashMap<{}, {}, {}> {{ {:?} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> StaticHashMap<K, V, CAPACITY> {
+    /// Creates a new [`StaticHashMap`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            map: unsafe { RelocatableHashMap::new_uninit(CAPACITY) },
+            _data: [const { [const { MaybeUninit::uninit() }; 4] }; CAPACITY],
+        };
+
+        // SAFETY: Creating a pointer to an existing member is always not null
+        let data_ptr =
+            unsafe { core::ptr::NonNull::<u8>::new_unchecked(new_self._data.as_mut_ptr().cast()) };
+
+        let allocator =
+            BumpAllocator::new(data_ptr, core::mem::size_of_val(new_self._data.as_ref()));
+        unsafe {
+            new_self
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Returns the maximum number of key-value pairs the [`StaticHashMap`] can hold.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the [`StaticHashMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`StaticHashMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Inserts a new key-value pair into the [`StaticHashMap`]. On success, the method returns
+    /// [`Ok`], otherwise a [`HashMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.map.insert_impl(key, value) }
+    }
+
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsafe { self.map.get_ref_impl(key).cloned() }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.map.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.map.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`StaticHashMap`], returning the Some(value) at the key if the
+    /// key was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.map.remove_entry_impl(key).map(|(_, value)| value) }
+    }
+
+    /// Returns true if the [`StaticHashMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.map.contains_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the [`StaticHashMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.map.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in an unspecified order.
+    pub fn iter(&self) -> HashMapIter<'_, K, V> {
+        unsafe { self.map.iter_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.map.list_keys_impl(callback) };
+    }
+}
@@ -0,0 +1,352 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A HashSet is a data structure to store unique values with a constant average lookup time.
+//! It is built on top of the [`hash_map`](crate::hash_map) and shares its properties.
+//! Multiple variations of that container are available.
+//!
+//!  * [`StaticHashSet`](crate::hash_set::StaticHashSet), compile-time fixed-size hash set
+//!    that is self-contained and shared-memory compatible.
+//!  * [`RelocatableHashSet`](crate::hash_set::RelocatableHashSet), run-time fixed-size hash
+//!    set that is shared-memory compatible.
+//!  * [`HashSet`](crate::hash_set::HashSet), run-time fixed-size hash set that is not
+//!    shared-memory compatible since the memory resides in the heap.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::hash_set::StaticHashSet;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut set = StaticHashSet::<u64, CAPACITY>::new();
+//! assert_eq!(set.insert(23), Ok(true));
+//! assert_eq!(set.insert(23), Ok(false));
+//! assert_eq!(set.contains(&23), true);
+//! ```
+
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ptr::NonNull;
+use iceoryx2_bb_elementary::owning_pointer::GenericOwningPointer;
+use iceoryx2_bb_elementary::relocatable_pointer::GenericRelocatablePointer;
+use iceoryx2_bb_elementary_traits::allocator::{Allocate, AllocationError};
+use iceoryx2_bb_elementary_traits::pointer_family::PointerFamily;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::fail;
+
+use crate::hash_map::{HashMapError, HashMapIter, MetaHashMap, StaticHashMap};
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HashSetError {
+    /// The HashSet is full and cannot hold an additional value.
+    IsFull,
+}
+
+impl core::fmt::Display for HashSetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "HashSetError::{self:?}")
+    }
+}
+
+impl core::error::Error for HashSetError {}
+
+/// A runtime fixed-size, non-shared memory compatible [`HashSet`]. The [`HashSet`]s memory
+/// resides in the heap.
+pub type HashSet<K> = MetaHashSet<K, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableHashSet`].
+pub type RelocatableHashSet<K> = MetaHashSet<K, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaHashSet<K: Eq + Hash, Ptr: PointerFamily> {
+    map: MetaHashMap<K, (), Ptr>,
+}
+
+impl<K: Eq + Hash + Debug, Ptr: PointerFamily> Debug for MetaHashSet<K, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MetaHashSet {{ {:?} }}", self.map)
+    }
+}
+
+/// Iterator over all values of a hash set. The order is unspecified.
+pub struct HashSetIter<'a, K> {
+    iter: HashMapIter<'a, K, ()>,
+}
+
+impl<'a, K> Iterator for HashSetIter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+}
+
+impl<K: Eq + Hash, Ptr: PointerFamily> MetaHashSet<K, Ptr> {
+    /// Returns the maximum number of values the set can hold.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the set is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the set is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    pub(crate) unsafe fn insert_impl(&mut self, value: K) -> Result<bool, HashSetError> {
+        match unsafe { self.map.insert_impl(value, ()) } {
+            Ok(()) => Ok(true),
+            Err(HashMapError::KeyAlreadyExists) => Ok(false),
+            Err(HashMapError::IsFull) => Err(HashSetError::IsFull),
+        }
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> HashSetIter<'_, K> {
+        HashSetIter {
+            iter: unsafe { self.map.iter_impl() },
+        }
+    }
+}
+
+impl<K: Eq + Hash> HashSet<K> {
+    /// Creates a new runtime-fixed size [`HashSet`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: MetaHashMap::new(capacity),
+        }
+    }
+
+    /// Adds a value to the [`HashSet`]. Returns `true` when the value was added and `false`
+    /// when it was already contained. When the [`HashSet`] is full a [`HashSetError`] is
+    /// returned.
+    pub fn insert(&mut self, value: K) -> Result<bool, HashSetError> {
+        unsafe { self.insert_impl(value) }
+    }
+
+    /// Removes a value from the [`HashSet`]. Returns `true` when the value was contained,
+    /// otherwise `false`.
+    pub fn remove(&mut self, value: &K) -> bool {
+        unsafe { self.map.remove_entry_impl(value).is_some() }
+    }
+
+    /// Returns true if the [`HashSet`] contains the given value, otherwise false.
+    pub fn contains(&self, value: &K) -> bool {
+        unsafe { self.map.contains_impl(value) }
+    }
+
+    /// Removes all values from the [`HashSet`].
+    pub fn clear(&mut self) {
+        unsafe { self.map.clear_impl() }
+    }
+
+    /// Returns an iterator over all values in an unspecified order.
+    pub fn iter(&self) -> HashSetIter<'_, K> {
+        unsafe { self.iter_impl() }
+    }
+}
+
+impl<K: Eq + Hash> RelocatableContainer for RelocatableHashSet<K> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            map: unsafe { MetaHashMap::new_uninit(capacity) },
+        }
+    }
+
+    unsafe fn init<Allocator: Allocate<NonNull<u8>>>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        fail!(from "RelocatableHashSet::init()", when unsafe { self.map.init(allocator) },
+            "Unable to initialize RelocatableHashSet since the underlying RelocatableHashMap could not be initialized.");
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend> ZeroCopySend for RelocatableHashSet<K> {}
+
+impl<K: Eq + Hash> RelocatableHashSet<K> {
+    /// Returns how much memory the [`RelocatableHashSet`] will allocate from the allocator
+    /// in [`RelocatableHashSet::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        MetaHashMap::<K, (), GenericRelocatablePointer>::const_memory_size(capacity)
+    }
+
+    /// Adds a value to the set. Returns `true` when the value was added and `false`
+    /// when it was already contained. When the set is full a [`HashSetError`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, value: K) -> Result<bool, HashSetError> {
+        unsafe { self.insert_impl(value) }
+    }
+
+    /// Removes a value from the set. Returns `true` when the value was contained,
+    /// otherwise `false`.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, value: &K) -> bool {
+        unsafe { self.map.remove_entry_impl(value).is_some() }
+    }
+
+    /// Returns true if the set contains the given value, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, value: &K) -> bool {
+        unsafe { self.map.contains_impl(value) }
+    }
+
+    /// Removes all values from the set.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.map.clear_impl() }
+    }
+
+    /// Returns an iterator over all values in an unspecified order.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashSet::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> HashSetIter<'_, K> {
+        unsafe { self.iter_impl() }
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`StaticHashSet`].
+#[repr(C)]
+pub struct StaticHashSet<K: Eq + Hash, const CAPACITY: usize> {
+    map: StaticHashMap<K, (), CAPACITY>,
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for StaticHashSet<K, CAPACITY>
+{
+}
+
+impl<K: Eq + Hash, const CAPACITY: usize> PlacementDefault for StaticHashSet<K, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe { StaticHashMap::placement_default(core::ptr::addr_of_mut!((*ptr).map)) }
+    }
+}
+
+impl<K: Eq + Hash, const CAPACITY: usize> Default for StaticHashSet<K, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Debug, const CAPACITY: usize> Debug for StaticHashSet<K, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticHashSet<{}, {}> {{ {:?} }}",
+            core::any::type_name::<K>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<K: Eq + Hash, const CAPACITY: usize> StaticHashSet<K, CAPACITY> {
+    /// Creates a new [`StaticHashSet`]
+    pub fn new() -> Self {
+        Self {
+            map: StaticHashMap::new(),
+        }
+    }
+
+    /// Returns the maximum number of values the [`StaticHashSet`] can hold.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the [`StaticHashSet`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`StaticHashSet`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Adds a value to the [`StaticHashSet`]. Returns `true` when the value was added and
+    /// `false` when it was already contained. When the [`StaticHashSet`] is full a
+    /// [`HashSetError`] is returned.
+    pub fn insert(&mut self, value: K) -> Result<bool, HashSetError> {
+        match self.map.insert(value, ()) {
+            Ok(()) => Ok(true),
+            Err(HashMapError::KeyAlreadyExists) => Ok(false),
+            Err(HashMapError::IsFull) => Err(HashSetError::IsFull),
+        }
+    }
+
+    /// Removes a value from the [`StaticHashSet`]. Returns `true` when the value was
+    /// contained, otherwise `false`.
+    pub fn remove(&mut self, value: &K) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Returns true if the [`StaticHashSet`] contains the given value, otherwise false.
+    pub fn contains(&self, value: &K) -> bool {
+        self.map.contains(value)
+    }
+
+    /// Removes all values from the [`StaticHashSet`].
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns an iterator over all values in an unspecified order.
+    pub fn iter(&self) -> HashSetIter<'_, K> {
+        HashSetIter {
+            iter: self.map.iter(),
+        }
+    }
+}
@@ -106,6 +106,14 @@ pub mod semantic_string;
 
 /// A container to store key-value pairs.
 pub mod flatmap;
+/// A container to store key-value pairs with a hash-based lookup.
+pub mod hash_map;
+/// A container to store unique keys with a hash-based lookup.
+pub mod hash_set;
+/// A doubly-linked list with a fixed capacity.
+pub mod list;
+/// A container to store key-value pairs sorted by their key.
+pub mod ordered_map;
 
 /// A trait that defines the interface of a string and several string variants.
 pub mod string;
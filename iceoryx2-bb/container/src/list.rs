@@ -0,0 +1,807 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A doubly-linked List with a fixed capacity. The nodes are taken from a preallocated node
+//! pool and are linked via indices instead of pointers, therefore the list can be stored in
+//! shared memory. Multiple variations of that container are available.
+//!
+//!  * [`StaticList`](crate::list::StaticList), compile-time fixed-size list that is
+//!    self-contained and shared-memory compatible.
+//!  * [`RelocatableList`](crate::list::RelocatableList), run-time fixed-size list that is
+//!    shared-memory compatible.
+//!  * [`List`](crate::list::List), run-time fixed-size list that is not shared-memory
+//!    compatible since the memory resides in the heap.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::list::StaticList;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut list = StaticList::<u64, CAPACITY>::new();
+//! assert_eq!(list.push_back(2), true);
+//! assert_eq!(list.push_front(1), true);
+//!
+//! assert_eq!(list.pop_front(), Some(1));
+//! assert_eq!(list.pop_back(), Some(2));
+//! ```
+
+use core::alloc::Layout;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::{GenericOwningPointer, OwningPointer};
+use iceoryx2_bb_elementary::relocatable_pointer::{GenericRelocatablePointer, RelocatablePointer};
+use iceoryx2_bb_elementary_traits::allocator::{Allocate, AllocationError};
+use iceoryx2_bb_elementary_traits::pointer::Pointer;
+use iceoryx2_bb_elementary_traits::pointer_family::PointerFamily;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+const INVALID_INDEX: usize = usize::MAX;
+
+#[repr(C)]
+pub(crate) struct Node<T> {
+    value: MaybeUninit<T>,
+    previous: usize,
+    next: usize,
+}
+
+impl<T> Debug for Node<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Node<{}> {{ previous: {}, next: {} }}",
+            core::any::type_name::<T>(),
+            self.previous,
+            self.next
+        )
+    }
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`List`]. The [`List`]s memory resides
+/// in the heap.
+pub type List<T> = MetaList<T, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableList`].
+pub type RelocatableList<T> = MetaList<T, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaList<T, Ptr: PointerFamily> {
+    nodes: Ptr::Pointer<Node<T>>,
+    head: usize,
+    tail: usize,
+    free_head: usize,
+    next_unused: usize,
+    capacity: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+    _phantom_data: PhantomData<T>,
+}
+
+unsafe impl<T: Send, Ptr: PointerFamily> Send for MetaList<T, Ptr> {}
+
+impl<T: Debug, Ptr: PointerFamily> Debug for MetaList<T, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaList<{}, {}> {{ len: {}, capacity: {}, is_initialized: {} }}",
+            core::any::type_name::<T>(),
+            core::any::type_name::<Ptr>(),
+            self.len,
+            self.capacity,
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+/// Iterator over all elements of a list from front to back.
+pub struct ListIter<'a, T> {
+    nodes: *const Node<T>,
+    front: usize,
+    back: usize,
+    remaining: usize,
+    _lifetime: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for ListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { &*self.nodes.add(self.front) };
+        self.front = node.next;
+        self.remaining -= 1;
+        Some(unsafe { node.value.assume_init_ref() })
+    }
+}
+
+impl<T> DoubleEndedIterator for ListIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { &*self.nodes.add(self.back) };
+        self.back = node.previous;
+        self.remaining -= 1;
+        Some(unsafe { node.value.assume_init_ref() })
+    }
+}
+
+impl<T, Ptr: PointerFamily> MetaList<T, Ptr> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaList<{}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<T>(),
+            source
+        );
+    }
+
+    /// Returns the maximum number of elements the list can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of stored elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the list is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    fn node(&self, index: usize) -> &Node<T> {
+        unsafe { &*self.nodes.as_ptr().add(index) }
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        unsafe { &mut *self.nodes.as_mut_ptr().add(index) }
+    }
+
+    fn acquire_node(&mut self, value: T) -> Option<usize> {
+        let index = if self.free_head != INVALID_INDEX {
+            let index = self.free_head;
+            self.free_head = self.node(index).next;
+            index
+        } else if self.next_unused < self.capacity {
+            let index = self.next_unused;
+            self.next_unused += 1;
+            index
+        } else {
+            return None;
+        };
+
+        unsafe {
+            self.nodes.as_mut_ptr().add(index).write(Node {
+                value: MaybeUninit::new(value),
+                previous: INVALID_INDEX,
+                next: INVALID_INDEX,
+            })
+        };
+
+        Some(index)
+    }
+
+    fn release_node(&mut self, index: usize) -> T {
+        let free_head = self.free_head;
+        let node = self.node_mut(index);
+        let value = unsafe { node.value.assume_init_read() };
+        node.next = free_head;
+        self.free_head = index;
+        value
+    }
+
+    fn unlink(&mut self, index: usize) -> T {
+        let previous = self.node(index).previous;
+        let next = self.node(index).next;
+
+        if previous == INVALID_INDEX {
+            self.head = next;
+        } else {
+            self.node_mut(previous).next = next;
+        }
+
+        if next == INVALID_INDEX {
+            self.tail = previous;
+        } else {
+            self.node_mut(next).previous = previous;
+        }
+
+        self.len -= 1;
+        self.release_node(index)
+    }
+
+    pub(crate) unsafe fn push_front_impl(&mut self, value: T) -> bool {
+        self.verify_init("push_front()");
+
+        let index = match self.acquire_node(value) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let head = self.head;
+        self.node_mut(index).next = head;
+        if head == INVALID_INDEX {
+            self.tail = index;
+        } else {
+            self.node_mut(head).previous = index;
+        }
+        self.head = index;
+        self.len += 1;
+
+        true
+    }
+
+    pub(crate) unsafe fn push_back_impl(&mut self, value: T) -> bool {
+        self.verify_init("push_back()");
+
+        let index = match self.acquire_node(value) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let tail = self.tail;
+        self.node_mut(index).previous = tail;
+        if tail == INVALID_INDEX {
+            self.head = index;
+        } else {
+            self.node_mut(tail).next = index;
+        }
+        self.tail = index;
+        self.len += 1;
+
+        true
+    }
+
+    pub(crate) unsafe fn pop_front_impl(&mut self) -> Option<T> {
+        self.verify_init("pop_front()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.unlink(self.head))
+    }
+
+    pub(crate) unsafe fn pop_back_impl(&mut self) -> Option<T> {
+        self.verify_init("pop_back()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.unlink(self.tail))
+    }
+
+    pub(crate) unsafe fn front_impl(&self) -> Option<&T> {
+        self.verify_init("front()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { self.node(self.head).value.assume_init_ref() })
+    }
+
+    pub(crate) unsafe fn front_mut_impl(&mut self) -> Option<&mut T> {
+        self.verify_init("front_mut()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let head = self.head;
+        Some(unsafe { self.node_mut(head).value.assume_init_mut() })
+    }
+
+    pub(crate) unsafe fn back_impl(&self) -> Option<&T> {
+        self.verify_init("back()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { self.node(self.tail).value.assume_init_ref() })
+    }
+
+    pub(crate) unsafe fn back_mut_impl(&mut self) -> Option<&mut T> {
+        self.verify_init("back_mut()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let tail = self.tail;
+        Some(unsafe { self.node_mut(tail).value.assume_init_mut() })
+    }
+
+    pub(crate) unsafe fn retain_impl<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        self.verify_init("retain()");
+
+        let mut index = self.head;
+        while index != INVALID_INDEX {
+            let next = self.node(index).next;
+            if !predicate(unsafe { self.node(index).value.assume_init_ref() }) {
+                drop(self.unlink(index));
+            }
+            index = next;
+        }
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        while !self.is_empty() {
+            drop(self.unlink(self.tail));
+        }
+
+        self.free_head = INVALID_INDEX;
+        self.next_unused = 0;
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> ListIter<'_, T> {
+        self.verify_init("iter()");
+
+        ListIter {
+            nodes: self.nodes.as_ptr(),
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<T, Ptr: PointerFamily> Drop for MetaList<T, Ptr> {
+    fn drop(&mut self) {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+impl<T> List<T> {
+    /// Creates a new runtime-fixed size [`List`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            nodes: OwningPointer::<Node<T>>::new_with_alloc(capacity),
+            head: INVALID_INDEX,
+            tail: INVALID_INDEX,
+            free_head: INVALID_INDEX,
+            next_unused: 0,
+            capacity,
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    /// Adds an element at the front of the [`List`]. Returns false when the [`List`] is
+    /// full, otherwise true.
+    pub fn push_front(&mut self, value: T) -> bool {
+        unsafe { self.push_front_impl(value) }
+    }
+
+    /// Adds an element at the back of the [`List`]. Returns false when the [`List`] is
+    /// full, otherwise true.
+    pub fn push_back(&mut self, value: T) -> bool {
+        unsafe { self.push_back_impl(value) }
+    }
+
+    /// Removes the first element of the [`List`] and returns it. If the [`List`] is empty,
+    /// [`None`] is returned.
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.pop_front_impl() }
+    }
+
+    /// Removes the last element of the [`List`] and returns it. If the [`List`] is empty,
+    /// [`None`] is returned.
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.pop_back_impl() }
+    }
+
+    /// Returns a reference to the first element. If the [`List`] is empty, [`None`] is
+    /// returned.
+    pub fn front(&self) -> Option<&T> {
+        unsafe { self.front_impl() }
+    }
+
+    /// Returns a mutable reference to the first element. If the [`List`] is empty, [`None`]
+    /// is returned.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.front_mut_impl() }
+    }
+
+    /// Returns a reference to the last element. If the [`List`] is empty, [`None`] is
+    /// returned.
+    pub fn back(&self) -> Option<&T> {
+        unsafe { self.back_impl() }
+    }
+
+    /// Returns a mutable reference to the last element. If the [`List`] is empty, [`None`]
+    /// is returned.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.back_mut_impl() }
+    }
+
+    /// Removes all elements for which the predicate returns false while preserving the
+    /// order of the remaining elements.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        unsafe { self.retain_impl(predicate) }
+    }
+
+    /// Removes all elements from the [`List`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an iterator over all elements from front to back.
+    pub fn iter(&self) -> ListIter<'_, T> {
+        unsafe { self.iter_impl() }
+    }
+}
+
+impl<T> RelocatableContainer for RelocatableList<T> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            nodes: unsafe { RelocatablePointer::new_uninit() },
+            head: INVALID_INDEX,
+            tail: INVALID_INDEX,
+            free_head: INVALID_INDEX,
+            next_unused: 0,
+            capacity,
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    unsafe fn init<Allocator: Allocate<NonNull<u8>>>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableList::init()",
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        let memory = fail!(from "RelocatableList::init()",
+            when allocator.allocate(unsafe {
+                Layout::from_size_align_unchecked(
+                    core::mem::size_of::<Node<T>>() * self.capacity,
+                    core::mem::align_of::<Node<T>>(),
+                )
+            }),
+            "Failed to initialize RelocatableList since the allocation of the data memory failed.");
+
+        unsafe { self.nodes.init(memory) };
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableList<T> {}
+
+impl<T> RelocatableList<T> {
+    /// Returns how much memory the [`RelocatableList`] will allocate from the allocator
+    /// in [`RelocatableList::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        unaligned_mem_size::<Node<T>>(capacity)
+    }
+
+    /// Adds an element at the front of the list. Returns false when the list is full,
+    /// otherwise true.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn push_front(&mut self, value: T) -> bool {
+        unsafe { self.push_front_impl(value) }
+    }
+
+    /// Adds an element at the back of the list. Returns false when the list is full,
+    /// otherwise true.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn push_back(&mut self, value: T) -> bool {
+        unsafe { self.push_back_impl(value) }
+    }
+
+    /// Removes the first element of the list and returns it. If the list is empty, [`None`]
+    /// is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.pop_front_impl() }
+    }
+
+    /// Removes the last element of the list and returns it. If the list is empty, [`None`]
+    /// is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.pop_back_impl() }
+    }
+
+    /// Returns a reference to the first element. If the list is empty, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn front(&self) -> Option<&T> {
+        unsafe { self.front_impl() }
+    }
+
+    /// Returns a mutable reference to the first element. If the list is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.front_mut_impl() }
+    }
+
+    /// Returns a reference to the last element. If the list is empty, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn back(&self) -> Option<&T> {
+        unsafe { self.back_impl() }
+    }
+
+    /// Returns a mutable reference to the last element. If the list is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.back_mut_impl() }
+    }
+
+    /// Removes all elements for which the predicate returns false while preserving the
+    /// order of the remaining elements.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        unsafe { self.retain_impl(predicate) }
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an iterator over all elements from front to back.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableList::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> ListIter<'_, T> {
+        unsafe { self.iter_impl() }
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`StaticList`].
+#[repr(C)]
+pub struct StaticList<T, const CAPACITY: usize> {
+    list: RelocatableList<T>,
+    _data: [MaybeUninit<Node<T>>; CAPACITY],
+}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticList<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> PlacementDefault for StaticList<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let list_ptr = core::ptr::addr_of_mut!((*ptr).list);
+            list_ptr.write(RelocatableList::new_uninit(CAPACITY));
+
+            // SAFETY: Creating a pointer to an existing member is always not null
+            let data_ptr =
+                core::ptr::NonNull::<u8>::new_unchecked((*ptr)._data.as_mut_ptr().cast());
+
+            let allocator =
+                BumpAllocator::new(data_ptr, size_of::<[MaybeUninit<Node<T>>; CAPACITY]>());
+            (*ptr)
+                .list
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for StaticList<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for StaticList<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticList<{}, {}> {{ {:?} }}",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.list
+        )
+    }
+}
+
+impl<T, const CAPACITY: usize> StaticList<T, CAPACITY> {
+    /// Creates a new [`StaticList`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            list: unsafe { RelocatableList::new_uninit(CAPACITY) },
+            _data: [const { MaybeUninit::uninit() }; CAPACITY],
+        };
+
+        // SAFETY: Creating a pointer to an existing member is always not null
+        let data_ptr =
+            unsafe { core::ptr::NonNull::<u8>::new_unchecked(new_self._data.as_mut_ptr().cast()) };
+
+        let allocator =
+            BumpAllocator::new(data_ptr, core::mem::size_of_val(new_self._data.as_ref()));
+        unsafe {
+            new_self
+                .list
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Returns the maximum number of elements the [`StaticList`] can hold.
+    pub fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    /// Returns the number of stored elements.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the [`StaticList`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if the [`StaticList`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.list.is_full()
+    }
+
+    /// Adds an element at the front of the [`StaticList`]. Returns false when the
+    /// [`StaticList`] is full, otherwise true.
+    pub fn push_front(&mut self, value: T) -> bool {
+        unsafe { self.list.push_front_impl(value) }
+    }
+
+    /// Adds an element at the back of the [`StaticList`]. Returns false when the
+    /// [`StaticList`] is full, otherwise true.
+    pub fn push_back(&mut self, value: T) -> bool {
+        unsafe { self.list.push_back_impl(value) }
+    }
+
+    /// Removes the first element of the [`StaticList`] and returns it. If the
+    /// [`StaticList`] is empty, [`None`] is returned.
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.list.pop_front_impl() }
+    }
+
+    /// Removes the last element of the [`StaticList`] and returns it. If the [`StaticList`]
+    /// is empty, [`None`] is returned.
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.list.pop_back_impl() }
+    }
+
+    /// Returns a reference to the first element. If the [`StaticList`] is empty, [`None`]
+    /// is returned.
+    pub fn front(&self) -> Option<&T> {
+        unsafe { self.list.front_impl() }
+    }
+
+    /// Returns a mutable reference to the first element. If the [`StaticList`] is empty,
+    /// [`None`] is returned.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.list.front_mut_impl() }
+    }
+
+    /// Returns a reference to the last element. If the [`StaticList`] is empty, [`None`]
+    /// is returned.
+    pub fn back(&self) -> Option<&T> {
+        unsafe { self.list.back_impl() }
+    }
+
+    /// Returns a mutable reference to the last element. If the [`StaticList`] is empty,
+    /// [`None`] is returned.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.list.back_mut_impl() }
+    }
+
+    /// Removes all elements for which the predicate returns false while preserving the
+    /// order of the remaining elements.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, predicate: F) {
+        unsafe { self.list.retain_impl(predicate) }
+    }
+
+    /// Removes all elements from the [`StaticList`].
+    pub fn clear(&mut self) {
+        unsafe { self.list.clear_impl() }
+    }
+
+    /// Returns an iterator over all elements from front to back.
+    pub fn iter(&self) -> ListIter<'_, T> {
+        unsafe { self.list.iter_impl() }
+    }
+}
@@ -0,0 +1,716 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An OrderedMap is a data structure to store key-value pairs sorted by their key. The
+//! key-value pairs are stored contiguously in ascending key order, a lookup is a binary
+//! search and insertion or removal moves the succeeding elements. Multiple variations of that
+//! container are available.
+//!
+//!  * [`StaticOrderedMap`](crate::ordered_map::StaticOrderedMap), compile-time fixed-size
+//!    ordered map that is self-contained and shared-memory compatible.
+//!  * [`RelocatableOrderedMap`](crate::ordered_map::RelocatableOrderedMap), run-time
+//!    fixed-size ordered map that is shared-memory compatible.
+//!  * [`OrderedMap`](crate::ordered_map::OrderedMap), run-time fixed-size ordered map that is
+//!    not shared-memory compatible since the memory resides in the heap.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::ordered_map::StaticOrderedMap;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut map = StaticOrderedMap::<u64, u8, CAPACITY>::new();
+//! assert_eq!(map.insert(42, 1).is_ok(), true);
+//! assert_eq!(map.insert(23, 2).is_ok(), true);
+//!
+//! let keys: Vec<u64> = map.iter().map(|(key, _)| *key).collect();
+//! assert_eq!(keys, [23, 42]);
+//! ```
+
+use core::alloc::Layout;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::{GenericOwningPointer, OwningPointer};
+use iceoryx2_bb_elementary::relocatable_pointer::{GenericRelocatablePointer, RelocatablePointer};
+use iceoryx2_bb_elementary_traits::allocator::{Allocate, AllocationError};
+use iceoryx2_bb_elementary_traits::pointer::Pointer;
+use iceoryx2_bb_elementary_traits::pointer_family::PointerFamily;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum OrderedMapError {
+    /// The OrderedMap already contains the key that shall be inserted.
+    KeyAlreadyExists,
+    /// The OrderedMap is full and cannot hold an additional key-value pair.
+    IsFull,
+}
+
+impl core::fmt::Display for OrderedMapError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "OrderedMapError::{self:?}")
+    }
+}
+
+impl core::error::Error for OrderedMapError {}
+
+#[repr(C)]
+pub(crate) struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`OrderedMap`]. The [`OrderedMap`]s
+/// memory resides in the heap.
+pub type OrderedMap<K, V> = MetaOrderedMap<K, V, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableOrderedMap`].
+pub type RelocatableOrderedMap<K, V> = MetaOrderedMap<K, V, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaOrderedMap<K: Ord, V, Ptr: PointerFamily> {
+    entries: Ptr::Pointer<MaybeUninit<Entry<K, V>>>,
+    capacity: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+    _phantom_data: PhantomData<(K, V)>,
+}
+
+unsafe impl<K: Ord + Send, V: Send, Ptr: PointerFamily> Send for MetaOrderedMap<K, V, Ptr> {}
+
+impl<K: Ord + Debug, V: Debug, Ptr: PointerFamily> Debug for MetaOrderedMap<K, V, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaOrderedMap<{}, {}, {}> {{ len: {}, capacity: {}, is_initialized: {} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            core::any::type_name::<Ptr>(),
+            self.len,
+            self.capacity,
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+/// Iterator over all key-value pairs of an ordered map in ascending key order.
+pub struct OrderedMapIter<'a, K, V> {
+    entries: *const MaybeUninit<Entry<K, V>>,
+    front: usize,
+    back: usize,
+    _lifetime: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V> Iterator for OrderedMapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let entry = unsafe { (*self.entries.add(self.front)).assume_init_ref() };
+        self.front += 1;
+        Some((&entry.key, &entry.value))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for OrderedMapIter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        let entry = unsafe { (*self.entries.add(self.back)).assume_init_ref() };
+        Some((&entry.key, &entry.value))
+    }
+}
+
+impl<K: Ord, V, Ptr: PointerFamily> MetaOrderedMap<K, V, Ptr> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaOrderedMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            source
+        );
+    }
+
+    /// Returns the maximum number of key-value pairs the map can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the map is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    fn entry(&self, index: usize) -> &Entry<K, V> {
+        unsafe { (*self.entries.as_ptr().add(index)).assume_init_ref() }
+    }
+
+    fn entry_mut(&mut self, index: usize) -> &mut Entry<K, V> {
+        unsafe { (*self.entries.as_mut_ptr().add(index)).assume_init_mut() }
+    }
+
+    // Returns Ok(index) when the key is stored at index, otherwise Err(index) with the
+    // position where the key would have to be inserted.
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        let mut low = 0;
+        let mut high = self.len;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.entry(mid).key.cmp(key) {
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+                core::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    pub(crate) unsafe fn insert_impl(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        self.verify_init("insert()");
+
+        let msg = "Unable to insert key-value pair into OrderedMap";
+        let origin = "MetaOrderedMap::insert_impl()";
+
+        let index = match self.search(&key) {
+            Ok(_) => {
+                fail!(from origin, with OrderedMapError::KeyAlreadyExists,
+                    "{msg} since the passed key already exists.");
+            }
+            Err(index) => index,
+        };
+
+        if self.is_full() {
+            fail!(from origin, with OrderedMapError::IsFull,
+                "{msg} since the OrderedMap is full.");
+        }
+
+        unsafe {
+            let position = self.entries.as_mut_ptr().add(index);
+            core::ptr::copy(position, position.add(1), self.len - index);
+            position.write(MaybeUninit::new(Entry { key, value }));
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub(crate) unsafe fn get_ref_impl(&self, key: &K) -> Option<&V> {
+        self.verify_init("get_ref()");
+
+        let index = self.search(key).ok()?;
+        Some(&self.entry(index).value)
+    }
+
+    pub(crate) unsafe fn get_mut_ref_impl(&mut self, key: &K) -> Option<&mut V> {
+        self.verify_init("get_mut_ref()");
+
+        let index = self.search(key).ok()?;
+        Some(&mut self.entry_mut(index).value)
+    }
+
+    pub(crate) unsafe fn contains_impl(&self, key: &K) -> bool {
+        self.verify_init("contains()");
+        self.search(key).is_ok()
+    }
+
+    pub(crate) unsafe fn remove_impl(&mut self, key: &K) -> Option<V> {
+        self.verify_init("remove()");
+
+        let index = self.search(key).ok()?;
+        let entry = unsafe {
+            let position = self.entries.as_mut_ptr().add(index);
+            let entry = position.read().assume_init();
+            core::ptr::copy(position.add(1), position, self.len - index - 1);
+            entry
+        };
+        self.len -= 1;
+
+        Some(entry.value)
+    }
+
+    pub(crate) unsafe fn first_impl(&self) -> Option<(&K, &V)> {
+        self.verify_init("first()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = self.entry(0);
+        Some((&entry.key, &entry.value))
+    }
+
+    pub(crate) unsafe fn last_impl(&self) -> Option<(&K, &V)> {
+        self.verify_init("last()");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = self.entry(self.len - 1);
+        Some((&entry.key, &entry.value))
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        let len = self.len;
+        self.len = 0;
+        for n in 0..len {
+            unsafe { (*self.entries.as_mut_ptr().add(n)).assume_init_drop() };
+        }
+    }
+
+    pub(crate) unsafe fn iter_impl(&self) -> OrderedMapIter<'_, K, V> {
+        self.verify_init("iter()");
+
+        OrderedMapIter {
+            entries: self.entries.as_ptr(),
+            front: 0,
+            back: self.len,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<K: Ord, V, Ptr: PointerFamily> Drop for MetaOrderedMap<K, V, Ptr> {
+    fn drop(&mut self) {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> {
+    /// Creates a new runtime-fixed size [`OrderedMap`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: OwningPointer::<MaybeUninit<Entry<K, V>>>::new_with_alloc(capacity),
+            capacity,
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    /// Inserts a new key-value pair into the [`OrderedMap`]. On success, the method returns
+    /// [`Ok`], otherwise an [`OrderedMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`OrderedMap`], returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Returns true if the [`OrderedMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.contains_impl(key) }
+    }
+
+    /// Returns the key-value pair with the smallest key. If the [`OrderedMap`] is empty,
+    /// [`None`] is returned.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        unsafe { self.first_impl() }
+    }
+
+    /// Returns the key-value pair with the largest key. If the [`OrderedMap`] is empty,
+    /// [`None`] is returned.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        unsafe { self.last_impl() }
+    }
+
+    /// Removes all key-value pairs from the [`OrderedMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in ascending key order.
+    pub fn iter(&self) -> OrderedMapIter<'_, K, V> {
+        unsafe { self.iter_impl() }
+    }
+}
+
+impl<K: Ord, V> RelocatableContainer for RelocatableOrderedMap<K, V> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            entries: unsafe { RelocatablePointer::new_uninit() },
+            capacity,
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    unsafe fn init<Allocator: Allocate<NonNull<u8>>>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableOrderedMap::init()",
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        let memory = fail!(from "RelocatableOrderedMap::init()",
+            when allocator.allocate(unsafe {
+                Layout::from_size_align_unchecked(
+                    core::mem::size_of::<Entry<K, V>>() * self.capacity,
+                    core::mem::align_of::<Entry<K, V>>(),
+                )
+            }),
+            "Failed to initialize RelocatableOrderedMap since the allocation of the data memory failed.");
+
+        unsafe { self.entries.init(memory) };
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<K: Ord + ZeroCopySend, V: ZeroCopySend> ZeroCopySend for RelocatableOrderedMap<K, V> {}
+
+impl<K: Ord, V> RelocatableOrderedMap<K, V> {
+    /// Returns how much memory the [`RelocatableOrderedMap`] will allocate from the allocator
+    /// in [`RelocatableOrderedMap::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        unaligned_mem_size::<Entry<K, V>>(capacity)
+    }
+
+    /// Inserts a new key-value pair into the map. On success, the method returns [`Ok`],
+    /// otherwise an [`OrderedMapError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn get(&self, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsafe { self.get_ref_impl(key).cloned() }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the map, returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Returns true if the map contains the given key, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, key: &K) -> bool {
+        unsafe { self.contains_impl(key) }
+    }
+
+    /// Returns the key-value pair with the smallest key. If the map is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn first(&self) -> Option<(&K, &V)> {
+        unsafe { self.first_impl() }
+    }
+
+    /// Returns the key-value pair with the largest key. If the map is empty, [`None`] is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn last(&self) -> Option<(&K, &V)> {
+        unsafe { self.last_impl() }
+    }
+
+    /// Removes all key-value pairs from the map.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in ascending key order.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableOrderedMap::init()`] must be called once before
+    ///
+    pub unsafe fn iter(&self) -> OrderedMapIter<'_, K, V> {
+        unsafe { self.iter_impl() }
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`StaticOrderedMap`].
+#[repr(C)]
+pub struct StaticOrderedMap<K: Ord, V, const CAPACITY: usize> {
+    map: RelocatableOrderedMap<K, V>,
+    _data: [MaybeUninit<Entry<K, V>>; CAPACITY],
+}
+
+unsafe impl<K: Ord + ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for StaticOrderedMap<K, V, CAPACITY>
+{
+}
+
+impl<K: Ord, V, const CAPACITY: usize> PlacementDefault for StaticOrderedMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+            map_ptr.write(RelocatableOrderedMap::new_uninit(CAPACITY));
+
+            // SAFETY: Creating a pointer to an existing member is always not null
+            let data_ptr =
+                core::ptr::NonNull::<u8>::new_unchecked((*ptr)._data.as_mut_ptr().cast());
+
+            let allocator =
+                BumpAllocator::new(data_ptr, size_of::<[MaybeUninit<Entry<K, V>>; CAPACITY]>());
+            (*ptr)
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> Default for StaticOrderedMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Debug, V: Debug, const CAPACITY: usize> Debug for StaticOrderedMap<K, V, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticOrderedMap<{}, {}, {}> {{ {:?} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> StaticOrderedMap<K, V, CAPACITY> {
+    /// Creates a new [`StaticOrderedMap`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            map: unsafe { RelocatableOrderedMap::new_uninit(CAPACITY) },
+            _data: [const { MaybeUninit::uninit() }; CAPACITY],
+        };
+
+        // SAFETY: Creating a pointer to an existing member is always not null
+        let data_ptr =
+            unsafe { core::ptr::NonNull::<u8>::new_unchecked(new_self._data.as_mut_ptr().cast()) };
+
+        let allocator =
+            BumpAllocator::new(data_ptr, core::mem::size_of_val(new_self._data.as_ref()));
+        unsafe {
+            new_self
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Returns the maximum number of key-value pairs the [`StaticOrderedMap`] can hold.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the [`StaticOrderedMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`StaticOrderedMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Inserts a new key-value pair into the [`StaticOrderedMap`]. On success, the method
+    /// returns [`Ok`], otherwise an [`OrderedMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), OrderedMapError> {
+        unsafe { self.map.insert_impl(key, value) }
+    }
+
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsafe { self.map.get_ref_impl(key).cloned() }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.map.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.map.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`StaticOrderedMap`], returning the Some(value) at the key if
+    /// the key was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.map.remove_impl(key) }
+    }
+
+    /// Returns true if the [`StaticOrderedMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.map.contains_impl(key) }
+    }
+
+    /// Returns the key-value pair with the smallest key. If the [`StaticOrderedMap`] is
+    /// empty, [`None`] is returned.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        unsafe { self.map.first_impl() }
+    }
+
+    /// Returns the key-value pair with the largest key. If the [`StaticOrderedMap`] is
+    /// empty, [`None`] is returned.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        unsafe { self.map.last_impl() }
+    }
+
+    /// Removes all key-value pairs from the [`StaticOrderedMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.map.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in ascending key order.
+    pub fn iter(&self) -> OrderedMapIter<'_, K, V> {
+        unsafe { self.map.iter_impl() }
+    }
+}
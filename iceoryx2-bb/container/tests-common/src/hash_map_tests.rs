@@ -0,0 +1,231 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::hash_map::*;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 100;
+
+#[test]
+pub fn new_creates_empty_hash_map() {
+    let sut = HashMap::<u64, u32>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+
+    let sut = StaticHashMap::<u64, u32, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = StaticHashMap<u64, u64, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.insert(4, 6), is_ok);
+    assert_that!(sut.get(&4), eq Some(6));
+}
+
+#[test]
+pub fn insert_until_full_works() {
+    let mut sut = StaticHashMap::<u64, u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.insert(n * 7919, n), is_ok);
+        assert_that!(sut, len n as usize + 1);
+    }
+
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(1, 1).err(), eq Some(HashMapError::IsFull));
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.get(&(n * 7919)), eq Some(n));
+    }
+}
+
+#[test]
+pub fn full_hash_map_keeps_capacity_and_finds_all_keys() {
+    for capacity in 0..18 {
+        let mut sut = HashMap::<u64, u64>::new(capacity);
+        for n in 0..capacity as u64 {
+            assert_that!(sut.insert(n, n * 3), is_ok);
+        }
+
+        assert_that!(sut.capacity(), eq capacity);
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(capacity as u64, 0).err(), eq Some(HashMapError::IsFull));
+        assert_that!(sut.iter().count(), eq capacity);
+
+        for n in 0..capacity as u64 {
+            assert_that!(sut.get(&n), eq Some(n * 3));
+        }
+        assert_that!(sut.get(&(capacity as u64)), eq None);
+    }
+}
+
+#[test]
+pub fn insert_existing_key_fails() {
+    let mut sut = HashMap::<u64, u64>::new(CAPACITY);
+
+    assert_that!(sut.insert(12, 1), is_ok);
+    assert_that!(sut.insert(12, 2).err(), eq Some(HashMapError::KeyAlreadyExists));
+    assert_that!(sut.get(&12), eq Some(1));
+    assert_that!(sut, len 1);
+}
+
+#[test]
+pub fn get_of_non_existing_key_returns_none() {
+    let mut sut = StaticHashMap::<u64, u64, CAPACITY>::new();
+    assert_that!(sut.get(&3), eq None);
+
+    assert_that!(sut.insert(4, 5), is_ok);
+    assert_that!(sut.get(&3), eq None);
+    assert_that!(sut.get_ref(&3), eq None);
+    assert_that!(sut.get_mut_ref(&3), eq None);
+    assert_that!(sut.contains(&3), eq false);
+    assert_that!(sut.contains(&4), eq true);
+}
+
+#[test]
+pub fn get_mut_ref_modifies_value() {
+    let mut sut = StaticHashMap::<u64, u64, CAPACITY>::new();
+    assert_that!(sut.insert(4, 5), is_ok);
+
+    *sut.get_mut_ref(&4).unwrap() = 99;
+    assert_that!(sut.get_ref(&4), eq Some(&99));
+}
+
+#[test]
+pub fn remove_keeps_remaining_keys_accessible() {
+    let mut sut = StaticHashMap::<u64, u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.insert(n, n + 1), is_ok);
+    }
+
+    for n in (0..CAPACITY as u64).step_by(2) {
+        assert_that!(sut.remove(&n), eq Some(n + 1));
+        assert_that!(sut.remove(&n), eq None);
+    }
+    assert_that!(sut, len CAPACITY / 2);
+
+    for n in 0..CAPACITY as u64 {
+        if n % 2 == 0 {
+            assert_that!(sut.contains(&n), eq false);
+        } else {
+            assert_that!(sut.get(&n), eq Some(n + 1));
+        }
+    }
+
+    for n in (0..CAPACITY as u64).step_by(2) {
+        assert_that!(sut.insert(n, n), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+}
+
+#[test]
+pub fn iter_and_list_keys_contain_all_elements() {
+    let mut sut = HashMap::<u64, u64>::new(CAPACITY);
+
+    for n in 0..CAPACITY as u64 / 2 {
+        assert_that!(sut.insert(n, 2 * n), is_ok);
+    }
+
+    let mut pairs: Vec<(u64, u64)> = sut.iter().map(|(k, v)| (*k, *v)).collect();
+    pairs.sort();
+    assert_that!(pairs, len CAPACITY / 2);
+    for (n, (key, value)) in pairs.iter().enumerate() {
+        assert_that!(*key, eq n as u64);
+        assert_that!(*value, eq 2 * n as u64);
+    }
+
+    let mut keys = Vec::new();
+    sut.list_keys(|key| {
+        keys.push(*key);
+        CallbackProgression::Continue
+    });
+    assert_that!(keys, len CAPACITY / 2);
+}
+
+#[test]
+pub fn clear_removes_all_elements() {
+    let mut sut = StaticHashMap::<u64, u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.insert(n, n), is_ok);
+    }
+
+    sut.clear();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.iter().count(), eq 0);
+    assert_that!(sut.insert(5, 5), is_ok);
+}
+
+#[test]
+pub fn drop_called_for_values() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticHashMap::<u64, LifetimeTracker, CAPACITY>::new();
+
+    for n in 0..CAPACITY {
+        assert_that!(
+            sut.insert(n as u64, LifetimeTracker::new_with_value(n)),
+            is_ok
+        );
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(sut.remove(&3));
+    assert_that!(state.number_of_living_instances(), eq CAPACITY - 1);
+
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn relocatable_hash_map_works_with_bump_allocator() {
+    const MEM_SIZE: usize = RelocatableHashMap::<u64, u64>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE + 64];
+    let allocator = BumpAllocator::new(
+        core::ptr::NonNull::new(memory.as_mut_ptr()).unwrap(),
+        memory.len(),
+    );
+
+    let mut sut = unsafe { RelocatableHashMap::<u64, u64>::new_uninit(CAPACITY) };
+    assert_that!(unsafe { sut.init(&allocator) }, is_ok);
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.insert(n, n) }, is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(unsafe { sut.get(&42) }, eq Some(42));
+}
+
+#[test]
+pub fn deterministic_hasher_produces_the_same_hash_for_the_same_value() {
+    assert_that!(DeterministicHasher::hash(&1234u64), eq DeterministicHasher::hash(&1234u64));
+    assert_that!(DeterministicHasher::hash(&1234u64), ne DeterministicHasher::hash(&1235u64));
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::hash_set::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 50;
+
+#[test]
+pub fn new_creates_empty_hash_set() {
+    let sut = HashSet::<u64>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+
+    let sut = StaticHashSet::<u64, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn insert_returns_whether_the_key_was_new() {
+    let mut sut = StaticHashSet::<u64, CAPACITY>::new();
+
+    assert_that!(sut.insert(7), eq Ok(true));
+    assert_that!(sut.insert(7), eq Ok(false));
+    assert_that!(sut, len 1);
+    assert_that!(sut.contains(&7), eq true);
+    assert_that!(sut.contains(&8), eq false);
+}
+
+#[test]
+pub fn insert_into_full_set_fails() {
+    let mut sut = HashSet::<u64>::new(CAPACITY);
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.insert(n), eq Ok(true));
+    }
+
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(CAPACITY as u64), eq Err(HashSetError::IsFull));
+    assert_that!(sut.insert(0), eq Ok(false));
+}
+
+#[test]
+pub fn remove_and_clear_work() {
+    let mut sut = StaticHashSet::<u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.insert(n), eq Ok(true));
+    }
+
+    assert_that!(sut.remove(&3), eq true);
+    assert_that!(sut.remove(&3), eq false);
+    assert_that!(sut.contains(&3), eq false);
+    assert_that!(sut.iter().count(), eq CAPACITY - 1);
+
+    sut.clear();
+    assert_that!(sut, is_empty);
+}
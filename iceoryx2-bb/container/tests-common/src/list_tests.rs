@@ -0,0 +1,144 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::list::*;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 32;
+
+#[test]
+pub fn new_creates_empty_list() {
+    let sut = List::<u64>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.front(), eq None);
+    assert_that!(sut.back(), eq None);
+
+    let sut = StaticList::<u64, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = StaticList<u64, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.push_back(4), eq true);
+    assert_that!(sut.front(), eq Some(&4));
+}
+
+#[test]
+pub fn push_and_pop_on_both_ends_work() {
+    let mut sut = StaticList::<u64, CAPACITY>::new();
+
+    assert_that!(sut.push_back(2), eq true);
+    assert_that!(sut.push_front(1), eq true);
+    assert_that!(sut.push_back(3), eq true);
+    assert_that!(sut.front(), eq Some(&1));
+    assert_that!(sut.back(), eq Some(&3));
+
+    let elements: Vec<u64> = sut.iter().copied().collect();
+    assert_that!(elements, eq alloc::vec![1, 2, 3]);
+    let elements: Vec<u64> = sut.iter().rev().copied().collect();
+    assert_that!(elements, eq alloc::vec![3, 2, 1]);
+
+    assert_that!(sut.pop_front(), eq Some(1));
+    assert_that!(sut.pop_back(), eq Some(3));
+    assert_that!(sut.pop_back(), eq Some(2));
+    assert_that!(sut.pop_back(), eq None);
+    assert_that!(sut.pop_front(), eq None);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn push_into_full_list_fails_and_released_nodes_are_reused() {
+    let mut sut = List::<u64>::new(CAPACITY);
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.push_back(n), eq true);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.push_back(99), eq false);
+    assert_that!(sut.push_front(99), eq false);
+
+    for _ in 0..3 {
+        for n in 0..CAPACITY as u64 / 2 {
+            assert_that!(sut.pop_front(), eq Some(n));
+        }
+        for n in 0..CAPACITY as u64 / 2 {
+            assert_that!(sut.push_front(CAPACITY as u64 / 2 - 1 - n), eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    let elements: Vec<u64> = sut.iter().copied().collect();
+    let expected: Vec<u64> = (0..CAPACITY as u64).collect();
+    assert_that!(elements, eq expected);
+}
+
+#[test]
+pub fn front_mut_and_back_mut_modify_elements() {
+    let mut sut = StaticList::<u64, CAPACITY>::new();
+    assert_that!(sut.push_back(1), eq true);
+    assert_that!(sut.push_back(2), eq true);
+
+    *sut.front_mut().unwrap() = 10;
+    *sut.back_mut().unwrap() = 20;
+    assert_that!(sut.front(), eq Some(&10));
+    assert_that!(sut.back(), eq Some(&20));
+}
+
+#[test]
+pub fn retain_removes_elements_and_preserves_order() {
+    let mut sut = StaticList::<u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.push_back(n), eq true);
+    }
+
+    sut.retain(|v| v % 3 == 0);
+    let elements: Vec<u64> = sut.iter().copied().collect();
+    let expected: Vec<u64> = (0..CAPACITY as u64).filter(|v| v % 3 == 0).collect();
+    assert_that!(elements, eq expected);
+    assert_that!(sut, len expected.len());
+}
+
+#[test]
+pub fn drop_called_for_elements() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticList::<LifetimeTracker, CAPACITY>::new();
+
+    for n in 0..CAPACITY {
+        assert_that!(sut.push_back(LifetimeTracker::new_with_value(n)), eq true);
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    sut.retain(|v| v.value % 2 == 0);
+    assert_that!(state.number_of_living_instances(), eq CAPACITY / 2);
+
+    sut.clear();
+    assert_that!(state.number_of_living_instances(), eq 0);
+
+    assert_that!(sut.push_back(LifetimeTracker::new_with_value(1)), eq true);
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::ordered_map::*;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 64;
+
+#[test]
+pub fn new_creates_empty_ordered_map() {
+    let sut = OrderedMap::<u64, u64>::new(CAPACITY);
+    assert_that!(sut, is_empty);
+    assert_that!(sut, len 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.first(), eq None);
+    assert_that!(sut.last(), eq None);
+
+    let sut = StaticOrderedMap::<u64, u64, CAPACITY>::new();
+    assert_that!(sut, is_empty);
+    assert_that!(sut.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = StaticOrderedMap<u64, u64, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.insert(4, 6), is_ok);
+    assert_that!(sut.get(&4), eq Some(6));
+}
+
+#[test]
+pub fn iteration_is_in_ascending_key_order() {
+    let mut sut = StaticOrderedMap::<u64, u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        let key = (n * 37) % CAPACITY as u64;
+        assert_that!(sut.insert(key, key + 1), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(1000, 0).err(), eq Some(OrderedMapError::IsFull));
+
+    let keys: Vec<u64> = sut.iter().map(|(k, _)| *k).collect();
+    let expected: Vec<u64> = (0..CAPACITY as u64).collect();
+    assert_that!(keys, eq expected);
+
+    let keys: Vec<u64> = sut.iter().rev().map(|(k, _)| *k).collect();
+    let expected: Vec<u64> = (0..CAPACITY as u64).rev().collect();
+    assert_that!(keys, eq expected);
+
+    assert_that!(sut.first(), eq Some((&0, &1)));
+    assert_that!(sut.last(), eq Some((&(CAPACITY as u64 - 1), &(CAPACITY as u64))));
+}
+
+#[test]
+pub fn insert_existing_key_fails() {
+    let mut sut = OrderedMap::<u64, u64>::new(CAPACITY);
+
+    assert_that!(sut.insert(3, 1), is_ok);
+    assert_that!(sut.insert(3, 2).err(), eq Some(OrderedMapError::KeyAlreadyExists));
+    assert_that!(sut.get(&3), eq Some(1));
+}
+
+#[test]
+pub fn remove_preserves_order() {
+    let mut sut = StaticOrderedMap::<u64, u64, CAPACITY>::new();
+
+    for n in 0..10 {
+        assert_that!(sut.insert(n, n), is_ok);
+    }
+
+    assert_that!(sut.remove(&5), eq Some(5));
+    assert_that!(sut.remove(&5), eq None);
+    assert_that!(sut.remove(&0), eq Some(0));
+    assert_that!(sut.remove(&9), eq Some(9));
+
+    let keys: Vec<u64> = sut.iter().map(|(k, _)| *k).collect();
+    assert_that!(keys, eq alloc::vec![1, 2, 3, 4, 6, 7, 8]);
+
+    *sut.get_mut_ref(&4).unwrap() = 44;
+    assert_that!(sut.get_ref(&4), eq Some(&44));
+    assert_that!(sut.contains(&5), eq false);
+}
+
+#[test]
+pub fn drop_called_for_values() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticOrderedMap::<u64, LifetimeTracker, CAPACITY>::new();
+
+    for n in 0..CAPACITY {
+        assert_that!(
+            sut.insert(n as u64, LifetimeTracker::new_with_value(n)),
+            is_ok
+        );
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(sut.remove(&7));
+    assert_that!(state.number_of_living_instances(), eq CAPACITY - 1);
+
+    sut.clear();
+    assert_that!(state.number_of_living_instances(), eq 0);
+
+    assert_that!(sut.insert(1, LifetimeTracker::new_with_value(1)), is_ok);
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
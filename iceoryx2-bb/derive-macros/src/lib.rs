@@ -25,7 +25,7 @@ use alloc::vec::Vec;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
+use syn::{Data, DeriveInput, Fields, LitInt, LitStr, parse_macro_input};
 
 /// Implements the [`iceoryx2_bb_elementary_traits::placement_default::PlacementDefault`] trait when all
 /// fields of the struct implement it.
@@ -153,7 +153,54 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// needs_zero_copy_send_type(&shape3);
 /// assert_eq!(unsafe { Shape::type_name() }, "GeometricShape");
 /// ```
-#[proc_macro_derive(ZeroCopySend, attributes(type_name))]
+///
+/// Structs can be versioned with the helper attribute `type_version`. Fields that were appended
+/// in a later version are annotated with `since_version` and must follow all fields of the
+/// previous versions. A versioned struct must implement [`Default`] which provides the values
+/// of the fields that are missing in a payload of an older version.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend, Default)]
+/// #[type_name("Position")]
+/// #[type_version(2)]
+/// struct Position {
+///     x: u64,
+///     y: u64,
+///     #[since_version(2)]
+///     z: u64,
+/// }
+///
+/// assert_eq!(Position::__type_version(), 2);
+/// assert_eq!(Position::__size_of_version(1), Some(16));
+/// ```
+///
+/// With the helper attribute `type_layout` the derive macro describes the memory layout of a
+/// struct field by field. The layout is stored in the type definition of a service so that
+/// processes that do not know the type can access the fields by name. Nested structs are
+/// described only when they are annotated as well, otherwise they remain opaque. The
+/// [`iceoryx2_bb_elementary_traits::type_layout::TypeLayoutVisitor`] must be in scope.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::type_layout::TypeLayoutVisitor;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[type_layout]
+/// struct Measurement {
+///     id: u32,
+///     values: [f64; 4],
+/// }
+/// ```
+#[proc_macro_derive(
+    ZeroCopySend,
+    attributes(type_name, type_version, since_version, type_layout)
+)]
 pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let struct_name = &ast.ident;
@@ -200,6 +247,9 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
         panic!("`#[derive(ZeroCopySend)]` requires the type to be annotated with #[repr(C)]");
     }
 
+    let versioning_impl = versioning_impl(&ast);
+    let layout_impl = layout_impl(&ast);
+
     // implement ZeroCopySend
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
@@ -214,12 +264,30 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                     }
                 });
 
+                let field_reports = fields_named.named.iter().map(|f| {
+                    let field_name = &f.ident;
+                    let field_type = &f.ty;
+                    quote! {
+                        callback(
+                            stringify!(#field_name),
+                            core::mem::offset_of!(Self, #field_name),
+                            <#field_type as ZeroCopySend>::__structural_fingerprint(),
+                        );
+                    }
+                });
+
                 quote! {
                     fn __is_zero_copy_send(&self) {
                         #(#field_inits)*
                     }
 
+                    fn __for_each_field(callback: &mut dyn FnMut(&'static str, usize, u32)) {
+                        #(#field_reports)*
+                    }
+
                     #type_name_impl
+                    #versioning_impl
+                    #layout_impl
                 }
             }
             Fields::Unnamed(ref fields_unnamed) => {
@@ -231,16 +299,37 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                     }
                 });
 
+                let field_reports = fields_unnamed.unnamed.iter().enumerate().map(|(i, f)| {
+                    let field_index = syn::Index::from(i);
+                    let field_name = LitStr::new(&format!("{i}"), proc_macro2::Span::call_site());
+                    let field_type = &f.ty;
+                    quote! {
+                        callback(
+                            #field_name,
+                            core::mem::offset_of!(Self, #field_index),
+                            <#field_type as ZeroCopySend>::__structural_fingerprint(),
+                        );
+                    }
+                });
+
                 quote! {
                     fn __is_zero_copy_send(&self) {
                         #(#field_inits)*
                     }
 
+                    fn __for_each_field(callback: &mut dyn FnMut(&'static str, usize, u32)) {
+                        #(#field_reports)*
+                    }
+
                     #type_name_impl
+                    #versioning_impl
+                    #layout_impl
                 }
             }
             Fields::Unit => quote! {
                 #type_name_impl
+                #versioning_impl
+                #layout_impl
             },
         },
         Data::Enum(ref data_enum) => {
@@ -310,6 +399,40 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                 }
             });
 
+            // the fields of a variant are reported as `Variant.field` with the variant index
+            let variant_reports = data_enum
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let variant_name = format!("{}", variant.ident);
+                    let field_reports = variant.fields.iter().enumerate().map(|(i, f)| {
+                        let field_name = match &f.ident {
+                            Some(ident) => format!("{variant_name}.{ident}"),
+                            None => format!("{variant_name}.{i}"),
+                        };
+                        let field_name = LitStr::new(&field_name, proc_macro2::Span::call_site());
+                        let field_type = &f.ty;
+                        quote! {
+                            callback(
+                                #field_name,
+                                #index,
+                                <#field_type as ZeroCopySend>::__structural_fingerprint(),
+                            );
+                        }
+                    });
+
+                    if variant.fields.is_empty() {
+                        let variant_name =
+                            LitStr::new(&variant_name, proc_macro2::Span::call_site());
+                        quote! {
+                            callback(#variant_name, #index, 0);
+                        }
+                    } else {
+                        quote! { #(#field_reports)* }
+                    }
+                });
+
             quote! {
                 fn __is_zero_copy_send(&self) {
                     match self {
@@ -317,6 +440,10 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                fn __for_each_field(callback: &mut dyn FnMut(&'static str, usize, u32)) {
+                    #(#variant_reports)*
+                }
+
                 #type_name_impl
             }
         }
@@ -329,11 +456,28 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                 }
             });
 
+            // all fields of a union start at offset 0
+            let field_reports = data_union.fields.named.iter().map(|f| {
+                let field_name = &f.ident;
+                let field_type = &f.ty;
+                quote! {
+                    callback(
+                        stringify!(#field_name),
+                        0,
+                        <#field_type as ZeroCopySend>::__structural_fingerprint(),
+                    );
+                }
+            });
+
             quote! {
                 fn __is_zero_copy_send(&self) {
                     #(#field_inits)*
                 }
 
+                fn __for_each_field(callback: &mut dyn FnMut(&'static str, usize, u32)) {
+                    #(#field_reports)*
+                }
+
                 #type_name_impl
             }
         }
@@ -348,6 +492,167 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+// generates the layout description of ZeroCopySend for structs annotated with `type_layout`
+fn layout_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    if !ast.attrs.iter().any(|a| a.path().is_ident("type_layout")) {
+        return quote! {};
+    }
+
+    let fields = match &ast.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        _ => panic!("`#[type_layout]` is only supported for structs"),
+    };
+
+    let field_descriptions = fields.iter().enumerate().map(|(i, f)| {
+        let field_type = &f.ty;
+        let (field_name, member) = match &f.ident {
+            Some(ident) => (
+                LitStr::new(&format!("{ident}"), proc_macro2::Span::call_site()),
+                syn::Member::Named(ident.clone()),
+            ),
+            None => (
+                LitStr::new(&format!("{i}"), proc_macro2::Span::call_site()),
+                syn::Member::Unnamed(syn::Index::from(i)),
+            ),
+        };
+        quote! {
+            visitor.field(#field_name, core::mem::offset_of!(Self, #member));
+            <#field_type as ZeroCopySend>::__describe_layout(visitor);
+        }
+    });
+
+    quote! {
+        fn __describe_layout(visitor: &mut dyn TypeLayoutVisitor) {
+            visitor.begin_struct(
+                unsafe { <Self as ZeroCopySend>::type_name() },
+                core::mem::size_of::<Self>(),
+                core::mem::align_of::<Self>(),
+            );
+            #(#field_descriptions)*
+            visitor.end_struct();
+        }
+    }
+}
+
+// generates the version hooks of ZeroCopySend for structs annotated with `type_version`
+fn versioning_impl(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    let parse_version = |attribute: &syn::Attribute, name: &str| -> u32 {
+        attribute
+            .parse_args::<LitInt>()
+            .and_then(|v| v.base10_parse::<u32>())
+            .unwrap_or_else(|_| {
+                panic!("Wrong format for ZeroCopySend attribute. Please provide exactly one positive integer as \"{name}\".")
+            })
+    };
+
+    let attributes: Vec<_> = ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("type_version"))
+        .collect();
+    if attributes.len() > 1 {
+        panic!("Too many \"type_version\" attributes provided for ZeroCopySend trait.");
+    }
+
+    let fields = match &ast.data {
+        Data::Struct(data_struct) => Some(&data_struct.fields),
+        _ => None,
+    };
+
+    let since_versions: Vec<_> = fields
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(i, f)| {
+            let member = match &f.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(i)),
+            };
+            let since = f
+                .attrs
+                .iter()
+                .find(|a| a.path().is_ident("since_version"))
+                .map(|a| parse_version(a, "since_version"));
+            (member, since)
+        })
+        .collect();
+
+    let version = match attributes.first() {
+        Some(attribute) => parse_version(attribute, "type_version"),
+        None => {
+            if since_versions.iter().any(|(_, since)| since.is_some()) {
+                panic!(
+                    "`#[since_version(..)]` requires the type to be annotated with #[type_version(..)]"
+                );
+            }
+            return quote! {};
+        }
+    };
+
+    if fields.is_none() {
+        panic!("`#[type_version(..)]` is only supported for structs");
+    }
+
+    if version == 0 {
+        panic!("`#[type_version(..)]` must be at least 1");
+    }
+
+    // appended fields must follow all fields of previous versions
+    let mut previous_since = 1;
+    let mut appended_fields = Vec::new();
+    for (member, since) in since_versions {
+        let since = since.unwrap_or(1);
+        if since == 0 || since > version {
+            panic!(
+                "`#[since_version({since})]` must be in the range of 1 to the type version {version}"
+            );
+        }
+        if since < previous_since {
+            panic!(
+                "Fields with `#[since_version(..)]` must be ordered by version and appended after the fields of the previous versions"
+            );
+        }
+        previous_since = since;
+
+        if since > 1 {
+            appended_fields.push(quote! {
+                if #since > version {
+                    return Some(core::mem::offset_of!(Self, #member));
+                }
+            });
+        }
+    }
+
+    quote! {
+        fn __type_version() -> u32 {
+            #version
+        }
+
+        fn __size_of_version(version: u32) -> Option<usize> {
+            if version == 0 || version > #version {
+                return None;
+            }
+
+            #(#appended_fields)*
+
+            Some(core::mem::size_of::<Self>())
+        }
+
+        unsafe fn __upgrade_from_version(version: u32, source: *const u8, target: *mut Self) -> bool {
+            match <Self as ZeroCopySend>::__size_of_version(version) {
+                Some(size) => {
+                    unsafe {
+                        target.write(<Self as Default>::default());
+                        core::ptr::copy_nonoverlapping(source, target.cast::<u8>(), size);
+                    }
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+}
+
 /// Implements the [`iceoryx2_bb_elementary_traits::atomic_copy::AtomicCopy`] trait for structs
 /// when all fields of the struct implement it and the struct implements `Copy`.
 ///
@@ -620,6 +925,57 @@ pub fn plain_old_data_without_padding_derive(input: TokenStream) -> TokenStream
     TokenStream::from(expanded)
 }
 
+/// Turns a trait into a typed remote procedure call interface on top of
+/// request-response. Every method must take `&self` and its arguments and return value
+/// must implement `ZeroCopySend`, `Debug` and `Clone`.
+///
+/// For a trait `Calculator` the macro generates
+///
+///  * `CalculatorRequest` - the request payload with one variant per method that contains
+///    the arguments,
+///  * `CalculatorResponse` - the response payload with one variant per method that contains
+///    the return value and an `as_{method}()` accessor per method,
+///  * `CalculatorClient` - wraps an `iceoryx2` `Client` and provides one method per trait
+///    method that sends the request and returns the `PendingResponse`,
+///  * `CalculatorServer` - wraps an `iceoryx2` `Server` and dispatches every received
+///    request to an implementation of the trait with `handle_next()` and `handle_all()`.
+///
+/// The generated code refers to `iceoryx2` and can only be used in crates that depend on it.
+///
+/// ```ignore
+/// use iceoryx2::prelude::*;
+///
+/// #[rpc_service]
+/// pub trait Calculator {
+///     fn add(&self, lhs: u64, rhs: u64) -> u64;
+///     fn negate(&self, value: i64) -> i64;
+/// }
+///
+/// let service = node
+///     .service_builder(&"Calculator".try_into()?)
+///     .request_response::<CalculatorRequest, CalculatorResponse>()
+///     .open_or_create()?;
+///
+/// let client = CalculatorClient::new(service.client_builder().create()?);
+/// let pending_response = client.add(3, 4)?;
+/// ```
+#[proc_macro_attribute]
+pub fn rpc_service(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`#[rpc_service]` does not accept arguments.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let item = parse_macro_input!(item as syn::ItemTrait);
+    rpc_service::expand(item).into()
+}
+
+mod rpc_service;
+
 #[cfg(doctest)]
 mod zero_copy_send_compile_tests;
 
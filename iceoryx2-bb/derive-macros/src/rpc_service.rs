@@ -0,0 +1,379 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, Ident, ItemTrait, Pat, ReturnType, TraitItem, Type, spanned::Spanned};
+
+struct Method {
+    name: Ident,
+    variant: Ident,
+    accessor: Ident,
+    arg_names: Vec<Ident>,
+    arg_types: Vec<Type>,
+    output: Type,
+}
+
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn parse_method(item: &TraitItem) -> Result<Method, syn::Error> {
+    let method = match item {
+        TraitItem::Fn(method) => method,
+        _ => {
+            return Err(syn::Error::new(
+                item.span(),
+                "`#[rpc_service]` traits must only contain methods.",
+            ));
+        }
+    };
+
+    let sig = &method.sig;
+    if sig.asyncness.is_some()
+        || sig.unsafety.is_some()
+        || sig.variadic.is_some()
+        || !sig.generics.params.is_empty()
+    {
+        return Err(syn::Error::new(
+            sig.span(),
+            "`#[rpc_service]` methods must not be async, unsafe, variadic or generic.",
+        ));
+    }
+
+    let mut inputs = sig.inputs.iter();
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+        _ => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "`#[rpc_service]` methods must take `&self` as first argument.",
+            ));
+        }
+    }
+
+    let mut arg_names = Vec::new();
+    let mut arg_types = Vec::new();
+    for input in inputs {
+        match input {
+            FnArg::Typed(pat_type) => match &*pat_type.pat {
+                Pat::Ident(pat_ident) => {
+                    arg_names.push(pat_ident.ident.clone());
+                    arg_types.push((*pat_type.ty).clone());
+                }
+                pat => {
+                    return Err(syn::Error::new(
+                        pat.span(),
+                        "`#[rpc_service]` method arguments must be plain identifiers.",
+                    ));
+                }
+            },
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new(
+                    receiver.span(),
+                    "`#[rpc_service]` methods must take `&self` as first argument.",
+                ));
+            }
+        }
+    }
+
+    let output = match &sig.output {
+        ReturnType::Default => syn::parse_quote!(()),
+        ReturnType::Type(_, ty) => (**ty).clone(),
+    };
+
+    Ok(Method {
+        name: sig.ident.clone(),
+        variant: Ident::new(
+            &to_upper_camel_case(&sig.ident.to_string()),
+            sig.ident.span(),
+        ),
+        accessor: format_ident!("as_{}", sig.ident),
+        arg_names,
+        arg_types,
+        output,
+    })
+}
+
+pub(crate) fn expand(item: ItemTrait) -> TokenStream {
+    if !item.generics.params.is_empty() {
+        let error = syn::Error::new(
+            item.generics.span(),
+            "`#[rpc_service]` traits must not be generic.",
+        )
+        .to_compile_error();
+        return quote! { #item #error };
+    }
+
+    let methods = match item
+        .items
+        .iter()
+        .map(parse_method)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(methods) if methods.is_empty() => {
+            let error = syn::Error::new(
+                item.ident.span(),
+                "`#[rpc_service]` traits must contain at least one method.",
+            )
+            .to_compile_error();
+            return quote! { #item #error };
+        }
+        Ok(methods) => methods,
+        Err(e) => {
+            let error = e.to_compile_error();
+            return quote! { #item #error };
+        }
+    };
+
+    let vis = &item.vis;
+    let trait_name = &item.ident;
+    let request = format_ident!("{}Request", trait_name);
+    let response = format_ident!("{}Response", trait_name);
+    let client = format_ident!("{}Client", trait_name);
+    let server = format_ident!("{}Server", trait_name);
+
+    let request_doc = format!("Request payload of the [`{trait_name}`] rpc service.");
+    let response_doc = format!("Response payload of the [`{trait_name}`] rpc service.");
+    let client_doc = format!(
+        "Typed client of the [`{trait_name}`] rpc service that sends one [`{request}`] per method call."
+    );
+    let server_doc = format!(
+        "Typed server of the [`{trait_name}`] rpc service that dispatches every received [`{request}`] to an implementation of [`{trait_name}`]."
+    );
+
+    let request_variants = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let arg_names = &m.arg_names;
+        let arg_types = &m.arg_types;
+        let doc = format!("Arguments of [`{}::{}()`].", trait_name, m.name);
+        quote! {
+            #[doc = #doc]
+            #variant { #(#arg_names: #arg_types),* }
+        }
+    });
+
+    let request_checks = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let arg_names = &m.arg_names;
+        quote! {
+            Self::#variant { #(#arg_names),* } => {
+                #(::iceoryx2::prelude::ZeroCopySend::__is_zero_copy_send(#arg_names);)*
+            }
+        }
+    });
+
+    let response_variants = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let output = &m.output;
+        let doc = format!("Return value of [`{}::{}()`].", trait_name, m.name);
+        quote! {
+            #[doc = #doc]
+            #variant(#output)
+        }
+    });
+
+    let response_checks = methods.iter().map(|m| {
+        let variant = &m.variant;
+        quote! {
+            Self::#variant(value) => ::iceoryx2::prelude::ZeroCopySend::__is_zero_copy_send(value),
+        }
+    });
+
+    let response_accessors = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let accessor = &m.accessor;
+        let output = &m.output;
+        let doc = format!(
+            "Returns the return value of [`{}::{}()`] or [`None`] when the response belongs to another method.",
+            trait_name, m.name
+        );
+        quote! {
+            #[doc = #doc]
+            #[allow(unreachable_patterns)]
+            pub fn #accessor(&self) -> ::core::option::Option<&#output> {
+                match self {
+                    Self::#variant(value) => ::core::option::Option::Some(value),
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    });
+
+    let client_methods = methods.iter().map(|m| {
+        let name = &m.name;
+        let variant = &m.variant;
+        let arg_names = &m.arg_names;
+        let arg_types = &m.arg_types;
+        let doc = format!(
+            "Calls [`{trait_name}::{name}()`] on the connected servers. The return value is received as [`{response}::{variant}`] from the returned [`PendingResponse`](::iceoryx2::pending_response::PendingResponse)."
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn #name(
+                &self,
+                #(#arg_names: #arg_types),*
+            ) -> ::core::result::Result<
+                ::iceoryx2::pending_response::PendingResponse<Service, #request, (), #response, ()>,
+                ::iceoryx2::port::client::RequestSendError,
+            > {
+                self.client.send_copy(#request::#variant { #(#arg_names),* })
+            }
+        }
+    });
+
+    let dispatch_arms = methods.iter().map(|m| {
+        let name = &m.name;
+        let variant = &m.variant;
+        let arg_names = &m.arg_names;
+        quote! {
+            #request::#variant { #(#arg_names),* } => #response::#variant(
+                implementation.#name(#(::core::clone::Clone::clone(#arg_names)),*)
+            ),
+        }
+    });
+
+    quote! {
+        #item
+
+        #[doc = #request_doc]
+        #[derive(Debug)]
+        #[repr(C)]
+        #vis enum #request {
+            #(#request_variants),*
+        }
+
+        unsafe impl ::iceoryx2::prelude::ZeroCopySend for #request {
+            fn __is_zero_copy_send(&self) {
+                match self {
+                    #(#request_checks)*
+                }
+            }
+        }
+
+        #[doc = #response_doc]
+        #[derive(Debug)]
+        #[repr(C)]
+        #vis enum #response {
+            #(#response_variants),*
+        }
+
+        unsafe impl ::iceoryx2::prelude::ZeroCopySend for #response {
+            fn __is_zero_copy_send(&self) {
+                match self {
+                    #(#response_checks)*
+                }
+            }
+        }
+
+        impl #response {
+            #(#response_accessors)*
+        }
+
+        #[doc = #client_doc]
+        #[derive(Debug)]
+        #vis struct #client<Service: ::iceoryx2::service::Service> {
+            client: ::iceoryx2::port::client::Client<Service, #request, (), #response, ()>,
+        }
+
+        impl<Service: ::iceoryx2::service::Service> #client<Service> {
+            /// Creates a new typed client from a [`Client`](::iceoryx2::port::client::Client)
+            /// of the rpc service.
+            pub fn new(
+                client: ::iceoryx2::port::client::Client<Service, #request, (), #response, ()>,
+            ) -> Self {
+                Self { client }
+            }
+
+            /// Returns a reference to the underlying
+            /// [`Client`](::iceoryx2::port::client::Client).
+            pub fn client(
+                &self,
+            ) -> &::iceoryx2::port::client::Client<Service, #request, (), #response, ()> {
+                &self.client
+            }
+
+            #(#client_methods)*
+        }
+
+        #[doc = #server_doc]
+        #[derive(Debug)]
+        #vis struct #server<Service: ::iceoryx2::service::Service> {
+            server: ::iceoryx2::port::server::Server<Service, #request, (), #response, ()>,
+        }
+
+        impl<Service: ::iceoryx2::service::Service> #server<Service> {
+            /// Creates a new typed server from a [`Server`](::iceoryx2::port::server::Server)
+            /// of the rpc service.
+            pub fn new(
+                server: ::iceoryx2::port::server::Server<Service, #request, (), #response, ()>,
+            ) -> Self {
+                Self { server }
+            }
+
+            /// Returns a reference to the underlying
+            /// [`Server`](::iceoryx2::port::server::Server).
+            pub fn server(
+                &self,
+            ) -> &::iceoryx2::port::server::Server<Service, #request, (), #response, ()> {
+                &self.server
+            }
+
+            /// Receives the next request, calls the corresponding method of the provided
+            /// implementation and sends its return value back to the client. Returns
+            /// [`false`] when no request was available.
+            pub fn handle_next<Implementation: #trait_name + ?Sized>(
+                &self,
+                implementation: &Implementation,
+            ) -> ::core::result::Result<bool, ::iceoryx2::rpc::RpcDispatchError> {
+                let active_request = match self.server.receive()? {
+                    ::core::option::Option::Some(active_request) => active_request,
+                    ::core::option::Option::None => return ::core::result::Result::Ok(false),
+                };
+
+                let response = match active_request.payload() {
+                    #(#dispatch_arms)*
+                };
+
+                active_request.send_copy(response)?;
+                ::core::result::Result::Ok(true)
+            }
+
+            /// Handles all available requests with [`Self::handle_next()`] and returns the
+            /// number of handled requests.
+            pub fn handle_all<Implementation: #trait_name + ?Sized>(
+                &self,
+                implementation: &Implementation,
+            ) -> ::core::result::Result<usize, ::iceoryx2::rpc::RpcDispatchError> {
+                let mut number_of_handled_requests = 0;
+                while self.handle_next(implementation)? {
+                    number_of_handled_requests += 1;
+                }
+                ::core::result::Result::Ok(number_of_handled_requests)
+            }
+        }
+    }
+}
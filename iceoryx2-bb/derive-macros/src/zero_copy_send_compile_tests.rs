@@ -407,3 +407,82 @@ fn zero_copy_send_derive_does_not_work_for_generic_union_when_members_do_not_imp
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_union_when_not_all_members_implement_it() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend, Default)]
+/// struct Position {
+///     x: u64,
+///     #[since_version(2)]
+///     y: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_with_since_version_but_without_type_version() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend, Default)]
+/// #[type_version(2)]
+/// struct Position {
+///     x: u64,
+///     #[since_version(3)]
+///     y: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_when_since_version_exceeds_type_version() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend, Default)]
+/// #[type_version(2)]
+/// struct Position {
+///     #[since_version(2)]
+///     x: u64,
+///     y: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_when_fields_are_not_appended() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[type_version(2)]
+/// struct PositionWithoutDefault {
+///     x: u64,
+///     #[since_version(2)]
+///     y: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_versioned_types_without_default() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::type_layout::TypeLayoutVisitor;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[type_layout]
+/// enum Direction {
+///     Left,
+///     Right,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_with_type_layout_on_enums() {}
@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::type_layout::{PrimitiveType, TypeLayoutVisitor};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing_macros::test;
@@ -186,3 +187,329 @@ pub fn for_unions() {
     assert_that!(is_zero_copy_send(&sut), eq true);
     assert_that!(unsafe { BasicUnionTest::type_name() }, eq "TryMadHoney");
 }
+
+mod fingerprint_v1 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Inner {
+        pub a: u32,
+        pub b: u32,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Outer {
+        pub inner: Inner,
+        pub value: u64,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Position {
+        pub x: u64,
+        pub y: u64,
+    }
+}
+
+mod fingerprint_v2 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Inner {
+        pub b: u32,
+        pub a: u32,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Outer {
+        pub inner: Inner,
+        pub value: u64,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Position {
+        pub y: u64,
+        pub x: u64,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct RenamedPosition {
+        pub x: u64,
+        pub z: u64,
+    }
+}
+
+mod fingerprint_v3 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Position {
+        pub x: u64,
+        pub y: u64,
+    }
+}
+
+#[test]
+pub fn structural_fingerprint_is_equal_for_structurally_identical_types() {
+    assert_that!(fingerprint_v1::Position::__structural_fingerprint(), eq fingerprint_v3::Position::__structural_fingerprint());
+}
+
+#[test]
+pub fn structural_fingerprint_differs_for_swapped_fields() {
+    assert_that!(fingerprint_v1::Position::__structural_fingerprint(), ne fingerprint_v2::Position::__structural_fingerprint());
+}
+
+#[test]
+pub fn structural_fingerprint_differs_for_renamed_fields() {
+    assert_that!(fingerprint_v1::Position::__structural_fingerprint(), ne fingerprint_v2::RenamedPosition::__structural_fingerprint());
+}
+
+#[test]
+pub fn structural_fingerprint_covers_nested_types() {
+    assert_that!(fingerprint_v1::Outer::__structural_fingerprint(), ne fingerprint_v2::Outer::__structural_fingerprint());
+}
+
+#[test]
+pub fn structural_fingerprint_reports_all_fields_with_offsets() {
+    let mut fields = [("", 0); 3];
+    let mut number_of_fields = 0;
+    NamedTestStruct::__for_each_field(&mut |name, offset, _| {
+        fields[number_of_fields] = (name, offset);
+        number_of_fields += 1;
+    });
+    assert_that!(number_of_fields, eq 2);
+    assert_that!(fields[0], eq("_val1", 0));
+    assert_that!(fields[1], eq("_val2", 8));
+
+    let mut number_of_fields = 0;
+    UnnamedTestStruct::__for_each_field(&mut |name, _, _| {
+        fields[number_of_fields] = (name, 0);
+        number_of_fields += 1;
+    });
+    assert_that!(number_of_fields, eq 3);
+    assert_that!(fields[0].0, eq "0");
+    assert_that!(fields[1].0, eq "1");
+    assert_that!(fields[2].0, eq "2");
+}
+
+mod versioned_v1 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend, Default)]
+    pub struct Position {
+        pub x: u64,
+        pub y: u64,
+    }
+}
+
+mod versioned_v3 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    #[type_version(3)]
+    pub struct Position {
+        pub x: u64,
+        pub y: u64,
+        #[since_version(2)]
+        pub z: u64,
+        #[since_version(3)]
+        pub w: u8,
+    }
+
+    impl Default for Position {
+        fn default() -> Self {
+            Self {
+                x: 0,
+                y: 0,
+                z: 7,
+                w: 9,
+            }
+        }
+    }
+}
+
+#[test]
+pub fn unversioned_type_has_version_one() {
+    assert_that!(versioned_v1::Position::__type_version(), eq 1);
+    assert_that!(versioned_v1::Position::__size_of_version(1), eq Some(16));
+    assert_that!(versioned_v1::Position::__size_of_version(2), eq None);
+}
+
+#[test]
+pub fn versioned_type_reports_the_size_of_every_version() {
+    assert_that!(versioned_v3::Position::__type_version(), eq 3);
+    assert_that!(versioned_v3::Position::__size_of_version(0), eq None);
+    assert_that!(versioned_v3::Position::__size_of_version(1), eq Some(16));
+    assert_that!(versioned_v3::Position::__size_of_version(2), eq Some(24));
+    assert_that!(versioned_v3::Position::__size_of_version(3), eq Some(core::mem::size_of::<versioned_v3::Position>()));
+    assert_that!(versioned_v3::Position::__size_of_version(4), eq None);
+}
+
+#[test]
+pub fn versioned_type_defaults_fields_added_after_the_source_version() {
+    let source = versioned_v1::Position { x: 3, y: 5 };
+    let mut target = core::mem::MaybeUninit::<versioned_v3::Position>::uninit();
+
+    let has_upgraded = unsafe {
+        versioned_v3::Position::__upgrade_from_version(
+            1,
+            (&source as *const versioned_v1::Position).cast(),
+            target.as_mut_ptr(),
+        )
+    };
+    let target = unsafe { target.assume_init() };
+
+    assert_that!(has_upgraded, eq true);
+    assert_that!(target.x, eq 3);
+    assert_that!(target.y, eq 5);
+    assert_that!(target.z, eq 7);
+    assert_that!(target.w, eq 9);
+}
+
+#[test]
+pub fn versioned_type_cannot_be_upgraded_from_an_unknown_version() {
+    let source = versioned_v1::Position { x: 3, y: 5 };
+    let mut target = core::mem::MaybeUninit::<versioned_v3::Position>::uninit();
+
+    let has_upgraded = unsafe {
+        versioned_v3::Position::__upgrade_from_version(
+            4,
+            (&source as *const versioned_v1::Position).cast(),
+            target.as_mut_ptr(),
+        )
+    };
+
+    assert_that!(has_upgraded, eq false);
+}
+
+mod described {
+    use super::*;
+
+    #[derive(ZeroCopySend)]
+    #[type_layout]
+    #[repr(C)]
+    pub struct Position {
+        pub x: u32,
+        pub y: u32,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[type_name("Route")]
+    #[type_layout]
+    #[repr(C)]
+    pub struct Route {
+        pub waypoints: [Position; 2],
+        pub id: u64,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[type_layout]
+    #[repr(C)]
+    pub struct Id(pub u16, pub u16);
+}
+
+#[derive(Debug, PartialEq)]
+enum LayoutEvent {
+    Primitive(PrimitiveType),
+    Opaque(usize),
+    Array(usize),
+    BeginStruct(usize),
+    Field(&'static str, usize),
+    EndStruct,
+}
+
+#[derive(Default)]
+struct LayoutRecorder {
+    events: [Option<LayoutEvent>; 16],
+    len: usize,
+}
+
+impl LayoutRecorder {
+    fn push(&mut self, event: LayoutEvent) {
+        self.events[self.len] = Some(event);
+        self.len += 1;
+    }
+
+    fn events(&self) -> &[Option<LayoutEvent>] {
+        &self.events[..self.len]
+    }
+}
+
+impl TypeLayoutVisitor for LayoutRecorder {
+    fn primitive(&mut self, primitive: PrimitiveType) {
+        self.push(LayoutEvent::Primitive(primitive));
+    }
+
+    fn opaque(&mut self, _type_name: &'static str, size: usize, _alignment: usize) {
+        self.push(LayoutEvent::Opaque(size));
+    }
+
+    fn array(&mut self, len: usize) {
+        self.push(LayoutEvent::Array(len));
+    }
+
+    fn begin_struct(&mut self, _type_name: &'static str, size: usize, _alignment: usize) {
+        self.push(LayoutEvent::BeginStruct(size));
+    }
+
+    fn field(&mut self, name: &'static str, offset: usize) {
+        self.push(LayoutEvent::Field(name, offset));
+    }
+
+    fn end_struct(&mut self) {
+        self.push(LayoutEvent::EndStruct);
+    }
+}
+
+#[test]
+pub fn type_without_layout_is_described_as_opaque() {
+    let mut sut = LayoutRecorder::default();
+    versioned_v1::Position::__describe_layout(&mut sut);
+
+    assert_that!(sut.events(), eq[Some(LayoutEvent::Opaque(16))]);
+}
+
+#[test]
+pub fn type_with_layout_describes_nested_structs_and_arrays() {
+    let mut sut = LayoutRecorder::default();
+    described::Route::__describe_layout(&mut sut);
+
+    assert_that!(sut.events(), eq [
+        Some(LayoutEvent::BeginStruct(24)),
+        Some(LayoutEvent::Field("waypoints", 0)),
+        Some(LayoutEvent::Array(2)),
+        Some(LayoutEvent::BeginStruct(8)),
+        Some(LayoutEvent::Field("x", 0)),
+        Some(LayoutEvent::Primitive(PrimitiveType::U32)),
+        Some(LayoutEvent::Field("y", 4)),
+        Some(LayoutEvent::Primitive(PrimitiveType::U32)),
+        Some(LayoutEvent::EndStruct),
+        Some(LayoutEvent::Field("id", 16)),
+        Some(LayoutEvent::Primitive(PrimitiveType::U64)),
+        Some(LayoutEvent::EndStruct)
+    ]);
+}
+
+#[test]
+pub fn tuple_struct_with_layout_names_fields_by_index() {
+    let mut sut = LayoutRecorder::default();
+    described::Id::__describe_layout(&mut sut);
+
+    assert_that!(sut.events(), eq [
+        Some(LayoutEvent::BeginStruct(4)),
+        Some(LayoutEvent::Field("0", 0)),
+        Some(LayoutEvent::Primitive(PrimitiveType::U16)),
+        Some(LayoutEvent::Field("1", 2)),
+        Some(LayoutEvent::Primitive(PrimitiveType::U16)),
+        Some(LayoutEvent::EndStruct)
+    ]);
+}
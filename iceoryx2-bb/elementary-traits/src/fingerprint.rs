@@ -0,0 +1,89 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A stable, non-cryptographic [`Fingerprint`] that is used to compare the structure of types
+//! across process boundaries.
+//!
+//! ```
+//! use iceoryx2_bb_elementary_traits::fingerprint::Fingerprint;
+//!
+//! let a = Fingerprint::new().add_str("value").add_usize(8);
+//! let b = Fingerprint::new().add_str("value").add_usize(16);
+//!
+//! assert_ne!(a.value(), b.value());
+//! assert_ne!(a.value(), Fingerprint::UNKNOWN);
+//! ```
+
+const FNV_OFFSET_BASIS: u32 = 0x811c9dc5;
+const FNV_PRIME: u32 = 0x01000193;
+const STR_TERMINATOR: u8 = 0xff;
+
+/// Computes a 32-bit FNV-1a hash over all added values. The same sequence of values results
+/// in the same [`Fingerprint::value()`] in every process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint {
+    value: u32,
+}
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fingerprint {
+    /// The value that represents an unknown structure. [`Fingerprint::value()`] never returns
+    /// it.
+    pub const UNKNOWN: u32 = 0;
+
+    /// Creates a new [`Fingerprint`] without any value.
+    pub const fn new() -> Self {
+        Self {
+            value: FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Adds raw bytes to the [`Fingerprint`].
+    pub fn add_bytes(mut self, bytes: &[u8]) -> Self {
+        for byte in bytes {
+            self.value ^= *byte as u32;
+            self.value = self.value.wrapping_mul(FNV_PRIME);
+        }
+        self
+    }
+
+    /// Adds a string to the [`Fingerprint`]. The string is terminated so that consecutive
+    /// strings cannot be shifted into each other.
+    pub fn add_str(self, value: &str) -> Self {
+        self.add_bytes(value.as_bytes())
+            .add_bytes(&[STR_TERMINATOR])
+    }
+
+    /// Adds a [`usize`] to the [`Fingerprint`]. It is added with 64-bit so that the value does
+    /// not depend on the pointer width.
+    pub fn add_usize(self, value: usize) -> Self {
+        self.add_bytes(&(value as u64).to_le_bytes())
+    }
+
+    /// Adds a [`u32`], like the value of another [`Fingerprint`], to the [`Fingerprint`].
+    pub fn add_u32(self, value: u32) -> Self {
+        self.add_bytes(&value.to_le_bytes())
+    }
+
+    /// Returns the value of the [`Fingerprint`].
+    pub fn value(&self) -> u32 {
+        match self.value {
+            Self::UNKNOWN => Self::UNKNOWN + 1,
+            v => v,
+        }
+    }
+}
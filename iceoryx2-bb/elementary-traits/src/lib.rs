@@ -21,6 +21,7 @@ pub mod allocator;
 mod as_cstr;
 pub use crate::as_cstr::*;
 pub mod atomic_copy;
+pub mod fingerprint;
 pub mod iceoryx_send;
 pub mod placement_default;
 pub mod plain_old_data_without_padding;
@@ -28,6 +29,7 @@ pub mod pointer;
 pub mod pointer_family;
 pub mod relocatable_container;
 pub mod testing;
+pub mod type_layout;
 pub mod type_name;
 pub mod zero_copy_send;
 pub mod zeroable;
@@ -0,0 +1,135 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Describes the memory layout of [`ZeroCopySend`](crate::zero_copy_send::ZeroCopySend) types,
+//! field by field, so that their content can be accessed by processes that do not know the
+//! type at compile time.
+
+/// The primitive types from which a described type layout is composed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrimitiveType {
+    Bool,
+    Char,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    F32,
+    F64,
+}
+
+impl PrimitiveType {
+    /// The size of the primitive type in bytes.
+    pub const fn size(&self) -> usize {
+        match self {
+            PrimitiveType::Bool => core::mem::size_of::<bool>(),
+            PrimitiveType::Char => core::mem::size_of::<char>(),
+            PrimitiveType::U8 => core::mem::size_of::<u8>(),
+            PrimitiveType::U16 => core::mem::size_of::<u16>(),
+            PrimitiveType::U32 => core::mem::size_of::<u32>(),
+            PrimitiveType::U64 => core::mem::size_of::<u64>(),
+            PrimitiveType::U128 => core::mem::size_of::<u128>(),
+            PrimitiveType::Usize => core::mem::size_of::<usize>(),
+            PrimitiveType::I8 => core::mem::size_of::<i8>(),
+            PrimitiveType::I16 => core::mem::size_of::<i16>(),
+            PrimitiveType::I32 => core::mem::size_of::<i32>(),
+            PrimitiveType::I64 => core::mem::size_of::<i64>(),
+            PrimitiveType::I128 => core::mem::size_of::<i128>(),
+            PrimitiveType::Isize => core::mem::size_of::<isize>(),
+            PrimitiveType::F32 => core::mem::size_of::<f32>(),
+            PrimitiveType::F64 => core::mem::size_of::<f64>(),
+        }
+    }
+
+    /// The alignment of the primitive type in bytes.
+    pub const fn alignment(&self) -> usize {
+        match self {
+            PrimitiveType::Bool => core::mem::align_of::<bool>(),
+            PrimitiveType::Char => core::mem::align_of::<char>(),
+            PrimitiveType::U8 => core::mem::align_of::<u8>(),
+            PrimitiveType::U16 => core::mem::align_of::<u16>(),
+            PrimitiveType::U32 => core::mem::align_of::<u32>(),
+            PrimitiveType::U64 => core::mem::align_of::<u64>(),
+            PrimitiveType::U128 => core::mem::align_of::<u128>(),
+            PrimitiveType::Usize => core::mem::align_of::<usize>(),
+            PrimitiveType::I8 => core::mem::align_of::<i8>(),
+            PrimitiveType::I16 => core::mem::align_of::<i16>(),
+            PrimitiveType::I32 => core::mem::align_of::<i32>(),
+            PrimitiveType::I64 => core::mem::align_of::<i64>(),
+            PrimitiveType::I128 => core::mem::align_of::<i128>(),
+            PrimitiveType::Isize => core::mem::align_of::<isize>(),
+            PrimitiveType::F32 => core::mem::align_of::<f32>(),
+            PrimitiveType::F64 => core::mem::align_of::<f64>(),
+        }
+    }
+
+    /// The Rust name of the primitive type.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "char",
+            PrimitiveType::U8 => "u8",
+            PrimitiveType::U16 => "u16",
+            PrimitiveType::U32 => "u32",
+            PrimitiveType::U64 => "u64",
+            PrimitiveType::U128 => "u128",
+            PrimitiveType::Usize => "usize",
+            PrimitiveType::I8 => "i8",
+            PrimitiveType::I16 => "i16",
+            PrimitiveType::I32 => "i32",
+            PrimitiveType::I64 => "i64",
+            PrimitiveType::I128 => "i128",
+            PrimitiveType::Isize => "isize",
+            PrimitiveType::F32 => "f32",
+            PrimitiveType::F64 => "f64",
+        }
+    }
+}
+
+/// Receives the layout description of a type from
+/// [`ZeroCopySend::__describe_layout()`](crate::zero_copy_send::ZeroCopySend::__describe_layout()).
+///
+/// A described type is either
+///  * a primitive, see [`TypeLayoutVisitor::primitive()`],
+///  * an opaque type whose fields are unknown, see [`TypeLayoutVisitor::opaque()`],
+///  * an array, see [`TypeLayoutVisitor::array()`], that is followed by the description of
+///    its element type or
+///  * a struct that starts with [`TypeLayoutVisitor::begin_struct()`], announces every field
+///    with [`TypeLayoutVisitor::field()`] followed by the description of the field type and
+///    ends with [`TypeLayoutVisitor::end_struct()`].
+pub trait TypeLayoutVisitor {
+    /// Describes a primitive type.
+    fn primitive(&mut self, primitive: PrimitiveType);
+
+    /// Describes a type whose fields are unknown.
+    fn opaque(&mut self, type_name: &'static str, size: usize, alignment: usize);
+
+    /// Describes an array with `len` elements, the element type is described next.
+    fn array(&mut self, len: usize);
+
+    /// Starts the description of a struct.
+    fn begin_struct(&mut self, type_name: &'static str, size: usize, alignment: usize);
+
+    /// Announces the next field of the struct, the field type is described next.
+    fn field(&mut self, name: &'static str, offset: usize);
+
+    /// Finishes the description of the struct.
+    fn end_struct(&mut self);
+}
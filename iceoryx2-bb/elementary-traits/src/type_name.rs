@@ -25,4 +25,72 @@ pub unsafe trait TypeName {
     unsafe fn type_name() -> &'static str {
         core::any::type_name::<Self>()
     }
+
+    #[doc(hidden)]
+    /// calls the callback with the name, the offset and the structural fingerprint of every
+    /// field of the type, see [`ZeroCopySend`](crate::zero_copy_send::ZeroCopySend)
+    fn __for_each_field(_callback: &mut dyn FnMut(&'static str, usize, u32))
+    where
+        Self: Sized,
+    {
+    }
+
+    #[doc(hidden)]
+    /// the structural fingerprint of the type or
+    /// [`Fingerprint::UNKNOWN`](crate::fingerprint::Fingerprint::UNKNOWN) when the structure
+    /// of the type is not known
+    fn __structural_fingerprint() -> u32
+    where
+        Self: Sized,
+    {
+        crate::fingerprint::Fingerprint::UNKNOWN
+    }
+
+    #[doc(hidden)]
+    /// the version of the type, see [`ZeroCopySend`](crate::zero_copy_send::ZeroCopySend)
+    fn __type_version() -> u32
+    where
+        Self: Sized,
+    {
+        1
+    }
+
+    #[doc(hidden)]
+    /// the number of bytes at the beginning of the type that are provided by the given
+    /// version or [`None`] when the version is not compatible
+    fn __size_of_version(version: u32) -> Option<usize>
+    where
+        Self: Sized,
+    {
+        (version == Self::__type_version()).then_some(core::mem::size_of::<Self>())
+    }
+
+    #[doc(hidden)]
+    /// initializes `target` from the payload of an older compatible version, returns `false`
+    /// when the version is not compatible
+    ///
+    /// # Safety
+    ///
+    ///  * `source` must point to a payload of the given version
+    ///  * `target` must point to valid memory for `Self`
+    unsafe fn __upgrade_from_version(_version: u32, _source: *const u8, _target: *mut Self) -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    #[doc(hidden)]
+    /// describes the memory layout of the type field by field, types whose fields are not
+    /// known are described as opaque
+    fn __describe_layout(visitor: &mut dyn crate::type_layout::TypeLayoutVisitor)
+    where
+        Self: Sized,
+    {
+        visitor.opaque(
+            unsafe { Self::type_name() },
+            core::mem::size_of::<Self>(),
+            core::mem::align_of::<Self>(),
+        );
+    }
 }
@@ -10,7 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::fingerprint::Fingerprint;
 use crate::iceoryx_send::*;
+use crate::type_layout::{PrimitiveType, TypeLayoutVisitor};
 use crate::type_name::TypeName;
 
 /// Marks types that can be sent to another process in a zero-copy manner, i.e. the types can be
@@ -48,33 +50,139 @@ pub unsafe trait ZeroCopySend: TypeName {
     /// used as dummy call in the derive macro to ensure at compile-time that all fields of
     /// a struct implement ZeroCopySend
     fn __is_zero_copy_send(&self) {}
-}
 
-unsafe impl ZeroCopySend for usize {}
-unsafe impl ZeroCopySend for u8 {}
-unsafe impl ZeroCopySend for u16 {}
-unsafe impl ZeroCopySend for u32 {}
-unsafe impl ZeroCopySend for u64 {}
-unsafe impl ZeroCopySend for u128 {}
+    #[doc(hidden)]
+    /// implemented by the derive macro, calls the callback with the name, the offset and the
+    /// structural fingerprint of every field. Enums provide the variant index as offset.
+    fn __for_each_field(_callback: &mut dyn FnMut(&'static str, usize, u32))
+    where
+        Self: Sized,
+    {
+    }
+
+    #[doc(hidden)]
+    /// the structural fingerprint of the type covers the size, the alignment and all fields
+    /// provided by [`ZeroCopySend::__for_each_field()`]. Types without fields are identified by
+    /// their [`ZeroCopySend::type_name()`].
+    fn __structural_fingerprint() -> u32
+    where
+        Self: Sized,
+    {
+        let mut fingerprint = Fingerprint::new()
+            .add_usize(core::mem::size_of::<Self>())
+            .add_usize(core::mem::align_of::<Self>());
+        let mut has_fields = false;
+        <Self as ZeroCopySend>::__for_each_field(&mut |name, offset, field_fingerprint| {
+            has_fields = true;
+            fingerprint = fingerprint
+                .add_str(name)
+                .add_usize(offset)
+                .add_u32(field_fingerprint);
+        });
+
+        if !has_fields {
+            fingerprint = fingerprint.add_str(unsafe { <Self as ZeroCopySend>::type_name() });
+        }
+
+        fingerprint.value()
+    }
+
+    #[doc(hidden)]
+    /// implemented by the derive macro when the type is annotated with `#[type_version(N)]`
+    fn __type_version() -> u32
+    where
+        Self: Sized,
+    {
+        1
+    }
+
+    #[doc(hidden)]
+    /// the number of bytes at the beginning of the type that are provided by an older version,
+    /// the offset of the first field that was appended in a later version. Returns [`None`]
+    /// when the version is not compatible.
+    fn __size_of_version(version: u32) -> Option<usize>
+    where
+        Self: Sized,
+    {
+        (version == <Self as ZeroCopySend>::__type_version())
+            .then_some(core::mem::size_of::<Self>())
+    }
 
-unsafe impl ZeroCopySend for isize {}
-unsafe impl ZeroCopySend for i8 {}
-unsafe impl ZeroCopySend for i16 {}
-unsafe impl ZeroCopySend for i32 {}
-unsafe impl ZeroCopySend for i64 {}
-unsafe impl ZeroCopySend for i128 {}
+    #[doc(hidden)]
+    /// implemented by the derive macro for versioned types, initializes `target` with
+    /// [`Default`] and copies the part that is provided by the older version from `source`.
+    /// Returns `false` when the version is not compatible.
+    ///
+    /// # Safety
+    ///
+    ///  * `source` must point to a payload of the given version
+    ///  * `target` must point to valid memory for `Self`
+    unsafe fn __upgrade_from_version(_version: u32, _source: *const u8, _target: *mut Self) -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    #[doc(hidden)]
+    /// describes the memory layout of the type, see [`TypeLayoutVisitor`]. Implemented for
+    /// primitives, arrays and by the derive macro for structs annotated with `#[type_layout]`.
+    /// All other types are described as opaque.
+    fn __describe_layout(visitor: &mut dyn TypeLayoutVisitor)
+    where
+        Self: Sized,
+    {
+        visitor.opaque(
+            unsafe { <Self as ZeroCopySend>::type_name() },
+            core::mem::size_of::<Self>(),
+            core::mem::align_of::<Self>(),
+        );
+    }
+}
 
-unsafe impl ZeroCopySend for f32 {}
-unsafe impl ZeroCopySend for f64 {}
+macro_rules! impl_zero_copy_send_for_primitives {
+    ($($t:ty => $primitive:ident),*) => {
+        $(unsafe impl ZeroCopySend for $t {
+            fn __describe_layout(visitor: &mut dyn TypeLayoutVisitor) {
+                visitor.primitive(PrimitiveType::$primitive);
+            }
+        })*
+    };
+}
 
-unsafe impl ZeroCopySend for char {}
-unsafe impl ZeroCopySend for bool {}
+impl_zero_copy_send_for_primitives!(
+    usize => Usize, u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128,
+    isize => Isize, i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128,
+    f32 => F32, f64 => F64,
+    char => Char, bool => Bool
+);
 
 unsafe impl ZeroCopySend for () {}
 
 unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
-unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {}
-unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {}
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {
+    fn __structural_fingerprint() -> u32 {
+        Fingerprint::new()
+            .add_str("[T; N]")
+            .add_usize(N)
+            .add_u32(<T as ZeroCopySend>::__structural_fingerprint())
+            .value()
+    }
+
+    fn __describe_layout(visitor: &mut dyn TypeLayoutVisitor) {
+        visitor.array(N);
+        <T as ZeroCopySend>::__describe_layout(visitor);
+    }
+}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {
+    fn __structural_fingerprint() -> u32 {
+        <T as ZeroCopySend>::__structural_fingerprint()
+    }
+
+    fn __describe_layout(visitor: &mut dyn TypeLayoutVisitor) {
+        <T as ZeroCopySend>::__describe_layout(visitor);
+    }
+}
 
 // Note: `ZeroCopySend` cannot be implemented for tuples because `#[repr(C)]` can only be applied
 // to structs, enums, and unions.
@@ -88,4 +196,46 @@ unsafe impl<T: ZeroCopySend + ?Sized> TypeName for T {
     unsafe fn type_name() -> &'static str {
         unsafe { <T as ZeroCopySend>::type_name() }
     }
+
+    fn __for_each_field(callback: &mut dyn FnMut(&'static str, usize, u32))
+    where
+        Self: Sized,
+    {
+        <T as ZeroCopySend>::__for_each_field(callback)
+    }
+
+    fn __structural_fingerprint() -> u32
+    where
+        Self: Sized,
+    {
+        <T as ZeroCopySend>::__structural_fingerprint()
+    }
+
+    fn __type_version() -> u32
+    where
+        Self: Sized,
+    {
+        <T as ZeroCopySend>::__type_version()
+    }
+
+    fn __size_of_version(version: u32) -> Option<usize>
+    where
+        Self: Sized,
+    {
+        <T as ZeroCopySend>::__size_of_version(version)
+    }
+
+    unsafe fn __upgrade_from_version(version: u32, source: *const u8, target: *mut Self) -> bool
+    where
+        Self: Sized,
+    {
+        unsafe { <T as ZeroCopySend>::__upgrade_from_version(version, source, target) }
+    }
+
+    fn __describe_layout(visitor: &mut dyn TypeLayoutVisitor)
+    where
+        Self: Sized,
+    {
+        <T as ZeroCopySend>::__describe_layout(visitor)
+    }
 }
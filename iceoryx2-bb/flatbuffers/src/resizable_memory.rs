@@ -156,6 +156,50 @@ impl<P: Pointer<u8>, A: Grow<P>> ResizableMemory<P, A> {
         self.current_layout = new_layout;
         Ok(())
     }
+
+    /// Grows the memory upwards to the specified `new_size`. The content remains at the
+    /// front of the memory chunk.
+    pub fn grow_upwards_with_size(&mut self, new_size: usize) -> Result<(), AllocationGrowError> {
+        let new_size = new_size + self.reserved_header_len;
+        let new_layout =
+            unsafe { Layout::from_size_align_unchecked(new_size, self.current_layout.align()) };
+
+        self.ptr = unsafe {
+            self.allocatable.grow(
+                self.ptr.clone(),
+                self.current_layout,
+                new_layout,
+                ContentPlacement::Front,
+            )?
+        };
+
+        self.current_layout = new_layout;
+        Ok(())
+    }
+
+    /// Grows the memory upwards so that at least `min_len` bytes are available. The new size
+    /// is acquired with the [`AllocationStrategy`] and the content remains at the front of
+    /// the memory chunk.
+    pub fn grow_upwards(&mut self, min_len: usize) -> Result<(), AllocationGrowError> {
+        if min_len <= self.len() {
+            return Ok(());
+        }
+
+        let msg = "Unable to grow memory";
+        let required_size = min_len + self.reserved_header_len;
+        let new_size = match self.strategy {
+            AllocationStrategy::Static => {
+                fail!(from self, with AllocationGrowError::OutOfMemory,
+                    "{msg} since the allocation strategy is static.");
+            }
+            AllocationStrategy::PowerOfTwo => required_size.next_power_of_two(),
+            AllocationStrategy::BestFit => {
+                required_size.next_multiple_of(self.current_layout.align())
+            }
+        };
+
+        self.grow_upwards_with_size(new_size - self.reserved_header_len)
+    }
 }
 
 unsafe impl<P: Pointer<u8>, A: Grow<P>> Allocator for ResizableMemory<P, A> {
@@ -190,3 +190,74 @@ pub fn growing_keeps_content_at_end() {
         }
     }
 }
+
+#[test]
+pub fn growing_upwards_with_static_allocation_strategy_fails() {
+    let heap_allocator = HeapAllocator::new();
+    let initial_layout = Layout::new::<u64>();
+    let memory = heap_allocator.allocate(initial_layout).unwrap();
+
+    let mut sut = ResizableMemoryBuilder::new(memory)
+        .initial_layout(initial_layout)
+        .allocation_strategy(AllocationStrategy::Static)
+        .create(heap_allocator);
+
+    assert_that!(sut.grow_upwards(sut.len()), is_ok);
+    let result = sut.grow_upwards(sut.len() + 1);
+    assert_that!(result.err(), eq Some(AllocationGrowError::OutOfMemory));
+}
+
+#[test]
+pub fn growing_upwards_provides_at_least_the_requested_len() {
+    let heap_allocator = HeapAllocator::new();
+    let initial_layout = Layout::from_size_align(5, 1).unwrap();
+    let memory = heap_allocator.allocate(initial_layout).unwrap();
+
+    let mut sut = ResizableMemoryBuilder::new(memory)
+        .initial_layout(initial_layout)
+        .reserved_header_len(3)
+        .allocation_strategy(AllocationStrategy::BestFit)
+        .create(heap_allocator);
+
+    for n in 1..20 {
+        let min_len = n * 13;
+        sut.grow_upwards(min_len).unwrap();
+        assert_that!(sut.len(), ge min_len);
+    }
+}
+
+#[test]
+pub fn growing_upwards_keeps_header_and_content_in_front() {
+    const HEADER_SIZE: usize = 7;
+    let heap_allocator = HeapAllocator::new();
+    let initial_layout = Layout::new::<u64>();
+    let mut memory = heap_allocator.allocate(initial_layout).unwrap();
+
+    for n in 0..HEADER_SIZE {
+        unsafe { *memory.as_mut_ptr().add(n) = 71 };
+    }
+
+    let mut sut = ResizableMemoryBuilder::new(memory)
+        .initial_layout(initial_layout)
+        .reserved_header_len(HEADER_SIZE)
+        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+        .create(heap_allocator);
+
+    for n in 0..10 {
+        for element in &mut *sut {
+            *element = n + 1;
+        }
+
+        let previous_len = sut.len();
+        sut.grow_upwards(previous_len + 1).unwrap();
+
+        let header_ptr = unsafe { sut.as_ptr().offset(-(HEADER_SIZE as isize)) };
+        for i in 0..HEADER_SIZE {
+            assert_that!(unsafe { *header_ptr.add(i) }, eq 71);
+        }
+
+        for element in &sut[0..previous_len] {
+            assert_that!(*element, eq n + 1);
+        }
+    }
+}
@@ -194,6 +194,34 @@ impl UnrestrictedAtomicMgmt {
         value_alignment: usize,
         data_ptr: *const u8,
     ) {
+        unsafe {
+            self.__internal_load_from_cell(
+                value_ptr,
+                value_size,
+                value_size,
+                value_alignment,
+                data_ptr,
+            )
+        }
+    }
+
+    #[doc(hidden)]
+    /// Loads `value_size` bytes from data cells that have a size of `cell_size`. Required when
+    /// the cells are shared by values of different types.
+    ///
+    /// # Safety
+    ///
+    ///   * see Safety section of core::ptr::copy_nonoverlapping
+    ///   * `value_size` <= `cell_size`
+    pub unsafe fn __internal_load_from_cell(
+        &self,
+        value_ptr: *mut u8,
+        value_size: usize,
+        cell_size: usize,
+        cell_alignment: usize,
+        data_ptr: *const u8,
+    ) {
+        debug_assert!(value_size <= cell_size);
         /////////////////////////
         // SYNC POINT - read
         /////////////////////////
@@ -203,12 +231,8 @@ impl UnrestrictedAtomicMgmt {
             let read_cell = current_write_cell - 1;
 
             unsafe {
-                let data_cell_ptr = Self::__internal_get_data_cell(
-                    value_size,
-                    value_alignment,
-                    data_ptr,
-                    read_cell,
-                );
+                let data_cell_ptr =
+                    Self::__internal_get_data_cell(cell_size, cell_alignment, data_ptr, read_cell);
                 core::ptr::copy_nonoverlapping(data_cell_ptr as *const u8, value_ptr, value_size);
             }
 
@@ -11,58 +11,36 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 fn main() {
-    // Validate logger selection
-    if cfg!(all(feature = "buffer", feature = "file")) {
-        panic!("Cannot enable both 'buffer' and 'file' features simultaneously");
-    }
-
-    if cfg!(all(feature = "buffer", feature = "console")) {
-        panic!("Cannot enable both 'buffer' and 'console' features simultaneously");
-    }
-
-    if cfg!(all(feature = "buffer", feature = "log")) {
-        panic!("Cannot enable both 'buffer' and 'log' features simultaneously");
-    }
-
-    if cfg!(all(feature = "buffer", feature = "tracing")) {
-        panic!("Cannot enable both 'buffer' and 'tracing' features simultaneously");
-    }
-
-    if cfg!(all(feature = "file", feature = "console")) {
-        panic!("Cannot enable both 'file' and 'console' features simultaneously");
-    }
-
-    if cfg!(all(feature = "file", feature = "log")) {
-        panic!("Cannot enable both 'file' and 'log' features simultaneously");
-    }
-
-    if cfg!(all(feature = "file", feature = "tracing")) {
-        panic!("Cannot enable both 'file' and 'tracing' features simultaneously");
-    }
+    let loggers = [
+        ("buffer", cfg!(feature = "buffer")),
+        ("file", cfg!(feature = "file")),
+        ("rotating_file", cfg!(feature = "rotating_file")),
+        ("journald", cfg!(feature = "journald")),
+        ("console", cfg!(feature = "console")),
+        ("log", cfg!(feature = "log")),
+        ("tracing", cfg!(feature = "tracing")),
+    ];
 
-    if cfg!(all(feature = "console", feature = "log")) {
-        panic!("Cannot enable both 'console' and 'log' features simultaneously");
-    }
-
-    if cfg!(all(feature = "console", feature = "tracing")) {
-        panic!("Cannot enable both 'console' and 'tracing' features simultaneously");
-    }
-
-    if cfg!(all(feature = "log", feature = "tracing")) {
-        panic!("Cannot enable both 'log' and 'tracing' features simultaneously");
+    // Validate logger selection
+    for (n, (logger, is_enabled)) in loggers.iter().enumerate() {
+        for (other_logger, is_other_enabled) in &loggers[n + 1..] {
+            if *is_enabled && *is_other_enabled {
+                panic!(
+                    "Cannot enable both '{logger}' and '{other_logger}' features simultaneously"
+                );
+            }
+        }
     }
 
     // Prevent invalid platform-logger combinations
-    if cfg!(all(not(feature = "std"), feature = "buffer")) {
-        panic!("Invalid combination: 'buffer' logger is only available for 'std' builds");
-    }
-    if cfg!(all(not(feature = "std"), feature = "file")) {
-        panic!("Invalid combination: 'file' logger is only available for 'std' builds");
+    for (logger, is_enabled) in loggers {
+        if is_enabled && logger != "console" && !cfg!(feature = "std") {
+            panic!("Invalid combination: '{logger}' logger is only available for 'std' builds");
+        }
     }
-    if cfg!(all(not(feature = "std"), feature = "log")) {
-        panic!("Invalid combination: 'log' logger is only available for 'std' builds");
-    }
-    if cfg!(all(not(feature = "std"), feature = "tracing")) {
-        panic!("Invalid combination: 'tracing' logger is only available for 'std' builds");
+
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if cfg!(feature = "journald") && target_os != "linux" {
+        panic!("Invalid combination: 'journald' logger is only available on linux");
     }
 }
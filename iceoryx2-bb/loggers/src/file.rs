@@ -30,12 +30,12 @@ use std::sync::mpsc::channel;
 use iceoryx2_log_types::Log;
 use iceoryx2_log_types::LogLevel;
 
-enum Message {
+pub(crate) enum Message {
     Entry(Entry),
     Stop,
 }
 
-struct Entry {
+pub(crate) struct Entry {
     timestamp: Duration,
     elapsed_time: Duration,
     log_level: LogLevel,
@@ -43,6 +43,25 @@ struct Entry {
     message: String,
 }
 
+impl Entry {
+    pub(crate) fn new(
+        log_level: LogLevel,
+        start_time: &Instant,
+        origin: core::fmt::Arguments,
+        formatted_message: core::fmt::Arguments,
+    ) -> Self {
+        Self {
+            log_level,
+            timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("Acquire current system time."),
+            elapsed_time: start_time.elapsed(),
+            origin: origin.to_string(),
+            message: formatted_message.to_string(),
+        }
+    }
+}
+
 impl Debug for Entry {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -112,17 +131,12 @@ impl Log for Logger {
         formatted_message: core::fmt::Arguments,
     ) {
         self.sender
-            .send({
-                Message::Entry(Entry {
-                    log_level,
-                    timestamp: SystemTime::now()
-                        .duration_since(SystemTime::UNIX_EPOCH)
-                        .expect("Acquire current system time."),
-                    elapsed_time: self.start_time.elapsed(),
-                    origin: origin.to_string(),
-                    message: formatted_message.to_string(),
-                })
-            })
+            .send(Message::Entry(Entry::new(
+                log_level,
+                &self.start_time,
+                origin,
+                formatted_message,
+            )))
             .expect("Send log message to log thread.");
     }
 }
@@ -0,0 +1,192 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Logger`] that sends every log message with structured fields to the systemd journal
+//! using the native journal protocol.
+
+use std::os::unix::net::UnixDatagram;
+use std::sync::OnceLock;
+
+use iceoryx2_log_types::Log;
+use iceoryx2_log_types::LogLevel;
+
+const JOURNAL_SOCKET: &str = "/run/systemd/journal/socket";
+
+static NODE_NAME: OnceLock<String> = OnceLock::new();
+
+/// Registers the node name that is attached to all subsequent log messages. Only the first
+/// non-empty node name of the process is used.
+pub(crate) fn set_node_name(name: &str) {
+    if !name.is_empty() {
+        let _ = NODE_NAME.set(name.to_string());
+    }
+}
+
+fn priority(log_level: LogLevel) -> &'static str {
+    // syslog priorities as defined in syslog(3)
+    match log_level {
+        LogLevel::Trace => "7",
+        LogLevel::Debug => "7",
+        LogLevel::Info => "6",
+        LogLevel::Warn => "4",
+        LogLevel::Error => "3",
+        LogLevel::Fatal => "2",
+    }
+}
+
+fn append_field(datagram: &mut Vec<u8>, name: &str, value: &str) {
+    datagram.extend_from_slice(name.as_bytes());
+    if value.contains('\n') {
+        // values with newlines are serialized in the binary format: the field name followed
+        // by a newline, the little-endian 64-bit length of the value and the value itself
+        datagram.push(b'\n');
+        datagram.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    } else {
+        datagram.push(b'=');
+    }
+    datagram.extend_from_slice(value.as_bytes());
+    datagram.push(b'\n');
+}
+
+pub struct Logger {
+    socket: Option<UnixDatagram>,
+    syslog_identifier: String,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger {
+    pub fn new() -> Self {
+        let syslog_identifier = std::env::current_exe()
+            .ok()
+            .and_then(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_else(|| "iceoryx2".to_string());
+
+        Self {
+            socket: UnixDatagram::unbound().ok(),
+            syslog_identifier,
+        }
+    }
+
+    fn datagram(&self, log_level: LogLevel, origin: &str, message: &str) -> Vec<u8> {
+        let mut datagram = Vec::with_capacity(message.len() + origin.len() + 128);
+        append_field(&mut datagram, "MESSAGE", message);
+        append_field(&mut datagram, "PRIORITY", priority(log_level));
+        append_field(&mut datagram, "SYSLOG_IDENTIFIER", &self.syslog_identifier);
+        append_field(&mut datagram, "IOX2_LOG_LEVEL", &format!("{log_level:?}"));
+        if !origin.is_empty() {
+            append_field(&mut datagram, "IOX2_ORIGIN", origin);
+        }
+        if let Some(node_name) = NODE_NAME.get() {
+            append_field(&mut datagram, "IOX2_NODE_NAME", node_name);
+        }
+        datagram
+    }
+}
+
+impl Log for Logger {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: core::fmt::Arguments,
+        formatted_message: core::fmt::Arguments,
+    ) {
+        let origin = origin.to_string();
+        let message = formatted_message.to_string();
+        let datagram = self.datagram(log_level, &origin, &message);
+
+        let is_sent = self
+            .socket
+            .as_ref()
+            .map(|socket| socket.send_to(&datagram, JOURNAL_SOCKET).is_ok())
+            .unwrap_or(false);
+
+        // when no journal is available, e.g. in a container, the message must not get lost
+        if !is_sent {
+            eprintln!("[{log_level:?}] {origin} | {message}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn sut() -> Logger {
+        Logger {
+            socket: None,
+            syslog_identifier: "test_app".to_string(),
+        }
+    }
+
+    #[test]
+    fn log_levels_are_mapped_to_syslog_priorities() {
+        assert_that!(priority(LogLevel::Trace), eq "7");
+        assert_that!(priority(LogLevel::Debug), eq "7");
+        assert_that!(priority(LogLevel::Info), eq "6");
+        assert_that!(priority(LogLevel::Warn), eq "4");
+        assert_that!(priority(LogLevel::Error), eq "3");
+        assert_that!(priority(LogLevel::Fatal), eq "2");
+    }
+
+    #[test]
+    fn single_line_field_is_serialized_as_key_value_pair() {
+        let mut datagram = vec![];
+        append_field(&mut datagram, "MESSAGE", "hello world");
+
+        assert_that!(datagram, eq b"MESSAGE=hello world\n".to_vec());
+    }
+
+    #[test]
+    fn multi_line_field_is_serialized_in_binary_format() {
+        let mut datagram = vec![];
+        append_field(&mut datagram, "MESSAGE", "hello\nworld");
+
+        let mut expected = b"MESSAGE\n".to_vec();
+        expected.extend_from_slice(&11u64.to_le_bytes());
+        expected.extend_from_slice(b"hello\nworld\n");
+        assert_that!(datagram, eq expected);
+    }
+
+    #[test]
+    fn datagram_contains_all_structured_fields() {
+        let datagram = sut().datagram(LogLevel::Warn, "some::origin", "the message");
+        let datagram = String::from_utf8(datagram).unwrap();
+
+        assert_that!(datagram.starts_with("MESSAGE=the message\nPRIORITY=4\nSYSLOG_IDENTIFIER=test_app\nIOX2_LOG_LEVEL=Warn\nIOX2_ORIGIN=some::origin\n"), eq true);
+    }
+
+    #[test]
+    fn datagram_without_origin_has_no_origin_field() {
+        let datagram = sut().datagram(LogLevel::Info, "", "the message");
+        let datagram = String::from_utf8(datagram).unwrap();
+
+        assert_that!(datagram.contains("IOX2_ORIGIN"), eq false);
+    }
+
+    #[test]
+    fn node_name_is_attached_to_datagram_once_set() {
+        set_node_name("");
+        set_node_name("my_node");
+        set_node_name("other_node");
+
+        let datagram = sut().datagram(LogLevel::Info, "origin", "the message");
+        let datagram = String::from_utf8(datagram).unwrap();
+
+        assert_that!(datagram.ends_with("IOX2_NODE_NAME=my_node\n"), eq true);
+    }
+}
@@ -43,10 +43,12 @@
 //!  * `buffer` - output log messages to a buffer
 //!  * `console` - output log messages to the console
 //!  * `file` - output log messages to the file
+//!  * `rotating_file` - output log messages to a file that is rotated by size and age
+//!  * `journald` - send log messages with structured fields to the systemd journal (Linux only)
 //!  * `log` - utilize the `log` crate to output log messages
 //!  * `tracing` - utilize the `tracing` crate to output log messages
 
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![warn(clippy::alloc_instead_of_core)]
 #![warn(clippy::std_instead_of_alloc)]
 #![warn(clippy::std_instead_of_core)]
@@ -57,10 +59,17 @@ use iceoryx2_log_types::Log;
 mod buffer;
 #[cfg(feature = "console")]
 mod console;
-#[cfg(feature = "file")]
-mod file;
+/// Logger that writes all log messages into a file.
+#[cfg(any(feature = "file", feature = "rotating_file", test))]
+pub mod file;
+#[cfg(any(feature = "journald", all(test, target_os = "linux")))]
+mod journald;
 #[cfg(feature = "log")]
 mod log;
+/// Logger that writes all log messages into a file that is rotated according to a
+/// configurable [`RotationPolicy`](rotating_file::RotationPolicy).
+#[cfg(any(feature = "rotating_file", test))]
+pub mod rotating_file;
 #[cfg(feature = "tracing")]
 mod tracing;
 
@@ -68,6 +77,15 @@ mod null;
 
 extern crate alloc;
 
+/// Attaches the name of the node to the log messages of loggers that support structured
+/// fields. Only the first node name of the process is used. Called by iceoryx2 when a node
+/// is created.
+#[doc(hidden)]
+pub fn __internal_set_node_name(_name: &str) {
+    #[cfg(feature = "journald")]
+    journald::set_node_name(_name);
+}
+
 #[cfg(feature = "console")]
 #[unsafe(no_mangle)]
 pub extern "Rust" fn __internal_default_logger() -> &'static dyn Log {
@@ -97,6 +115,28 @@ pub extern "Rust" fn __internal_default_logger() -> &'static dyn Log {
     }
 }
 
+#[cfg(feature = "rotating_file")]
+#[unsafe(no_mangle)]
+pub extern "Rust" fn __internal_default_logger() -> &'static dyn Log {
+    {
+        static ROTATING_FILE_LOGGER: std::sync::LazyLock<rotating_file::Logger> =
+            std::sync::LazyLock::new(|| {
+                rotating_file::Logger::new(rotating_file::RotationPolicy::default())
+            });
+        &*ROTATING_FILE_LOGGER
+    }
+}
+
+#[cfg(feature = "journald")]
+#[unsafe(no_mangle)]
+pub extern "Rust" fn __internal_default_logger() -> &'static dyn Log {
+    {
+        static JOURNALD_LOGGER: std::sync::LazyLock<journald::Logger> =
+            std::sync::LazyLock::new(journald::Logger::new);
+        &*JOURNALD_LOGGER
+    }
+}
+
 #[cfg(feature = "log")]
 #[unsafe(no_mangle)]
 pub extern "Rust" fn __internal_default_logger() -> &'static dyn Log {
@@ -119,6 +159,8 @@ pub extern "Rust" fn __internal_default_logger() -> &'static dyn Log {
     feature = "console",
     feature = "buffer",
     feature = "file",
+    feature = "rotating_file",
+    feature = "journald",
     feature = "log",
     feature = "tracing"
 )))]
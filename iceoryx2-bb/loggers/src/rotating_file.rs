@@ -0,0 +1,364 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    sync::mpsc::{Sender, channel},
+    thread::JoinHandle,
+    time::Instant,
+};
+
+use iceoryx2_log_types::Log;
+use iceoryx2_log_types::LogLevel;
+
+use crate::file::{Entry, Message};
+
+/// Defines when the log file is rotated and how many rotated files are kept.
+///
+/// The default policy writes into `iceoryx2.log`, rotates it when it exceeds 10 MiB or is
+/// older than 24 hours and keeps 5 rotated files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationPolicy {
+    /// The name of the active log file. Rotated files get the suffix `.1`, `.2`, ... where
+    /// `.1` is the most recent one.
+    pub file_name: String,
+    /// The log file is rotated before it would exceed this size in bytes.
+    pub max_file_size: u64,
+    /// The log file is rotated when it is older than this duration.
+    pub max_file_age: Duration,
+    /// The number of rotated files that are kept, older ones are removed.
+    pub retention_count: usize,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self {
+            file_name: "iceoryx2.log".into(),
+            max_file_size: 10 * 1024 * 1024,
+            max_file_age: Duration::from_secs(24 * 60 * 60),
+            retention_count: 5,
+        }
+    }
+}
+
+struct RotatingFile {
+    policy: RotationPolicy,
+    file: File,
+    file_size: u64,
+    opened_at: Instant,
+}
+
+impl RotatingFile {
+    fn open(policy: RotationPolicy) -> Self {
+        let file = Self::open_file(&policy.file_name);
+        let file_size = file.metadata().expect("Acquire size of log file.").len();
+
+        Self {
+            policy,
+            file,
+            file_size,
+            opened_at: Instant::now(),
+        }
+    }
+
+    fn open_file(file_name: &str) -> File {
+        OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(file_name)
+            .expect("Open log file for writing.")
+    }
+
+    fn rotated_file_name(&self, index: usize) -> String {
+        format!("{}.{index}", self.policy.file_name)
+    }
+
+    fn requires_rotation(&self, entry_size: u64) -> bool {
+        let exceeds_size =
+            self.file_size != 0 && self.file_size + entry_size > self.policy.max_file_size;
+        let exceeds_age = self.opened_at.elapsed() >= self.policy.max_file_age;
+
+        exceeds_size || exceeds_age
+    }
+
+    fn rotate(&mut self) {
+        // errors are ignored since rotated files may have been removed externally and a
+        // failed rotation must not stop the logging
+        if self.policy.retention_count == 0 {
+            let _ = std::fs::remove_file(&self.policy.file_name);
+        } else {
+            let _ = std::fs::remove_file(self.rotated_file_name(self.policy.retention_count));
+            for index in (1..self.policy.retention_count).rev() {
+                let _ = std::fs::rename(
+                    self.rotated_file_name(index),
+                    self.rotated_file_name(index + 1),
+                );
+            }
+            let _ = std::fs::rename(&self.policy.file_name, self.rotated_file_name(1));
+        }
+
+        self.file = Self::open_file(&self.policy.file_name);
+        self.file_size = 0;
+        self.opened_at = Instant::now();
+    }
+
+    fn write(&mut self, line: &str) {
+        let entry_size = line.len() as u64;
+        if self.requires_rotation(entry_size) {
+            self.rotate();
+        }
+
+        self.file
+            .write_all(line.as_bytes())
+            .expect("Writing log message into log file.");
+        self.file.sync_all().expect("Sync log file with disc.");
+        self.file_size += entry_size;
+    }
+}
+
+/// A logger that logs all messages into a file which is rotated according to a
+/// [`RotationPolicy`]. Like the file logger, it implements an active object pattern where a
+/// background thread writes the queued log messages and performs the rotation.
+///
+/// When the `rotating_file` feature is enabled, a [`Logger`] with the default
+/// [`RotationPolicy`] is the default logger. A logger with a custom policy can be stored in
+/// a static and registered with `iceoryx2_log::set_logger()`. All queued messages are
+/// written before the [`Logger`] is dropped.
+pub struct Logger {
+    sender: Sender<Message>,
+    start_time: Instant,
+    background_thread: Option<JoinHandle<()>>,
+}
+
+impl Logger {
+    /// Creates a new rotating file logger.
+    pub fn new(policy: RotationPolicy) -> Self {
+        let mut file = RotatingFile::open(policy);
+        let (sender, receiver) = channel();
+
+        let write_buffer_to_file = move || loop {
+            match receiver.recv() {
+                Ok(Message::Entry(entry)) => file.write(&format!("{entry:?}\n")),
+                Ok(Message::Stop) => break,
+                Err(e) => file.write(&format!(
+                    "[This should never happen!] Rotating File Logger got error: {e:?}\n"
+                )),
+            };
+        };
+
+        Self {
+            sender,
+            background_thread: Some(std::thread::spawn(write_buffer_to_file)),
+            start_time: Instant::now(),
+        }
+    }
+}
+
+impl Drop for Logger {
+    fn drop(&mut self) {
+        self.sender
+            .send(Message::Stop)
+            .expect("Send stop notification to background thread.");
+        if let Some(background_thread) = self.background_thread.take() {
+            background_thread
+                .join()
+                .expect("Join background thread of the log file.");
+        }
+    }
+}
+
+impl Log for Logger {
+    fn log(
+        &self,
+        log_level: LogLevel,
+        origin: core::fmt::Arguments,
+        formatted_message: core::fmt::Arguments,
+    ) {
+        self.sender
+            .send(Message::Entry(Entry::new(
+                log_level,
+                &self.start_time,
+                origin,
+                formatted_message,
+            )))
+            .expect("Send log message to log thread.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    const LINE: &str = "0123456789\n";
+
+    struct TestDirectory {
+        path: std::path::PathBuf,
+    }
+
+    impl TestDirectory {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "iox2_rotating_file_logger_{}_{name}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).expect("Create test directory.");
+            Self { path }
+        }
+
+        fn policy(
+            &self,
+            max_file_size: u64,
+            max_file_age: Duration,
+            retention_count: usize,
+        ) -> RotationPolicy {
+            RotationPolicy {
+                file_name: self.file("test.log"),
+                max_file_size,
+                max_file_age,
+                retention_count,
+            }
+        }
+
+        fn file(&self, name: &str) -> String {
+            self.path.join(name).to_string_lossy().to_string()
+        }
+
+        fn content(&self, name: &str) -> Option<String> {
+            std::fs::read_to_string(self.file(name)).ok()
+        }
+    }
+
+    impl Drop for TestDirectory {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn file_is_not_rotated_while_policy_is_satisfied() {
+        let directory = TestDirectory::new("not_rotated");
+        let mut sut = RotatingFile::open(directory.policy(100, Duration::from_secs(60), 2));
+
+        sut.write(LINE);
+        sut.write(LINE);
+
+        assert_that!(directory.content("test.log"), eq Some(format!("{LINE}{LINE}")));
+        assert_that!(directory.content("test.log.1"), is_none);
+    }
+
+    #[test]
+    fn file_is_rotated_when_it_would_exceed_max_file_size() {
+        let directory = TestDirectory::new("size");
+        let mut sut =
+            RotatingFile::open(directory.policy(2 * LINE.len() as u64, Duration::from_secs(60), 2));
+
+        sut.write("first\n");
+        sut.write(LINE);
+        sut.write(LINE);
+
+        assert_that!(directory.content("test.log.1"), eq Some(format!("first\n{LINE}")));
+        assert_that!(directory.content("test.log"), eq Some(LINE.to_string()));
+    }
+
+    #[test]
+    fn entry_larger_than_max_file_size_is_written_into_empty_file() {
+        let directory = TestDirectory::new("large_entry");
+        let mut sut = RotatingFile::open(directory.policy(4, Duration::from_secs(60), 2));
+
+        sut.write(LINE);
+
+        assert_that!(directory.content("test.log"), eq Some(LINE.to_string()));
+        assert_that!(directory.content("test.log.1"), is_none);
+    }
+
+    #[test]
+    fn file_is_rotated_when_it_exceeds_max_file_age() {
+        let directory = TestDirectory::new("age");
+        let mut sut = RotatingFile::open(directory.policy(100, Duration::from_millis(20), 2));
+
+        sut.write("first\n");
+        std::thread::sleep(Duration::from_millis(40));
+        sut.write("second\n");
+
+        assert_that!(directory.content("test.log.1"), eq Some("first\n".to_string()));
+        assert_that!(directory.content("test.log"), eq Some("second\n".to_string()));
+    }
+
+    #[test]
+    fn only_retention_count_rotated_files_are_kept() {
+        let directory = TestDirectory::new("retention");
+        let mut sut = RotatingFile::open(directory.policy(1, Duration::from_secs(60), 2));
+
+        for n in 0..4 {
+            sut.write(&format!("{n}\n"));
+        }
+
+        assert_that!(directory.content("test.log"), eq Some("3\n".to_string()));
+        assert_that!(directory.content("test.log.1"), eq Some("2\n".to_string()));
+        assert_that!(directory.content("test.log.2"), eq Some("1\n".to_string()));
+        assert_that!(directory.content("test.log.3"), is_none);
+    }
+
+    #[test]
+    fn zero_retention_count_discards_rotated_content() {
+        let directory = TestDirectory::new("no_retention");
+        let mut sut = RotatingFile::open(directory.policy(1, Duration::from_secs(60), 0));
+
+        sut.write("0\n");
+        sut.write("1\n");
+
+        assert_that!(directory.content("test.log"), eq Some("1\n".to_string()));
+        assert_that!(directory.content("test.log.1"), is_none);
+    }
+
+    #[test]
+    fn existing_file_size_is_considered_after_reopening() {
+        let directory = TestDirectory::new("reopen");
+        let policy = directory.policy(2 * LINE.len() as u64, Duration::from_secs(60), 1);
+        RotatingFile::open(policy.clone()).write(LINE);
+
+        let mut sut = RotatingFile::open(policy);
+        sut.write(LINE);
+        sut.write(LINE);
+
+        assert_that!(directory.content("test.log.1"), eq Some(format!("{LINE}{LINE}")));
+        assert_that!(directory.content("test.log"), eq Some(LINE.to_string()));
+    }
+
+    #[test]
+    fn logger_writes_all_messages_before_it_is_dropped() {
+        let directory = TestDirectory::new("logger");
+        let sut = Logger::new(directory.policy(1024, Duration::from_secs(60), 1));
+
+        sut.log(
+            LogLevel::Warn,
+            format_args!("origin"),
+            format_args!("hello"),
+        );
+        sut.log(
+            LogLevel::Error,
+            format_args!("origin"),
+            format_args!("world"),
+        );
+        drop(sut);
+
+        let content = directory.content("test.log").unwrap();
+        assert_that!(content.lines().count(), eq 2);
+        assert_that!(content.contains("log_level: Warn, origin: origin, message: hello"), eq true);
+        assert_that!(content.contains("log_level: Error, origin: origin, message: world"), eq true);
+    }
+}
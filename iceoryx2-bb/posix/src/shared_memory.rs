@@ -83,13 +83,14 @@ use iceoryx2_bb_system_types::path::*;
 use iceoryx2_log::{debug, error, fail, fatal_panic, trace, warn};
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::*;
 
 pub use crate::access_mode::AccessMode;
 pub use crate::creation_mode::CreationMode;
-use crate::file::{FileStatError, FileTruncateError};
+use crate::file::{FileBuilder, FileStatError, FileTruncateError};
 use crate::file_descriptor::*;
 use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
 use crate::memory_mapping::{
@@ -97,13 +98,17 @@ use crate::memory_mapping::{
 };
 pub use crate::permission::Permission;
 use crate::signal::SignalHandler;
-use crate::system_configuration::Limit;
+use crate::system_configuration::{Limit, SystemInfo};
+
+const THP_SHMEM_ENABLED: &str = "/sys/kernel/mm/transparent_hugepage/shmem_enabled";
+const PROC_MOUNTS: &str = "/proc/mounts";
 
 enum_gen! { SharedMemoryCreationError
   entry:
     SizeDoesNotFit,
     InsufficientMemory,
     InsufficientMemoryToBeMemoryLocked,
+    HugePagesNotSupported,
     UnsupportedSizeOfZero,
     InsufficientPermissions,
     MappedRegionLimitReached,
@@ -134,6 +139,8 @@ pub struct SharedMemoryBuilder {
     name: FileName,
     size: usize,
     is_memory_locked: bool,
+    use_huge_pages: bool,
+    prefault_memory: bool,
     has_ownership: bool,
     permission: Permission,
     creation_mode: Option<CreationMode>,
@@ -149,6 +156,8 @@ impl SharedMemoryBuilder {
             name: *name,
             size: 0,
             is_memory_locked: false,
+            use_huge_pages: false,
+            prefault_memory: false,
             permission: Permission::OWNER_READ_WRITE,
             access_mode: AccessMode::None,
             has_ownership: true,
@@ -167,12 +176,30 @@ impl SharedMemoryBuilder {
     }
 
     /// Locks the shared memory into the heap. If this is enabled swapping of the
-    /// created shared memory segment is no longer possible.
+    /// created or opened shared memory segment is no longer possible.
     pub fn is_memory_locked(mut self, value: bool) -> Self {
         self.is_memory_locked = value;
         self
     }
 
+    /// Advises the operating system to back the shared memory with transparent huge pages. It
+    /// reduces the TLB pressure when large memory regions are accessed. If the platform does not
+    /// support huge pages or the system would ignore the advice for shared memory, e.g. the
+    /// tmpfs of the shared memory directory is not mounted with `huge=advise`, the creation or
+    /// opening fails with [`SharedMemoryCreationError::HugePagesNotSupported`]. The kernel still
+    /// falls back to regular pages when no huge page is available.
+    pub fn use_huge_pages(mut self, value: bool) -> Self {
+        self.use_huge_pages = value;
+        self
+    }
+
+    /// Touches every page of the shared memory when it is mapped into the process space so
+    /// that no page fault occurs on the first access.
+    pub fn prefault_memory(mut self, value: bool) -> Self {
+        self.prefault_memory = value;
+        self
+    }
+
     /// Sets a base address for the shared memory which is enforced. When the shared memory
     /// could not mapped at the provided address the creation fails.
     pub fn enforce_base_address(mut self, value: u64) -> Self {
@@ -210,6 +237,141 @@ impl SharedMemoryBuilder {
         }
     }
 
+    fn read_system_file(path: &str) -> Option<Vec<u8>> {
+        let file = FileBuilder::new(&unsafe { FilePath::new_unchecked(path.as_bytes()) })
+            .has_ownership(false)
+            .open_existing(AccessMode::Read)
+            .ok()?;
+
+        // files in /proc and /sys report a size of zero, therefore the content is read until
+        // the end of the file is reached
+        let mut content = vec![];
+        let mut buffer = [0u8; 1024];
+        loop {
+            match file.read(&mut buffer) {
+                Ok(0) => return Some(content),
+                Ok(n) => content.extend_from_slice(&buffer[..n as usize]),
+                Err(_) => return None,
+            }
+        }
+    }
+
+    /// `madvise(MADV_HUGEPAGE)` succeeds even when the kernel ignores it for shared memory.
+    /// The advice is only honored when transparent huge pages are forced for shared memory or
+    /// when the tmpfs of the shared memory directory is mounted with a `huge=` option that
+    /// respects it.
+    fn system_provides_huge_pages_for_shared_memory() -> bool {
+        let shmem_enabled = match Self::read_system_file(THP_SHMEM_ENABLED) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let selected = shmem_enabled
+            .split(|c| *c == b'[')
+            .nth(1)
+            .and_then(|v| v.split(|c| *c == b']').next());
+        match selected {
+            Some(b"force") => return true,
+            Some(b"deny") | None => return false,
+            Some(_) => (),
+        }
+
+        let mounts = match Self::read_system_file(PROC_MOUNTS) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let shm_directory = iceoryx2_pal_configuration::SHARED_MEMORY_DIRECTORY;
+        let shm_directory = shm_directory
+            .strip_suffix(&[PATH_SEPARATOR])
+            .unwrap_or(shm_directory);
+
+        // the last mount entry of the directory hides all previous ones
+        let mut provides_huge_pages = false;
+        for line in mounts.split(|c| *c == b'\n') {
+            let mut entries = line.split(|c| *c == b' ').skip(1);
+            let (Some(mount_point), Some(fs_type), Some(options)) =
+                (entries.next(), entries.next(), entries.next())
+            else {
+                continue;
+            };
+
+            if mount_point != shm_directory {
+                continue;
+            }
+
+            provides_huge_pages = fs_type == b"tmpfs"
+                && options.split(|c| *c == b',').any(|option| {
+                    matches!(
+                        option,
+                        b"huge=always" | b"huge=within_size" | b"huge=advise"
+                    )
+                });
+        }
+
+        provides_huge_pages
+    }
+
+    fn apply_memory_properties(
+        shm: &mut SharedMemory,
+        config: &SharedMemoryBuilder,
+    ) -> Result<(), SharedMemoryCreationError> {
+        let msg = "Unable to apply the memory properties to the shared memory";
+
+        if config.use_huge_pages {
+            if !POSIX_SUPPORT_HUGE_PAGES {
+                fail!(from config, with SharedMemoryCreationError::HugePagesNotSupported,
+                    "{} since huge pages are not supported on this platform.", msg);
+            }
+
+            if !Self::system_provides_huge_pages_for_shared_memory() {
+                fail!(from config, with SharedMemoryCreationError::HugePagesNotSupported,
+                    "{} since the system does not back shared memory with transparent huge pages. Either mount the shared memory directory with \"huge=advise\" or set \"{}\" to \"force\".",
+                    msg, THP_SHMEM_ENABLED);
+            }
+
+            if unsafe {
+                posix::madvise(
+                    shm.memory_mapping.base_address_mut().cast(),
+                    shm.memory_mapping.size(),
+                    posix::MADV_HUGEPAGE,
+                )
+            } != 0
+            {
+                fail!(from config, with SharedMemoryCreationError::HugePagesNotSupported,
+                    "{} since the system does not support huge pages for shared memory ({}).", msg, Errno::get());
+            }
+        }
+
+        if config.is_memory_locked {
+            shm.memory_lock = Some(
+                fail!(from config, when unsafe { MemoryLock::new(shm.memory_mapping.base_address().cast(), shm.memory_mapping.size()) },
+                        "{} since the memory lock failed.", msg),
+            )
+        }
+
+        if config.prefault_memory {
+            let base_address = shm.memory_mapping.base_address();
+            let size = shm.memory_mapping.size();
+            let touch_pages = || {
+                for offset in (0..size).step_by(SystemInfo::PageSize.value().max(1)) {
+                    unsafe { core::ptr::read_volatile(base_address.add(offset)) };
+                }
+            };
+
+            if POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING {
+                if let Some(v) = SignalHandler::call_and_fetch(touch_pages) {
+                    fail!(from config, with SharedMemoryCreationError::InsufficientMemory,
+                        "{} since a signal {} was raised while prefaulting the memory. Is enough memory available on the system?", msg, v);
+                }
+            } else {
+                touch_pages();
+            }
+        }
+
+        Ok(())
+    }
+
     fn open(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to open shared memory";
         let fd = SharedMemory::shm_open(&self.name, &self)?;
@@ -226,13 +388,14 @@ impl SharedMemoryBuilder {
 
         let memory_mapping = Self::create_memory_mapping(fd, &self)?;
 
-        let shm = SharedMemory {
+        let mut shm = SharedMemory {
             name: self.name,
             has_ownership: AtomicBool::new(false),
             memory_lock: None,
             memory_mapping,
             mapping_offset: self.mapping_offset,
         };
+        Self::apply_memory_properties(&mut shm, &self)?;
 
         trace!(from shm, "opened");
         Ok(shm)
@@ -341,13 +504,14 @@ impl SharedMemoryCreationBuilder {
             self.config.size = actual_shm_size as _;
             let memory_mapping = SharedMemoryBuilder::create_memory_mapping(fd, &self.config)?;
 
-            let shm = SharedMemory {
+            let mut shm = SharedMemory {
                 name: self.config.name,
                 has_ownership: AtomicBool::new(self.config.has_ownership),
                 memory_lock: None,
                 memory_mapping,
                 mapping_offset: self.config.mapping_offset,
             };
+            SharedMemoryBuilder::apply_memory_properties(&mut shm, &self.config)?;
 
             trace!(from shm, "opened");
             return Ok(shm);
@@ -374,12 +538,7 @@ impl SharedMemoryCreationBuilder {
             mapping_offset: self.config.mapping_offset,
         };
 
-        if self.config.is_memory_locked {
-            shm.memory_lock = Some(
-                fail!(from self.config, when unsafe { MemoryLock::new(shm.memory_mapping.base_address().cast(), shm.memory_mapping.size()) },
-                        "{} since the memory lock failed.", msg),
-            )
-        }
+        SharedMemoryBuilder::apply_memory_properties(&mut shm, &self.config)?;
 
         if self.config.zero_memory {
             if POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING {
@@ -418,16 +577,17 @@ impl SharedMemoryCreationBuilder {
 pub struct SharedMemory {
     name: FileName,
     has_ownership: AtomicBool,
-    memory_mapping: MemoryMapping,
+    // the memory lock must be released before the memory is unmapped
     memory_lock: Option<MemoryLock>,
+    memory_mapping: MemoryMapping,
     mapping_offset: isize,
 }
 
 impl Abandonable for SharedMemory {
     unsafe fn abandon_in_place(mut this: NonNull<Self>) {
         let this = unsafe { this.as_mut() };
-        unsafe { core::ptr::drop_in_place(&mut this.memory_mapping) };
         unsafe { core::ptr::drop_in_place(&mut this.memory_lock) };
+        unsafe { core::ptr::drop_in_place(&mut this.memory_mapping) };
     }
 }
 
@@ -17,7 +17,9 @@ use iceoryx2_bb_posix::shared_memory::*;
 use iceoryx2_bb_posix::testing::generate_file_path;
 use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_bb_testing_macros::test;
-use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
+use iceoryx2_pal_posix::posix::{
+    POSIX_SUPPORT_HUGE_PAGES, POSIX_SUPPORT_MEMORY_LOCK, POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY,
+};
 
 #[test]
 pub fn create_and_open_works() {
@@ -293,3 +295,74 @@ pub fn abandoning_shared_memory_keeps_resources() {
 
     sut_open.acquire_ownership();
 }
+
+#[test]
+pub fn prefaulted_shared_memory_can_be_created_and_opened() {
+    let shm_name = generate_file_path().file_name();
+    let mut sut_create = SharedMemoryBuilder::new(&shm_name)
+        .prefault_memory(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024 * 1024)
+        .zero_memory(false)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .prefault_memory(true)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    sut_create.as_mut_slice()[0] = 73;
+    assert_that!(sut_open.as_slice()[0], eq 73);
+}
+
+#[test]
+pub fn memory_locked_shared_memory_can_be_opened() {
+    test_requires!(POSIX_SUPPORT_MEMORY_LOCK);
+
+    let shm_name = generate_file_path().file_name();
+    let _sut_create = SharedMemoryBuilder::new(&shm_name)
+        .is_memory_locked(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .is_memory_locked(true)
+        .open_existing(AccessMode::Read);
+
+    assert_that!(sut_open, is_ok);
+}
+
+#[test]
+pub fn huge_pages_fail_when_not_supported_by_the_platform() {
+    test_requires!(!POSIX_SUPPORT_HUGE_PAGES);
+
+    let shm_name = generate_file_path().file_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create();
+
+    assert_that!(sut.err(), eq Some(SharedMemoryCreationError::HugePagesNotSupported));
+}
+
+#[test]
+pub fn huge_pages_are_either_applied_or_reported_as_not_supported() {
+    let shm_name = generate_file_path().file_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .use_huge_pages(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(4 * 1024 * 1024)
+        .create();
+
+    match sut {
+        Ok(mut shm) => {
+            shm.as_mut_slice()[0] = 12;
+            assert_that!(shm.as_slice()[0], eq 12);
+        }
+        Err(e) => assert_that!(e, eq SharedMemoryCreationError::HugePagesNotSupported),
+    }
+}
@@ -20,6 +20,7 @@ pub type DefaultAllocator = PoolAllocator;
 pub mod resizable_shared_memory_trait {
     use alloc::vec;
     use core::alloc::Layout;
+    use core::time::Duration;
     use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
     use iceoryx2_bb_posix::file::AccessMode;
     use iceoryx2_cal::shm_allocator::AllocationError;
@@ -218,6 +219,54 @@ pub mod resizable_shared_memory_trait {
         assert_that!(sut_creator.number_of_active_segments(), eq 1);
     }
 
+    #[conformance_test]
+    pub fn memory_without_ownership_can_be_opened_after_it_went_out_of_scope<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_creator = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let test_value = 8127391283;
+        let ptr_1 = sut_creator.allocate(Layout::new::<u64>()).unwrap();
+        let ptr_2 = sut_creator.allocate(Layout::new::<u64>()).unwrap();
+        unsafe { (ptr_1.data_ptr as *mut u64).write(test_value) };
+        unsafe { (ptr_2.data_ptr as *mut u64).write(test_value + 1) };
+        assert_that!(sut_creator.number_of_active_segments(), eq 2);
+
+        sut_creator.release_ownership();
+        drop(sut_creator);
+
+        assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(true));
+        let sut_viewer = Sut::ViewBuilder::new(&storage_name)
+            .config(&config)
+            .open(AccessMode::Read)
+            .unwrap();
+
+        let ptr_view_1 = unsafe {
+            sut_viewer
+                .register_and_translate_offset(ptr_1.offset)
+                .unwrap() as *const u64
+        };
+        let ptr_view_2 = unsafe {
+            sut_viewer
+                .register_and_translate_offset(ptr_2.offset)
+                .unwrap() as *const u64
+        };
+        assert_that!(unsafe{ *ptr_view_1 }, eq test_value);
+        assert_that!(unsafe{ *ptr_view_2 }, eq test_value + 1);
+
+        assert_that!(unsafe { Sut::remove_cfg(&storage_name, &config) }, eq Ok(true));
+        assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(false));
+    }
+
     pub fn allocate_more_than_hinted_with_increasing_chunk_size_works<
         Shm: SharedMemory<DefaultAllocator>,
         Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
@@ -1217,4 +1266,251 @@ pub mod resizable_shared_memory_trait {
 
         assert_that!(sut.allocation_strategy(), eq AllocationStrategy::BestFit);
     }
+
+    #[conformance_test]
+    pub fn idle_resized_segment_is_replaced_with_initial_segment<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        let resized_segment_id = chunk.offset.segment_id();
+        assert_that!(sut.release_idle_segments(), eq false);
+
+        unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+        assert_that!(sut.release_idle_segments(), eq true);
+        assert_that!(sut.number_of_active_segments(), eq 1);
+        assert_that!(sut.release_idle_segments(), eq false);
+
+        let chunk = sut.allocate(Layout::new::<u8>()).unwrap();
+        assert_that!(chunk.offset.segment_id().value(), eq resized_segment_id.value() + 1);
+    }
+
+    #[conformance_test]
+    pub fn resized_segment_is_not_replaced_before_idle_timeout_has_passed<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .segment_idle_timeout(Duration::from_secs(3600))
+            .create()
+            .unwrap();
+
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        let resized_segment_id = chunk.offset.segment_id();
+        unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+
+        assert_that!(sut.release_idle_segments(), eq false);
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        assert_that!(chunk.offset.segment_id().value(), eq resized_segment_id.value());
+    }
+
+    #[conformance_test]
+    pub fn idle_segment_is_not_replaced_without_idle_timeout<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+
+        assert_that!(sut.release_idle_segments(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn idle_initial_segment_is_not_replaced<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u64>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+
+        assert_that!(sut.release_idle_segments(), eq false);
+        assert_that!(sut.allocate(Layout::new::<u64>()).unwrap().offset.segment_id().value(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn view_releases_unused_segment_that_was_replaced<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+        let sut_viewer = Sut::ViewBuilder::new(&storage_name)
+            .config(&config)
+            .open(AccessMode::ReadWrite)
+            .unwrap();
+
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        unsafe {
+            sut_viewer
+                .register_and_translate_offset(chunk.offset)
+                .unwrap()
+        };
+        unsafe { sut_viewer.unregister_offset(chunk.offset) };
+        assert_that!(sut_viewer.number_of_active_segments(), eq 1);
+
+        unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+        sut_viewer.release_unused_segments();
+        assert_that!(sut_viewer.number_of_active_segments(), eq 1);
+
+        assert_that!(sut.release_idle_segments(), eq true);
+        sut_viewer.release_unused_segments();
+        assert_that!(sut_viewer.number_of_active_segments(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn view_releases_segment_that_was_replaced_when_last_offset_is_unregistered<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+        let sut_viewer = Sut::ViewBuilder::new(&storage_name)
+            .config(&config)
+            .open(AccessMode::ReadWrite)
+            .unwrap();
+
+        let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+        unsafe {
+            sut_viewer
+                .register_and_translate_offset(chunk.offset)
+                .unwrap()
+        };
+        unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+        assert_that!(sut.release_idle_segments(), eq true);
+
+        assert_that!(sut_viewer.number_of_active_segments(), eq 1);
+        unsafe { sut_viewer.unregister_offset(chunk.offset) };
+        assert_that!(sut_viewer.number_of_active_segments(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn when_max_number_of_segments_is_exceeded_another_allocation_fails<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .max_number_of_segments(2)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+
+        let chunk_1 = sut.allocate(Layout::new::<u8>()).unwrap();
+        assert_that!(chunk_1.offset.segment_id().value(), eq 0);
+        let chunk_2 = sut.allocate(Layout::new::<u16>()).unwrap();
+        assert_that!(chunk_2.offset.segment_id().value(), eq 1);
+
+        let result = sut.allocate(Layout::new::<u64>());
+        assert_that!(result.err(), eq Some(AllocationError::OutOfMemory));
+    }
+
+    #[conformance_test]
+    pub fn segment_ids_of_released_idle_segments_are_reused<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        const MAX_NUMBER_OF_SEGMENTS: usize = 2;
+        const ITERATIONS: usize = MAX_NUMBER_OF_SEGMENTS * 4;
+        let storage_name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .max_number_of_segments(MAX_NUMBER_OF_SEGMENTS)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+        let sut_viewer = Sut::ViewBuilder::new(&storage_name)
+            .config(&config)
+            .open(AccessMode::ReadWrite)
+            .unwrap();
+
+        for n in 0..ITERATIONS {
+            let chunk = sut.allocate(Layout::new::<u64>()).unwrap();
+            assert_that!((chunk.offset.segment_id().value() as usize), lt MAX_NUMBER_OF_SEGMENTS);
+            unsafe { (chunk.data_ptr as *mut u64).write(n as u64) };
+
+            // the viewer must not read from an outdated mapping of a reused segment id
+            let received = unsafe {
+                sut_viewer
+                    .register_and_translate_offset(chunk.offset)
+                    .unwrap()
+            };
+            assert_that!(unsafe { *(received as *const u64) }, eq n as u64);
+            unsafe { sut_viewer.unregister_offset(chunk.offset) };
+
+            unsafe { sut.deallocate(chunk, Layout::new::<u64>()) };
+            assert_that!(sut.release_idle_segments(), eq true);
+            assert_that!(sut.number_of_active_segments(), eq 1);
+        }
+    }
 }
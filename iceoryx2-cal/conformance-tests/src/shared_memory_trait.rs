@@ -86,6 +86,34 @@ pub mod shared_memory_trait {
         assert_that!(sut_open.size(), ge DEFAULT_SIZE);
     }
 
+    #[conformance_test]
+    pub fn locked_and_prefaulted_memory_can_be_created_and_opened<
+        Sut: SharedMemory<DefaultAllocator>,
+    >() {
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+        let memory_properties = MemoryProperties {
+            is_memory_locked: true,
+            prefault_memory: true,
+            ..Default::default()
+        };
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .memory_properties(memory_properties)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name)
+            .config(&config)
+            .memory_properties(memory_properties)
+            .open(AccessMode::ReadWrite)
+            .unwrap();
+
+        assert_that!(sut_create.size(), ge DEFAULT_SIZE);
+        assert_that!(sut_open.size(), ge DEFAULT_SIZE);
+    }
+
     #[conformance_test]
     pub fn create_after_drop_works<Sut: SharedMemory<DefaultAllocator>>() {
         let name = generate_file_path().file_name();
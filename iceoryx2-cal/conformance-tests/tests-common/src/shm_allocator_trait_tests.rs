@@ -23,3 +23,9 @@ instantiate_conformance_tests_with_module!(
     iceoryx2_cal_conformance_tests::shm_allocator_trait,
     iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator
 );
+
+instantiate_conformance_tests_with_module!(
+    buddy_allocator,
+    iceoryx2_cal_conformance_tests::shm_allocator_trait,
+    iceoryx2_cal::shm_allocator::buddy_allocator::BuddyAllocator
+);
@@ -470,6 +470,10 @@ impl<'builder, T: Send + Sync + Debug + ZeroCopySend> DynamicStorageBuilder<'bui
         self
     }
 
+    fn memory_properties(self, _value: MemoryProperties) -> Self {
+        self
+    }
+
     fn create(mut self) -> Result<Storage<T>, DynamicStorageCreateError> {
         let shm = self.create_impl()?;
         self.init_impl(shm)
@@ -98,6 +98,8 @@ pub enum DynamicStorageCreateError {
     InsufficientPermissions,
     InitializationFailed,
     RootDirectoryCreationFailure,
+    HugePagesNotSupported,
+    MemoryLockFailed,
     InternalError,
 }
 
@@ -115,6 +117,8 @@ pub enum DynamicStorageOpenError {
     DoesNotExist,
     InitializationNotYetFinalized,
     VersionMismatch,
+    HugePagesNotSupported,
+    MemoryLockFailed,
     InternalError,
 }
 
@@ -133,6 +137,19 @@ enum_gen! {
     DynamicStorageCreateError
 }
 
+/// Defines how the operating system shall back the memory of a [`DynamicStorage`] or a
+/// [`crate::shared_memory::SharedMemory`].
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct MemoryProperties {
+    /// Backs the memory with huge pages to reduce the TLB pressure for large memory regions.
+    pub use_huge_pages: bool,
+    /// Locks the memory so that it cannot be swapped out.
+    pub is_memory_locked: bool,
+    /// Maps every page when the memory is created or opened so that no page fault occurs
+    /// on the first access.
+    pub prefault_memory: bool,
+}
+
 /// Builder for the [`DynamicStorage`]. T is not allowed to implement the [`Drop`] trait.
 pub trait DynamicStorageBuilder<'builder, T: Send + Sync + ZeroCopySend, D: DynamicStorage<T>>:
     Debug + Sized + NamedConceptBuilder<D>
@@ -149,6 +166,11 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync + ZeroCopySend, D: Dyna
     /// the already initialized [`DynamicStorage`] with the full size is used.
     fn supplementary_size(self, value: usize) -> Self;
 
+    /// Defines how the operating system shall back the memory when the [`DynamicStorage`] is
+    /// created or opened. Concepts that are not based on shared memory of the operating
+    /// system ignore the setting. By default, no property is enabled.
+    fn memory_properties(self, value: MemoryProperties) -> Self;
+
     /// The timeout defines how long the [`DynamicStorageBuilder`] should wait for
     /// [`DynamicStorageBuilder::create()`]
     /// to finalize the initialization. This is required when the [`DynamicStorage`] is
@@ -98,6 +98,7 @@ pub struct Builder<'builder, T: Send + Sync + Debug + ZeroCopySend> {
     has_ownership: bool,
     config: Configuration<T>,
     timeout: Duration,
+    memory_properties: MemoryProperties,
     initializer: Initializer<'builder, T>,
     _phantom_data: PhantomData<T>,
 }
@@ -193,6 +194,7 @@ impl<T: Send + Sync + Debug + ZeroCopySend> NamedConceptBuilder<Storage<T>> for
             config: Configuration::default(),
             enable_global_access: false,
             timeout: Duration::ZERO,
+            memory_properties: MemoryProperties::default(),
             initializer: Initializer::new(|_, _| false),
             _phantom_data: PhantomData,
         }
@@ -217,7 +219,12 @@ impl<T: Send + Sync + Debug + ZeroCopySend> Builder<'_, T> {
 
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(access_mode) {
+            match SharedMemoryBuilder::new(&full_name)
+                .is_memory_locked(self.memory_properties.is_memory_locked)
+                .use_huge_pages(self.memory_properties.use_huge_pages)
+                .prefault_memory(self.memory_properties.prefault_memory)
+                .open_existing(access_mode)
+            {
                 Ok(v) => {
                     let permissions = match v.permission() {
                         Ok(p) => p,
@@ -251,6 +258,14 @@ impl<T: Send + Sync + Debug + ZeroCopySend> Builder<'_, T> {
                 Err(SharedMemoryCreationError::MemoryMappingCreationError(
                     MemoryMappingCreationError::MappingSizeIsZero,
                 )) => (),
+                Err(SharedMemoryCreationError::HugePagesNotSupported) => {
+                    fail!(from self, with DynamicStorageOpenError::HugePagesNotSupported,
+                        "{} since the underlying shared memory cannot be backed by huge pages.", msg);
+                }
+                Err(SharedMemoryCreationError::MemoryLockCreationError(e)) => {
+                    fail!(from self, with DynamicStorageOpenError::MemoryLockFailed,
+                        "{} since the underlying shared memory could not be locked ({:?}).", msg, e);
+                }
                 Err(e) => {
                     fail!(from self, with DynamicStorageOpenError::InternalError, "{} since the underlying shared memory could not be opened. Error: {:?}", msg, e);
                 }
@@ -307,6 +322,9 @@ impl<T: Send + Sync + Debug + ZeroCopySend> Builder<'_, T> {
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
         let shm = match SharedMemoryBuilder::new(&full_name)
+            .is_memory_locked(self.memory_properties.is_memory_locked)
+            .use_huge_pages(self.memory_properties.use_huge_pages)
+            .prefault_memory(self.memory_properties.prefault_memory)
             .creation_mode(CreationMode::CreateExclusive)
             // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
             // therefore we do not have to add additional alignment space for T
@@ -325,6 +343,14 @@ impl<T: Send + Sync + Debug + ZeroCopySend> Builder<'_, T> {
                 fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
                     "{} due to insufficient permissions.", msg);
             }
+            Err(SharedMemoryCreationError::HugePagesNotSupported) => {
+                fail!(from self, with DynamicStorageCreateError::HugePagesNotSupported,
+                    "{} since the underlying shared memory cannot be backed by huge pages.", msg);
+            }
+            Err(SharedMemoryCreationError::MemoryLockCreationError(e)) => {
+                fail!(from self, with DynamicStorageCreateError::MemoryLockFailed,
+                    "{} since the underlying shared memory could not be locked ({:?}).", msg, e);
+            }
             Err(_) => {
                 fail!(from self, with DynamicStorageCreateError::InternalError,
                     "{} since the underlying shared memory could not be created.", msg);
@@ -427,6 +453,11 @@ impl<'builder, T: Send + Sync + Debug + ZeroCopySend> DynamicStorageBuilder<'bui
         self
     }
 
+    fn memory_properties(mut self, value: MemoryProperties) -> Self {
+        self.memory_properties = value;
+        self
+    }
+
     fn create(mut self) -> Result<Storage<T>, DynamicStorageCreateError> {
         let shm = self.create_impl()?;
         self.init_impl(shm)
@@ -485,6 +485,10 @@ impl<'builder, T: Send + Sync + Debug + 'static + ZeroCopySend>
         self
     }
 
+    fn memory_properties(self, _value: MemoryProperties) -> Self {
+        self
+    }
+
     fn open(self, _access_mode: AccessMode) -> Result<Storage<T>, DynamicStorageOpenError> {
         let msg = "Failed to open dynamic storage";
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
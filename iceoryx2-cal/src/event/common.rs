@@ -580,7 +580,11 @@ impl<
                 fail!(from self, with NotifierOpenError::VersionMismatch,
                     "{msg} since the iceoryx2 version of the listener does not match.");
             }
-            Err(DynamicStorageOpenError::InternalError) => {
+            Err(
+                DynamicStorageOpenError::InternalError
+                | DynamicStorageOpenError::HugePagesNotSupported
+                | DynamicStorageOpenError::MemoryLockFailed,
+            ) => {
                 fail!(from self, with NotifierOpenError::InternalFailure,
                     "{msg} due to an internal failure.");
             }
@@ -708,7 +712,11 @@ impl<
                 fail!(from self, with ListenerCreateError::InternalFailure,
                     "{msg} since the initialization of the underlying waiter failed.");
             }
-            Err(DynamicStorageCreateError::InternalError) => {
+            Err(
+                DynamicStorageCreateError::InternalError
+                | DynamicStorageCreateError::HugePagesNotSupported
+                | DynamicStorageCreateError::MemoryLockFailed,
+            ) => {
                 fail!(from self, with ListenerCreateError::InternalFailure,
                     "{msg} due to an internal error.");
             }
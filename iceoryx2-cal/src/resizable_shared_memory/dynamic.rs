@@ -31,17 +31,18 @@ use iceoryx2_bb_elementary_traits::allocator::{
     Allocate, AllocationError, AllocationGrowError, ContentPlacement, Deallocate, Grow,
 };
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_bb_posix::file::AccessMode;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_log::fatal_panic;
 use iceoryx2_log::{fail, warn};
 
+use crate::shared_memory::{MemoryProperties, SegmentId, SharedMemoryForPoolAllocator, ShmPointer};
 use crate::shared_memory::{
     PointerOffset, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError,
     SharedMemoryOpenError, ShmAllocator,
 };
-use crate::shared_memory::{SegmentId, SharedMemoryForPoolAllocator, ShmPointer};
 use crate::shm_allocator::pool_allocator::PoolAllocator;
 
 use super::{
@@ -65,11 +66,35 @@ struct SharedState {
     max_chunk_alignment_hint: AtomicU64,
 }
 
+#[repr(C)]
+#[derive(Debug)]
+struct ManagementState {
+    current_segment_id: AtomicU64,
+    // incremented whenever the current segment is replaced, since the ids of released
+    // segments are reused, the views require it to detect that a mapped segment id refers
+    // to a newer segment
+    segment_generation: AtomicU64,
+}
+
+impl ManagementState {
+    fn from_segment<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>>(
+        mgmt_segment: &Shm,
+    ) -> &Self {
+        let address = mgmt_segment
+            .payload_start_address()
+            .next_multiple_of(core::mem::align_of::<Self>());
+        unsafe { &*(address as *const Self) }
+    }
+}
+
 #[derive(Debug)]
 struct MemoryConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     allocator_config_hint: Allocator::Configuration,
+    max_number_of_segments: usize,
+    segment_idle_timeout: Option<Duration>,
+    memory_properties: MemoryProperties,
 }
 
 #[derive(Debug)]
@@ -77,6 +102,7 @@ struct ViewConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     shm_builder_timeout: Duration,
+    memory_properties: MemoryProperties,
     _data: PhantomData<Allocator>,
 }
 
@@ -86,6 +112,10 @@ struct InternalState<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     shared_state: SharedState,
     shared_memory_map: SlotMap<ShmEntry<Allocator, Shm>>,
     current_idx: SlotMapKey,
+    initial_allocator_config: Allocator::Configuration,
+    initial_payload_size: usize,
+    is_current_segment_resized: bool,
+    idle_since: Option<Time>,
 }
 
 impl<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> Abandonable
@@ -156,6 +186,7 @@ where
                 base_name: *name,
                 shm: Shm::Configuration::default(),
                 shm_builder_timeout: Duration::ZERO,
+                memory_properties: MemoryProperties::default(),
                 _data: PhantomData,
             },
         }
@@ -182,6 +213,11 @@ where
         self
     }
 
+    fn memory_properties(mut self, value: MemoryProperties) -> Self {
+        self.config.memory_properties = value;
+        self
+    }
+
     fn open(
         self,
         access_mode: AccessMode,
@@ -204,6 +240,7 @@ where
             mgmt_segment,
             shared_memory_map: UnsafeCell::new(shared_memory_map),
             current_idx: AtomicUsize::new(INVALID_KEY),
+            current_generation: AtomicU64::new(0),
             access_mode,
             _data: PhantomData,
         })
@@ -230,6 +267,9 @@ where
                 base_name: *name,
                 allocator_config_hint: Allocator::Configuration::default(),
                 shm: Shm::Configuration::default(),
+                max_number_of_segments: MAX_NUMBER_OF_REALLOCATIONS,
+                segment_idle_timeout: None,
+                memory_properties: MemoryProperties::default(),
             },
             shared_state: SharedState {
                 allocation_strategy: AllocationStrategy::default(),
@@ -275,49 +315,77 @@ where
         self
     }
 
+    fn max_number_of_segments(mut self, value: usize) -> Self {
+        self.config.max_number_of_segments = value.clamp(1, MAX_NUMBER_OF_REALLOCATIONS);
+        self
+    }
+
+    fn segment_idle_timeout(mut self, value: Duration) -> Self {
+        self.config.segment_idle_timeout = Some(value);
+        self
+    }
+
+    fn memory_properties(mut self, value: MemoryProperties) -> Self {
+        self.config.memory_properties = value;
+        self
+    }
+
     fn create(mut self) -> Result<DynamicMemory<Allocator, Shm>, SharedMemoryCreateError> {
         let msg = "Unable to create ResizableSharedMemory";
         let origin = format!("{self:?}");
 
-        let hint = Allocator::initial_setup_hint(Layout::new::<u8>(), 1);
+        let hint = Allocator::initial_setup_hint(Layout::new::<ManagementState>(), 1);
         let adjusted_name =
             DynamicMemory::<Allocator, Shm>::managment_segment_name(&self.config.base_name);
         let mgmt_segment = fail!(from origin, when Shm::Builder::new(&adjusted_name)
-                                                    .size(hint.payload_size)
+                                                    .size(hint.payload_size + core::mem::align_of::<ManagementState>() - 1)
                                                     .config(&self.config.shm)
                                                     .has_ownership(true)
                                                     .create(&hint.config),
                             "{msg} since the management segment could not be created.");
 
+        let max_chunk_layout = unsafe {
+            Layout::from_size_align_unchecked(
+                self.shared_state
+                    .max_chunk_size_hint
+                    .load(Ordering::Relaxed) as usize,
+                self.shared_state
+                    .max_chunk_alignment_hint
+                    .load(Ordering::Relaxed) as usize,
+            )
+        };
         let hint = Allocator::initial_setup_hint(
-            unsafe {
-                Layout::from_size_align_unchecked(
-                    self.shared_state
-                        .max_chunk_size_hint
-                        .load(Ordering::Relaxed) as usize,
-                    self.shared_state
-                        .max_chunk_alignment_hint
-                        .load(Ordering::Relaxed) as usize,
-                )
-            },
+            max_chunk_layout,
             self.shared_state
                 .max_number_of_chunks_hint
                 .load(Ordering::Relaxed) as usize,
         );
         self.config.allocator_config_hint = hint.config;
+        // the payload start is aligned to the chunk alignment, without the additional memory the
+        // segment would lose a chunk
+        let payload_size = hint.payload_size + max_chunk_layout.align() - 1;
 
-        let shm = fail!(from origin, when DynamicMemory::create_segment(&self.config, SegmentId::new(0), hint.payload_size),
+        let shm = fail!(from origin, when DynamicMemory::create_segment(&self.config, SegmentId::new(0), payload_size),
             "Unable to create ResizableSharedMemory since the underlying shared memory could not be created.");
         let mut shared_memory_map = SlotMap::new(MAX_NUMBER_OF_REALLOCATIONS);
         let current_idx = fatal_panic!(from origin, when shared_memory_map.insert(ShmEntry::new(shm)).ok_or(""),
                 "This should never happen! {msg} since the newly constructed SlotMap does not have space for one insert.");
 
+        let mgmt = ManagementState::from_segment(&mgmt_segment);
+        mgmt.segment_generation.store(0, Ordering::Relaxed);
+        mgmt.current_segment_id
+            .store(current_idx.value() as u64, Ordering::Release);
+
         Ok(DynamicMemory {
             state: UnsafeCell::new(InternalState {
+                initial_allocator_config: self.config.allocator_config_hint,
+                initial_payload_size: payload_size,
                 builder_config: self.config,
                 shared_memory_map,
                 current_idx,
                 shared_state: self.shared_state,
+                is_current_segment_resized: false,
+                idle_since: None,
             }),
             mgmt_segment,
             _data: PhantomData,
@@ -331,6 +399,7 @@ pub struct DynamicView<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     mgmt_segment: Shm,
     shared_memory_map: UnsafeCell<SlotMap<ShmEntry<Allocator, Shm>>>,
     current_idx: AtomicUsize,
+    current_generation: AtomicU64,
     access_mode: AccessMode,
     _data: PhantomData<Allocator>,
 }
@@ -363,6 +432,24 @@ where
             shared_memory_map.remove(old_key);
         }
     }
+
+    fn is_replaced_segment(&self, key: SlotMapKey) -> bool {
+        ManagementState::from_segment(&self.mgmt_segment)
+            .current_segment_id
+            .load(Ordering::Acquire)
+            != key.value() as u64
+    }
+
+    // the current segment stays mapped while it has no registered offsets, when the segment
+    // was released in the meantime its id can be reused by a new segment
+    fn is_outdated_mapping(&self, key: SlotMapKey, entry: &ShmEntry<Allocator, Shm>) -> bool {
+        entry.chunk_count.load(Ordering::Relaxed) == 0
+            && self.current_idx.load(Ordering::Relaxed) == key.value()
+            && ManagementState::from_segment(&self.mgmt_segment)
+                .segment_generation
+                .load(Ordering::Acquire)
+                != self.current_generation.load(Ordering::Relaxed)
+    }
 }
 
 impl<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>>
@@ -380,8 +467,20 @@ where
         let key = SlotMapKey::new(segment_id.value() as usize);
         let shared_memory_map = unsafe { &mut *self.shared_memory_map.get() };
 
+        if let Some(entry) = shared_memory_map.get(key)
+            && self.is_outdated_mapping(key, entry)
+        {
+            shared_memory_map.remove(key);
+            self.current_idx.store(INVALID_KEY, Ordering::Relaxed);
+        }
+
         let payload_start_address = match shared_memory_map.get(key) {
             None => {
+                // acquired before the segment is opened, a concurrent replacement leads at most
+                // to an unnecessary remapping
+                let generation = ManagementState::from_segment(&self.mgmt_segment)
+                    .segment_generation
+                    .load(Ordering::Acquire);
                 let shm = fail!(from self,
                                 when DynamicMemory::open_segment(&self.view_config, segment_id, self.access_mode),
                                 "{msg} {:?} since the corresponding shared memory segment could not be opened.", offset);
@@ -393,6 +492,7 @@ where
                     shared_memory_map,
                     self.current_idx.swap(key.value(), Ordering::Relaxed),
                 );
+                self.current_generation.store(generation, Ordering::Relaxed);
 
                 payload_start_address
             }
@@ -413,10 +513,13 @@ where
         match shared_memory_map.get(key) {
             Some(entry) => {
                 let state = entry.unregister_offset();
-                if state == ShmEntryState::Empty
-                    && self.current_idx.load(Ordering::Relaxed) != key.value()
-                {
-                    shared_memory_map.remove(key);
+                if state == ShmEntryState::Empty {
+                    if self.current_idx.load(Ordering::Relaxed) != key.value() {
+                        shared_memory_map.remove(key);
+                    } else if self.is_replaced_segment(key) {
+                        shared_memory_map.remove(key);
+                        self.current_idx.store(INVALID_KEY, Ordering::Relaxed);
+                    }
                 }
             }
             None => {
@@ -426,6 +529,23 @@ where
         }
     }
 
+    fn release_unused_segments(&self) {
+        let current_idx = self.current_idx.load(Ordering::Relaxed);
+        if current_idx == INVALID_KEY {
+            return;
+        }
+
+        let key = SlotMapKey::new(current_idx);
+        let shared_memory_map = unsafe { &mut *self.shared_memory_map.get() };
+        if let Some(entry) = shared_memory_map.get(key)
+            && entry.chunk_count.load(Ordering::Relaxed) == 0
+            && self.is_replaced_segment(key)
+        {
+            shared_memory_map.remove(key);
+            self.current_idx.store(INVALID_KEY, Ordering::Relaxed);
+        }
+    }
+
     fn number_of_active_segments(&self) -> usize {
         let shared_memory_map = unsafe { &mut *self.shared_memory_map.get() };
         shared_memory_map.len()
@@ -621,6 +741,7 @@ where
         Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(true)
             .size(payload_size)
+            .memory_properties(config.memory_properties)
             .create(&config.allocator_config_hint)
     }
 
@@ -632,6 +753,7 @@ where
         Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(false)
             .timeout(config.shm_builder_timeout)
+            .memory_properties(config.memory_properties)
             .open(access_mode)
     }
 
@@ -653,20 +775,20 @@ where
         let adjusted_segment_setup = shm
             .allocator()
             .resize_hint(layout, state.shared_state.allocation_strategy);
-        let new_number_of_reallocations = state.current_idx.value() + 1;
-        let segment_id = if new_number_of_reallocations < MAX_NUMBER_OF_REALLOCATIONS {
-            SlotMapKey::new(new_number_of_reallocations)
-        } else {
-            fail!(from self, with AllocationError::OutOfMemory,
-                "{msg} {:?} since it would exceed the maximum amount of reallocations of {}. With a better configuration hint, this issue can be avoided.",
-                layout, Self::max_number_of_reallocations());
+        let segment_id = match Self::next_free_segment_id(state) {
+            Some(segment_id) => segment_id,
+            None => {
+                fail!(from self, with AllocationError::OutOfMemory,
+                    "{msg} {:?} since all {} segments are in use. With a better configuration hint, this issue can be avoided.",
+                    layout, state.builder_config.max_number_of_segments);
+            }
         };
 
         state.builder_config.allocator_config_hint = adjusted_segment_setup.config;
         let shm = match Self::create_segment(
             &state.builder_config,
             SegmentId::new(segment_id.value() as u8),
-            adjusted_segment_setup.payload_size,
+            adjusted_segment_setup.payload_size + layout.align().max(shm.max_alignment()) - 1,
         ) {
             Ok(shm) => shm,
             Err(e) => {
@@ -688,12 +810,47 @@ where
             }
         }
 
+        self.replace_current_segment(segment_id, shm);
+        state.is_current_segment_resized = true;
+
+        Ok(())
+    }
+
+    // the ids of released segments are reused, otherwise every release of an idle segment
+    // would use up one of the limited segment ids
+    fn next_free_segment_id(state: &InternalState<Allocator, Shm>) -> Option<SlotMapKey> {
+        let max_number_of_segments = state.builder_config.max_number_of_segments;
+        (1..max_number_of_segments)
+            .map(|n| SlotMapKey::new((state.current_idx.value() + n) % max_number_of_segments))
+            .find(|key| state.shared_memory_map.get(*key).is_none())
+    }
+
+    fn replace_current_segment(&self, segment_id: SlotMapKey, shm: Shm) {
+        let state = self.state_mut();
         state
             .shared_memory_map
             .insert_at(segment_id, ShmEntry::new(shm));
         state.current_idx = segment_id;
+        state.idle_since = None;
+        let mgmt = ManagementState::from_segment(&self.mgmt_segment);
+        mgmt.segment_generation.fetch_add(1, Ordering::Release);
+        mgmt.current_segment_id
+            .store(segment_id.value() as u64, Ordering::Release);
+    }
 
-        Ok(())
+    fn has_exceeded_idle_timeout(state: &InternalState<Allocator, Shm>) -> bool {
+        match (state.builder_config.segment_idle_timeout, &state.idle_since) {
+            (Some(timeout), Some(idle_since)) => match idle_since.elapsed() {
+                Ok(idle_time) => timeout <= idle_time,
+                Err(_) => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn register_offset_in_current_segment(&self, entry: &ShmEntry<Allocator, Shm>) {
+        entry.register_offset();
+        self.state_mut().idle_since = None;
     }
 
     fn handle_reallocation(
@@ -723,10 +880,12 @@ where
         match state.shared_memory_map.get(segment_id) {
             Some(entry) => {
                 deallocation_call(entry);
-                if entry.unregister_offset() == ShmEntryState::Empty
-                    && segment_id != state.current_idx
-                {
-                    state.shared_memory_map.remove(segment_id);
+                if entry.unregister_offset() == ShmEntryState::Empty {
+                    if segment_id != state.current_idx {
+                        state.shared_memory_map.remove(segment_id);
+                    } else if state.builder_config.segment_idle_timeout.is_some() {
+                        state.idle_since = Time::now().ok();
+                    }
                 }
             }
             None => fatal_panic!(from self,
@@ -787,6 +946,46 @@ where
     fn allocation_strategy(&self) -> AllocationStrategy {
         self.state().shared_state.allocation_strategy
     }
+
+    fn release_idle_segments(&self) -> bool {
+        let state = self.state_mut();
+        if !state.is_current_segment_resized || !Self::has_exceeded_idle_timeout(state) {
+            return false;
+        }
+
+        // the id of the released segment becomes available for the next resize
+        let segment_id = match Self::next_free_segment_id(state) {
+            Some(segment_id) => segment_id,
+            None => return false,
+        };
+
+        state.builder_config.allocator_config_hint = state.initial_allocator_config;
+        let shm = match Self::create_segment(
+            &state.builder_config,
+            SegmentId::new(segment_id.value() as u8),
+            state.initial_payload_size,
+        ) {
+            Ok(shm) => shm,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to release the idle segment since the replacement segment could not be created. [{e:?}]");
+                return false;
+            }
+        };
+
+        state.shared_memory_map.remove(state.current_idx);
+        self.replace_current_segment(segment_id, shm);
+        state.is_current_segment_resized = false;
+
+        true
+    }
+
+    fn release_ownership(&self) {
+        self.mgmt_segment.release_ownership();
+        for (_, entry) in self.state().shared_memory_map.iter() {
+            entry.shm.release_ownership();
+        }
+    }
 }
 
 impl<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> Allocate<ShmPointer>
@@ -796,13 +995,14 @@ where
 {
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, AllocationError> {
         let msg = "Unable to allocate memory";
+        self.release_idle_segments();
         let state = self.state_mut();
 
         loop {
             let current_segment = self.current_segment(msg);
             match current_segment.shm.allocate(layout) {
                 Ok(mut ptr) => {
-                    current_segment.register_offset();
+                    self.register_offset_in_current_segment(current_segment);
                     ptr.offset
                         .set_segment_id(SegmentId::new(state.current_idx.value() as u8));
                     return Ok(ptr);
@@ -860,7 +1060,7 @@ where
 
         let new_pointer = match resized_segment.shm.allocate(new_layout) {
             Ok(mut ptr) => {
-                resized_segment.register_offset();
+                self.register_offset_in_current_segment(resized_segment);
                 ptr.offset
                     .set_segment_id(SegmentId::new(state.current_idx.value() as u8));
                 ptr
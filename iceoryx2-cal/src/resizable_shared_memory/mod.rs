@@ -104,7 +104,8 @@ use iceoryx2_bb_posix::file::AccessMode;
 
 use crate::named_concept::*;
 use crate::shared_memory::{
-    SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError, ShmPointer,
+    MemoryProperties, SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError,
+    ShmPointer,
 };
 use crate::shm_allocator::{PointerOffset, ShmAllocator};
 
@@ -125,6 +126,10 @@ pub trait ResizableSharedMemoryViewBuilder<
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines the [`MemoryProperties`] that are applied to every [`SharedMemory`] segment that
+    /// is mapped into the process space. By default, no property is enabled.
+    fn memory_properties(self, value: MemoryProperties) -> Self;
+
     /// Opens already existing [`SharedMemory`]. If it does not exist or the initialization is not
     /// yet finished the method will fail.
     fn open(self, access_mode: AccessMode) -> Result<ResizableShmView, SharedMemoryOpenError>;
@@ -153,6 +158,21 @@ pub trait ResizableSharedMemoryBuilder<
     /// acquired.
     fn allocation_strategy(self, value: AllocationStrategy) -> Self;
 
+    /// Defines how many [`SharedMemory`] segments can exist at the same time. The ids of
+    /// released segments are reused. It is capped at
+    /// [`ResizableSharedMemory::max_number_of_reallocations()`], which is also the default.
+    fn max_number_of_segments(self, value: usize) -> Self;
+
+    /// Defines how long the current [`SharedMemory`] segment must be unused before it is
+    /// replaced by a segment that is sized according to the initial hints, see
+    /// [`ResizableSharedMemory::release_idle_segments()`]. The replacement happens only when the
+    /// current segment was acquired by a resize. By default, segments are never shrunk.
+    fn segment_idle_timeout(self, value: Duration) -> Self;
+
+    /// Defines the [`MemoryProperties`] of every [`SharedMemory`] segment that stores the
+    /// payload. By default, no property is enabled.
+    fn memory_properties(self, value: MemoryProperties) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(self) -> Result<ResizableShm, SharedMemoryCreateError>;
 }
@@ -184,6 +204,10 @@ pub trait ResizableSharedMemoryView<Allocator: ShmAllocator, Shm: SharedMemory<A
     ///  * This function must be called at most once for any received [`PointerOffset`]
     unsafe fn unregister_offset(&self, offset: PointerOffset);
 
+    /// Unmaps all [`SharedMemory`] segments that have no registered [`PointerOffset`] and that
+    /// were replaced by a newer segment of the [`ResizableSharedMemory`].
+    fn release_unused_segments(&self);
+
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
 }
@@ -223,6 +247,21 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
 
     /// Returns the configured [`AllocationStrategy`].
     fn allocation_strategy(&self) -> AllocationStrategy;
+
+    /// Replaces the current [`SharedMemory`] segment with a segment that is sized according to
+    /// the initial hints when it was acquired by a resize and was not used for longer than the
+    /// timeout provided with [`ResizableSharedMemoryBuilder::segment_idle_timeout()`]. Returns
+    /// [`true`] when the segment was replaced.
+    ///
+    /// The replacement segment requires a free segment id. When all segment ids of
+    /// [`ResizableSharedMemoryBuilder::max_number_of_segments()`] are in use, the current
+    /// segment is kept. The id of the released segment is reused by later resizes.
+    fn release_idle_segments(&self) -> bool;
+
+    /// Releases the ownership of the [`ResizableSharedMemory`] and all of its current
+    /// [`SharedMemory`] segments meaning when it goes out of scope the underlying resources
+    /// will not be removed.
+    fn release_ownership(&self);
 }
 
 pub trait ResizableSharedMemoryForPoolAllocator<Shm: SharedMemory<PoolAllocator>>:
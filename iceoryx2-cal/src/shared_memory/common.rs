@@ -35,6 +35,7 @@ use crate::static_storage::file::{
 pub mod details {
     use alloc::vec::Vec;
 
+    use buddy_allocator::BuddyAllocator;
     use iceoryx2_bb_derive_macros::ZeroCopySend;
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
     use pool_allocator::PoolAllocator;
@@ -147,6 +148,7 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        memory_properties: MemoryProperties,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -159,6 +161,7 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                memory_properties: MemoryProperties::default(),
             }
         }
 
@@ -234,6 +237,11 @@ pub mod details {
             self
         }
 
+        fn memory_properties(mut self, value: MemoryProperties) -> Self {
+            self.memory_properties = value;
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -251,6 +259,7 @@ pub mod details {
                 .config(&self.config.dynamic_storage_config)
                 .supplementary_size(self.size + allocator_mgmt_size)
                 .has_ownership(self.has_ownership)
+                .memory_properties(self.memory_properties)
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(
                         allocator_config,
@@ -278,6 +287,14 @@ pub mod details {
                     fail!(from self, with SharedMemoryCreateError::InternalError,
                         "{} since the initialization failed.", msg);
                 }
+                Err(DynamicStorageCreateError::HugePagesNotSupported) => {
+                    fail!(from self, with SharedMemoryCreateError::HugePagesNotSupported,
+                        "{} since the memory cannot be backed by huge pages.", msg);
+                }
+                Err(DynamicStorageCreateError::MemoryLockFailed) => {
+                    fail!(from self, with SharedMemoryCreateError::MemoryLockFailed,
+                        "{} since the memory could not be locked.", msg);
+                }
                 Err(DynamicStorageCreateError::InternalError) => {
                     fail!(from self, with SharedMemoryCreateError::InternalError,
                         "{} since an unknown error has occurred.", msg);
@@ -302,6 +319,7 @@ pub mod details {
                 .config(&self.config.dynamic_storage_config)
                 .has_ownership(false)
                 .timeout(self.timeout)
+                .memory_properties(self.memory_properties)
                 .open(access_mode)
             {
                 Ok(s) => s,
@@ -317,6 +335,14 @@ pub mod details {
                     fail!(from self, with SharedMemoryOpenError::VersionMismatch,
                         "{} since the version number of the construct does not match.", msg);
                 }
+                Err(DynamicStorageOpenError::HugePagesNotSupported) => {
+                    fail!(from self, with SharedMemoryOpenError::HugePagesNotSupported,
+                        "{} since the memory cannot be backed by huge pages.", msg);
+                }
+                Err(DynamicStorageOpenError::MemoryLockFailed) => {
+                    fail!(from self, with SharedMemoryOpenError::MemoryLockFailed,
+                        "{} since the memory could not be locked.", msg);
+                }
                 Err(DynamicStorageOpenError::InternalError) => {
                     fail!(from self, with SharedMemoryOpenError::InternalError,
                         "{} since an unknown error has occurred.", msg);
@@ -536,4 +562,38 @@ pub mod details {
             unsafe { self.storage.get().allocator.assume_init_ref().bucket_size() }
         }
     }
+
+    impl<Storage: DynamicStorage<AllocatorDetails<BuddyAllocator>>> SharedMemoryForBuddyAllocator
+        for Memory<BuddyAllocator, Storage>
+    {
+        unsafe fn deallocate_block(&self, offset: PointerOffset) {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .deallocate_block(offset);
+            }
+        }
+
+        fn min_block_size(&self) -> usize {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .min_block_size()
+            }
+        }
+
+        fn number_of_blocks(&self) -> usize {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .number_of_blocks()
+            }
+        }
+    }
 }
@@ -64,8 +64,10 @@ pub mod recommended;
 
 use core::{fmt::Debug, time::Duration};
 
+pub use crate::dynamic_storage::MemoryProperties;
 pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use buddy_allocator::BuddyAllocator;
 use iceoryx2_bb_elementary_traits::{
     allocator::{Allocation, Deallocate, Grow},
     pointer::Pointer,
@@ -82,6 +84,8 @@ pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
     InsufficientPermissions,
+    HugePagesNotSupported,
+    MemoryLockFailed,
     InternalError,
 }
 
@@ -103,6 +107,8 @@ pub enum SharedMemoryOpenError {
     WrongAllocatorSelected,
     InitializationNotYetFinalized,
     VersionMismatch,
+    HugePagesNotSupported,
+    MemoryLockFailed,
     InternalError,
 }
 
@@ -167,6 +173,10 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines how the operating system shall back the memory when the [`SharedMemory`] is
+    /// created or opened. By default, no property is enabled.
+    fn memory_properties(self, value: MemoryProperties) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -237,3 +247,19 @@ pub trait SharedMemoryForPoolAllocator: SharedMemory<PoolAllocator> {
     /// Returns the bucket size of the [`PoolAllocator`]
     fn bucket_size(&self) -> usize;
 }
+
+pub trait SharedMemoryForBuddyAllocator: SharedMemory<BuddyAllocator> {
+    /// Release previously allocated memory
+    ///
+    /// # Safety
+    ///
+    ///  * the offset must be acquired with [`Allocate::allocate()`] - extracted from the
+    ///    [`ShmPointer`]
+    unsafe fn deallocate_block(&self, offset: PointerOffset);
+
+    /// Returns the minimum block size of the [`BuddyAllocator`]
+    fn min_block_size(&self) -> usize;
+
+    /// Returns the number of minimum sized blocks managed by the [`BuddyAllocator`]
+    fn number_of_blocks(&self) -> usize;
+}
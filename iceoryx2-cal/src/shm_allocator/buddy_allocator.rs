@@ -0,0 +1,528 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`BuddyAllocator`] manages variable-sized chunks with power-of-two sized blocks. Every
+//! allocation is rounded up to the next power-of-two multiple of the
+//! [`Config::min_block_size`], therefore the internal fragmentation is bounded by a factor of
+//! two. Free blocks are merged with their buddy on deallocation which bounds the external
+//! fragmentation.
+//!
+//! Allocation and deallocation require at most one split or merge per block order and are
+//! therefore `O(log n)` in the number of managed blocks.
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use crate::shm_allocator::*;
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicUsize, Ordering};
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
+use iceoryx2_bb_elementary::relocatable_pointer::RelocatablePointer;
+use iceoryx2_bb_elementary_traits::pointer::Pointer;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::{fail, fatal_panic};
+
+const NUMBER_OF_ORDERS: usize = 32;
+const NO_BLOCK: u32 = u32::MAX;
+const BLOCK_STATE_NONE: u8 = 0;
+const BLOCK_STATE_ALLOCATED: u8 = 0x40;
+const BLOCK_STATE_FREE: u8 = 0x80;
+const BLOCK_STATE_ORDER_MASK: u8 = 0x3f;
+const MIN_BLOCK_SIZE: usize = 8;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The size of the smallest block. Every allocation is rounded up to a power-of-two
+    /// multiple of it. Must be a power of two and at least 8.
+    pub min_block_size: usize,
+    /// The maximum supported alignment of an allocation. Must be a power of two.
+    pub max_alignment: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_block_size: 64,
+            max_alignment: 64,
+        }
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+impl Config {
+    fn adjusted_min_block_size(&self) -> usize {
+        self.min_block_size.max(MIN_BLOCK_SIZE).next_power_of_two()
+    }
+
+    fn adjusted_max_alignment(&self) -> usize {
+        self.max_alignment.max(1).next_power_of_two()
+    }
+
+    fn block_size_for(&self, layout: Layout) -> usize {
+        layout
+            .size()
+            .max(layout.align())
+            .max(self.adjusted_min_block_size())
+            .next_power_of_two()
+    }
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+pub struct BuddyAllocator {
+    block_states: RelocatablePointer<AtomicU8>,
+    free_list_heads: [AtomicU32; NUMBER_OF_ORDERS],
+    number_of_blocks: usize,
+    min_block_size: usize,
+    max_alignment: usize,
+    // is even with absolute base address relocatable since every process acquire and return
+    // the same relative offset which map then to the same absolute base address
+    base_address: usize,
+    start_address: usize,
+    max_supported_alignment_by_memory: usize,
+    used_space: AtomicUsize,
+    is_locked: AtomicBool,
+}
+
+struct LockGuard<'a>(&'a AtomicBool);
+
+impl Drop for LockGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl BuddyAllocator {
+    /// Returns the size of the managed memory that can be used for allocations.
+    pub fn total_space(&self) -> usize {
+        self.number_of_blocks * self.min_block_size
+    }
+
+    /// Returns the size of the smallest block. Every allocated block starts at a multiple of
+    /// it.
+    pub fn min_block_size(&self) -> usize {
+        self.min_block_size
+    }
+
+    /// Returns the number of blocks of [`BuddyAllocator::min_block_size()`] that fit into the
+    /// managed memory.
+    pub fn number_of_blocks(&self) -> usize {
+        self.number_of_blocks
+    }
+
+    /// Releases the block that starts at the provided [`PointerOffset`].
+    ///
+    /// # Safety
+    ///
+    ///  * provided [`PointerOffset`] must be allocated with [`Allocate::allocate()`]
+    pub unsafe fn deallocate_block(&self, offset: PointerOffset) {
+        self.release_block(self.index_of(offset));
+    }
+
+    /// Returns the size of all blocks that are currently allocated.
+    pub fn used_space(&self) -> usize {
+        self.used_space.load(Ordering::Relaxed)
+    }
+
+    /// Returns the size of the largest block that can be allocated without a resize.
+    pub fn largest_free_block_size(&self) -> usize {
+        let _guard = self.lock();
+        (0..NUMBER_OF_ORDERS)
+            .rev()
+            .find(|order| self.free_list_head(*order) != NO_BLOCK)
+            .map(|order| self.min_block_size << order)
+            .unwrap_or(0)
+    }
+
+    fn lock(&self) -> LockGuard<'_> {
+        while self
+            .is_locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        LockGuard(&self.is_locked)
+    }
+
+    fn order_of(&self, block_size: usize) -> usize {
+        (block_size / self.min_block_size).trailing_zeros() as usize
+    }
+
+    fn block_state(&self, index: u32) -> &AtomicU8 {
+        unsafe { &*self.block_states.as_ptr().add(index as usize) }
+    }
+
+    fn free_list_head(&self, order: usize) -> u32 {
+        self.free_list_heads[order].load(Ordering::Relaxed)
+    }
+
+    // The links of the free lists are stored inside the free blocks itself. A block is at least
+    // [`MIN_BLOCK_SIZE`] large and aligned to it, therefore the two links always fit.
+    fn links(&self, index: u32) -> *mut [u32; 2] {
+        (self.start_address + index as usize * self.min_block_size) as *mut [u32; 2]
+    }
+
+    fn push_free_block(&self, index: u32, order: usize) {
+        let head = self.free_list_head(order);
+        unsafe { self.links(index).write([NO_BLOCK, head]) };
+        if head != NO_BLOCK {
+            unsafe { (*self.links(head))[0] = index };
+        }
+        self.free_list_heads[order].store(index, Ordering::Relaxed);
+        self.block_state(index)
+            .store(BLOCK_STATE_FREE | order as u8, Ordering::Relaxed);
+    }
+
+    fn remove_free_block(&self, index: u32, order: usize) {
+        let [prev, next] = unsafe { self.links(index).read() };
+        if prev == NO_BLOCK {
+            self.free_list_heads[order].store(next, Ordering::Relaxed);
+        } else {
+            unsafe { (*self.links(prev))[1] = next };
+        }
+
+        if next != NO_BLOCK {
+            unsafe { (*self.links(next))[0] = prev };
+        }
+        self.block_state(index)
+            .store(BLOCK_STATE_NONE, Ordering::Relaxed);
+    }
+
+    fn allocate_block(&self, order: usize) -> Option<u32> {
+        let _guard = self.lock();
+        // only the heads of the free lists that are large enough are compared and the head
+        // with the lowest block index is used, this is not necessarily the lowest free block
+        // since the free lists itself are not sorted
+        let (index, available_order) = (order..NUMBER_OF_ORDERS)
+            .map(|o| (self.free_list_head(o), o))
+            .filter(|(head, _)| *head != NO_BLOCK)
+            .min()?;
+
+        self.remove_free_block(index, available_order);
+
+        for split_order in (order..available_order).rev() {
+            self.push_free_block(index + (1 << split_order), split_order);
+        }
+
+        self.block_state(index)
+            .store(BLOCK_STATE_ALLOCATED | order as u8, Ordering::Relaxed);
+        self.used_space
+            .fetch_add(self.min_block_size << order, Ordering::Relaxed);
+        Some(index)
+    }
+
+    fn allocated_order(&self, index: u32) -> usize {
+        let state = self.block_state(index).load(Ordering::Relaxed);
+        if state & BLOCK_STATE_ALLOCATED == 0 {
+            fatal_panic!(from self,
+                "This should never happen! The block {} is not allocated.", index);
+        }
+
+        (state & BLOCK_STATE_ORDER_MASK) as usize
+    }
+
+    fn release_block(&self, mut index: u32) {
+        let _guard = self.lock();
+        let mut order = self.allocated_order(index);
+        self.used_space
+            .fetch_sub(self.min_block_size << order, Ordering::Relaxed);
+
+        while order + 1 < NUMBER_OF_ORDERS {
+            let buddy = index ^ (1 << order);
+            if buddy as usize >= self.number_of_blocks
+                || self.block_state(buddy).load(Ordering::Relaxed) != BLOCK_STATE_FREE | order as u8
+            {
+                break;
+            }
+
+            self.remove_free_block(buddy, order);
+            self.block_state(index)
+                .store(BLOCK_STATE_NONE, Ordering::Relaxed);
+            index = index.min(buddy);
+            order += 1;
+        }
+
+        self.push_free_block(index, order);
+    }
+
+    fn index_of(&self, offset: PointerOffset) -> u32 {
+        (offset.offset() / self.min_block_size) as u32
+    }
+}
+
+pub struct InitializedBuddyAllocator<'shm_allocator>(&'shm_allocator BuddyAllocator);
+
+impl InitializedBuddyAllocator<'_> {
+    fn block_size(&self, layout: Layout) -> usize {
+        layout
+            .size()
+            .max(layout.align())
+            .max(self.0.min_block_size)
+            .next_power_of_two()
+    }
+}
+
+impl<'shm_allocator> Allocate<PointerOffset> for InitializedBuddyAllocator<'shm_allocator> {
+    fn allocate(&self, layout: Layout) -> Result<PointerOffset, AllocationError> {
+        let msg = "Unable to allocate memory";
+        if layout.align() > self.0.max_alignment() {
+            fail!(from self.0, with AllocationError::AlignmentFailure,
+                    "{} since an alignment of {} exceeds the maximum supported alignment of {}.",
+                    msg, layout.align(), self.0.max_alignment());
+        }
+
+        let block_size = self.block_size(layout);
+        if block_size > self.0.total_space() {
+            fail!(from self.0, with AllocationError::SizeTooLarge,
+                    "{} since the requested size {} exceeds the managed memory size of {}.",
+                    msg, layout.size(), self.0.total_space());
+        }
+
+        match self.0.allocate_block(self.0.order_of(block_size)) {
+            Some(index) => Ok(PointerOffset::new(index as usize * self.0.min_block_size)),
+            None => {
+                fail!(from self.0, with AllocationError::OutOfMemory,
+                    "{} since there is no free block of size {} left.", msg, block_size);
+            }
+        }
+    }
+}
+
+impl<'shm_allocator> Deallocate<PointerOffset> for InitializedBuddyAllocator<'shm_allocator> {
+    unsafe fn deallocate(&self, ptr: PointerOffset, _layout: Layout) {
+        self.0.release_block(self.0.index_of(ptr));
+    }
+}
+
+impl<'shm_allocator> Grow<PointerOffset> for InitializedBuddyAllocator<'shm_allocator> {
+    unsafe fn grow(
+        &self,
+        offset: PointerOffset,
+        old_layout: Layout,
+        new_layout: Layout,
+        content_placement: ContentPlacement,
+    ) -> Result<PointerOffset, AllocationGrowError> {
+        let msg = "Unable to grow memory";
+        if new_layout.align() > self.0.max_alignment() {
+            fail!(from self.0, with AllocationGrowError::AlignmentFailure,
+                    "{} since the alignment of {} exceeds the maximum supported alignment of {}.",
+                    msg, new_layout.align(), self.0.max_alignment());
+        }
+
+        if new_layout.size() < old_layout.size() {
+            fail!(from self.0, with AllocationGrowError::GrowWouldShrink,
+                    "{} since new layout has a smaller size of {} than the old layout with {}.",
+                    msg, new_layout.size(), old_layout.size());
+        }
+
+        if new_layout.size() == old_layout.size() {
+            return Ok(offset);
+        }
+
+        let old_block_size = {
+            let _guard = self.0.lock();
+            self.0.min_block_size << self.0.allocated_order(self.0.index_of(offset))
+        };
+
+        let src = self.0.start_address + offset.offset();
+        if self.block_size(new_layout) <= old_block_size {
+            if content_placement == ContentPlacement::Back {
+                let dst = src + (new_layout.size() - old_layout.size());
+                unsafe { core::ptr::copy(src as *const u8, dst as *mut u8, old_layout.size()) };
+            }
+
+            return Ok(offset);
+        }
+
+        let new_offset = match self.allocate(new_layout) {
+            Ok(new_offset) => new_offset,
+            Err(AllocationError::OutOfMemory) | Err(AllocationError::SizeTooLarge) => {
+                fail!(from self.0, with AllocationGrowError::OutOfMemory,
+                    "{} since the allocator is out-of-memory.", msg);
+            }
+            Err(e) => {
+                fatal_panic!(from self.0,
+                    "This should never happen! Failed to allocate memory to grow the memory chunk. [{e:?}]");
+            }
+        };
+
+        let dst = match content_placement {
+            ContentPlacement::Front => self.0.start_address + new_offset.offset(),
+            ContentPlacement::Back => {
+                self.0.start_address + new_offset.offset() + new_layout.size() - old_layout.size()
+            }
+        };
+        unsafe {
+            core::ptr::copy_nonoverlapping(src as *const u8, dst as *mut u8, old_layout.size())
+        };
+        unsafe { self.deallocate(offset, old_layout) };
+
+        Ok(new_offset)
+    }
+}
+
+impl<'shm_allocator> InitializedShmAllocator<'shm_allocator>
+    for InitializedBuddyAllocator<'shm_allocator>
+{
+}
+
+impl ShmAllocator for BuddyAllocator {
+    type Configuration = Config;
+    type Initialized<'shm_allocator> = InitializedBuddyAllocator<'shm_allocator>;
+
+    unsafe fn assume_init<'shm_allocator>(
+        &'shm_allocator self,
+    ) -> Self::Initialized<'shm_allocator> {
+        InitializedBuddyAllocator(self)
+    }
+
+    fn resize_hint(
+        &self,
+        layout: Layout,
+        strategy: AllocationStrategy,
+    ) -> SharedMemorySetupHint<Self::Configuration> {
+        let config = Config {
+            min_block_size: self.min_block_size,
+            max_alignment: self.max_alignment.max(layout.align()),
+        };
+        let block_size = config.block_size_for(layout);
+        let current_payload_size = self.total_space();
+
+        if block_size <= self.largest_free_block_size() && layout.align() <= self.max_alignment {
+            return SharedMemorySetupHint {
+                payload_size: current_payload_size,
+                config,
+            };
+        }
+
+        let payload_size = match strategy {
+            AllocationStrategy::BestFit => current_payload_size + block_size,
+            AllocationStrategy::PowerOfTwo => {
+                (current_payload_size + block_size).next_power_of_two()
+            }
+            AllocationStrategy::Static => current_payload_size,
+        };
+
+        SharedMemorySetupHint {
+            payload_size,
+            config,
+        }
+    }
+
+    fn initial_setup_hint(
+        max_chunk_layout: Layout,
+        max_number_of_chunks: usize,
+    ) -> SharedMemorySetupHint<Self::Configuration> {
+        let default = Config::default();
+        let config = Config {
+            min_block_size: default.min_block_size,
+            max_alignment: default.max_alignment.max(max_chunk_layout.align()),
+        };
+
+        SharedMemorySetupHint {
+            payload_size: config.block_size_for(max_chunk_layout) * max_number_of_chunks,
+            config,
+        }
+    }
+
+    fn management_size(memory_size: usize, config: &Self::Configuration) -> usize {
+        memory_size / config.adjusted_min_block_size() * core::mem::size_of::<AtomicU8>()
+    }
+
+    fn relative_start_address(&self) -> usize {
+        self.start_address - self.base_address
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        managed_memory: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        let min_block_size = config.adjusted_min_block_size();
+        let max_alignment = config.adjusted_max_alignment();
+        let base_address = (managed_memory.as_ptr() as *mut u8) as usize;
+        let start_address = base_address.next_multiple_of(max_alignment.max(MIN_BLOCK_SIZE));
+        let end_address = base_address + managed_memory.len();
+        let number_of_blocks =
+            (end_address.saturating_sub(start_address) / min_block_size).min(NO_BLOCK as usize);
+
+        Self {
+            block_states: unsafe { RelocatablePointer::new_uninit() },
+            free_list_heads: core::array::from_fn(|_| AtomicU32::new(NO_BLOCK)),
+            number_of_blocks,
+            min_block_size,
+            max_alignment,
+            base_address,
+            start_address,
+            max_supported_alignment_by_memory,
+            used_space: AtomicUsize::new(0),
+            is_locked: AtomicBool::new(false),
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.max_alignment
+    }
+
+    unsafe fn init<Allocator: Allocate<NonNull<u8>>>(
+        &mut self,
+        mgmt_allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        let msg = "Unable to initialize allocator";
+        if self.max_supported_alignment_by_memory < self.max_alignment() {
+            fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
+                "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
+                msg, self.max_alignment(), self.max_supported_alignment_by_memory);
+        }
+
+        let block_states = match mgmt_allocator.allocate(unsafe {
+            Layout::from_size_align_unchecked(
+                self.number_of_blocks.max(1) * core::mem::size_of::<AtomicU8>(),
+                core::mem::align_of::<AtomicU8>(),
+            )
+        }) {
+            Ok(memory) => memory,
+            Err(e) => {
+                fail!(from self, with ShmAllocatorInitError::AllocationFailed,
+                    "{} since the allocation of the allocator management memory failed. [{e:?}]", msg);
+            }
+        };
+
+        for n in 0..self.number_of_blocks {
+            unsafe {
+                (block_states.as_ptr() as *mut AtomicU8)
+                    .add(n)
+                    .write(AtomicU8::new(BLOCK_STATE_NONE))
+            };
+        }
+        unsafe { self.block_states.init(block_states) };
+
+        // decompose the managed memory into the largest possible blocks where every block is
+        // aligned to its own size
+        let mut index = 0;
+        while index < self.number_of_blocks {
+            let mut order = (index.trailing_zeros() as usize).min(NUMBER_OF_ORDERS - 1);
+            while index + (1 << order) > self.number_of_blocks {
+                order -= 1;
+            }
+            self.push_free_block(index as u32, order);
+            index += 1 << order;
+        }
+
+        Ok(())
+    }
+
+    fn unique_id() -> u8 {
+        2
+    }
+}
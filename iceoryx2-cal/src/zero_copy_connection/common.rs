@@ -1120,7 +1120,9 @@ pub mod details {
                                fail!(from origin, with ZeroCopyPortRemoveError::DoesNotExist,
                                    "{msg} since the underlying dynamic storage does not exist.");
                            }
-                           Err(DynamicStorageOpenError::InternalError) => {
+                           Err(DynamicStorageOpenError::InternalError
+                               | DynamicStorageOpenError::HugePagesNotSupported
+                               | DynamicStorageOpenError::MemoryLockFailed) => {
                                fail!(from origin, with ZeroCopyPortRemoveError::InternalError,
                                    "{msg} due to an internal error.");
                            }
@@ -0,0 +1,290 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::{alloc::Layout, ptr::NonNull};
+use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
+use iceoryx2_bb_elementary_traits::allocator::{
+    Allocate, AllocationError, AllocationGrowError, ContentPlacement, Deallocate, Grow,
+};
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use iceoryx2_cal::{
+    shm_allocator::{ShmAllocator, buddy_allocator::*},
+    zero_copy_connection::PointerOffset,
+};
+
+const MAX_SUPPORTED_ALIGNMENT: usize = 4096;
+const MIN_BLOCK_SIZE: usize = 64;
+const MEM_SIZE: usize = 16384 * 2;
+const PAYLOAD_SIZE: usize = 8192;
+
+#[repr(C, align(4096))]
+struct Memory([u8; MEM_SIZE]);
+
+struct Test {
+    _memory: Box<Memory>,
+    base_address: NonNull<[u8]>,
+    sut: Box<BuddyAllocator>,
+}
+
+impl Test {
+    fn new(payload_size: usize) -> Self {
+        let mut memory = Box::new(Memory([0u8; MEM_SIZE]));
+        let base_address =
+            unsafe { NonNull::<[u8]>::new_unchecked(&mut memory.0[0..payload_size]) };
+        let allocator = BumpAllocator::new(
+            unsafe { NonNull::new_unchecked(memory.0[PAYLOAD_SIZE..].as_mut_ptr()) },
+            MEM_SIZE - PAYLOAD_SIZE,
+        );
+        let config = &Config {
+            min_block_size: MIN_BLOCK_SIZE,
+            max_alignment: MIN_BLOCK_SIZE,
+        };
+        let mut sut = Box::new(unsafe {
+            BuddyAllocator::new_uninit(MAX_SUPPORTED_ALIGNMENT, base_address, config)
+        });
+
+        unsafe { sut.init(&allocator).unwrap() };
+
+        Self {
+            _memory: memory,
+            base_address,
+            sut,
+        }
+    }
+
+    fn layout(size: usize) -> Layout {
+        unsafe { Layout::from_size_align_unchecked(size, 1) }
+    }
+
+    fn offset_to_ptr(&self, offset: PointerOffset) -> *mut u8 {
+        unsafe { (self.base_address.as_ptr() as *mut u8).add(offset.offset()) }
+    }
+}
+
+#[test]
+fn is_setup_correctly() {
+    let test = Test::new(PAYLOAD_SIZE);
+
+    assert_that!(test.sut.total_space(), eq PAYLOAD_SIZE);
+    assert_that!(test.sut.used_space(), eq 0);
+    assert_that!(test.sut.largest_free_block_size(), eq PAYLOAD_SIZE);
+    assert_that!(test.sut.relative_start_address(), eq 0);
+    assert_that!(test.sut.max_alignment(), eq MIN_BLOCK_SIZE);
+}
+
+#[test]
+fn allocations_are_rounded_up_to_the_next_power_of_two_block() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    let chunk_1 = sut.allocate(Test::layout(1)).unwrap();
+    assert_that!(test.sut.used_space(), eq MIN_BLOCK_SIZE);
+
+    let chunk_2 = sut.allocate(Test::layout(MIN_BLOCK_SIZE + 1)).unwrap();
+    assert_that!(test.sut.used_space(), eq 3 * MIN_BLOCK_SIZE);
+    assert_that!(chunk_2.offset() % (2 * MIN_BLOCK_SIZE), eq 0);
+
+    unsafe { sut.deallocate(chunk_1, Test::layout(1)) };
+    unsafe { sut.deallocate(chunk_2, Test::layout(MIN_BLOCK_SIZE + 1)) };
+    assert_that!(test.sut.used_space(), eq 0);
+}
+
+#[test]
+fn variable_sized_allocations_use_the_whole_memory() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    let mut chunks = Vec::new();
+    let mut size = PAYLOAD_SIZE / 2;
+    while size >= MIN_BLOCK_SIZE {
+        chunks.push((sut.allocate(Test::layout(size)).unwrap(), size));
+        size /= 2;
+    }
+    chunks.push((
+        sut.allocate(Test::layout(MIN_BLOCK_SIZE)).unwrap(),
+        MIN_BLOCK_SIZE,
+    ));
+
+    assert_that!(test.sut.used_space(), eq PAYLOAD_SIZE);
+    assert_that!(sut.allocate(Test::layout(1)).err(), eq Some(AllocationError::OutOfMemory));
+
+    for (chunk, size) in chunks {
+        unsafe { sut.deallocate(chunk, Test::layout(size)) };
+    }
+    assert_that!(test.sut.largest_free_block_size(), eq PAYLOAD_SIZE);
+}
+
+#[test]
+fn released_buddies_are_merged() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    let chunks: Vec<PointerOffset> = (0..PAYLOAD_SIZE / MIN_BLOCK_SIZE)
+        .map(|_| sut.allocate(Test::layout(MIN_BLOCK_SIZE)).unwrap())
+        .collect();
+    assert_that!(test.sut.largest_free_block_size(), eq 0);
+
+    // release every second block, no buddies can be merged
+    for chunk in chunks.iter().step_by(2) {
+        unsafe { sut.deallocate(*chunk, Test::layout(MIN_BLOCK_SIZE)) };
+    }
+    assert_that!(test.sut.largest_free_block_size(), eq MIN_BLOCK_SIZE);
+    assert_that!(sut.allocate(Test::layout(2 * MIN_BLOCK_SIZE)).err(), eq Some(AllocationError::OutOfMemory));
+
+    for chunk in chunks.iter().skip(1).step_by(2) {
+        unsafe { sut.deallocate(*chunk, Test::layout(MIN_BLOCK_SIZE)) };
+    }
+    assert_that!(test.sut.largest_free_block_size(), eq PAYLOAD_SIZE);
+}
+
+#[test]
+fn memory_that_is_not_a_power_of_two_is_fully_usable() {
+    const SIZE: usize = 3 * 1024 + 5 * MIN_BLOCK_SIZE;
+    let test = Test::new(SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    assert_that!(test.sut.total_space(), eq SIZE);
+    assert_that!(test.sut.largest_free_block_size(), eq 2048);
+
+    let chunks: Vec<PointerOffset> = (0..SIZE / MIN_BLOCK_SIZE)
+        .map(|_| sut.allocate(Test::layout(MIN_BLOCK_SIZE)).unwrap())
+        .collect();
+    assert_that!(sut.allocate(Test::layout(1)).err(), eq Some(AllocationError::OutOfMemory));
+
+    for chunk in chunks {
+        unsafe { sut.deallocate(chunk, Test::layout(MIN_BLOCK_SIZE)) };
+    }
+    assert_that!(test.sut.used_space(), eq 0);
+    assert_that!(test.sut.largest_free_block_size(), eq 2048);
+}
+
+#[test]
+fn allocate_more_than_available_fails() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    assert_that!(sut.allocate(Test::layout(PAYLOAD_SIZE + 1)).err(), eq Some(AllocationError::SizeTooLarge));
+}
+
+#[test]
+fn grow_within_block_keeps_the_offset() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    let offset = sut.allocate(Test::layout(10)).unwrap();
+    let new_offset = unsafe {
+        sut.grow(
+            offset,
+            Test::layout(10),
+            Test::layout(MIN_BLOCK_SIZE),
+            ContentPlacement::Front,
+        )
+        .unwrap()
+    };
+    assert_that!(new_offset, eq offset);
+    assert_that!(test.sut.used_space(), eq MIN_BLOCK_SIZE);
+
+    let ptr = test.offset_to_ptr(offset);
+    unsafe { *ptr = 137 };
+}
+
+#[test]
+fn grow_beyond_block_moves_the_content() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    let offset = sut.allocate(Test::layout(MIN_BLOCK_SIZE)).unwrap();
+    let _blocker = sut.allocate(Test::layout(MIN_BLOCK_SIZE)).unwrap();
+    let ptr = test.offset_to_ptr(offset);
+    for n in 0..MIN_BLOCK_SIZE {
+        unsafe { *ptr.add(n) = n as u8 };
+    }
+
+    let new_offset = unsafe {
+        sut.grow(
+            offset,
+            Test::layout(MIN_BLOCK_SIZE),
+            Test::layout(4 * MIN_BLOCK_SIZE),
+            ContentPlacement::Front,
+        )
+        .unwrap()
+    };
+    assert_that!(new_offset, ne offset);
+    assert_that!(test.sut.used_space(), eq 5 * MIN_BLOCK_SIZE);
+
+    let ptr = test.offset_to_ptr(new_offset);
+    for n in 0..MIN_BLOCK_SIZE {
+        assert_that!(unsafe { *ptr.add(n) }, eq n as u8);
+    }
+}
+
+#[test]
+fn grow_larger_than_memory_fails() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+
+    let offset = sut.allocate(Test::layout(1)).unwrap();
+    let result = unsafe {
+        sut.grow(
+            offset,
+            Test::layout(1),
+            Test::layout(2 * PAYLOAD_SIZE),
+            ContentPlacement::Front,
+        )
+    };
+    assert_that!(result.err(), eq Some(AllocationGrowError::OutOfMemory));
+}
+
+#[test]
+fn resize_hint_keeps_size_when_block_is_available() {
+    let test = Test::new(PAYLOAD_SIZE);
+
+    let hint = test
+        .sut
+        .resize_hint(Test::layout(PAYLOAD_SIZE), AllocationStrategy::PowerOfTwo);
+    assert_that!(hint.payload_size, eq PAYLOAD_SIZE);
+}
+
+#[test]
+fn resize_hint_increases_size_when_no_block_is_available() {
+    let test = Test::new(PAYLOAD_SIZE);
+    let sut = unsafe { test.sut.assume_init() };
+    let _chunk = sut.allocate(Test::layout(1)).unwrap();
+
+    let hint = test
+        .sut
+        .resize_hint(Test::layout(PAYLOAD_SIZE), AllocationStrategy::BestFit);
+    assert_that!(hint.payload_size, eq 2 * PAYLOAD_SIZE);
+
+    let hint = test.sut.resize_hint(
+        Test::layout(PAYLOAD_SIZE + 1),
+        AllocationStrategy::PowerOfTwo,
+    );
+    assert_that!(hint.payload_size, eq 4 * PAYLOAD_SIZE);
+
+    let hint = test
+        .sut
+        .resize_hint(Test::layout(PAYLOAD_SIZE), AllocationStrategy::Static);
+    assert_that!(hint.payload_size, eq PAYLOAD_SIZE);
+}
+
+#[test]
+fn initial_setup_hint_provides_space_for_all_chunks() {
+    let hint = BuddyAllocator::initial_setup_hint(Test::layout(100), 5);
+
+    assert_that!(hint.payload_size, eq 5 * 128);
+    assert_that!(hint.config.max_alignment, eq MIN_BLOCK_SIZE);
+}
@@ -40,7 +40,7 @@ pub enum ShowSubcommand {
     )]
     System,
     #[clap(
-        about = "Show the currently loaded configuration",
+        about = "Show the currently loaded configuration and the origin of every entry",
         help_template = help_template().build()
     )]
     Current,
@@ -310,7 +310,7 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                 },
                 Field {
                     key: "defaults.publish-subscribe.backpressure-strategy",
-                    value_type: "`RetryUntilDelivered`|`DiscardData`",
+                    value_type: "`RetryUntilDelivered`|`RetryUntilDeliveredOrTimeout(<duration>)`|`DiscardData`",
                     default_value: format!(
                         "{:?}",
                         config.defaults.publish_subscribe.backpressure_strategy
@@ -339,6 +339,36 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     ),
                     description: "Default allocation strategy used by the publisher when the initially preallocated memory is insufficient.",
                 },
+                Field {
+                    key: "defaults.publish-subscribe.data-segment-use-huge-pages",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .publish_subscribe
+                        .data_segment_use_huge_pages
+                        .to_string(),
+                    description: "Back the data segments of the publishers with transparent huge pages, fails when the system does not provide them for shared memory.",
+                },
+                Field {
+                    key: "defaults.publish-subscribe.data-segment-lock-memory",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .publish_subscribe
+                        .data_segment_lock_memory
+                        .to_string(),
+                    description: "Lock the data segments of the publishers in memory so that they are never swapped out.",
+                },
+                Field {
+                    key: "defaults.publish-subscribe.data-segment-prefault-memory",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .publish_subscribe
+                        .data_segment_prefault_memory
+                        .to_string(),
+                    description: "Touch every page of a data segment of the publishers when it is mapped.",
+                },
             ],
         },
         Section {
@@ -509,7 +539,7 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                 },
                 Field {
                     key: "defaults.request-response.client-backpressure-strategy",
-                    value_type: "`RetryUntilDelivered`|`DiscardData`",
+                    value_type: "`RetryUntilDelivered`|`RetryUntilDeliveredOrTimeout(<duration>)`|`DiscardData`",
                     default_value: format!(
                         "{:?}",
                         config
@@ -521,7 +551,7 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                 },
                 Field {
                     key: "defaults.request-response.server-backpressure-strategy",
-                    value_type: "`RetryUntilDelivered`|`DiscardData`",
+                    value_type: "`RetryUntilDelivered`|`RetryUntilDeliveredOrTimeout(<duration>)`|`DiscardData`",
                     default_value: format!(
                         "{:?}",
                         config
@@ -579,6 +609,36 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     ),
                     description: "Default allocation strategy used by the server when the initially preallocated memory is insufficient.",
                 },
+                Field {
+                    key: "defaults.request-response.data-segment-use-huge-pages",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .request_response
+                        .data_segment_use_huge_pages
+                        .to_string(),
+                    description: "Back the data segments of the clients and servers with transparent huge pages, fails when the system does not provide them for shared memory.",
+                },
+                Field {
+                    key: "defaults.request-response.data-segment-lock-memory",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .request_response
+                        .data_segment_lock_memory
+                        .to_string(),
+                    description: "Lock the data segments of the clients and servers in memory so that they are never swapped out.",
+                },
+                Field {
+                    key: "defaults.request-response.data-segment-prefault-memory",
+                    value_type: "`true`|`false`",
+                    default_value: config
+                        .defaults
+                        .request_response
+                        .data_segment_prefault_memory
+                        .to_string(),
+                    description: "Touch every page of a data segment of the clients and servers when it is mapped.",
+                },
             ],
         },
         Section {
@@ -598,6 +658,15 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                 },
             ],
         },
+        Section {
+            name: "Service Name Remapping",
+            fields: vec![Field {
+                key: "remappings",
+                value_type: "[{ from = string, to = string }]",
+                default_value: format!("{:?}", config.remappings),
+                description: "Rewrites the service name `from` into `to` when a service is created or opened. When multiple remappings match, the first one is applied.",
+            }],
+        },
     ]
 }
 
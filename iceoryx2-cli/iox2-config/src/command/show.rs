@@ -21,9 +21,15 @@ pub fn show_system_config() -> Result<()> {
 }
 
 pub fn show_current_config() -> Result<()> {
-    let config = Config::global_config();
-    let toml_config = toml::to_string_pretty(&config)?;
+    let layered_config = Config::load_layered(None)?;
+    let toml_config = toml::to_string_pretty(&layered_config.config()?)?;
     println!("{toml_config}");
 
+    // the origins are printed as comments so that the output remains a valid config file
+    println!("# origin of every entry");
+    for (key, origin) in layered_config.origins() {
+        println!("# {key}: {origin}");
+    }
+
     Ok(())
 }
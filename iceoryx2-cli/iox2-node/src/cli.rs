@@ -59,10 +59,36 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Args)]
+pub struct SetLogFilterOptions {
+    #[clap(help = "Log filter, e.g. \"warn,iceoryx2::port=trace\"")]
+    pub filter: String,
+
+    #[clap(
+        short,
+        long,
+        help = "Name, ID or PID of the node, when omitted the filter is sent to all nodes"
+    )]
+    pub node: Option<NodeIdentifier>,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 500,
+        help = "Time the nodes have to acknowledge the log filter"
+    )]
+    pub timeout_in_ms: u64,
+}
+
 #[derive(Subcommand)]
 pub enum Action {
     #[clap(about = "List all nodes", help_template = help_template().build())]
     List(ListOptions),
     #[clap(about = "Show node details", help_template = help_template().with_positionals().build())]
     Details(DetailsOptions),
+    #[clap(
+        about = "Change the log filter of running nodes with a log control",
+        help_template = help_template().with_positionals().build()
+    )]
+    SetLogFilter(SetLogFilterOptions),
 }
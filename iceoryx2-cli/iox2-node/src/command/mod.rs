@@ -12,6 +12,8 @@
 
 mod details;
 mod list;
+mod set_log_filter;
 
 pub(crate) use details::*;
 pub(crate) use list::*;
+pub(crate) use set_log_filter::*;
@@ -0,0 +1,107 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::time::Instant;
+
+use anyhow::{Context, Result, anyhow};
+use iceoryx2::node::NodeView;
+use iceoryx2::prelude::*;
+use iceoryx2_cli::filter::Filter;
+use iceoryx2_services_log_control::log_control::{
+    Config as LogControlConfig, LogControlTarget, LogController,
+};
+
+use crate::cli::SetLogFilterOptions;
+
+pub(crate) fn set_log_filter(options: SetLogFilterOptions) -> Result<()> {
+    let mut targets = vec![];
+    match &options.node {
+        Some(identifier) => {
+            Node::<ipc::Service>::list(Config::global_config(), |node| {
+                if let NodeState::Alive(view) = &node
+                    && identifier.matches(&node)
+                {
+                    targets.push(LogControlTarget::Node(*view.id()));
+                }
+                CallbackProgression::Continue
+            })
+            .context("failed to retrieve nodes")?;
+
+            if targets.is_empty() {
+                return Err(anyhow!("no alive node matches {identifier:?}"));
+            }
+        }
+        None => targets.push(LogControlTarget::AllNodes),
+    }
+
+    let node = NodeBuilder::new().create::<ipc::Service>()?;
+    let controller = LogController::create(&node, &LogControlConfig::default())?;
+
+    let mut pending_filters = vec![];
+    for target in targets {
+        let pending = controller.set_log_filter(target, &options.filter)?;
+        let expected_acknowledgments = match target {
+            LogControlTarget::AllNodes => pending.number_of_receivers(),
+            LogControlTarget::Node(_) => 1,
+        };
+        pending_filters.push((pending, expected_acknowledgments));
+    }
+
+    let expected_acknowledgments: usize = pending_filters.iter().map(|(_, n)| n).sum();
+    if pending_filters
+        .iter()
+        .all(|(pending, _)| pending.number_of_receivers() == 0)
+    {
+        return Err(anyhow!(
+            "the log filter was not delivered since no node has a log control"
+        ));
+    }
+    let mut applied = vec![];
+    let mut rejected = vec![];
+
+    // the nodes receive the log filter in their next spin, it is lost when the controller
+    // goes out of scope before
+    let deadline = Instant::now() + Duration::from_millis(options.timeout_in_ms);
+    while applied.len() + rejected.len() < expected_acknowledgments && Instant::now() < deadline {
+        for (pending, _) in &pending_filters {
+            while let Some(acknowledgment) = pending.receive()? {
+                if acknowledgment.is_applied {
+                    applied.push(acknowledgment.node_id);
+                } else {
+                    rejected.push(acknowledgment.node_id);
+                }
+            }
+        }
+        node.wait(Duration::from_millis(10))?;
+    }
+
+    println!(
+        "log filter applied by {} of {} node(s)",
+        applied.len(),
+        expected_acknowledgments
+    );
+
+    if !rejected.is_empty() {
+        return Err(anyhow!("the log filter was rejected by {rejected:?}"));
+    }
+
+    if applied.len() < expected_acknowledgments {
+        return Err(anyhow!(
+            "{} node(s) did not acknowledge the log filter within {}ms",
+            expected_acknowledgments - applied.len(),
+            options.timeout_in_ms
+        ));
+    }
+
+    Ok(())
+}
@@ -40,6 +40,11 @@ fn main() -> Result<()> {
                     eprintln!("Failed to retrieve node details: {e}");
                 }
             }
+            Action::SetLogFilter(options) => {
+                if let Err(e) = command::set_log_filter(options) {
+                    eprintln!("Failed to set log filter: {e}");
+                }
+            }
         }
     } else {
         Cli::command().print_help().expect("Failed to print help");
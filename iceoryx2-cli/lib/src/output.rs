@@ -55,6 +55,8 @@ where
 pub struct ServiceDescription {
     pub service_hash: String,
     pub service_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_service_name: Option<String>,
     pub attributes: IceoryxAttributeSet,
     pub pattern: IceoryxMessagingPattern,
     pub nodes: Option<NodeList>,
@@ -70,6 +72,7 @@ where
         ServiceDescription {
             service_hash: config.service_hash().as_str().to_string(),
             service_name: config.name().as_str().to_string(),
+            original_service_name: config.original_name().map(|name| name.as_str().to_string()),
             attributes: config.attributes().clone(),
             pattern: *config.messaging_pattern(),
             nodes: service.dynamic_details.as_ref().map(NodeList::from),
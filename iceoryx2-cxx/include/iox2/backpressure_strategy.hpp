@@ -22,6 +22,10 @@ enum class BackpressureStrategy : uint8_t {
     /// Retries until the receiver has consumed some
     /// data from the full buffer and there is space again
     RetryUntilDelivered,
+    /// Retries until the receiver has consumed some data from the full buffer and there is
+    /// space again or until the timeout has passed. The timeout is provided with the
+    /// `backpressure_timeout` setting of the port factories, otherwise a timeout of zero is used.
+    RetryUntilDeliveredOrTimeout,
     /// Do not deliver the data to receiver with a full buffer
     DiscardData,
 };
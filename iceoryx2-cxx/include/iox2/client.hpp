@@ -13,7 +13,9 @@
 #ifndef IOX2_CLIENT_HPP
 #define IOX2_CLIENT_HPP
 
+#include "iox2/bb/duration.hpp"
 #include "iox2/bb/expected.hpp"
+#include "iox2/bb/optional.hpp"
 #include "iox2/bb/slice.hpp"
 #include "iox2/deployment/iceoryx2_cxx_feature_flags.hpp"
 #include "iox2/internal/helper.hpp"
@@ -51,6 +53,10 @@ class Client {
     /// if the [`Server`]s buffer is full.
     auto backpressure_strategy() const -> BackpressureStrategy;
 
+    /// Returns the timeout of [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`] or
+    /// [`bb::NULLOPT`] if the [`Client`] uses another strategy.
+    auto backpressure_timeout() const -> bb::Optional<bb::Duration>;
+
     /// Returns the maximal active requests a [`Client`] can send.
     auto max_active_requests() const -> uint64_t;
 
@@ -190,6 +196,22 @@ Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUser
     return iox2::bb::into<BackpressureStrategy>(static_cast<int>(iox2_client_backpressure_strategy(&m_handle)));
 }
 
+template <ServiceType Service,
+          typename RequestPayload,
+          typename RequestUserHeader,
+          typename ResponsePayload,
+          typename ResponseUserHeader>
+inline auto
+Client<Service, RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader>::backpressure_timeout() const
+    -> bb::Optional<bb::Duration> {
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    if (iox2_client_backpressure_timeout(&m_handle, &seconds, &nanoseconds)) {
+        return bb::Duration::from_secs(seconds) + bb::Duration::from_nanos(nanoseconds);
+    }
+    return bb::NULLOPT;
+}
+
 template <ServiceType Service,
           typename RequestPayload,
           typename RequestUserHeader,
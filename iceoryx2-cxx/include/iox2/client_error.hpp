@@ -34,6 +34,12 @@ enum class ClientCreateError : uint8_t {
     /// When the [`Client`] requires more active requests than the
     /// [`Service`] offers, the creation will fail.
     MaxActiveRequestsExceedsMaxSupportedActiveRequestsOfService,
+    /// The data segment of the [`Client`] shall be backed by huge pages but the
+    /// system does not support them.
+    DataSegmentHugePagesNotSupported,
+    /// The data segment of the [`Client`] could not be locked in memory, for instance
+    /// since the memory lock limit of the process is exceeded.
+    UnableToLockDataSegment,
 };
 } // namespace iox2
 #endif
@@ -0,0 +1,29 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_DATA_SEGMENT_ALLOCATOR_HPP
+#define IOX2_DATA_SEGMENT_ALLOCATOR_HPP
+
+#include <cstdint>
+
+namespace iox2 {
+/// Defines the allocator that manages the samples in the data segment of a [`Publisher`].
+enum class DataSegmentAllocator : uint8_t {
+    /// Every sample has the size of the largest sample.
+    Pool,
+    /// Every sample is sized to its slice length and rounded up to the next power of two.
+    /// The data segment is never reallocated.
+    Buddy
+};
+} // namespace iox2
+
+#endif
@@ -24,6 +24,7 @@
 #include "iox2/client_error.hpp"
 #include "iox2/config_creation_error.hpp"
 #include "iox2/connection_failure.hpp"
+#include "iox2/data_segment_allocator.hpp"
 #include "iox2/degradation_action.hpp"
 #include "iox2/degradation_cause.hpp"
 #include "iox2/entry_handle_error.hpp"
@@ -709,6 +710,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenInternalFailure;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPES:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypes;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_STRUCTURE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypeStructure;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleMessagingPattern;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
@@ -786,6 +789,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::InternalFailure;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPES:
         return iox2::PublishSubscribeOpenError::IncompatibleTypes;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_STRUCTURE:
+        return iox2::PublishSubscribeOpenError::IncompatibleTypeStructure;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
         return iox2::PublishSubscribeOpenError::IncompatibleMessagingPattern;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
@@ -864,6 +869,8 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_INTERNAL_FAILURE;
     case iox2::PublishSubscribeOpenError::IncompatibleTypes:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPES;
+    case iox2::PublishSubscribeOpenError::IncompatibleTypeStructure:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_STRUCTURE;
     case iox2::PublishSubscribeOpenError::IncompatibleMessagingPattern:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN;
     case iox2::PublishSubscribeOpenError::IncompatibleAttributes:
@@ -946,6 +953,8 @@ constexpr auto from<int, iox2::PublishSubscribeCreateError>(const int value) noe
         IOX2_UNREACHABLE();
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPES:
         IOX2_UNREACHABLE();
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_STRUCTURE:
+        IOX2_UNREACHABLE();
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
         IOX2_UNREACHABLE();
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
@@ -1036,6 +1045,8 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_INTERNAL_FAILURE;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypes:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPES;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleTypeStructure:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_TYPE_STRUCTURE;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleMessagingPattern:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleAttributes:
@@ -1157,6 +1168,8 @@ constexpr auto from<int, iox2::RequestResponseCreateError>(const int value) noex
         IOX2_UNREACHABLE();
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE:
         IOX2_UNREACHABLE();
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE:
+        IOX2_UNREACHABLE();
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
         IOX2_UNREACHABLE();
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
@@ -1255,6 +1268,8 @@ constexpr auto from<int, iox2::RequestResponseOpenError>(const int value) noexce
         return iox2::RequestResponseOpenError::HangsInCreation;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE:
         return iox2::RequestResponseOpenError::IncompatibleRequestOrResponseType;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE:
+        return iox2::RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
         return iox2::RequestResponseOpenError::IncompatibleAttributes;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
@@ -1336,6 +1351,8 @@ constexpr auto from<iox2::RequestResponseOpenError, iox2_request_response_open_o
         return iox2_request_response_open_or_create_error_e_O_HANGS_IN_CREATION;
     case iox2::RequestResponseOpenError::IncompatibleRequestOrResponseType:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE;
+    case iox2::RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE;
     case iox2::RequestResponseOpenError::IncompatibleAttributes:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES;
     case iox2::RequestResponseOpenError::IncompatibleMessagingPattern:
@@ -1402,6 +1419,8 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::OpenHangsInCreation;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestOrResponseType;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestOrResponseTypeStructure;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleAttributes;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN:
@@ -1483,6 +1502,8 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_O_HANGS_IN_CREATION;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestOrResponseType:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleRequestOrResponseTypeStructure:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleAttributes:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_ATTRIBUTES;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleMessagingPattern:
@@ -1569,6 +1590,8 @@ constexpr auto from<int, iox2::BlackboardCreateError>(const int value) noexcept
         return iox2::BlackboardCreateError::UnableToCreateServiceTag;
     case iox2_blackboard_create_error_e_C_SERVICE_CONFIG_COULD_NOT_BE_CREATED:
         return iox2::BlackboardCreateError::ServiceConfigCouldNotBeCreated;
+    case iox2_blackboard_create_error_e_C_INCOMPATIBLE_SNAPSHOT:
+        return iox2::BlackboardCreateError::IncompatibleSnapshot;
     }
 
     IOX2_UNREACHABLE();
@@ -1599,6 +1622,8 @@ from<iox2::BlackboardCreateError, iox2_blackboard_create_error_e>(const iox2::Bl
         return iox2_blackboard_create_error_e_C_UNABLE_TO_CREATE_SERVICE_TAG;
     case iox2::BlackboardCreateError::ServiceConfigCouldNotBeCreated:
         return iox2_blackboard_create_error_e_C_SERVICE_CONFIG_COULD_NOT_BE_CREATED;
+    case iox2::BlackboardCreateError::IncompatibleSnapshot:
+        return iox2_blackboard_create_error_e_C_INCOMPATIBLE_SNAPSHOT;
     }
 
     IOX2_UNREACHABLE();
@@ -1843,6 +1868,10 @@ constexpr auto from<int, iox2::ClientCreateError>(const int value) noexcept -> i
         return iox2::ClientCreateError::UnableToCreatePortTag;
     case iox2_client_create_error_e_MAX_ACTIVE_REQUESTS_EXCEEDS_MAX_SUPPORTED_ACTIVE_REQUESTS_OF_SERVICE:
         return iox2::ClientCreateError::MaxActiveRequestsExceedsMaxSupportedActiveRequestsOfService;
+    case iox2_client_create_error_e_DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED:
+        return iox2::ClientCreateError::DataSegmentHugePagesNotSupported;
+    case iox2_client_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT:
+        return iox2::ClientCreateError::UnableToLockDataSegment;
     }
 
     IOX2_UNREACHABLE();
@@ -1862,6 +1891,10 @@ constexpr auto from<iox2::ClientCreateError, iox2_client_create_error_e>(const i
         return iox2_client_create_error_e_UNABLE_TO_CREATE_PORT_TAG;
     case iox2::ClientCreateError::MaxActiveRequestsExceedsMaxSupportedActiveRequestsOfService:
         return iox2_client_create_error_e_MAX_ACTIVE_REQUESTS_EXCEEDS_MAX_SUPPORTED_ACTIVE_REQUESTS_OF_SERVICE;
+    case iox2::ClientCreateError::DataSegmentHugePagesNotSupported:
+        return iox2_client_create_error_e_DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED;
+    case iox2::ClientCreateError::UnableToLockDataSegment:
+        return iox2_client_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT;
     }
 
     IOX2_UNREACHABLE();
@@ -1884,6 +1917,10 @@ constexpr auto from<int, iox2::ServerCreateError>(const int value) noexcept -> i
         return iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy;
     case iox2_server_create_error_e_UNABLE_TO_CREATE_PORT_TAG:
         return iox2::ServerCreateError::UnableToCreatePortTag;
+    case iox2_server_create_error_e_DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED:
+        return iox2::ServerCreateError::DataSegmentHugePagesNotSupported;
+    case iox2_server_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT:
+        return iox2::ServerCreateError::UnableToLockDataSegment;
     }
 
     IOX2_UNREACHABLE();
@@ -1901,6 +1938,10 @@ constexpr auto from<iox2::ServerCreateError, iox2_server_create_error_e>(const i
         return iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
     case iox2::ServerCreateError::UnableToCreatePortTag:
         return iox2_server_create_error_e_UNABLE_TO_CREATE_PORT_TAG;
+    case iox2::ServerCreateError::DataSegmentHugePagesNotSupported:
+        return iox2_server_create_error_e_DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED;
+    case iox2::ServerCreateError::UnableToLockDataSegment:
+        return iox2_server_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT;
     }
 
     IOX2_UNREACHABLE();
@@ -2077,6 +2118,12 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy;
     case iox2_publisher_create_error_e_UNABLE_TO_CREATE_PORT_TAG:
         return iox2::PublisherCreateError::UnableToCreatePortTag;
+    case iox2_publisher_create_error_e_DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED:
+        return iox2::PublisherCreateError::DataSegmentHugePagesNotSupported;
+    case iox2_publisher_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT:
+        return iox2::PublisherCreateError::UnableToLockDataSegment;
+    case iox2_publisher_create_error_e_INCOMPATIBLE_PAYLOAD_VERSION:
+        return iox2::PublisherCreateError::IncompatiblePayloadVersion;
     }
 
     IOX2_UNREACHABLE();
@@ -2095,6 +2142,12 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
     case iox2::PublisherCreateError::UnableToCreatePortTag:
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_PORT_TAG;
+    case iox2::PublisherCreateError::DataSegmentHugePagesNotSupported:
+        return iox2_publisher_create_error_e_DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED;
+    case iox2::PublisherCreateError::UnableToLockDataSegment:
+        return iox2_publisher_create_error_e_UNABLE_TO_LOCK_DATA_SEGMENT;
+    case iox2::PublisherCreateError::IncompatiblePayloadVersion:
+        return iox2_publisher_create_error_e_INCOMPATIBLE_PAYLOAD_VERSION;
     }
 
     IOX2_UNREACHABLE();
@@ -2177,6 +2230,10 @@ constexpr auto from<int, iox2::SendError>(const int value) noexcept -> iox2::Sen
         return iox2::SendError::UnableToDeliver;
     case iox2_send_error_e_INTERNAL_ERROR:
         return iox2::SendError::InternalError;
+    case iox2_send_error_e_DELIVERY_TIMEOUT:
+        return iox2::SendError::DeliveryTimeout;
+    case iox2_send_error_e_REQUEST_EXPIRED:
+        return iox2::SendError::RequestExpired;
     }
 
     IOX2_UNREACHABLE();
@@ -2203,6 +2260,10 @@ constexpr auto from<iox2::SendError, iox2_send_error_e>(const iox2::SendError va
         return iox2_send_error_e_UNABLE_TO_DELIVER;
     case iox2::SendError::InternalError:
         return iox2_send_error_e_INTERNAL_ERROR;
+    case iox2::SendError::DeliveryTimeout:
+        return iox2_send_error_e_DELIVERY_TIMEOUT;
+    case iox2::SendError::RequestExpired:
+        return iox2_send_error_e_REQUEST_EXPIRED;
     }
 
     IOX2_UNREACHABLE();
@@ -2310,6 +2371,10 @@ constexpr auto from<int, iox2::RequestSendError>(const int value) noexcept -> io
         return iox2::RequestSendError::UnableToDeliver;
     case iox2_request_send_error_e_INTERNAL_ERROR:
         return iox2::RequestSendError::InternalError;
+    case iox2_request_send_error_e_DELIVERY_TIMEOUT:
+        return iox2::RequestSendError::DeliveryTimeout;
+    case iox2_request_send_error_e_REQUEST_EXPIRED:
+        return iox2::RequestSendError::RequestExpired;
     }
 
     IOX2_UNREACHABLE();
@@ -2339,6 +2404,10 @@ constexpr auto from<iox2::RequestSendError, iox2_request_send_error_e>(const iox
         return iox2_request_send_error_e_UNABLE_TO_DELIVER;
     case iox2::RequestSendError::InternalError:
         return iox2_request_send_error_e_INTERNAL_ERROR;
+    case iox2::RequestSendError::DeliveryTimeout:
+        return iox2_request_send_error_e_DELIVERY_TIMEOUT;
+    case iox2::RequestSendError::RequestExpired:
+        return iox2_request_send_error_e_REQUEST_EXPIRED;
     }
 
     IOX2_UNREACHABLE();
@@ -2416,6 +2485,8 @@ constexpr auto from<int, iox2::BackpressureStrategy>(const int value) noexcept -
     switch (variant) {
     case iox2_backpressure_strategy_e_RETRY_UNTIL_DELIVERED:
         return iox2::BackpressureStrategy::RetryUntilDelivered;
+    case iox2_backpressure_strategy_e_RETRY_UNTIL_DELIVERED_OR_TIMEOUT:
+        return iox2::BackpressureStrategy::RetryUntilDeliveredOrTimeout;
     case iox2_backpressure_strategy_e_DISCARD_DATA:
         return iox2::BackpressureStrategy::DiscardData;
     }
@@ -2428,6 +2499,8 @@ constexpr auto from<iox2::BackpressureStrategy, int>(const iox2::BackpressureStr
     switch (value) {
     case iox2::BackpressureStrategy::RetryUntilDelivered:
         return iox2_backpressure_strategy_e_RETRY_UNTIL_DELIVERED;
+    case iox2::BackpressureStrategy::RetryUntilDeliveredOrTimeout:
+        return iox2_backpressure_strategy_e_RETRY_UNTIL_DELIVERED_OR_TIMEOUT;
     case iox2::BackpressureStrategy::DiscardData:
         return iox2_backpressure_strategy_e_DISCARD_DATA;
     }
@@ -2632,6 +2705,8 @@ constexpr auto from<int, iox2::WaitSetAttachmentError>(const int value) noexcept
         return iox2::WaitSetAttachmentError::InternalError;
     case iox2_waitset_attachment_error_e_INSUFFICIENT_RESOURCES:
         return iox2::WaitSetAttachmentError::InsufficientResources;
+    case iox2_waitset_attachment_error_e_NO_DEADLINE:
+        return iox2::WaitSetAttachmentError::NoDeadline;
     }
 
     IOX2_UNREACHABLE();
@@ -2650,6 +2725,8 @@ from<iox2::WaitSetAttachmentError, iox2_waitset_attachment_error_e>(const iox2::
         return iox2_waitset_attachment_error_e_INTERNAL_ERROR;
     case iox2::WaitSetAttachmentError::InsufficientResources:
         return iox2_waitset_attachment_error_e_INSUFFICIENT_RESOURCES;
+    case iox2::WaitSetAttachmentError::NoDeadline:
+        return iox2_waitset_attachment_error_e_NO_DEADLINE;
     }
 
     IOX2_UNREACHABLE();
@@ -2747,6 +2824,19 @@ constexpr auto from<iox2::AllocationStrategy, iox2_allocation_strategy_e>(const
     IOX2_UNREACHABLE();
 }
 
+template <>
+constexpr auto from<iox2::DataSegmentAllocator, iox2_data_segment_allocator_e>(
+    const iox2::DataSegmentAllocator value) noexcept -> iox2_data_segment_allocator_e {
+    switch (value) {
+    case iox2::DataSegmentAllocator::Pool:
+        return iox2_data_segment_allocator_e_POOL;
+    case iox2::DataSegmentAllocator::Buddy:
+        return iox2_data_segment_allocator_e_BUDDY;
+    }
+
+    IOX2_UNREACHABLE();
+}
+
 template <>
 constexpr auto from<int, iox2::NodeCleanupFailure>(const int value) noexcept -> iox2::NodeCleanupFailure {
     const auto variant = static_cast<iox2_node_cleanup_failure_e>(value);
@@ -30,6 +30,7 @@
 #include "iox2/config.hpp"
 #include "iox2/config_creation_error.hpp"
 #include "iox2/connection_failure.hpp"
+#include "iox2/data_segment_allocator.hpp"
 #include "iox2/dynamic_config_blackboard.hpp"
 #include "iox2/dynamic_config_event.hpp"
 #include "iox2/dynamic_config_publish_subscribe.hpp"
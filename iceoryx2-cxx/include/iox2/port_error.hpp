@@ -58,7 +58,12 @@ enum class SendError : uint8_t {
     /// The sample could not be delivered
     UnableToDeliver,
     /// An internal mechanisms failed and the data could not be delivered to all receivers.
-    InternalError
+    InternalError,
+    /// The sample could not be delivered to all receivers within the delivery timeout.
+    DeliveryTimeout,
+    /// The response was not sent since the deadline of the corresponding request was already
+    /// reached.
+    RequestExpired
 };
 
 /// Defines the failure that can occur when receiving data with
@@ -104,6 +109,10 @@ enum class RequestSendError : uint8_t {
     UnableToDeliver,
     /// An internal mechanisms failed and the data could not be delivered to all receivers.
     InternalError,
+    /// The request could not be delivered to all receivers within the delivery timeout.
+    DeliveryTimeout,
+    /// The deadline of the request was already reached.
+    RequestExpired,
 };
 } // namespace iox2
 
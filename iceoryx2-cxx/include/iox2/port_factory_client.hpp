@@ -16,6 +16,7 @@
 #include "iox2/backpressure_handler.hpp"
 #include "iox2/backpressure_strategy.hpp"
 #include "iox2/bb/detail/builder.hpp"
+#include "iox2/bb/duration.hpp"
 #include "iox2/bb/expected.hpp"
 #include "iox2/bb/optional.hpp"
 #include "iox2/client.hpp"
@@ -45,6 +46,14 @@ class PortFactoryClient {
 #else
     IOX2_BUILDER_OPTIONAL(BackpressureStrategy, backpressure_strategy);
 #endif
+
+    /// Sets the [`BackpressureStrategy`] to [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`]
+    /// with the provided timeout. Overrides the value set with `backpressure_strategy`.
+#ifdef DOXYGEN_MACRO_FIX
+    auto backpressure_timeout(const bb::Duration value) -> decltype(auto);
+#else
+    IOX2_BUILDER_OPTIONAL(bb::Duration, backpressure_timeout);
+#endif
     /// Defines the required maximal amount of active requests the [`Client`] can send.
     /// Smallest possible value is `1`.
 #ifdef DOXYGEN_MACRO_FIX
@@ -237,6 +246,10 @@ inline auto PortFactoryClient<Service, RequestPayload, RequestUserHeader, Respon
         iox2_port_factory_client_builder_backpressure_strategy(
             &m_handle, static_cast<iox2_backpressure_strategy_e>(bb::into<int>(m_backpressure_strategy.value())));
     }
+    if (m_backpressure_timeout.has_value()) {
+        iox2_port_factory_client_builder_backpressure_timeout(
+            &m_handle, m_backpressure_timeout.value().as_secs(), m_backpressure_timeout.value().subsec_nanos());
+    }
     if (m_max_slice_len.has_value()) {
         iox2_port_factory_client_builder_set_initial_max_slice_len(&m_handle, m_max_slice_len.value());
     } else {
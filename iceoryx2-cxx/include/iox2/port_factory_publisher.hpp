@@ -17,8 +17,10 @@
 #include "iox2/backpressure_handler.hpp"
 #include "iox2/backpressure_strategy.hpp"
 #include "iox2/bb/detail/builder.hpp"
+#include "iox2/bb/duration.hpp"
 #include "iox2/bb/expected.hpp"
 #include "iox2/bb/optional.hpp"
+#include "iox2/data_segment_allocator.hpp"
 #include "iox2/degradation_handler.hpp"
 #include "iox2/internal/callback_context.hpp"
 #include "iox2/internal/iceoryx2.hpp"
@@ -42,6 +44,14 @@ class PortFactoryPublisher {
     IOX2_BUILDER_OPTIONAL(BackpressureStrategy, backpressure_strategy);
 #endif
 
+    /// Sets the [`BackpressureStrategy`] to [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`]
+    /// with the provided timeout. Overrides the value set with `backpressure_strategy`.
+#ifdef DOXYGEN_MACRO_FIX
+    auto backpressure_timeout(const bb::Duration value) -> decltype(auto);
+#else
+    IOX2_BUILDER_OPTIONAL(bb::Duration, backpressure_timeout);
+#endif
+
     /// Defines how many [`SampleMut`] the [`Publisher`] can loan with
     /// [`Publisher::loan()`] or [`Publisher::loan_uninit()`] in parallel.
 #ifdef DOXYGEN_MACRO_FIX
@@ -98,6 +108,12 @@ class PortFactoryPublisher {
               typename = std::enable_if_t<bb::IsSlice<T>::VALUE || has_flatbuffer_marker<T>(), void>>
     auto allocation_strategy(AllocationStrategy value) && -> PortFactoryPublisher&&;
 
+    /// Defines the [`DataSegmentAllocator`] that manages the samples in the data segment. With
+    /// [`DataSegmentAllocator::Buddy`] every sample occupies only the memory its slice requires.
+    /// The data segment is then never reallocated and the [`AllocationStrategy`] is ignored.
+    template <typename T = Payload, typename = std::enable_if_t<bb::IsSlice<T>::VALUE, void>>
+    auto data_segment_allocator(DataSegmentAllocator value) && -> PortFactoryPublisher&&;
+
     /// Sets the [`DegradationHandler`] of the [`Publisher`]. Whenever a connection to a
     /// [`Subscriber`] is corrupted, this handler is called and depending on the returned
     /// [`DegradationAction`] measures will be taken.
@@ -127,6 +143,7 @@ class PortFactoryPublisher {
     iox2_port_factory_publisher_builder_h m_handle = nullptr;
     bb::Optional<uint64_t> m_max_slice_len;
     bb::Optional<AllocationStrategy> m_allocation_strategy;
+    bb::Optional<DataSegmentAllocator> m_data_segment_allocator;
     bb::Optional<OverridePreallocationCallback> m_override_preallocation_callback;
     bb::Optional<DegradationHandler* const> m_degradation_handler;
     bb::Optional<BackpressureHandler* const> m_backpressure_handler;
@@ -160,6 +177,14 @@ inline auto PortFactoryPublisher<S, Payload, UserHeader>::allocation_strategy(
     return std::move(*this);
 }
 
+template <ServiceType S, typename Payload, typename UserHeader>
+template <typename T, typename>
+inline auto PortFactoryPublisher<S, Payload, UserHeader>::data_segment_allocator(
+    DataSegmentAllocator value) && -> PortFactoryPublisher&& {
+    m_data_segment_allocator.emplace(value);
+    return std::move(*this);
+}
+
 template <ServiceType S, typename Payload, typename UserHeader>
 template <typename T, typename>
 inline auto
@@ -189,6 +214,10 @@ inline auto PortFactoryPublisher<S, Payload, UserHeader>::create() && -> bb::Exp
         iox2_port_factory_publisher_builder_backpressure_strategy(
             &m_handle, static_cast<iox2_backpressure_strategy_e>(bb::into<int>(m_backpressure_strategy.value())));
     }
+    if (m_backpressure_timeout.has_value()) {
+        iox2_port_factory_publisher_builder_backpressure_timeout(
+            &m_handle, m_backpressure_timeout.value().as_secs(), m_backpressure_timeout.value().subsec_nanos());
+    }
     if (m_max_slice_len.has_value()) {
         iox2_port_factory_publisher_builder_set_initial_max_slice_len(&m_handle, m_max_slice_len.value());
     } else {
@@ -201,6 +230,10 @@ inline auto PortFactoryPublisher<S, Payload, UserHeader>::create() && -> bb::Exp
         iox2_port_factory_publisher_builder_set_allocation_strategy(
             &m_handle, bb::into<iox2_allocation_strategy_e>(m_allocation_strategy.value()));
     }
+    if (m_data_segment_allocator.has_value()) {
+        iox2_port_factory_publisher_builder_set_data_segment_allocator(
+            &m_handle, bb::into<iox2_data_segment_allocator_e>(m_data_segment_allocator.value()));
+    }
 
     if (m_degradation_handler.has_value()) {
         iox2_port_factory_publisher_builder_set_degradation_handler(
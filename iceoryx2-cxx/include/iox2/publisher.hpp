@@ -13,7 +13,9 @@
 #ifndef IOX2_PUBLISHER_HPP
 #define IOX2_PUBLISHER_HPP
 
+#include "iox2/bb/duration.hpp"
 #include "iox2/bb/expected.hpp"
+#include "iox2/bb/optional.hpp"
 #include "iox2/bb/slice.hpp"
 #include "iox2/connection_failure.hpp"
 #include "iox2/deployment/iceoryx2_cxx_feature_flags.hpp"
@@ -58,6 +60,10 @@ class Publisher {
     /// since the [`Subscriber`]s buffer is full.
     auto backpressure_strategy() const -> BackpressureStrategy;
 
+    /// Returns the timeout of [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`] or
+    /// [`bb::NULLOPT`] if the [`Publisher`] uses another strategy.
+    auto backpressure_timeout() const -> bb::Optional<bb::Duration>;
+
     /// Returns the maximum number of elements that can be loaned in a slice.
     template <typename T = Payload, typename = std::enable_if_t<bb::IsSlice<T>::VALUE, void>>
     auto initial_max_slice_len() const -> uint64_t;
@@ -170,6 +176,16 @@ inline auto Publisher<S, Payload, UserHeader>::backpressure_strategy() const ->
     return iox2::bb::into<BackpressureStrategy>(static_cast<int>(iox2_publisher_backpressure_strategy(&m_handle)));
 }
 
+template <ServiceType S, typename Payload, typename UserHeader>
+inline auto Publisher<S, Payload, UserHeader>::backpressure_timeout() const -> bb::Optional<bb::Duration> {
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    if (iox2_publisher_backpressure_timeout(&m_handle, &seconds, &nanoseconds)) {
+        return bb::Duration::from_secs(seconds) + bb::Duration::from_nanos(nanoseconds);
+    }
+    return bb::NULLOPT;
+}
+
 
 template <ServiceType S, typename Payload, typename UserHeader>
 template <typename T, typename>
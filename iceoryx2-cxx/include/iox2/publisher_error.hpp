@@ -34,6 +34,15 @@ enum class PublisherCreateError : uint8_t {
     FailedToDeployThreadsafetyPolicy,
     /// The tracking port tag, required for cleanup, could not be created.
     UnableToCreatePortTag,
+    /// The data segment of the [`Publisher`] shall be backed by huge pages but the
+    /// system does not support them.
+    DataSegmentHugePagesNotSupported,
+    /// The data segment of the [`Publisher`] could not be locked in memory, for instance
+    /// since the memory lock limit of the process is exceeded.
+    UnableToLockDataSegment,
+    /// The version of the payload type differs from the version of the [`Service`].
+    /// Only [`Subscriber`]s can use a different but compatible version.
+    IncompatiblePayloadVersion,
 };
 } // namespace iox2
 
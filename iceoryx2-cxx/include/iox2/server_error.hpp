@@ -29,6 +29,12 @@ enum class ServerCreateError : uint8_t {
     FailedToDeployThreadsafetyPolicy,
     /// The tracking port tag, required for cleanup, could not be created.
     UnableToCreatePortTag,
+    /// The data segment of the [`Server`] shall be backed by huge pages but the
+    /// system does not support them.
+    DataSegmentHugePagesNotSupported,
+    /// The data segment of the [`Server`] could not be locked in memory, for instance
+    /// since the memory lock limit of the process is exceeded.
+    UnableToLockDataSegment,
 };
 } // namespace iox2
 #endif
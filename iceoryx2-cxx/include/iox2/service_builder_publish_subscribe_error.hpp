@@ -28,6 +28,9 @@ enum class PublishSubscribeOpenError : uint8_t {
     InternalFailure,
     /// The [`Service`] has the wrong payload type.
     IncompatibleTypes,
+    /// The [`Service`] has a payload or user header type with the same name and layout but a
+    /// different structure, for instance swapped fields.
+    IncompatibleTypeStructure,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does
@@ -128,6 +131,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenInternalFailure,
     /// The [`Service`] has the wrong payload type.
     OpenIncompatibleTypes,
+    /// The [`Service`] has a payload or user header type with the same name and layout but a
+    /// different structure, for instance swapped fields.
+    OpenIncompatibleTypeStructure,
     /// The [`Service`] has the wrong messaging pattern.
     OpenIncompatibleMessagingPattern,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does
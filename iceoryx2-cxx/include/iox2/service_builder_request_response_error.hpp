@@ -47,6 +47,9 @@ enum class RequestResponseOpenError : uint8_t {
     /// The [`Service`] has the wrong request or response payload type, request or response header
     /// type or type alignment.
     IncompatibleRequestOrResponseType,
+    /// The [`Service`] has a request or response type with the same name and layout but a
+    /// different structure, for instance swapped fields.
+    IncompatibleRequestOrResponseTypeStructure,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -137,6 +140,9 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     /// The [`Service`] has the wrong request or response payload type, request or response header
     /// type or type alignment.
     OpenIncompatibleRequestOrResponseType,
+    /// The [`Service`] has a request or response type with the same name and layout but a
+    /// different structure, for instance swapped fields.
+    OpenIncompatibleRequestOrResponseTypeStructure,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     OpenIncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -41,6 +41,9 @@ class SubscriberDetailsView {
     /// The history request for the subscriber.
     auto history_request() const -> uint64_t;
 
+    /// The payload version that was negotiated between the subscriber and the service.
+    auto payload_version() const -> uint32_t;
+
   private:
     template <typename T, typename>
     friend auto internal::list_ports_callback(void* context, T port_details_view) -> iox2_callback_progression_e;
@@ -47,6 +47,8 @@ enum class WaitSetAttachmentError : uint8_t {
     InternalError,
     /// Insufficient resources to add another attachment to the [`WaitSet`].
     InsufficientResources,
+    /// The attached request has no deadline.
+    NoDeadline,
 };
 
 /// Defines the failures that can occur when calling [`WaitSet::run()`].
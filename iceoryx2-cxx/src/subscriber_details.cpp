@@ -51,4 +51,8 @@ auto SubscriberDetailsView::buffer_size() const -> uint64_t {
 auto SubscriberDetailsView::history_request() const -> uint64_t {
     return iox2_subscriber_details_history_request(m_handle);
 }
+
+auto SubscriberDetailsView::payload_version() const -> uint32_t {
+    return iox2_subscriber_details_payload_version(m_handle);
+}
 } // namespace iox2
@@ -88,6 +88,7 @@ TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatiblePayloadVersion)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -164,6 +165,7 @@ TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotExist)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::InternalFailure)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleTypes)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleTypeStructure)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleMessagingPattern)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IncompatibleAttributes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedMinBufferSize)), 1U);
@@ -196,6 +198,7 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotExist)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenInternalFailure)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleTypes)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleTypeStructure)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleMessagingPattern)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIncompatibleAttributes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedMinBufferSize)), 1U);
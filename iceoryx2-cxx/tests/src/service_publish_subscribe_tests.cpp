@@ -1627,6 +1627,7 @@ TYPED_TEST(ServicePublishSubscribeTest, subscriber_details_are_correct) {
         EXPECT_TRUE(subscriber_details_view.node_id() == node.id());
         EXPECT_TRUE(subscriber_details_view.buffer_size() == subscriber.buffer_size());
         EXPECT_THAT(subscriber_details_view.history_request(), Eq(HISTORY_REQUEST));
+        EXPECT_THAT(subscriber_details_view.payload_version(), Eq(1U));
         return CallbackProgression::Stop;
     });
 
@@ -1880,4 +1881,24 @@ TYPED_TEST(ServicePublishSubscribeTest, port_names_can_be_set) {
     ASSERT_THAT(subscriber.name().to_string(), subscriber_name.to_string());
 }
 
+TYPED_TEST(ServicePublishSubscribeTest, publisher_backpressure_timeout_can_be_set) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+    constexpr uint64_t TIMEOUT_IN_MS = 25;
+
+    const auto service_name = iox2::testing::generate_service_name();
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().value();
+    auto service = node.service_builder(service_name).template publish_subscribe<uint64_t>().create().value();
+
+    auto publisher_without_timeout =
+        service.publisher_builder().backpressure_strategy(BackpressureStrategy::DiscardData).create().value();
+    ASSERT_FALSE(publisher_without_timeout.backpressure_timeout().has_value());
+
+    auto publisher =
+        service.publisher_builder().backpressure_timeout(bb::Duration::from_millis(TIMEOUT_IN_MS)).create().value();
+    ASSERT_THAT(publisher.backpressure_strategy(), Eq(BackpressureStrategy::RetryUntilDeliveredOrTimeout));
+    ASSERT_TRUE(publisher.backpressure_timeout().has_value());
+    ASSERT_THAT(publisher.backpressure_timeout().value().as_millis(), Eq(TIMEOUT_IN_MS));
+}
+
 } // namespace
@@ -164,6 +164,45 @@ pub unsafe extern "C" fn iox2_client_backpressure_strategy(
     }
 }
 
+/// Acquires the timeout of the backpressure strategy of the client. If the client uses
+/// `BackpressureStrategy::RetryUntilDeliveredOrTimeout` the arguments `seconds` and
+/// `nanoseconds` are set and `true` is returned. Otherwise, `false` is returned and the
+/// arguments are not touched.
+///
+/// # Safety
+///
+/// * `handle` is valid and non-null
+/// * `seconds` is pointing to a valid memory location and non-null
+/// * `nanoseconds` is pointing to a valid memory location and non-null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_client_backpressure_timeout(
+    handle: iox2_client_h_ref,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) -> bool {
+    handle.assert_non_null();
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    unsafe {
+        let client = &mut *handle.as_type();
+
+        let strategy = match client.service_type {
+            iox2_service_type_e::IPC => client.value.as_mut().ipc.backpressure_strategy(),
+            iox2_service_type_e::LOCAL => client.value.as_mut().local.backpressure_strategy(),
+        };
+
+        match strategy {
+            BackpressureStrategy::RetryUntilDeliveredOrTimeout(timeout) => {
+                *seconds = timeout.as_secs();
+                *nanoseconds = timeout.subsec_nanos();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Returns the initial max slice len with which the client was created.
 ///
 /// # Arguments
@@ -143,10 +143,12 @@ pub unsafe extern "C" fn iox2_log(
             CStr::from_ptr(origin)
         };
         let message = CStr::from_ptr(message);
+        let origin = origin.to_string_lossy();
 
         __internal_print_log_msg(
             log_level.into(),
-            format_args!("{}", origin.to_string_lossy()),
+            &origin,
+            format_args!("{origin}"),
             format_args!("{}", message.to_string_lossy()),
         );
     }
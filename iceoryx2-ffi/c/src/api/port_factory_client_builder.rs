@@ -24,6 +24,8 @@ use crate::IOX2_OK;
 use crate::api::ClientUnion;
 use core::ffi::{c_char, c_int};
 use core::mem::ManuallyDrop;
+use core::time::Duration;
+use iceoryx2::port::backpressure_strategy::BackpressureStrategy;
 use iceoryx2::service::port_factory::client::{ClientCreateError, PortFactoryClient};
 use iceoryx2_bb_elementary_traits::AsCStr;
 use iceoryx2_ffi_macros::{CStrRepr, iceoryx2_ffi};
@@ -37,6 +39,8 @@ pub enum iox2_client_create_error_e {
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
     UNABLE_TO_CREATE_PORT_TAG,
     MAX_ACTIVE_REQUESTS_EXCEEDS_MAX_SUPPORTED_ACTIVE_REQUESTS_OF_SERVICE,
+    DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED,
+    UNABLE_TO_LOCK_DATA_SEGMENT,
 }
 
 impl IntoCInt for ClientCreateError {
@@ -57,6 +61,12 @@ impl IntoCInt for ClientCreateError {
             ClientCreateError::MaxActiveRequestsExceedsMaxSupportedActiveRequestsOfService => {
                 iox2_client_create_error_e::MAX_ACTIVE_REQUESTS_EXCEEDS_MAX_SUPPORTED_ACTIVE_REQUESTS_OF_SERVICE
             }
+            ClientCreateError::DataSegmentHugePagesNotSupported => {
+                iox2_client_create_error_e::DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED
+            }
+            ClientCreateError::UnableToLockDataSegment => {
+                iox2_client_create_error_e::UNABLE_TO_LOCK_DATA_SEGMENT
+            }
         }) as c_int
     }
 }
@@ -118,7 +128,7 @@ impl PortFactoryClientBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryClientBuilderUnion>
 pub struct iox2_port_factory_client_builder_storage_t {
-    internal: [u8; 352], // magic number obtained with size_of::<Option<PortFactoryClientBuilderUnion>>()
+    internal: [u8; 400], // magic number obtained with size_of::<Option<PortFactoryClientBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -540,6 +550,50 @@ pub unsafe extern "C" fn iox2_port_factory_client_builder_backpressure_strategy(
     }
 }
 
+/// Sets the backpressure strategy of the client to
+/// `BackpressureStrategy::RetryUntilDeliveredOrTimeout` with the provided timeout.
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_client_builder_h_ref`]
+///   obtained by [`iox2_port_factory_request_response_client_builder`](crate::iox2_port_factory_request_response_client_builder).
+/// * `seconds` - the seconds part of the timeout
+/// * `nanoseconds` - the nanoseconds part of the timeout
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_port_factory_client_builder_backpressure_timeout(
+    port_factory_handle: iox2_port_factory_client_builder_h_ref,
+    seconds: u64,
+    nanoseconds: u32,
+) {
+    port_factory_handle.assert_non_null();
+    let value = BackpressureStrategy::RetryUntilDeliveredOrTimeout(
+        Duration::from_secs(seconds) + Duration::from_nanos(nanoseconds as u64),
+    );
+    unsafe {
+        let handle = &mut *port_factory_handle.as_type();
+        match handle.service_type {
+            iox2_service_type_e::IPC => {
+                let builder = ManuallyDrop::take(&mut handle.value.as_mut().ipc);
+
+                handle.set(PortFactoryClientBuilderUnion::new_ipc(
+                    builder.backpressure_strategy(value),
+                ));
+            }
+            iox2_service_type_e::LOCAL => {
+                let builder = ManuallyDrop::take(&mut handle.value.as_mut().local);
+
+                handle.set(PortFactoryClientBuilderUnion::new_local(
+                    builder.backpressure_strategy(value),
+                ));
+            }
+        }
+    }
+}
+
 /// Sets the maximal active requests for the client.
 ///
 /// # Arguments
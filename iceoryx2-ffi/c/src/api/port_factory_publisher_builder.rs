@@ -29,6 +29,7 @@ use iceoryx2_ffi_macros::iceoryx2_ffi;
 
 use core::ffi::{c_char, c_int};
 use core::mem::ManuallyDrop;
+use core::time::Duration;
 
 // BEGIN types definition
 
@@ -57,10 +58,33 @@ impl From<iox2_allocation_strategy_e> for AllocationStrategy {
     }
 }
 
+/// Defines the allocator that manages the samples in the data segment of the publisher.
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_data_segment_allocator_e {
+    /// Every sample has the size of the largest sample.
+    POOL,
+    /// Every sample is sized to its slice length and rounded up to the next power of two.
+    /// The data segment is never reallocated.
+    BUDDY,
+}
+
+impl From<iox2_data_segment_allocator_e> for DataSegmentAllocator {
+    fn from(value: iox2_data_segment_allocator_e) -> Self {
+        match value {
+            iox2_data_segment_allocator_e::POOL => DataSegmentAllocator::Pool,
+            iox2_data_segment_allocator_e::BUDDY => DataSegmentAllocator::Buddy,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub enum iox2_backpressure_strategy_e {
     RETRY_UNTIL_DELIVERED,
+    /// When set via the enum alone, a timeout of zero is used. The timeout can be provided
+    /// with the `*_backpressure_timeout` functions of the port builders.
+    RETRY_UNTIL_DELIVERED_OR_TIMEOUT,
     DISCARD_DATA,
 }
 
@@ -70,6 +94,9 @@ impl From<iox2_backpressure_strategy_e> for BackpressureStrategy {
             iox2_backpressure_strategy_e::RETRY_UNTIL_DELIVERED => {
                 BackpressureStrategy::RetryUntilDelivered
             }
+            iox2_backpressure_strategy_e::RETRY_UNTIL_DELIVERED_OR_TIMEOUT => {
+                BackpressureStrategy::RetryUntilDeliveredOrTimeout(Duration::ZERO)
+            }
             iox2_backpressure_strategy_e::DISCARD_DATA => BackpressureStrategy::DiscardData,
         }
     }
@@ -81,6 +108,9 @@ impl From<BackpressureStrategy> for iox2_backpressure_strategy_e {
             BackpressureStrategy::RetryUntilDelivered => {
                 iox2_backpressure_strategy_e::RETRY_UNTIL_DELIVERED
             }
+            BackpressureStrategy::RetryUntilDeliveredOrTimeout(_) => {
+                iox2_backpressure_strategy_e::RETRY_UNTIL_DELIVERED_OR_TIMEOUT
+            }
             BackpressureStrategy::DiscardData => iox2_backpressure_strategy_e::DISCARD_DATA,
         }
     }
@@ -99,6 +129,9 @@ pub enum iox2_publisher_create_error_e {
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
     UNABLE_TO_CREATE_PORT_TAG,
+    DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED,
+    UNABLE_TO_LOCK_DATA_SEGMENT,
+    INCOMPATIBLE_PAYLOAD_VERSION,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -116,6 +149,15 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::UnableToCreatePortTag => {
                 iox2_publisher_create_error_e::UNABLE_TO_CREATE_PORT_TAG
             }
+            PublisherCreateError::DataSegmentHugePagesNotSupported => {
+                iox2_publisher_create_error_e::DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED
+            }
+            PublisherCreateError::UnableToLockDataSegment => {
+                iox2_publisher_create_error_e::UNABLE_TO_LOCK_DATA_SEGMENT
+            }
+            PublisherCreateError::IncompatiblePayloadVersion => {
+                iox2_publisher_create_error_e::INCOMPATIBLE_PAYLOAD_VERSION
+            }
         }) as c_int
     }
 }
@@ -146,7 +188,7 @@ impl PortFactoryPublisherBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryPublisherBuilderUnion>
 pub struct iox2_port_factory_publisher_builder_storage_t {
-    internal: [u8; 288], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
+    internal: [u8; 320], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -336,6 +378,45 @@ pub unsafe extern "C" fn iox2_port_factory_publisher_builder_set_allocation_stra
     }
 }
 
+/// Sets the [`iox2_data_segment_allocator_e`] for the publisher
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_publisher_builder_h_ref`]
+///   obtained by [`iox2_port_factory_pub_sub_publisher_builder`](crate::iox2_port_factory_pub_sub_publisher_builder).
+/// * `value` - The allocator that manages the samples of the data segment
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_port_factory_publisher_builder_set_data_segment_allocator(
+    port_factory_handle: iox2_port_factory_publisher_builder_h_ref,
+    value: iox2_data_segment_allocator_e,
+) {
+    port_factory_handle.assert_non_null();
+    unsafe {
+        let port_factory_struct = &mut *port_factory_handle.as_type();
+        match port_factory_struct.service_type {
+            iox2_service_type_e::IPC => {
+                let port_factory = ManuallyDrop::take(&mut port_factory_struct.value.as_mut().ipc);
+
+                port_factory_struct.set(PortFactoryPublisherBuilderUnion::new_ipc(
+                    port_factory.data_segment_allocator(value.into()),
+                ));
+            }
+            iox2_service_type_e::LOCAL => {
+                let port_factory =
+                    ManuallyDrop::take(&mut port_factory_struct.value.as_mut().local);
+
+                port_factory_struct.set(PortFactoryPublisherBuilderUnion::new_local(
+                    port_factory.data_segment_allocator(value.into()),
+                ));
+            }
+        }
+    }
+}
+
 /// Sets the degradation handler for the publisher
 ///
 /// # Arguments
@@ -556,6 +637,50 @@ pub unsafe extern "C" fn iox2_port_factory_publisher_builder_backpressure_strate
     }
 }
 
+/// Sets the backpressure strategy of the publisher to
+/// `BackpressureStrategy::RetryUntilDeliveredOrTimeout` with the provided timeout.
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_publisher_builder_h_ref`]
+///   obtained by [`iox2_port_factory_pub_sub_publisher_builder`](crate::iox2_port_factory_pub_sub_publisher_builder).
+/// * `seconds` - the seconds part of the timeout
+/// * `nanoseconds` - the nanoseconds part of the timeout
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_port_factory_publisher_builder_backpressure_timeout(
+    port_factory_handle: iox2_port_factory_publisher_builder_h_ref,
+    seconds: u64,
+    nanoseconds: u32,
+) {
+    port_factory_handle.assert_non_null();
+    let value = BackpressureStrategy::RetryUntilDeliveredOrTimeout(
+        Duration::from_secs(seconds) + Duration::from_nanos(nanoseconds as u64),
+    );
+    unsafe {
+        let handle = &mut *port_factory_handle.as_type();
+        match handle.service_type {
+            iox2_service_type_e::IPC => {
+                let builder = ManuallyDrop::take(&mut handle.value.as_mut().ipc);
+
+                handle.set(PortFactoryPublisherBuilderUnion::new_ipc(
+                    builder.backpressure_strategy(value),
+                ));
+            }
+            iox2_service_type_e::LOCAL => {
+                let builder = ManuallyDrop::take(&mut handle.value.as_mut().local);
+
+                handle.set(PortFactoryPublisherBuilderUnion::new_local(
+                    builder.backpressure_strategy(value),
+                ));
+            }
+        }
+    }
+}
+
 /// Sets the port name for the `Publisher`
 ///
 /// # Arguments
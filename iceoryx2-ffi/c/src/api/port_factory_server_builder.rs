@@ -13,6 +13,7 @@
 #![allow(non_camel_case_types)]
 
 use core::mem::ManuallyDrop;
+use core::time::Duration;
 
 use crate::IOX2_OK;
 use crate::api::ServerUnion;
@@ -26,6 +27,7 @@ use super::{
     iox2_service_type_e,
 };
 use core::ffi::{c_char, c_int};
+use iceoryx2::port::backpressure_strategy::BackpressureStrategy;
 use iceoryx2::service::port_factory::server::{PortFactoryServer, ServerCreateError};
 use iceoryx2_bb_elementary_traits::AsCStr;
 use iceoryx2_ffi_macros::{CStrRepr, iceoryx2_ffi};
@@ -38,6 +40,8 @@ pub enum iox2_server_create_error_e {
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
     UNABLE_TO_CREATE_PORT_TAG,
+    DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED,
+    UNABLE_TO_LOCK_DATA_SEGMENT,
 }
 
 impl IntoCInt for ServerCreateError {
@@ -55,6 +59,12 @@ impl IntoCInt for ServerCreateError {
             ServerCreateError::UnableToCreatePortTag => {
                 iox2_server_create_error_e::UNABLE_TO_CREATE_PORT_TAG
             }
+            ServerCreateError::DataSegmentHugePagesNotSupported => {
+                iox2_server_create_error_e::DATA_SEGMENT_HUGE_PAGES_NOT_SUPPORTED
+            }
+            ServerCreateError::UnableToLockDataSegment => {
+                iox2_server_create_error_e::UNABLE_TO_LOCK_DATA_SEGMENT
+            }
         }) as c_int
     }
 }
@@ -116,7 +126,7 @@ impl PortFactoryServerBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryServerBuilderUnion>
 pub struct iox2_port_factory_server_builder_storage_t {
-    internal: [u8; 336], // magic number obtained with size_of::<Option<PortFactoryServerBuilderUnion>>()
+    internal: [u8; 352], // magic number obtained with size_of::<Option<PortFactoryServerBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -581,6 +591,50 @@ pub unsafe extern "C" fn iox2_port_factory_server_builder_backpressure_strategy(
     }
 }
 
+/// Sets the backpressure strategy of the server to
+/// `BackpressureStrategy::RetryUntilDeliveredOrTimeout` with the provided timeout.
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_server_builder_h_ref`]
+///   obtained by [`iox2_port_factory_request_response_server_builder`](crate::iox2_port_factory_request_response_server_builder).
+/// * `seconds` - the seconds part of the timeout
+/// * `nanoseconds` - the nanoseconds part of the timeout
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_port_factory_server_builder_backpressure_timeout(
+    port_factory_handle: iox2_port_factory_server_builder_h_ref,
+    seconds: u64,
+    nanoseconds: u32,
+) {
+    port_factory_handle.assert_non_null();
+    let value = BackpressureStrategy::RetryUntilDeliveredOrTimeout(
+        Duration::from_secs(seconds) + Duration::from_nanos(nanoseconds as u64),
+    );
+    unsafe {
+        let handle = &mut *port_factory_handle.as_type();
+        match handle.service_type {
+            iox2_service_type_e::IPC => {
+                let builder = ManuallyDrop::take(&mut handle.value.as_mut().ipc);
+
+                handle.set(PortFactoryServerBuilderUnion::new_ipc(
+                    builder.backpressure_strategy(value),
+                ));
+            }
+            iox2_service_type_e::LOCAL => {
+                let builder = ManuallyDrop::take(&mut handle.value.as_mut().local);
+
+                handle.set(PortFactoryServerBuilderUnion::new_local(
+                    builder.backpressure_strategy(value),
+                ));
+            }
+        }
+    }
+}
+
 /// Sets the port name for the `Server`
 ///
 /// # Arguments
@@ -26,7 +26,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 56], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 64], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
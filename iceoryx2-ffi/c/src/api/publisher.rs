@@ -47,6 +47,8 @@ pub enum iox2_send_error_e {
     CONNECTION_ERROR,
     UNABLE_TO_DELIVER,
     INTERNAL_ERROR,
+    DELIVERY_TIMEOUT,
+    REQUEST_EXPIRED,
 }
 
 impl IntoCInt for SendError {
@@ -71,6 +73,8 @@ impl IntoCInt for SendError {
             }
             SendError::ConnectionError(_) => iox2_send_error_e::CONNECTION_ERROR,
             SendError::UnableToDeliver => iox2_send_error_e::UNABLE_TO_DELIVER,
+            SendError::DeliveryTimeout => iox2_send_error_e::DELIVERY_TIMEOUT,
+            SendError::RequestExpired => iox2_send_error_e::REQUEST_EXPIRED,
         }) as c_int
     }
 }
@@ -320,6 +324,45 @@ pub unsafe extern "C" fn iox2_publisher_backpressure_strategy(
     }
 }
 
+/// Acquires the timeout of the backpressure strategy of the publisher. If the publisher uses
+/// `BackpressureStrategy::RetryUntilDeliveredOrTimeout` the arguments `seconds` and
+/// `nanoseconds` are set and `true` is returned. Otherwise, `false` is returned and the
+/// arguments are not touched.
+///
+/// # Safety
+///
+/// * `handle` is valid and non-null
+/// * `seconds` is pointing to a valid memory location and non-null
+/// * `nanoseconds` is pointing to a valid memory location and non-null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_publisher_backpressure_timeout(
+    handle: iox2_publisher_h_ref,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) -> bool {
+    handle.assert_non_null();
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    unsafe {
+        let publisher = &mut *handle.as_type();
+
+        let strategy = match publisher.service_type {
+            iox2_service_type_e::IPC => publisher.value.as_mut().ipc.backpressure_strategy(),
+            iox2_service_type_e::LOCAL => publisher.value.as_mut().local.backpressure_strategy(),
+        };
+
+        match strategy {
+            BackpressureStrategy::RetryUntilDeliveredOrTimeout(timeout) => {
+                *seconds = timeout.as_secs();
+                *nanoseconds = timeout.subsec_nanos();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Returns the maximum `[u8]` length that can be loaned in one sample, i.e. the max number of
 /// elements in the `[u8]` payload type used by the C binding.
 ///
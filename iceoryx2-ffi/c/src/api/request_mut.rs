@@ -50,6 +50,8 @@ pub enum iox2_request_send_error_e {
     EXCEEDS_MAX_ACTIVE_REQUESTS,
     UNABLE_TO_DELIVER,
     INTERNAL_ERROR,
+    DELIVERY_TIMEOUT,
+    REQUEST_EXPIRED,
 }
 
 impl IntoCInt for RequestSendError {
@@ -85,6 +87,12 @@ impl IntoCInt for RequestSendError {
             RequestSendError::SendError(SendError::InternalError) => {
                 iox2_request_send_error_e::INTERNAL_ERROR
             }
+            RequestSendError::SendError(SendError::DeliveryTimeout) => {
+                iox2_request_send_error_e::DELIVERY_TIMEOUT
+            }
+            RequestSendError::SendError(SendError::RequestExpired) => {
+                iox2_request_send_error_e::REQUEST_EXPIRED
+            }
         }) as c_int
     }
 }
@@ -103,6 +103,8 @@ pub enum iox2_blackboard_create_error_e {
     C_SERVICE_CONFIG_COULD_NOT_BE_CREATED,
     #[CStr = "interrupt"]
     C_INTERRUPT,
+    #[CStr = "incompatible snapshot"]
+    C_INCOMPATIBLE_SNAPSHOT,
 }
 
 impl IntoCInt for BlackboardOpenError {
@@ -184,6 +186,9 @@ impl IntoCInt for BlackboardCreateError {
             BlackboardCreateError::UnableToCreateServiceTag => {
                 iox2_blackboard_create_error_e::C_UNABLE_TO_CREATE_SERVICE_TAG
             }
+            BlackboardCreateError::IncompatibleSnapshot => {
+                iox2_blackboard_create_error_e::C_INCOMPATIBLE_SNAPSHOT
+            }
         }) as c_int
     }
 }
@@ -49,6 +49,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_INTERNAL_FAILURE,
     #[CStr = "incompatible types"]
     O_INCOMPATIBLE_TYPES,
+    #[CStr = "incompatible type structure"]
+    O_INCOMPATIBLE_TYPE_STRUCTURE,
     #[CStr = "incompatible messaging pattern"]
     O_INCOMPATIBLE_MESSAGING_PATTERN,
     #[CStr = "incompatible attributes"]
@@ -122,6 +124,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleTypes => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPES
          }
+         PublishSubscribeOpenError::IncompatibleTypeStructure => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPE_STRUCTURE
+         }
          PublishSubscribeOpenError::IncompatibleMessagingPattern => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN
          }
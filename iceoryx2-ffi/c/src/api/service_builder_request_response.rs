@@ -78,6 +78,8 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_HANGS_IN_CREATION,
     #[CStr = "incompatible request or response type"]
     O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE,
+    #[CStr = "incompatible request or response type structure"]
+    O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE,
     #[CStr = "incompatible attributes"]
     O_INCOMPATIBLE_ATTRIBUTES,
     #[CStr = "incompatible messaging pattern"]
@@ -143,6 +145,7 @@ impl IntoCInt for RequestResponseOpenError {
             RequestResponseOpenError::ExceedsMaxNumberOfNodes => iox2_request_response_open_or_create_error_e::O_EXCEEDS_MAX_NUMBER_OF_NODES,
             RequestResponseOpenError::HangsInCreation => iox2_request_response_open_or_create_error_e::O_HANGS_IN_CREATION,
             RequestResponseOpenError::IncompatibleRequestOrResponseType => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE,
+            RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_OR_RESPONSE_TYPE_STRUCTURE,
             RequestResponseOpenError::IncompatibleAttributes => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_ATTRIBUTES,
             RequestResponseOpenError::IncompatibleMessagingPattern => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN,
             RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS,
@@ -92,3 +92,16 @@ pub unsafe extern "C" fn iox2_subscriber_details_history_request(
     debug_assert!(!handle.is_null());
     unsafe { (*handle).history_request as _ }
 }
+
+/// Returns the payload version that was negotiated between the subscriber and the service.
+///
+/// # Safety
+///
+/// * `handle` valid pointer to the subscriber details
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iox2_subscriber_details_payload_version(
+    handle: iox2_subscriber_details_ptr,
+) -> u32 {
+    debug_assert!(!handle.is_null());
+    unsafe { (*handle).payload_version }
+}
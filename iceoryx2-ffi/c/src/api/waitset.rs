@@ -86,6 +86,7 @@ pub enum iox2_waitset_attachment_error_e {
     ALREADY_ATTACHED,
     INTERNAL_ERROR,
     INSUFFICIENT_RESOURCES,
+    NO_DEADLINE,
 }
 
 impl IntoCInt for WaitSetAttachmentError {
@@ -103,6 +104,7 @@ impl IntoCInt for WaitSetAttachmentError {
             WaitSetAttachmentError::InsufficientResources => {
                 iox2_waitset_attachment_error_e::INSUFFICIENT_RESOURCES
             }
+            WaitSetAttachmentError::NoDeadline => iox2_waitset_attachment_error_e::NO_DEADLINE,
         }) as c_int
     }
 }
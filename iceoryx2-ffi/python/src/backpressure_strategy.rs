@@ -34,7 +34,10 @@ impl BackpressureStrategy {
 impl From<iceoryx2::prelude::BackpressureStrategy> for BackpressureStrategy {
     fn from(value: iceoryx2::prelude::BackpressureStrategy) -> Self {
         match value {
-            iceoryx2::prelude::BackpressureStrategy::RetryUntilDelivered => {
+            // the language bindings do not yet support a delivery timeout, the closest
+            // strategy is reported
+            iceoryx2::prelude::BackpressureStrategy::RetryUntilDelivered
+            | iceoryx2::prelude::BackpressureStrategy::RetryUntilDeliveredOrTimeout(_) => {
                 BackpressureStrategy::RetryUntilDelivered
             }
             iceoryx2::prelude::BackpressureStrategy::DiscardData => {
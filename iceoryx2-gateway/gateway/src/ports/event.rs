@@ -82,7 +82,7 @@ impl<S: Service> EventPorts<S> {
     ) -> Result<Self, CreationError> {
         let origin = format!("EventPorts<{}>::new", core::any::type_name::<S>());
 
-        let builder = node.service_builder(name).event();
+        let builder = node.service_builder_without_remapping(name).event();
         let builder = match &description.settings {
             PortSettings::Value(settings) => apply_settings(builder, settings),
             PortSettings::LocalDefaults => builder,
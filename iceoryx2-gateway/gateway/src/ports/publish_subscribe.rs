@@ -106,7 +106,7 @@ impl<S: Service> PublishSubscribePorts<S> {
         );
 
         let builder = unsafe {
-            node.service_builder(name)
+            node.service_builder_without_remapping(name)
                 .publish_subscribe::<Payload>()
                 .user_header::<Header>()
                 .__internal_set_user_header_type_details(&user_header_details)
@@ -0,0 +1,367 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Target based filtering of log messages.
+//!
+//! Every log message has a target. For messages emitted with the logging macros it is the
+//! module path of the caller, e.g. `iceoryx2::port::publisher`, for messages coming from the
+//! language bindings it is the provided origin.
+//!
+//! A log filter is a comma-separated list of directives:
+//!  * `<target>=<log level>` - sets the log level for the target and all its sub-modules
+//!  * `<log level>` - sets the global log level, see [`set_log_level()`](crate::set_log_level)
+//!  * `<target>` - enables all log messages of the target
+//!
+//! When multiple directives match a target, the most specific one wins. Targets without a
+//! matching directive use the global log level.
+//!
+//! ```
+//! use iceoryx2_log::{LogLevel, get_log_level_for, set_log_filter};
+//!
+//! set_log_filter("warn,iceoryx2_cal=trace,iceoryx2::port=error").unwrap();
+//!
+//! assert_eq!(get_log_level_for("iceoryx2_cal::event"), LogLevel::Trace);
+//! assert_eq!(get_log_level_for("iceoryx2::port::publisher"), LogLevel::Error);
+//! assert_eq!(get_log_level_for("iceoryx2::node"), LogLevel::Warn);
+//! ```
+
+use iceoryx2_log_types::LogLevel;
+use iceoryx2_pal_concurrency_sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering, fence};
+use iceoryx2_pal_concurrency_sync::cell::UnsafeCell;
+use iceoryx2_pal_concurrency_sync::spin_lock::SpinLock;
+
+use crate::{get_log_level, set_log_level};
+
+/// The maximum number of target specific directives that can be active at the same time.
+pub const MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES: usize = 32;
+
+/// The maximum length of a target in a log filter directive.
+pub const MAX_LOG_FILTER_TARGET_LENGTH: usize = 128;
+
+/// Errors that can occur when a log filter is parsed or applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFilterError {
+    /// The log level of a directive is not one of `trace`, `debug`, `info`, `warn`, `error`
+    /// or `fatal`.
+    InvalidLogLevel,
+    /// A directive has a log level but no target.
+    EmptyTarget,
+    /// The target of a directive exceeds [`MAX_LOG_FILTER_TARGET_LENGTH`].
+    TargetTooLong,
+    /// The filter contains more than [`MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES`] different targets.
+    ExceedsMaxNumberOfDirectives,
+}
+
+impl core::fmt::Display for LogFilterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LogFilterError::{self:?}")
+    }
+}
+
+impl core::error::Error for LogFilterError {}
+
+#[derive(Debug, Clone, Copy)]
+struct Directive {
+    target: [u8; MAX_LOG_FILTER_TARGET_LENGTH],
+    target_len: usize,
+    log_level: LogLevel,
+}
+
+impl Directive {
+    const fn empty() -> Self {
+        Self {
+            target: [0; MAX_LOG_FILTER_TARGET_LENGTH],
+            target_len: 0,
+            log_level: LogLevel::Trace,
+        }
+    }
+
+    fn new(target: &str, log_level: LogLevel) -> Result<Self, LogFilterError> {
+        if target.is_empty() {
+            return Err(LogFilterError::EmptyTarget);
+        }
+
+        if target.len() > MAX_LOG_FILTER_TARGET_LENGTH {
+            return Err(LogFilterError::TargetTooLong);
+        }
+
+        let mut directive = Self::empty();
+        directive.target[..target.len()].copy_from_slice(target.as_bytes());
+        directive.target_len = target.len();
+        directive.log_level = log_level;
+        Ok(directive)
+    }
+
+    fn target(&self) -> &[u8] {
+        &self.target[..self.target_len]
+    }
+
+    fn matches(&self, target: &str) -> bool {
+        let directive_target = self.target();
+        let target = target.as_bytes();
+
+        target.starts_with(directive_target)
+            && (target.len() == directive_target.len()
+                || target[directive_target.len()..].starts_with(b"::"))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Directives {
+    entries: [Directive; MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES],
+    len: usize,
+}
+
+impl Directives {
+    const fn new() -> Self {
+        Self {
+            entries: [Directive::empty(); MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES],
+            len: 0,
+        }
+    }
+
+    fn as_slice(&self) -> &[Directive] {
+        &self.entries[..self.len]
+    }
+
+    fn insert(&mut self, directive: Directive) -> Result<(), LogFilterError> {
+        if let Some(entry) = self.entries[..self.len]
+            .iter_mut()
+            .find(|entry| entry.target() == directive.target())
+        {
+            entry.log_level = directive.log_level;
+            return Ok(());
+        }
+
+        if self.len == MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES {
+            return Err(LogFilterError::ExceedsMaxNumberOfDirectives);
+        }
+
+        self.entries[self.len] = directive;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn min_log_level(&self) -> LogLevel {
+        self.as_slice()
+            .iter()
+            .map(|directive| directive.log_level)
+            .min()
+            .unwrap_or(LogLevel::Fatal)
+    }
+}
+
+/// Copy of the [`Directives`] that is read by the logging hot path without acquiring a lock.
+/// Writers are serialized by the lock of [`DIRECTIVES`] and increment the generation before
+/// and after every update. Readers retry whenever the generation was odd or changed while
+/// they were reading.
+struct DirectivesSnapshot {
+    generation: AtomicUsize,
+    directives: UnsafeCell<Directives>,
+}
+
+// SAFETY: concurrent access to the directives is guarded by the generation counter
+unsafe impl Sync for DirectivesSnapshot {}
+
+impl DirectivesSnapshot {
+    const fn new() -> Self {
+        Self {
+            generation: AtomicUsize::new(0),
+            directives: UnsafeCell::new(Directives::new()),
+        }
+    }
+
+    /// Must only be called while the lock of [`DIRECTIVES`] is held.
+    fn store(&self, directives: &Directives) {
+        let generation = self.generation.load(Ordering::Relaxed);
+        self.generation
+            .store(generation.wrapping_add(1), Ordering::Relaxed);
+        fence(Ordering::Release);
+        // SAFETY: writers are serialized, readers detect the concurrent modification with the
+        //         generation counter and discard what they have read
+        unsafe { core::ptr::write_volatile(self.directives.get(), *directives) };
+        self.generation
+            .store(generation.wrapping_add(2), Ordering::Release);
+    }
+
+    fn log_level_for(&self, target: &str) -> Option<LogLevel> {
+        loop {
+            let generation = self.generation.load(Ordering::Acquire);
+            if generation % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            let directives = self.directives.get();
+            // SAFETY: the read may race with a writer, the result is only used when the
+            //         generation did not change in the meantime
+            let len = unsafe { core::ptr::read_volatile(&raw const (*directives).len) }
+                .min(MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES);
+            let mut result: Option<Directive> = None;
+            for n in 0..len {
+                // SAFETY: see above
+                let directive =
+                    unsafe { core::ptr::read_volatile(&raw const (*directives).entries[n]) };
+                if directive.target_len <= MAX_LOG_FILTER_TARGET_LENGTH
+                    && directive.matches(target)
+                    && result.is_none_or(|r| r.target_len < directive.target_len)
+                {
+                    result = Some(directive);
+                }
+            }
+
+            fence(Ordering::Acquire);
+            if self.generation.load(Ordering::Relaxed) == generation {
+                return result.map(|directive| directive.log_level);
+            }
+        }
+    }
+}
+
+static DIRECTIVES: SpinLock<Directives> = SpinLock::new(Directives::new());
+static DIRECTIVES_SNAPSHOT: DirectivesSnapshot = DirectivesSnapshot::new();
+static HAS_DIRECTIVES: AtomicBool = AtomicBool::new(false);
+static MIN_DIRECTIVE_LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Fatal as u8);
+
+fn log_level_from_u8(value: u8) -> LogLevel {
+    match value {
+        0 => LogLevel::Trace,
+        1 => LogLevel::Debug,
+        2 => LogLevel::Info,
+        3 => LogLevel::Warn,
+        4 => LogLevel::Error,
+        _ => LogLevel::Fatal,
+    }
+}
+
+/// Parses a [`LogLevel`] case-insensitively. Returns [`None`] when the value is not one of
+/// `trace`, `debug`, `info`, `warn`, `error` or `fatal`.
+pub fn parse_log_level(value: &str) -> Option<LogLevel> {
+    [
+        ("trace", LogLevel::Trace),
+        ("debug", LogLevel::Debug),
+        ("info", LogLevel::Info),
+        ("warn", LogLevel::Warn),
+        ("error", LogLevel::Error),
+        ("fatal", LogLevel::Fatal),
+    ]
+    .iter()
+    .find(|(name, _)| name.eq_ignore_ascii_case(value))
+    .map(|(_, log_level)| *log_level)
+}
+
+fn parse_log_filter(
+    filter: &str,
+    directives: &mut Directives,
+) -> Result<Option<LogLevel>, LogFilterError> {
+    let mut global_log_level = None;
+
+    for directive in filter.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        match directive.split_once('=') {
+            Some((target, log_level)) => {
+                let log_level =
+                    parse_log_level(log_level.trim()).ok_or(LogFilterError::InvalidLogLevel)?;
+                directives.insert(Directive::new(target.trim(), log_level)?)?;
+            }
+            None => match parse_log_level(directive) {
+                Some(log_level) => global_log_level = Some(log_level),
+                None => directives.insert(Directive::new(directive, LogLevel::Trace)?)?,
+            },
+        }
+    }
+
+    Ok(global_log_level)
+}
+
+fn publish(directives: &Directives) {
+    DIRECTIVES_SNAPSHOT.store(directives);
+    MIN_DIRECTIVE_LOG_LEVEL.store(directives.min_log_level() as u8, Ordering::Relaxed);
+    HAS_DIRECTIVES.store(directives.len != 0, Ordering::Relaxed);
+}
+
+/// Checks whether the provided log filter is valid without applying it.
+pub fn validate_log_filter(filter: &str) -> Result<(), LogFilterError> {
+    let mut directives = Directives::new();
+    parse_log_filter(filter, &mut directives)?;
+    Ok(())
+}
+
+/// Replaces all target specific log levels with the directives of the provided log filter.
+/// When the filter contains a log level without target, the global log level is set as well.
+/// If the filter is invalid, nothing is changed.
+pub fn set_log_filter(filter: &str) -> Result<(), LogFilterError> {
+    let mut new_directives = Directives::new();
+    let global_log_level = parse_log_filter(filter, &mut new_directives)?;
+
+    {
+        let mut directives = DIRECTIVES.blocking_lock();
+        *directives = new_directives;
+        publish(&directives);
+    }
+
+    if let Some(log_level) = global_log_level {
+        set_log_level(log_level);
+    }
+
+    Ok(())
+}
+
+/// Sets the log level of a target and all its sub-modules. An existing directive for the
+/// same target is replaced.
+pub fn set_log_level_for(target: &str, log_level: LogLevel) -> Result<(), LogFilterError> {
+    let directive = Directive::new(target, log_level)?;
+
+    let mut directives = DIRECTIVES.blocking_lock();
+    directives.insert(directive)?;
+    publish(&directives);
+
+    Ok(())
+}
+
+/// Removes all target specific log levels. Afterwards, only the global log level is used.
+pub fn clear_log_filter() {
+    let mut directives = DIRECTIVES.blocking_lock();
+    *directives = Directives::new();
+    publish(&directives);
+}
+
+/// Returns the effective [`LogLevel`] for log messages of the provided target.
+pub fn get_log_level_for(target: &str) -> LogLevel {
+    if HAS_DIRECTIVES.load(Ordering::Relaxed)
+        && let Some(log_level) = DIRECTIVES_SNAPSHOT.log_level_for(target)
+    {
+        return log_level;
+    }
+
+    log_level_from_u8(get_log_level())
+}
+
+pub(crate) fn is_enabled(log_level: LogLevel, target: &str) -> bool {
+    let log_level = log_level as u8;
+    let global_log_level = get_log_level();
+
+    if !HAS_DIRECTIVES.load(Ordering::Relaxed) {
+        return global_log_level <= log_level;
+    }
+
+    // fast path, the message is filtered out no matter which directive matches
+    if log_level < global_log_level && log_level < MIN_DIRECTIVE_LOG_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    let threshold = DIRECTIVES_SNAPSHOT
+        .log_level_for(target)
+        .map(|log_level| log_level as u8)
+        .unwrap_or(global_log_level);
+
+    threshold <= log_level
+}
@@ -16,7 +16,9 @@
 #![warn(clippy::std_instead_of_core)]
 
 //! The Logging API for iceoryx2. It has 6 [`LogLevel`]s which can be set via
-//! [`set_log_level()`] and read via [`get_log_level()`].
+//! [`set_log_level()`] and read via [`get_log_level()`]. The log level can be
+//! refined per target, the module path of the log message origin, with
+//! [`set_log_filter()`] and [`set_log_level_for()`], see [`filter`] for details.
 //!
 //! The API includes convenience macros to combine error/panic handling
 //! directly with a logger selected from the `iceoryx2_bb_loggers` crate.
@@ -124,6 +126,10 @@
 //! }
 //! ```
 
+pub use filter::{
+    LogFilterError, clear_log_filter, get_log_level_for, parse_log_level, set_log_filter,
+    set_log_level_for, validate_log_filter,
+};
 #[cfg(feature = "std")]
 pub use from_env::{set_log_level_from_env_or, set_log_level_from_env_or_default};
 
@@ -134,6 +140,8 @@ use iceoryx2_pal_concurrency_sync::atomic::AtomicU8;
 use iceoryx2_pal_concurrency_sync::atomic::Ordering;
 use iceoryx2_pal_concurrency_sync::once::Once;
 
+pub mod filter;
+
 mod fail;
 mod log;
 
@@ -206,25 +214,21 @@ pub fn set_logger(logger: &'static dyn Log) -> bool {
 
 #[cfg(feature = "std")]
 mod from_env {
-    use super::{DEFAULT_LOG_LEVEL, LogLevel, set_log_level};
+    use super::{DEFAULT_LOG_LEVEL, LogLevel, parse_log_level, set_log_filter, set_log_level};
     use std::env;
 
     fn get_log_level_from_str_fuzzy(
         log_level_string: &str,
         log_level_fallback: LogLevel,
     ) -> LogLevel {
-        match log_level_string.to_lowercase().as_str() {
-            "trace" => LogLevel::Trace,
-            "debug" => LogLevel::Debug,
-            "info" => LogLevel::Info,
-            "warn" => LogLevel::Warn,
-            "error" => LogLevel::Error,
-            "fatal" => LogLevel::Fatal,
-            _ => {
+        match parse_log_level(log_level_string) {
+            Some(log_level) => log_level,
+            None => {
                 eprintln!(
                     "Invalid value for 'IOX2_LOG_LEVEL' environment variable!\
                     \nFound: {log_level_string:?}\
                     \nAllowed is one of: fatal, error, warn, info, debug, trace\
+                    \nor a log filter like: warn,iceoryx2_cal=trace,iceoryx2::port=error\
                     \nSetting log level as : {log_level_fallback:?}"
                 );
                 log_level_fallback
@@ -232,6 +236,10 @@ mod from_env {
         }
     }
 
+    fn is_log_filter(value: &str) -> bool {
+        value.contains('=') || value.contains(',')
+    }
+
     /// Sets the log level by reading environment variable "IOX2_LOG_LEVEL" or default it with LogLevel::INFO
     ///
     /// # Important
@@ -244,7 +252,8 @@ mod from_env {
     }
 
     /// Sets the log level by reading environment variable "IOX2_LOG_LEVEL", and if the environment variable
-    /// doesn't exit it sets it with a user-defined logging level
+    /// doesn't exit it sets it with a user-defined logging level. The environment variable
+    /// can also contain a log filter, see [`set_log_filter()`](crate::set_log_filter()).
     ///
     /// # Important
     ///
@@ -252,21 +261,31 @@ mod from_env {
     /// only messages matching that level will be forwarded. You may also need to
     /// configure the framework’s own log level settings.
     pub fn set_log_level_from_env_or(v: LogLevel) {
-        let log_level = env::var("IOX2_LOG_LEVEL")
-            .ok()
-            .map(|s| get_log_level_from_str_fuzzy(&s, v))
-            .unwrap_or(v);
-        set_log_level(log_level);
+        match env::var("IOX2_LOG_LEVEL") {
+            Ok(value) if is_log_filter(&value) => {
+                set_log_level(v);
+                if let Err(e) = set_log_filter(&value) {
+                    eprintln!(
+                        "Invalid log filter in 'IOX2_LOG_LEVEL' environment variable ({e})!\
+                        \nFound: {value:?}\
+                        \nSetting log level as : {v:?}"
+                    );
+                }
+            }
+            Ok(value) => set_log_level(get_log_level_from_str_fuzzy(&value, v)),
+            Err(_) => set_log_level(v),
+        }
     }
 }
 
 #[doc(hidden)]
 pub fn __internal_print_log_msg(
     log_level: LogLevel,
+    target: &str,
     origin: core::fmt::Arguments,
     args: core::fmt::Arguments,
 ) {
-    if get_log_level() <= log_level as u8 {
+    if filter::is_enabled(log_level, target) {
         get_logger().log(log_level, origin, args)
     }
 }
@@ -0,0 +1,235 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+extern crate iceoryx2_bb_loggers;
+
+use iceoryx2_pal_concurrency_sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_log::filter::{MAX_LOG_FILTER_TARGET_LENGTH, MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES};
+use iceoryx2_log::*;
+
+// the log filter is process global, the tests must not run concurrently
+static LOCK: Mutex<()> = Mutex::new(());
+
+struct CountingLogger {
+    counter: AtomicUsize,
+}
+
+impl Log for CountingLogger {
+    fn log(
+        &self,
+        _log_level: LogLevel,
+        _origin: core::fmt::Arguments,
+        _formatted_message: core::fmt::Arguments,
+    ) {
+        self.counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+static LOGGER: CountingLogger = CountingLogger {
+    counter: AtomicUsize::new(0),
+};
+
+fn reset(log_level: LogLevel) {
+    clear_log_filter();
+    set_log_level(log_level);
+}
+
+#[test]
+fn log_level_for_target_without_directive_is_global_log_level() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Warn);
+
+    assert_that!(get_log_level_for("iceoryx2::port"), eq LogLevel::Warn);
+
+    set_log_level(LogLevel::Debug);
+    assert_that!(get_log_level_for("iceoryx2::port"), eq LogLevel::Debug);
+}
+
+#[test]
+fn log_filter_applies_to_target_and_its_sub_modules() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+
+    assert_that!(
+        set_log_filter("iceoryx2_cal=trace,iceoryx2::port=warn"),
+        is_ok
+    );
+
+    assert_that!(get_log_level_for("iceoryx2_cal"), eq LogLevel::Trace);
+    assert_that!(get_log_level_for("iceoryx2_cal::event::common"), eq LogLevel::Trace);
+    assert_that!(get_log_level_for("iceoryx2::port"), eq LogLevel::Warn);
+    assert_that!(get_log_level_for("iceoryx2::port::publisher"), eq LogLevel::Warn);
+    assert_that!(get_log_level_for("iceoryx2::node"), eq LogLevel::Info);
+    assert_that!(get_log_level_for("iceoryx2_cal_extension"), eq LogLevel::Info);
+    assert_that!(get_log_level_for("iceoryx2::portable"), eq LogLevel::Info);
+}
+
+#[test]
+fn most_specific_directive_wins() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+
+    assert_that!(
+        set_log_filter("iceoryx2::port::publisher=trace,iceoryx2=error"),
+        is_ok
+    );
+
+    assert_that!(get_log_level_for("iceoryx2::port::publisher"), eq LogLevel::Trace);
+    assert_that!(get_log_level_for("iceoryx2::port::subscriber"), eq LogLevel::Error);
+}
+
+#[test]
+fn log_filter_with_level_only_directive_sets_global_log_level() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+
+    assert_that!(set_log_filter(" ERROR , iceoryx2 = Debug "), is_ok);
+
+    assert_that!(get_log_level(), eq LogLevel::Error as u8);
+    assert_that!(get_log_level_for("iceoryx2"), eq LogLevel::Debug);
+}
+
+#[test]
+fn target_only_directive_enables_all_messages() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Fatal);
+
+    assert_that!(set_log_filter("my_app::module"), is_ok);
+
+    assert_that!(get_log_level_for("my_app::module"), eq LogLevel::Trace);
+}
+
+#[test]
+fn set_log_filter_replaces_previous_directives() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+
+    assert_that!(set_log_filter("iceoryx2=trace"), is_ok);
+    assert_that!(set_log_filter("iceoryx2_cal=error"), is_ok);
+
+    assert_that!(get_log_level_for("iceoryx2"), eq LogLevel::Info);
+    assert_that!(get_log_level_for("iceoryx2_cal"), eq LogLevel::Error);
+}
+
+#[test]
+fn set_log_level_for_adds_and_updates_directives() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+
+    assert_that!(set_log_level_for("iceoryx2", LogLevel::Trace), is_ok);
+    assert_that!(set_log_level_for("iceoryx2_cal", LogLevel::Warn), is_ok);
+    assert_that!(get_log_level_for("iceoryx2"), eq LogLevel::Trace);
+    assert_that!(get_log_level_for("iceoryx2_cal"), eq LogLevel::Warn);
+
+    assert_that!(set_log_level_for("iceoryx2", LogLevel::Error), is_ok);
+    assert_that!(get_log_level_for("iceoryx2"), eq LogLevel::Error);
+
+    clear_log_filter();
+    assert_that!(get_log_level_for("iceoryx2"), eq LogLevel::Info);
+    assert_that!(get_log_level_for("iceoryx2_cal"), eq LogLevel::Info);
+}
+
+#[test]
+fn invalid_log_filter_is_rejected_and_changes_nothing() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+    assert_that!(set_log_filter("iceoryx2=debug"), is_ok);
+
+    let too_long_target = "a".repeat(MAX_LOG_FILTER_TARGET_LENGTH + 1);
+
+    assert_that!(set_log_filter("warn,iceoryx2=verbose"), eq Err(LogFilterError::InvalidLogLevel));
+    assert_that!(set_log_filter("=trace"), eq Err(LogFilterError::EmptyTarget));
+    assert_that!(set_log_filter(&too_long_target), eq Err(LogFilterError::TargetTooLong));
+    assert_that!(validate_log_filter("iceoryx2=verbose"), eq Err(LogFilterError::InvalidLogLevel));
+
+    assert_that!(get_log_level(), eq LogLevel::Info as u8);
+    assert_that!(get_log_level_for("iceoryx2"), eq LogLevel::Debug);
+}
+
+#[test]
+fn log_filter_with_too_many_directives_fails() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Info);
+
+    for i in 0..MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES {
+        assert_that!(
+            set_log_level_for(&format!("target_{i}"), LogLevel::Trace),
+            is_ok
+        );
+    }
+    assert_that!(set_log_level_for("target_0", LogLevel::Warn), is_ok);
+    assert_that!(set_log_level_for("one_more", LogLevel::Trace), eq Err(LogFilterError::ExceedsMaxNumberOfDirectives));
+
+    let filter = (0..=MAX_NUMBER_OF_LOG_FILTER_DIRECTIVES)
+        .map(|i| format!("target_{i}=debug"))
+        .collect::<Vec<_>>()
+        .join(",");
+    assert_that!(validate_log_filter(&filter), eq Err(LogFilterError::ExceedsMaxNumberOfDirectives));
+
+    clear_log_filter();
+}
+
+#[test]
+fn log_messages_are_filtered_by_target() {
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Error);
+    set_logger(&LOGGER);
+
+    let counter = || LOGGER.counter.load(Ordering::Relaxed);
+    let start = counter();
+
+    debug!("filtered by global log level");
+    assert_that!(counter(), eq start);
+
+    assert_that!(set_log_level_for(module_path!(), LogLevel::Debug), is_ok);
+    debug!("enabled for this module");
+    trace!("still filtered");
+    assert_that!(counter(), eq start + 1);
+
+    assert_that!(set_log_filter("trace,log_filter_tests=fatal"), is_ok);
+    error!("filtered for this module");
+    assert_that!(counter(), eq start + 1);
+
+    reset(LogLevel::Info);
+}
+
+#[test]
+fn readers_observe_consistent_log_filter_while_it_is_replaced() {
+    const NUMBER_OF_UPDATES: usize = 2000;
+    let _guard = LOCK.lock().unwrap();
+    reset(LogLevel::Warn);
+    set_log_filter("iceoryx2::port=trace").unwrap();
+
+    let keep_running = AtomicBool::new(true);
+    std::thread::scope(|s| {
+        let reader = s.spawn(|| {
+            while keep_running.load(Ordering::Relaxed) {
+                let log_level = get_log_level_for("iceoryx2::port::publisher");
+                assert_that!(log_level == LogLevel::Trace || log_level == LogLevel::Error, eq true);
+            }
+        });
+
+        for n in 0..NUMBER_OF_UPDATES {
+            if n % 2 == 0 {
+                set_log_filter("iceoryx2=info,iceoryx2::port=error").unwrap();
+            } else {
+                set_log_filter("iceoryx2::port=trace,iceoryx2_cal=debug").unwrap();
+            }
+        }
+
+        keep_running.store(false, Ordering::Relaxed);
+        reader.join().unwrap();
+    });
+}
@@ -59,6 +59,7 @@ pub const MAP_SHARED: int = libc::MAP_SHARED as _;
 pub const MAP_PRIVATE: int = libc::MAP_PRIVATE as _;
 pub const MAP_ANONYMOUS: int = libc::MAP_ANONYMOUS as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MADV_HUGEPAGE: int = libc::MADV_HUGEPAGE as _;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = libc::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = libc::PTHREAD_EXPLICIT_SCHED as _;
@@ -31,6 +31,10 @@ pub unsafe fn munlockall() -> int {
     unsafe { libc::munlockall() }
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    unsafe { libc::madvise(addr, len, advice) }
+}
+
 pub unsafe fn shm_open(_name: *const c_char, _oflag: int, _mode: mode_t) -> int {
     Errno::set(Errno::ENOSYS);
 
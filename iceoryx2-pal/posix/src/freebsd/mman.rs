@@ -38,6 +38,10 @@ pub unsafe fn munlockall() -> int {
     unsafe { crate::internal::munlockall() }
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    unsafe { crate::internal::madvise(addr, len as _, advice) }
+}
+
 unsafe fn remove_leading_path_separator(value: *const c_char) -> *const c_char {
     unsafe {
         if *value as u8 == PATH_SEPARATOR {
@@ -34,6 +34,10 @@ pub unsafe fn munlockall() -> int {
     unsafe { libc::munlockall() }
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    unsafe { libc::madvise(addr, len, advice) }
+}
+
 pub unsafe fn shm_open(name: *const c_char, oflag: int, mode: mode_t) -> int {
     unsafe { libc::shm_open(name, oflag, mode) }
 }
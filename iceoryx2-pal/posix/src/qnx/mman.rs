@@ -34,6 +34,10 @@ pub unsafe fn munlockall() -> int {
     unsafe { crate::internal::munlockall() }
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    unsafe { crate::internal::madvise(addr, len as _, advice) }
+}
+
 pub unsafe fn shm_open(name: *const c_char, oflag: int, mode: mode_t) -> int {
     unsafe { crate::internal::shm_open(name, oflag, mode) }
 }
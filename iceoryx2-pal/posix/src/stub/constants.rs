@@ -58,6 +58,7 @@ pub const PROT_EXEC: int = 8;
 pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_FAILED: *mut void = 0 as *mut void;
+pub const MADV_HUGEPAGE: int = 14;
 pub const MAP_PRIVATE: int = 2;
 pub const MAP_ANONYMOUS: int = 32;
 pub const MAP_SHARED: int = 64;
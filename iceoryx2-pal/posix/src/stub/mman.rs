@@ -35,6 +35,10 @@ pub unsafe fn munlockall() -> int {
     unimplemented!("munlockall")
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    unimplemented!("madvise")
+}
+
 pub unsafe fn shm_open(name: *const c_char, oflag: int, mode: mode_t) -> int {
     unimplemented!("shm_open")
 }
@@ -57,6 +57,7 @@ pub const MAP_SHARED: int = 64;
 pub const MAP_ANONYMOUS: int = 128;
 pub const MAP_PRIVATE: int = 256;
 pub const MAP_FAILED: *mut void = core::ptr::null_mut::<void>();
+pub const MADV_HUGEPAGE: int = 14;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
 pub const PTHREAD_MUTEX_RECURSIVE: int = 2;
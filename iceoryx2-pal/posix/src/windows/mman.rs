@@ -138,6 +138,10 @@ pub unsafe fn munlockall() -> int {
     -1
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    -1
+}
+
 unsafe fn remove_leading_path_separator(value: *const c_char) -> *const c_char {
     unsafe {
         if *value as u8 == PATH_SEPARATOR {
@@ -24,3 +24,6 @@ extern crate alloc;
 
 /// Discovery and tracking of services in an iceoryx2 system
 pub mod service_discovery;
+
+/// Subscription to all publish-subscribe services whose name matches a pattern
+pub mod multi_service_subscriber;
@@ -0,0 +1,360 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Multi Service Subscriber
+//!
+//! A `MultiServiceSubscriber` receives the samples of all publish-subscribe services whose
+//! `ServiceName` matches a pattern. In the pattern `*` matches any sequence of characters and
+//! `?` matches exactly one character. The services are discovered with the `Tracker` on every
+//! `update()` call. A matching service with the same payload and user header type is opened and
+//! a `Subscriber` is created, services with another type are ignored.
+//!
+//! A subscription is released as soon as no other `Node` is attached to the service anymore,
+//! no publisher is connected and all of its samples were received, so that the service can be
+//! removed.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_services_discovery::multi_service_subscriber::MultiServiceSubscriber;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let mut subscriber =
+//!     MultiServiceSubscriber::<ipc::Service, f32>::new(&node, "sensors/*/temperature");
+//!
+//! while node.wait(CYCLE_TIME).is_ok() {
+//!     // opens new and releases vanished services
+//!     subscriber.update()?;
+//!
+//!     while let Some(sample) = subscriber.receive()? {
+//!         println!("{}: {}", sample.service_name(), *sample.payload());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! # const CYCLE_TIME: core::time::Duration = core::time::Duration::from_millis(100);
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use core::fmt::Debug;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::Deref;
+
+use iceoryx2::node::Node;
+use iceoryx2::port::ReceiveError;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::port::update_connections::ConnectionFailure;
+use iceoryx2::prelude::{CallbackProgression, IceoryxSend, PortFactory as _, ZeroCopySend};
+use iceoryx2::sample::Sample;
+use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+use iceoryx2::service::port_factory::publish_subscribe;
+use iceoryx2::service::service_hash::ServiceHash;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+use iceoryx2::service::{Service, ServiceDetails};
+
+use crate::service_discovery::{SyncError, Tracker, TrackerEvent};
+
+/// A [`Sample`] received by a [`MultiServiceSubscriber`] together with the [`ServiceName`] of
+/// the service it originates from. It dereferences to the [`Sample`].
+#[derive(Debug)]
+pub struct MultiServiceSample<S: Service, Payload: IceoryxSend + Debug, UserHeader: ZeroCopySend> {
+    service_name: ServiceName,
+    sample: Sample<S, Payload, UserHeader>,
+}
+
+impl<S: Service, Payload: IceoryxSend + Debug, UserHeader: ZeroCopySend>
+    MultiServiceSample<S, Payload, UserHeader>
+{
+    /// Returns the [`ServiceName`] of the service the [`Sample`] was received from.
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the received [`Sample`] and releases the [`ServiceName`].
+    pub fn into_sample(self) -> Sample<S, Payload, UserHeader> {
+        self.sample
+    }
+}
+
+impl<S: Service, Payload: IceoryxSend + Debug, UserHeader: ZeroCopySend> Deref
+    for MultiServiceSample<S, Payload, UserHeader>
+{
+    type Target = Sample<S, Payload, UserHeader>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sample
+    }
+}
+
+#[derive(Debug)]
+struct Subscription<
+    S: Service,
+    Payload: IceoryxSend + Debug + 'static,
+    UserHeader: ZeroCopySend + Debug,
+> {
+    // the subscriber must be dropped before the service
+    subscriber: Subscriber<S, Payload, UserHeader>,
+    service: publish_subscribe::PortFactory<S, Payload, UserHeader>,
+}
+
+#[derive(Debug)]
+enum SubscriptionState<
+    S: Service,
+    Payload: IceoryxSend + Debug + 'static,
+    UserHeader: ZeroCopySend + Debug,
+> {
+    /// The service matches but could not yet be opened, it is retried on the next update.
+    Pending,
+    /// The service matches but has another payload type, user header type or messaging
+    /// pattern.
+    Incompatible,
+    /// The service is opened and a subscriber is connected.
+    Subscribed(Subscription<S, Payload, UserHeader>),
+}
+
+#[derive(Debug)]
+struct MatchingService<
+    S: Service,
+    Payload: IceoryxSend + Debug + 'static,
+    UserHeader: ZeroCopySend + Debug,
+> {
+    name: ServiceName,
+    state: SubscriptionState<S, Payload, UserHeader>,
+}
+
+/// Receives the samples of all publish-subscribe services whose [`ServiceName`] matches a
+/// pattern and that have the same payload and user header type.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type of the [`Node`]
+/// * `Payload` - The payload type of the matching services
+/// * `UserHeader` - The user header type of the matching services
+#[derive(Debug)]
+pub struct MultiServiceSubscriber<
+    'node,
+    S: Service,
+    Payload: IceoryxSend + Debug + 'static,
+    UserHeader: ZeroCopySend + Debug = (),
+> {
+    node: &'node Node<S>,
+    pattern: String,
+    buffer_size: Option<usize>,
+    tracker: Tracker<S>,
+    services: BTreeMap<ServiceHash, MatchingService<S, Payload, UserHeader>>,
+    last_received: Option<ServiceHash>,
+}
+
+impl<'node, S: Service, Payload: IceoryxSend + Debug + 'static, UserHeader: ZeroCopySend + Debug>
+    MultiServiceSubscriber<'node, S, Payload, UserHeader>
+{
+    /// Creates a new [`MultiServiceSubscriber`] for all services whose [`ServiceName`] matches
+    /// the pattern. `*` matches any sequence of characters and `?` matches exactly one
+    /// character. No service is opened until [`MultiServiceSubscriber::update()`] is called.
+    pub fn new(node: &'node Node<S>, pattern: &str) -> Self {
+        Self {
+            node,
+            pattern: pattern.to_string(),
+            buffer_size: None,
+            tracker: Tracker::new(node.config()),
+            services: BTreeMap::new(),
+            last_received: None,
+        }
+    }
+
+    /// Defines the buffer size of the [`Subscriber`]s that are created on the next
+    /// [`MultiServiceSubscriber::update()`] calls. If it is not set, the buffer size of the
+    /// service is used.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.buffer_size = Some(value);
+        self
+    }
+
+    /// Returns the pattern the [`ServiceName`]s are matched against.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Synchronizes with the services in the system. New matching services are opened and a
+    /// [`Subscriber`] is created, services that were removed are forgotten and subscriptions
+    /// of services where no other [`Node`] and no publisher is attached anymore are released
+    /// once all their samples were received. Services that cannot be opened yet are retried on
+    /// the next call.
+    pub fn update(&mut self) -> Result<(), SyncError> {
+        let pattern = &self.pattern;
+        let services = &mut self.services;
+        self.tracker.sync(|event| match event {
+            TrackerEvent::Added(details) => {
+                if let Some(name) = Self::matching_name(pattern, details) {
+                    services.insert(
+                        *details.static_details.service_hash(),
+                        MatchingService {
+                            name,
+                            state: SubscriptionState::Pending,
+                        },
+                    );
+                }
+            }
+            TrackerEvent::Removed(details) => {
+                services.remove(details.static_details.service_hash());
+            }
+        })?;
+
+        for service in self.services.values_mut() {
+            match &service.state {
+                SubscriptionState::Pending => {
+                    service.state = Self::subscribe(self.node, self.buffer_size, &service.name);
+                }
+                SubscriptionState::Subscribed(subscription) => {
+                    if Self::is_abandoned(self.node, subscription) {
+                        // releases the service so that it can be removed, when it is still
+                        // available on the next update, it is opened again
+                        service.state = SubscriptionState::Pending;
+                    }
+                }
+                SubscriptionState::Incompatible => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Receives a [`Sample`] from one of the subscribed services. The services are served in
+    /// a round-robin fashion so that one service cannot starve the others. If no
+    /// [`Sample`] is available [`None`] is returned.
+    pub fn receive(
+        &mut self,
+    ) -> Result<Option<MultiServiceSample<S, Payload, UserHeader>>, ReceiveError> {
+        // continue with the service after the one that delivered the last sample
+        let (after_last, up_to_last) = match self.last_received {
+            Some(last) => (
+                self.services.range((Excluded(last), Unbounded)),
+                Some(self.services.range((Unbounded, Included(last)))),
+            ),
+            None => (self.services.range::<ServiceHash, _>(..), None),
+        };
+
+        for (service_hash, service) in after_last.chain(up_to_last.into_iter().flatten()) {
+            if let SubscriptionState::Subscribed(subscription) = &service.state
+                && let Some(sample) = subscription.subscriber.receive()?
+            {
+                self.last_received = Some(*service_hash);
+                return Ok(Some(MultiServiceSample {
+                    service_name: service.name,
+                    sample,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns true when at least one subscribed service has a [`Sample`] that can be received
+    /// with [`MultiServiceSubscriber::receive()`].
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        for subscription in self.subscriptions() {
+            if subscription.subscriber.has_samples()? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns an iterator over the [`ServiceName`]s of all currently subscribed services.
+    pub fn service_names(&self) -> impl Iterator<Item = &ServiceName> + '_ {
+        self.services
+            .values()
+            .filter(|service| matches!(service.state, SubscriptionState::Subscribed(_)))
+            .map(|service| &service.name)
+    }
+
+    fn subscriptions(&self) -> impl Iterator<Item = &Subscription<S, Payload, UserHeader>> + '_ {
+        self.services
+            .values()
+            .filter_map(|service| match &service.state {
+                SubscriptionState::Subscribed(subscription) => Some(subscription),
+                _ => None,
+            })
+    }
+
+    fn matching_name(pattern: &str, details: &ServiceDetails<S>) -> Option<ServiceName> {
+        let static_details = &details.static_details;
+        match static_details.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(_) if static_details.name().matches(pattern) => {
+                Some(*static_details.name())
+            }
+            _ => None,
+        }
+    }
+
+    fn subscribe(
+        node: &Node<S>,
+        buffer_size: Option<usize>,
+        service_name: &ServiceName,
+    ) -> SubscriptionState<S, Payload, UserHeader> {
+        let service = match node
+            .service_builder_without_remapping(service_name)
+            .publish_subscribe::<Payload>()
+            .user_header::<UserHeader>()
+            .open()
+        {
+            Ok(service) => service,
+            Err(PublishSubscribeOpenError::IncompatibleTypes)
+            | Err(PublishSubscribeOpenError::IncompatibleMessagingPattern) => {
+                return SubscriptionState::Incompatible;
+            }
+            Err(_) => return SubscriptionState::Pending,
+        };
+
+        let subscriber_builder = service.subscriber_builder();
+        let subscriber_builder = match buffer_size {
+            Some(buffer_size) => subscriber_builder.buffer_size(buffer_size),
+            None => subscriber_builder,
+        };
+
+        match subscriber_builder.create() {
+            Ok(subscriber) => SubscriptionState::Subscribed(Subscription {
+                subscriber,
+                service,
+            }),
+            Err(_) => SubscriptionState::Pending,
+        }
+    }
+
+    /// A subscription is abandoned when no other [`Node`] is attached to the service, no
+    /// publisher is connected and all samples were received.
+    fn is_abandoned(node: &Node<S>, subscription: &Subscription<S, Payload, UserHeader>) -> bool {
+        if subscription.service.dynamic_config().number_of_publishers() != 0
+            || subscription.subscriber.has_samples().unwrap_or(true)
+        {
+            return false;
+        }
+
+        let own_node_id = *node.id();
+        let mut is_used_by_other_nodes = false;
+        let result = subscription.service.nodes(|node_state| {
+            if *node_state.node_id() != own_node_id {
+                is_used_by_other_nodes = true;
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+
+        // when the nodes cannot be acquired, the subscription is kept
+        !is_used_by_other_nodes && result.is_ok()
+    }
+}
@@ -137,8 +137,13 @@ impl From<PublisherCreateError> for CreationError {
                 CreationError::PublisherAlreadyExists
             }
             PublisherCreateError::UnableToCreateDataSegment
+            | PublisherCreateError::DataSegmentHugePagesNotSupported
+            | PublisherCreateError::UnableToLockDataSegment
             | PublisherCreateError::FailedToDeployThreadsafetyPolicy
-            | PublisherCreateError::UnableToCreatePortTag => CreationError::PublisherCreationError,
+            | PublisherCreateError::UnableToCreatePortTag
+            | PublisherCreateError::IncompatiblePayloadVersion => {
+                CreationError::PublisherCreationError
+            }
         }
     }
 }
@@ -0,0 +1,220 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod multi_service_subscriber {
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_discovery::multi_service_subscriber::MultiServiceSubscriber;
+
+    fn service_name_with_prefix(prefix: &ServiceName, suffix: &str) -> ServiceName {
+        ServiceName::new(&format!("{prefix}/{suffix}")).unwrap()
+    }
+
+    fn receive_all<S: Service>(
+        sut: &mut MultiServiceSubscriber<S, u64>,
+    ) -> Vec<(ServiceName, u64)> {
+        let mut samples = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            samples.push((*sample.service_name(), *sample.payload()));
+        }
+        samples
+    }
+
+    #[test]
+    fn receives_samples_of_all_matching_services<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let prefix = generate_service_name();
+        let front = service_name_with_prefix(&prefix, "front/temperature");
+        let back = service_name_with_prefix(&prefix, "back/temperature");
+        let not_matching = service_name_with_prefix(&prefix, "front/pressure");
+
+        let mut publishers = vec![];
+        for service_name in [&front, &back, &not_matching] {
+            let service = node
+                .service_builder(service_name)
+                .publish_subscribe::<u64>()
+                .create()
+                .unwrap();
+            publishers.push(service.publisher_builder().create().unwrap());
+        }
+
+        let mut sut =
+            MultiServiceSubscriber::<S, u64>::new(&node, &format!("{prefix}/*/temperature"));
+        sut.update().unwrap();
+
+        let mut service_names: Vec<ServiceName> = sut.service_names().copied().collect();
+        service_names.sort();
+        let mut expected_service_names = vec![front, back];
+        expected_service_names.sort();
+        assert_that!(service_names, eq expected_service_names);
+
+        for (n, publisher) in publishers.iter().enumerate() {
+            publisher.send_copy(n as u64).unwrap();
+        }
+
+        let mut samples = receive_all(&mut sut);
+        samples.sort();
+        let mut expected_samples = vec![(front, 0), (back, 1)];
+        expected_samples.sort();
+        assert_that!(samples, eq expected_samples);
+    }
+
+    #[test]
+    fn services_with_incompatible_types_are_ignored<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let prefix = generate_service_name();
+        let compatible = service_name_with_prefix(&prefix, "compatible");
+
+        let compatible_service = node
+            .service_builder(&compatible)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _publisher = compatible_service.publisher_builder().create().unwrap();
+        let _other_payload_service = node
+            .service_builder(&service_name_with_prefix(&prefix, "other_payload"))
+            .publish_subscribe::<u32>()
+            .create()
+            .unwrap();
+        let _other_user_header_service = node
+            .service_builder(&service_name_with_prefix(&prefix, "other_user_header"))
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+        let _event_service = node
+            .service_builder(&service_name_with_prefix(&prefix, "event"))
+            .event()
+            .create()
+            .unwrap();
+
+        let mut sut = MultiServiceSubscriber::<S, u64>::new(&node, &format!("{prefix}/*"));
+        sut.update().unwrap();
+        sut.update().unwrap();
+
+        let service_names: Vec<ServiceName> = sut.service_names().copied().collect();
+        assert_that!(service_names, eq vec![compatible]);
+    }
+
+    #[test]
+    fn services_created_later_are_subscribed_on_update<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let prefix = generate_service_name();
+        let service_name = service_name_with_prefix(&prefix, "late");
+
+        let mut sut = MultiServiceSubscriber::<S, u64>::new(&node, &format!("{prefix}/*"));
+        sut.update().unwrap();
+        assert_that!(sut.service_names().count(), eq 0);
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        sut.update().unwrap();
+        assert_that!(sut.service_names().count(), eq 1);
+
+        publisher.send_copy(42).unwrap();
+        assert_that!(sut.has_samples(), eq Ok(true));
+        assert_that!(receive_all(&mut sut), eq vec![(service_name, 42)]);
+        assert_that!(sut.has_samples(), eq Ok(false));
+    }
+
+    #[test]
+    fn services_are_received_in_round_robin_fashion<S: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let prefix = generate_service_name();
+
+        let mut publishers = vec![];
+        for suffix in ["a", "b"] {
+            let service = node
+                .service_builder(&service_name_with_prefix(&prefix, suffix))
+                .publish_subscribe::<u64>()
+                .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+                .create()
+                .unwrap();
+            publishers.push(service.publisher_builder().create().unwrap());
+        }
+
+        let mut sut = MultiServiceSubscriber::<S, u64>::new(&node, &format!("{prefix}/?"));
+        sut.update().unwrap();
+
+        for publisher in &publishers {
+            for n in 0..NUMBER_OF_SAMPLES {
+                publisher.send_copy(n).unwrap();
+            }
+        }
+
+        let samples = receive_all(&mut sut);
+        assert_that!(samples, len 2 * NUMBER_OF_SAMPLES as usize);
+        for pair in samples.chunks(2) {
+            assert_that!(pair[0].0, ne pair[1].0);
+            assert_that!(pair[0].1, eq pair[1].1);
+        }
+    }
+
+    #[test]
+    fn subscription_is_released_when_no_other_node_uses_the_service<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let publisher_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let prefix = generate_service_name();
+        let service_name = service_name_with_prefix(&prefix, "vanishing");
+
+        let service = publisher_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut sut = MultiServiceSubscriber::<S, u64>::new(&node, &format!("{prefix}/*"));
+        sut.update().unwrap();
+        assert_that!(sut.service_names().count(), eq 1);
+
+        publisher.send_copy(7).unwrap();
+        drop(publisher);
+        drop(service);
+        drop(publisher_node);
+
+        // the subscription is kept until all samples are received
+        sut.update().unwrap();
+        assert_that!(sut.service_names().count(), eq 1);
+        assert_that!(receive_all(&mut sut), eq vec![(service_name, 7)]);
+
+        sut.update().unwrap();
+        assert_that!(sut.service_names().count(), eq 0);
+        assert_that!(
+            S::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe),
+            eq Ok(false)
+        );
+
+        sut.update().unwrap();
+        assert_that!(sut.service_names().count(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::prelude::ZeroCopySend;
+use iceoryx2_bb_posix::clock::RelocatableDuration;
+
+/// The liveness contract a monitored node registers at the [`Monitor`](crate::health_monitor::Monitor).
+///
+/// The node promises to emit at least one heartbeat every `heartbeat_interval`. When it
+/// misses a heartbeat it is considered [`HealthState::Late`] and when it does not emit a
+/// heartbeat within the additional `grace_period` it is considered [`HealthState::Dead`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct LivenessContract {
+    heartbeat_interval: RelocatableDuration,
+    grace_period: RelocatableDuration,
+}
+
+impl LivenessContract {
+    /// Creates a new [`LivenessContract`].
+    pub fn new(heartbeat_interval: Duration, grace_period: Duration) -> Self {
+        Self {
+            heartbeat_interval: heartbeat_interval.into(),
+            grace_period: grace_period.into(),
+        }
+    }
+
+    /// Returns the maximum time between two heartbeats of a healthy node.
+    pub fn heartbeat_interval(&self) -> Duration {
+        self.heartbeat_interval.into()
+    }
+
+    /// Returns the time a late node has to emit a heartbeat before it is considered dead.
+    pub fn grace_period(&self) -> Duration {
+        self.grace_period.into()
+    }
+
+    /// Returns the [`HealthState`] of a node whose last heartbeat was `elapsed` ago.
+    pub fn health_state_after(&self, elapsed: Duration) -> HealthState {
+        let heartbeat_interval = self.heartbeat_interval();
+
+        if elapsed <= heartbeat_interval {
+            HealthState::Healthy
+        } else if elapsed <= heartbeat_interval.saturating_add(self.grace_period()) {
+            HealthState::Late
+        } else {
+            HealthState::Dead
+        }
+    }
+}
+
+/// The health of a node as seen by the [`Monitor`](crate::health_monitor::Monitor). The
+/// states are ordered by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ZeroCopySend)]
+#[repr(C)]
+pub enum HealthState {
+    /// The node is not registered at the monitor, either because it never emitted a
+    /// heartbeat or because it unregistered itself.
+    Unmonitored,
+
+    /// The node emits its heartbeats as agreed in its [`LivenessContract`].
+    Healthy,
+
+    /// The node missed its heartbeat interval but is still within the grace period.
+    Late,
+
+    /// The node exceeded the grace period of its [`LivenessContract`] or its process died.
+    Dead,
+}
+
+/// Published by the [`Monitor`](crate::health_monitor::Monitor) whenever the
+/// [`HealthState`] of a monitored node changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct HealthTransition {
+    /// The id of the node whose [`HealthState`] changed.
+    pub node_id: UniqueNodeId,
+
+    /// The [`HealthState`] before the transition.
+    pub previous: HealthState,
+
+    /// The [`HealthState`] after the transition.
+    pub current: HealthState,
+}
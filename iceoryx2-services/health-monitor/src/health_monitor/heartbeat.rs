@@ -0,0 +1,196 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::Node;
+use iceoryx2::port::SendError;
+use iceoryx2::port::publisher::{Publisher, PublisherCreateError};
+use iceoryx2::prelude::{ServiceName, ZeroCopySend};
+use iceoryx2::service::Service as ServiceType;
+use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
+
+use crate::health_monitor::{Config, LivenessContract};
+
+const HEARTBEAT_SERVICE_NAME: &str = "health/heartbeats/";
+
+/// The message a [`Heartbeat`] sends to the [`Monitor`](crate::health_monitor::Monitor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub enum HeartbeatMessage {
+    /// The node is alive. The first beat registers the node with the contained
+    /// [`LivenessContract`], every following beat refreshes it.
+    Beat(UniqueNodeId, LivenessContract),
+
+    /// The node shall no longer be monitored.
+    Unregister(UniqueNodeId),
+}
+
+/// Errors that can occur when creating a [`Heartbeat`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum HeartbeatCreationError {
+    /// Failed to open or create the heartbeat service.
+    ServiceCreationFailure,
+
+    /// Failed to create the publisher for the heartbeats.
+    PublisherCreationError,
+
+    /// The heartbeat service already has the maximum number of monitored nodes.
+    ExceedsMaxMonitoredNodes,
+
+    /// The initial heartbeat that registers the node could not be delivered.
+    RegistrationFailure,
+}
+
+impl core::fmt::Display for HeartbeatCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "HeartbeatCreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for HeartbeatCreationError {}
+
+impl From<PublishSubscribeOpenOrCreateError> for HeartbeatCreationError {
+    fn from(_: PublishSubscribeOpenOrCreateError) -> Self {
+        HeartbeatCreationError::ServiceCreationFailure
+    }
+}
+
+impl From<PublisherCreateError> for HeartbeatCreationError {
+    fn from(error: PublisherCreateError) -> Self {
+        match error {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => {
+                HeartbeatCreationError::ExceedsMaxMonitoredNodes
+            }
+            PublisherCreateError::UnableToCreateDataSegment
+            | PublisherCreateError::DataSegmentHugePagesNotSupported
+            | PublisherCreateError::UnableToLockDataSegment
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::UnableToCreatePortTag
+            | PublisherCreateError::IncompatiblePayloadVersion => {
+                HeartbeatCreationError::PublisherCreationError
+            }
+        }
+    }
+}
+
+impl From<SendError> for HeartbeatCreationError {
+    fn from(_: SendError) -> Self {
+        HeartbeatCreationError::RegistrationFailure
+    }
+}
+
+/// Emits the heartbeats of a monitored [`Node`].
+///
+/// On creation the [`Node`] is registered with its [`LivenessContract`] at the
+/// [`Monitor`](crate::health_monitor::Monitor). The owner must call [`Heartbeat::beat()`] at
+/// least once per [`LivenessContract::heartbeat_interval()`]. When the [`Heartbeat`] goes out
+/// of scope the [`Node`] is unregistered.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type of the monitored [`Node`].
+#[derive(Debug)]
+pub struct Heartbeat<S: ServiceType> {
+    node_id: UniqueNodeId,
+    contract: LivenessContract,
+    publisher: Publisher<S, HeartbeatMessage, ()>,
+}
+
+impl<S: ServiceType> Drop for Heartbeat<S> {
+    fn drop(&mut self) {
+        // the monitor detects the disappearance of the node anyway, when the
+        // unregistration fails the node is reported as dead instead
+        let _ = self
+            .publisher
+            .send_copy(HeartbeatMessage::Unregister(self.node_id));
+    }
+}
+
+impl<S: ServiceType> Heartbeat<S> {
+    /// Creates a [`Heartbeat`] for the provided [`Node`] and registers it with the
+    /// given [`LivenessContract`].
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - The monitored node.
+    /// * `contract` - The liveness contract the node agrees to.
+    /// * `monitor_config` - The configuration of the [`Monitor`](crate::health_monitor::Monitor),
+    ///   required to open or create a compatible heartbeat service.
+    pub fn create(
+        node: &Node<S>,
+        contract: &LivenessContract,
+        monitor_config: &Config,
+    ) -> Result<Self, HeartbeatCreationError> {
+        let service = open_or_create_heartbeat_service(node, monitor_config)?;
+        let publisher = service.publisher_builder().create()?;
+
+        let heartbeat = Self {
+            node_id: *node.id(),
+            contract: *contract,
+            publisher,
+        };
+        heartbeat.beat()?;
+
+        Ok(heartbeat)
+    }
+
+    /// Emits a heartbeat.
+    pub fn beat(&self) -> Result<(), SendError> {
+        self.publisher
+            .send_copy(HeartbeatMessage::Beat(self.node_id, self.contract))?;
+        Ok(())
+    }
+
+    /// Returns the [`LivenessContract`] the [`Node`] agreed to.
+    pub fn contract(&self) -> &LivenessContract {
+        &self.contract
+    }
+
+    /// Returns the [`UniqueNodeId`] of the monitored [`Node`].
+    pub fn node_id(&self) -> &UniqueNodeId {
+        &self.node_id
+    }
+}
+
+/// Opens or creates the heartbeat service with the settings of the monitor configuration so
+/// that monitor and monitored nodes can be started in any order.
+pub(crate) fn open_or_create_heartbeat_service<S: ServiceType>(
+    node: &Node<S>,
+    config: &Config,
+) -> Result<PortFactory<S, HeartbeatMessage, ()>, PublishSubscribeOpenOrCreateError> {
+    node.service_builder(heartbeat_service_name())
+        .publish_subscribe::<HeartbeatMessage>()
+        .max_publishers(config.max_monitored_nodes)
+        .max_subscribers(1)
+        .max_nodes(config.max_monitored_nodes + 1)
+        .subscriber_max_buffer_size(config.heartbeat_buffer_size)
+        .history_size(0)
+        .enable_safe_overflow(true)
+        .open_or_create()
+}
+
+/// Returns the service name used by [`Heartbeat`]s to reach the health monitor.
+///
+/// # Panics
+///
+/// This function will panic during the first call if the service name is invalid,
+/// which should never happen with the predefined constants.
+pub fn heartbeat_service_name() -> &'static ServiceName {
+    static SERVICE_NAME_INSTANCE: LazyLock<ServiceName> = LazyLock::new(|| {
+        ServiceName::__internal_new_prefixed(HEARTBEAT_SERVICE_NAME)
+            .expect("shouldn't occur: invalid service name for health monitor heartbeats")
+    });
+
+    &SERVICE_NAME_INSTANCE
+}
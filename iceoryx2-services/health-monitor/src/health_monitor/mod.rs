@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Health Monitor
+//!
+//! This module provides heartbeat based liveness monitoring of [`Node`](iceoryx2::node::Node)s.
+//!
+//! The health monitor consists of three main components:
+//!
+//! 1. **LivenessContract**: Defines how often a node promises to emit a heartbeat and how
+//!    long it may be late before it is considered dead.
+//!
+//! 2. **Heartbeat**: Owned by the monitored node. It registers the node with its
+//!    [`LivenessContract`] and emits the heartbeats. When it goes out of scope the node is
+//!    unregistered.
+//!
+//! 3. **Monitor**: Receives the heartbeats, detects the death of monitored nodes via
+//!    [`Node::list()`](iceoryx2::node::Node::list()) and publishes every
+//!    [`HealthTransition`] on a well-known publish-subscribe service.
+//!
+//! ## Usage
+//!
+//! The monitor is a separate process that periodically calls [`Monitor::spin()`].
+//!
+//! ```no_run
+//! use iceoryx2_services_health_monitor::health_monitor::Monitor;
+//! use iceoryx2_services_health_monitor::health_monitor::Config as MonitorConfig;
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!     let config = MonitorConfig::default();
+//!     let mut monitor = Monitor::<ipc::Service>::create(&config, &Config::global_config())?;
+//!
+//!     loop {
+//!         monitor.spin(|transition| {
+//!             println!("node {} changed from {:?} to {:?}",
+//!                 transition.node_id, transition.previous, transition.current);
+//!         })?;
+//!         // Sleep or do other work...
+//!     }
+//! }
+//! ```
+//!
+//! Every monitored node owns a [`Heartbeat`] and calls [`Heartbeat::beat()`] at least once
+//! per heartbeat interval.
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2_services_health_monitor::health_monitor::{Heartbeat, LivenessContract};
+//! use iceoryx2_services_health_monitor::health_monitor::Config as MonitorConfig;
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!     let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!     let contract = LivenessContract::new(Duration::from_millis(100), Duration::from_millis(400));
+//!     let heartbeat = Heartbeat::create(&node, &contract, &MonitorConfig::default())?;
+//!
+//!     while node.wait(contract.heartbeat_interval()).is_ok() {
+//!         heartbeat.beat()?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// The contract a monitored node agrees to and the resulting health states.
+mod contract;
+
+/// The port a monitored node uses to emit heartbeats.
+mod heartbeat;
+
+/// The monitor that tracks heartbeats and publishes health transitions.
+mod monitor;
+
+pub use contract::*;
+pub use heartbeat::*;
+pub use monitor::*;
@@ -0,0 +1,534 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::BTreeMap;
+use alloc::collections::btree_map::Entry;
+use alloc::vec::Vec;
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::{NodeListFailure, NodeState};
+use iceoryx2::port::ReceiveError;
+use iceoryx2::prelude::CallbackProgression;
+use iceoryx2::{
+    config::Config as IceoryxConfig,
+    node::{Node, NodeBuilder, NodeCreationFailure},
+    port::{
+        LoanError, SendError,
+        notifier::{Notifier, NotifierCreateError, NotifierNotifyError},
+        publisher::{Publisher, PublisherCreateError},
+        subscriber::{Subscriber, SubscriberCreateError},
+    },
+    prelude::ServiceName,
+    service::{
+        Service as ServiceType,
+        builder::{
+            event::EventOpenOrCreateError, publish_subscribe::PublishSubscribeOpenOrCreateError,
+        },
+    },
+};
+use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
+use iceoryx2_bb_posix::clock::{Time, TimeError};
+
+use crate::health_monitor::heartbeat::open_or_create_heartbeat_service;
+use crate::health_monitor::{HealthState, HealthTransition, HeartbeatMessage, LivenessContract};
+
+const SERVICE_NAME: &str = "health/states/";
+
+/// The payload type used for publishing health transitions
+pub type Payload = HealthTransition;
+
+/// Errors that can occur when creating the health monitor.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// Failed to create the underlying node.
+    NodeCreationFailure,
+
+    /// Failed to create the heartbeat or health state services.
+    ServiceCreationFailure,
+
+    /// Failed to create the publisher for reasons other than it already existing.
+    PublisherCreationError,
+
+    /// A publisher to the service already exists.
+    PublisherAlreadyExists,
+
+    /// A notifier to the service already exists.
+    NotifierAlreadyExists,
+
+    /// Failed to create the heartbeat subscriber for reasons other than another monitor
+    /// already existing.
+    SubscriberCreationError,
+
+    /// Another monitor is already receiving the heartbeats.
+    MonitorAlreadyExists,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+impl From<NodeCreationFailure> for CreationError {
+    fn from(_: NodeCreationFailure) -> Self {
+        CreationError::NodeCreationFailure
+    }
+}
+
+impl From<PublishSubscribeOpenOrCreateError> for CreationError {
+    fn from(_: PublishSubscribeOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<PublisherCreateError> for CreationError {
+    fn from(error: PublisherCreateError) -> Self {
+        match error {
+            PublisherCreateError::ExceedsMaxSupportedPublishers => {
+                CreationError::PublisherAlreadyExists
+            }
+            PublisherCreateError::UnableToCreateDataSegment
+            | PublisherCreateError::DataSegmentHugePagesNotSupported
+            | PublisherCreateError::UnableToLockDataSegment
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::UnableToCreatePortTag
+            | PublisherCreateError::IncompatiblePayloadVersion => {
+                CreationError::PublisherCreationError
+            }
+        }
+    }
+}
+
+impl From<SubscriberCreateError> for CreationError {
+    fn from(error: SubscriberCreateError) -> Self {
+        match error {
+            SubscriberCreateError::ExceedsMaxSupportedSubscribers => {
+                CreationError::MonitorAlreadyExists
+            }
+            SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService
+            | SubscriberCreateError::FailedToDeployThreadsafetyPolicy
+            | SubscriberCreateError::UnableToCreatePortTag
+            | SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService
+            | SubscriberCreateError::HistoryRequestExceedsBufferSizeOfSubscriber => {
+                CreationError::SubscriberCreationError
+            }
+        }
+    }
+}
+
+impl From<EventOpenOrCreateError> for CreationError {
+    fn from(_: EventOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<NotifierCreateError> for CreationError {
+    fn from(_: NotifierCreateError) -> Self {
+        CreationError::NotifierAlreadyExists
+    }
+}
+
+/// Errors that can occur during the spin operation of the health monitor.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpinError {
+    /// The caller does not have sufficient permissions to list the nodes of the system.
+    InsufficientPermissions,
+
+    /// Failed to receive heartbeats.
+    ReceiveFailure,
+
+    /// Failed to acquire the current time.
+    ClockFailure,
+
+    /// Failed to acquire the list of nodes in the system.
+    NodeListFailure,
+
+    /// Failed to publish a health transition.
+    PublishFailure,
+
+    /// Failed to send a notification about health transitions.
+    NotifyFailure,
+}
+
+impl core::fmt::Display for SpinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SpinError::{self:?}")
+    }
+}
+
+impl core::error::Error for SpinError {}
+
+impl From<ReceiveError> for SpinError {
+    fn from(_: ReceiveError) -> Self {
+        SpinError::ReceiveFailure
+    }
+}
+
+impl From<TimeError> for SpinError {
+    fn from(_: TimeError) -> Self {
+        SpinError::ClockFailure
+    }
+}
+
+impl From<NodeListFailure> for SpinError {
+    fn from(error: NodeListFailure) -> Self {
+        match error {
+            NodeListFailure::InsufficientPermissions => SpinError::InsufficientPermissions,
+            NodeListFailure::Interrupt | NodeListFailure::InternalError => {
+                SpinError::NodeListFailure
+            }
+        }
+    }
+}
+
+impl From<LoanError> for SpinError {
+    fn from(_: LoanError) -> Self {
+        SpinError::PublishFailure
+    }
+}
+
+impl From<SendError> for SpinError {
+    fn from(_: SendError) -> Self {
+        SpinError::PublishFailure
+    }
+}
+
+impl From<NotifierNotifyError> for SpinError {
+    fn from(_: NotifierNotifyError) -> Self {
+        SpinError::NotifyFailure
+    }
+}
+
+/// Configuration for the health monitor.
+///
+/// The heartbeat related settings must be identical for the [`Monitor`] and all
+/// [`Heartbeat`](crate::health_monitor::Heartbeat)s since both open or create the
+/// heartbeat service.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The maximum number of nodes that can be monitored at the same time.
+    pub max_monitored_nodes: usize,
+
+    /// The maximum number of heartbeats the monitor buffers between two spins.
+    pub heartbeat_buffer_size: usize,
+
+    /// Whether to verify with [`Node::list()`] that the process of a monitored node is
+    /// still alive. If disabled, death is detected only via the [`LivenessContract`].
+    pub detect_node_death: bool,
+
+    /// Whether to publish health transitions.
+    pub publish_transitions: bool,
+
+    /// The maximum number of subscribers to the health state service permitted.
+    pub max_subscribers: usize,
+
+    /// The maximum number of samples the subscriber retains in its buffer.
+    pub max_buffer_size: usize,
+
+    /// The number of older samples the subscriber can request from the service when starting.
+    pub history_size: usize,
+
+    /// Whether to send notifications on health transitions.
+    pub send_notifications: bool,
+
+    /// The maximum number of listeners to the service permitted.
+    pub max_listeners: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let defaults = iceoryx2::config::Config::default().defaults;
+        Self {
+            max_monitored_nodes: 64,
+            heartbeat_buffer_size: 256,
+            detect_node_death: true,
+            publish_transitions: true,
+            max_subscribers: defaults.publish_subscribe.max_subscribers,
+            max_buffer_size: defaults.publish_subscribe.subscriber_max_buffer_size,
+            history_size: defaults.publish_subscribe.publisher_history_size,
+            send_notifications: true,
+            max_listeners: defaults.event.max_listeners,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MonitoredNode {
+    contract: LivenessContract,
+    last_heartbeat: Time,
+    state: HealthState,
+}
+
+/// The health monitor.
+///
+/// Receives the heartbeats of all [`Heartbeat`](crate::health_monitor::Heartbeat)s,
+/// evaluates them against their [`LivenessContract`] and publishes every
+/// [`HealthTransition`] so that supervisors can restart failed components.
+///
+/// The time of a heartbeat is the time when it is received in [`Monitor::spin()`], therefore
+/// the monitor must spin considerably faster than the shortest heartbeat interval.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type that this health monitor operates on.
+#[derive(Debug)]
+pub struct Monitor<S: ServiceType> {
+    config: Config,
+    iceoryx_config: IceoryxConfig,
+    _node: Node<S>,
+    subscriber: Subscriber<S, HeartbeatMessage, ()>,
+    publisher: Option<Publisher<S, Payload, ()>>,
+    notifier: Option<Notifier<S>>,
+    nodes: BTreeMap<UniqueNodeId, MonitoredNode>,
+}
+
+impl<S: ServiceType> Monitor<S> {
+    /// Creates the health monitor.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration for the health monitor.
+    /// * `iceoryx_config` - Configuration for the underlying iceoryx system.
+    pub fn create(config: &Config, iceoryx_config: &IceoryxConfig) -> Result<Self, CreationError> {
+        let node = NodeBuilder::new().config(iceoryx_config).create::<S>()?;
+
+        let heartbeats = open_or_create_heartbeat_service(&node, config)?;
+        let subscriber = heartbeats.subscriber_builder().create()?;
+
+        let mut publisher = None;
+        if config.publish_transitions {
+            let publish_subscribe = node
+                .service_builder(service_name())
+                .publish_subscribe::<Payload>()
+                .subscriber_max_buffer_size(config.max_buffer_size)
+                .history_size(config.history_size)
+                .max_subscribers(config.max_subscribers)
+                .max_publishers(1)
+                .open_or_create()?;
+
+            publisher = Some(publish_subscribe.publisher_builder().create()?);
+        }
+
+        let mut notifier = None;
+        if config.send_notifications {
+            let event = node
+                .service_builder(service_name())
+                .event()
+                .max_listeners(config.max_listeners)
+                .max_notifiers(1)
+                .open_or_create()?;
+
+            notifier = Some(event.notifier_builder().create()?);
+        }
+
+        Ok(Monitor::<S> {
+            config: config.clone(),
+            iceoryx_config: iceoryx_config.clone(),
+            _node: node,
+            subscriber,
+            publisher,
+            notifier,
+            nodes: BTreeMap::new(),
+        })
+    }
+
+    /// Processes received heartbeats, evaluates the [`LivenessContract`]s of all monitored
+    /// nodes and publishes the resulting [`HealthTransition`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_transition` - Callback function that is called for every health transition
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SpinError`] if heartbeats could not be received, the node list could not be
+    /// acquired or a transition could not be published or notified.
+    pub fn spin<F: FnMut(&HealthTransition)>(
+        &mut self,
+        mut on_transition: F,
+    ) -> Result<(), SpinError> {
+        let mut transitions = Vec::new();
+
+        self.process_heartbeats(&mut transitions)?;
+        // dead nodes are removed before the contracts are evaluated, otherwise a dead node
+        // would first be reported as late or unresponsive
+        if self.config.detect_node_death {
+            self.detect_dead_nodes(&mut transitions)?;
+        }
+        self.evaluate_contracts(&mut transitions)?;
+
+        if let Some(publisher) = &self.publisher {
+            for transition in &transitions {
+                publisher.send_copy(*transition)?;
+            }
+        }
+
+        for transition in &transitions {
+            on_transition(transition);
+        }
+
+        if let Some(notifier) = &self.notifier
+            && !transitions.is_empty()
+        {
+            notifier.notify()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the current [`HealthState`] of the node with the given [`UniqueNodeId`].
+    pub fn health_state(&self, node_id: &UniqueNodeId) -> HealthState {
+        self.nodes
+            .get(node_id)
+            .map(|n| n.state)
+            .unwrap_or(HealthState::Unmonitored)
+    }
+
+    /// Returns the [`LivenessContract`] of the node with the given [`UniqueNodeId`] if it is
+    /// monitored.
+    pub fn contract(&self, node_id: &UniqueNodeId) -> Option<&LivenessContract> {
+        self.nodes.get(node_id).map(|n| &n.contract)
+    }
+
+    /// Iterates over all monitored nodes and their current [`HealthState`].
+    pub fn iter(&self) -> impl Iterator<Item = (&UniqueNodeId, HealthState)> + '_ {
+        self.nodes.iter().map(|(id, n)| (id, n.state))
+    }
+
+    fn process_heartbeats(
+        &mut self,
+        transitions: &mut Vec<HealthTransition>,
+    ) -> Result<(), SpinError> {
+        while let Some(sample) = self.subscriber.receive()? {
+            let now = Time::now()?;
+
+            match *sample.payload() {
+                HeartbeatMessage::Beat(node_id, contract) => match self.nodes.entry(node_id) {
+                    Entry::Vacant(slot) => {
+                        slot.insert(MonitoredNode {
+                            contract,
+                            last_heartbeat: now,
+                            state: HealthState::Healthy,
+                        });
+                        transitions.push(HealthTransition {
+                            node_id,
+                            previous: HealthState::Unmonitored,
+                            current: HealthState::Healthy,
+                        });
+                    }
+                    Entry::Occupied(mut slot) => {
+                        let node = slot.get_mut();
+                        node.contract = contract;
+                        node.last_heartbeat = now;
+                        if node.state != HealthState::Healthy {
+                            transitions.push(HealthTransition {
+                                node_id,
+                                previous: node.state,
+                                current: HealthState::Healthy,
+                            });
+                            node.state = HealthState::Healthy;
+                        }
+                    }
+                },
+                HeartbeatMessage::Unregister(node_id) => {
+                    if let Some(node) = self.nodes.remove(&node_id) {
+                        transitions.push(HealthTransition {
+                            node_id,
+                            previous: node.state,
+                            current: HealthState::Unmonitored,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn evaluate_contracts(
+        &mut self,
+        transitions: &mut Vec<HealthTransition>,
+    ) -> Result<(), SpinError> {
+        for (node_id, node) in self.nodes.iter_mut() {
+            let elapsed = node.last_heartbeat.elapsed()?;
+            let state = node.contract.health_state_after(elapsed);
+
+            // a node can only recover with a new heartbeat
+            if state > node.state {
+                transitions.push(HealthTransition {
+                    node_id: *node_id,
+                    previous: node.state,
+                    current: state,
+                });
+                node.state = state;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn detect_dead_nodes(
+        &mut self,
+        transitions: &mut Vec<HealthTransition>,
+    ) -> Result<(), SpinError> {
+        if self.nodes.is_empty() {
+            return Ok(());
+        }
+
+        let mut alive_nodes = Vec::new();
+        Node::<S>::list(&self.iceoryx_config, |node_state| {
+            match node_state {
+                NodeState::Dead(_) => (),
+                // nodes that are inaccessible or undefined are not declared dead since the
+                // monitor cannot decide it, the liveness contract covers them
+                _ => alive_nodes.push(*node_state.node_id()),
+            }
+            CallbackProgression::Continue
+        })?;
+
+        self.nodes.retain(|node_id, node| {
+            if alive_nodes.contains(node_id) {
+                return true;
+            }
+
+            if node.state != HealthState::Dead {
+                transitions.push(HealthTransition {
+                    node_id: *node_id,
+                    previous: node.state,
+                    current: HealthState::Dead,
+                });
+            }
+
+            // the process is gone, no further heartbeat can ever arrive
+            false
+        });
+
+        Ok(())
+    }
+}
+
+/// Returns the service name used by the health monitor to publish [`HealthTransition`]s.
+///
+/// # Panics
+///
+/// This function will panic during the first call if the service name is invalid,
+/// which should never happen with the predefined constants.
+pub fn service_name() -> &'static ServiceName {
+    static SERVICE_NAME_INSTANCE: LazyLock<ServiceName> = LazyLock::new(|| {
+        ServiceName::__internal_new_prefixed(SERVICE_NAME)
+            .expect("shouldn't occur: invalid service name for health monitor service")
+    });
+
+    &SERVICE_NAME_INSTANCE
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Health Monitor Services
+//!
+//! The `iceoryx2-services-health-monitor` crate provides a health monitor for the nodes
+//! of an iceoryx2 system. Nodes register a liveness contract and emit heartbeats, the
+//! monitor tracks them and publishes every change of their health so that supervisors
+//! can react on late or dead components.
+//!
+
+#![no_std]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+/// Heartbeat based liveness monitoring of nodes in an iceoryx2 system
+pub mod health_monitor;
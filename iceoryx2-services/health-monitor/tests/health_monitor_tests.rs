@@ -0,0 +1,238 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod health_monitor {
+
+    use core::time::Duration;
+
+    use iceoryx2::identifiers::UniqueNodeId;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_health_monitor::health_monitor::{
+        Config, HealthState, HealthTransition, Heartbeat, HeartbeatMessage, LivenessContract,
+        Monitor, Payload, heartbeat_service_name, service_name,
+    };
+    use iceoryx2_testing::Test;
+
+    const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(50);
+    const GRACE_PERIOD: Duration = Duration::from_millis(100);
+
+    fn contract() -> LivenessContract {
+        LivenessContract::new(HEARTBEAT_INTERVAL, GRACE_PERIOD)
+    }
+
+    fn collect_spin<S: Service>(sut: &mut Monitor<S>) -> Vec<HealthTransition> {
+        let mut transitions = vec![];
+        sut.spin(|t| transitions.push(*t)).unwrap();
+        transitions
+    }
+
+    fn transition(
+        node_id: UniqueNodeId,
+        previous: HealthState,
+        current: HealthState,
+    ) -> HealthTransition {
+        HealthTransition {
+            node_id,
+            previous,
+            current,
+        }
+    }
+
+    #[test]
+    fn heartbeat_registers_node_as_healthy<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = Monitor::<S>::create(&Config::default(), &config).unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.health_state(node.id()), eq HealthState::Unmonitored);
+
+        let heartbeat = Heartbeat::create(&node, &contract(), &Config::default()).unwrap();
+
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(*node.id(), HealthState::Unmonitored, HealthState::Healthy)]);
+        assert_that!(sut.health_state(node.id()), eq HealthState::Healthy);
+        assert_that!(sut.contract(node.id()), eq Some(heartbeat.contract()));
+        assert_that!(sut.iter().count(), eq 1);
+
+        heartbeat.beat().unwrap();
+        assert_that!(collect_spin(&mut sut), len 0);
+    }
+
+    #[test]
+    fn dropping_heartbeat_unregisters_node<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = Monitor::<S>::create(&Config::default(), &config).unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let heartbeat = Heartbeat::create(&node, &contract(), &Config::default()).unwrap();
+        collect_spin(&mut sut);
+
+        drop(heartbeat);
+
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(*node.id(), HealthState::Healthy, HealthState::Unmonitored)]);
+        assert_that!(sut.health_state(node.id()), eq HealthState::Unmonitored);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn node_without_heartbeats_becomes_late_and_then_dead<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = Monitor::<S>::create(&Config::default(), &config).unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let _heartbeat = Heartbeat::create(&node, &contract(), &Config::default()).unwrap();
+        collect_spin(&mut sut);
+
+        std::thread::sleep(HEARTBEAT_INTERVAL + Duration::from_millis(10));
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(*node.id(), HealthState::Healthy, HealthState::Late)]);
+
+        std::thread::sleep(GRACE_PERIOD);
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(*node.id(), HealthState::Late, HealthState::Dead)]);
+        assert_that!(sut.health_state(node.id()), eq HealthState::Dead);
+    }
+
+    #[test]
+    fn late_node_recovers_with_next_heartbeat<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = Monitor::<S>::create(&Config::default(), &config).unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let heartbeat = Heartbeat::create(&node, &contract(), &Config::default()).unwrap();
+        collect_spin(&mut sut);
+
+        std::thread::sleep(HEARTBEAT_INTERVAL + Duration::from_millis(10));
+        collect_spin(&mut sut);
+        assert_that!(sut.health_state(node.id()), eq HealthState::Late);
+
+        heartbeat.beat().unwrap();
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(*node.id(), HealthState::Late, HealthState::Healthy)]);
+    }
+
+    #[test]
+    fn dead_process_is_reported_as_dead<S: Service>() {
+        let test = Test::<S>::new();
+        let mut sut = Monitor::<S>::create(&Config::default(), test.config()).unwrap();
+
+        let node = test.create_node();
+        let monitored_node = test.create_node();
+        let monitored_node_id = *monitored_node.id();
+
+        let heartbeats = node
+            .service_builder(heartbeat_service_name())
+            .publish_subscribe::<HeartbeatMessage>()
+            .open()
+            .unwrap();
+        let publisher = heartbeats.publisher_builder().create().unwrap();
+        publisher
+            .send_copy(HeartbeatMessage::Beat(monitored_node_id, contract()))
+            .unwrap();
+        collect_spin(&mut sut);
+
+        Node::abandon(monitored_node);
+
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(monitored_node_id, HealthState::Healthy, HealthState::Dead)]);
+        assert_that!(sut.health_state(&monitored_node_id), eq HealthState::Unmonitored);
+    }
+
+    #[test]
+    fn dead_process_with_missed_heartbeats_is_only_reported_as_dead<S: Service>() {
+        let test = Test::<S>::new();
+        let mut sut = Monitor::<S>::create(&Config::default(), test.config()).unwrap();
+
+        let node = test.create_node();
+        let monitored_node = test.create_node();
+        let monitored_node_id = *monitored_node.id();
+
+        let heartbeats = node
+            .service_builder(heartbeat_service_name())
+            .publish_subscribe::<HeartbeatMessage>()
+            .open()
+            .unwrap();
+        let publisher = heartbeats.publisher_builder().create().unwrap();
+        publisher
+            .send_copy(HeartbeatMessage::Beat(monitored_node_id, contract()))
+            .unwrap();
+        collect_spin(&mut sut);
+
+        Node::abandon(monitored_node);
+        std::thread::sleep(HEARTBEAT_INTERVAL + Duration::from_millis(10));
+
+        let transitions = collect_spin(&mut sut);
+        assert_that!(transitions, eq vec![transition(monitored_node_id, HealthState::Healthy, HealthState::Dead)]);
+    }
+
+    #[test]
+    fn transitions_are_published_and_notified<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = Monitor::<S>::create(&Config::default(), &config).unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let subscriber = node
+            .service_builder(service_name())
+            .publish_subscribe::<Payload>()
+            .open()
+            .unwrap()
+            .subscriber_builder()
+            .create()
+            .unwrap();
+        let listener = node
+            .service_builder(service_name())
+            .event()
+            .open()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+
+        let heartbeat = Heartbeat::create(&node, &contract(), &Config::default()).unwrap();
+        collect_spin(&mut sut);
+        drop(heartbeat);
+        collect_spin(&mut sut);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq transition(*node.id(), HealthState::Unmonitored, HealthState::Healthy));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq transition(*node.id(), HealthState::Healthy, HealthState::Unmonitored));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(listener.try_wait(|_| {}).unwrap(), ge 1);
+    }
+
+    #[test]
+    fn only_one_monitor_can_receive_heartbeats<S: Service>() {
+        let config = generate_isolated_config();
+        let monitor_config = Config {
+            publish_transitions: false,
+            send_notifications: false,
+            ..Default::default()
+        };
+        let _sut = Monitor::<S>::create(&monitor_config, &config).unwrap();
+
+        let result = Monitor::<S>::create(&monitor_config, &config);
+        assert_that!(result.err(), eq Some(iceoryx2_services_health_monitor::health_monitor::CreationError::MonitorAlreadyExists));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
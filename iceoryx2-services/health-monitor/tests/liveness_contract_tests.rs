@@ -0,0 +1,43 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod liveness_contract {
+
+    use core::time::Duration;
+
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_services_health_monitor::health_monitor::{HealthState, LivenessContract};
+
+    const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(50);
+    const GRACE_PERIOD: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn maps_elapsed_time_to_health_state() {
+        let sut = LivenessContract::new(HEARTBEAT_INTERVAL, GRACE_PERIOD);
+
+        assert_that!(sut.heartbeat_interval(), eq HEARTBEAT_INTERVAL);
+        assert_that!(sut.grace_period(), eq GRACE_PERIOD);
+
+        assert_that!(sut.health_state_after(Duration::ZERO), eq HealthState::Healthy);
+        assert_that!(sut.health_state_after(HEARTBEAT_INTERVAL), eq HealthState::Healthy);
+        assert_that!(sut.health_state_after(HEARTBEAT_INTERVAL + Duration::from_nanos(1)), eq HealthState::Late);
+        assert_that!(sut.health_state_after(HEARTBEAT_INTERVAL + GRACE_PERIOD), eq HealthState::Late);
+        assert_that!(sut.health_state_after(HEARTBEAT_INTERVAL + GRACE_PERIOD + Duration::from_nanos(1)), eq HealthState::Dead);
+    }
+
+    #[test]
+    fn health_states_are_ordered_by_severity() {
+        assert_that!(HealthState::Unmonitored, lt HealthState::Healthy);
+        assert_that!(HealthState::Healthy, lt HealthState::Late);
+        assert_that!(HealthState::Late, lt HealthState::Dead);
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Log Control Services
+//!
+//! The `iceoryx2-services-log-control` crate allows an operator to change the log filter
+//! of running processes without restarting them. Processes that shall be controllable
+//! create a log control port, operators, for instance `iox2 node set-log-filter`, send
+//! new log filters to them.
+//!
+
+#![no_std]
+#![warn(missing_docs)]
+
+/// Runtime control of the log filter of processes in an iceoryx2 system
+pub mod log_control;
@@ -0,0 +1,145 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::Node;
+use iceoryx2::prelude::{ServiceName, ZeroCopySend};
+use iceoryx2::service::Service as ServiceType;
+use iceoryx2::service::builder::event::EventOpenOrCreateError;
+use iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError;
+use iceoryx2::service::port_factory::event::PortFactory as EventPortFactory;
+use iceoryx2::service::port_factory::request_response::PortFactory as RequestResponsePortFactory;
+use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
+use iceoryx2_bb_container::string::StaticString;
+
+const SERVICE_NAME: &str = "log/control/";
+
+/// The maximum length of a log filter that can be sent with a [`LogControlCommand`].
+pub const MAX_LOG_FILTER_LENGTH: usize = 512;
+
+/// Selects the nodes a [`LogControlCommand`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub enum LogControlTarget {
+    /// Every node with a [`LogControl`](crate::log_control::LogControl) applies the command.
+    AllNodes,
+
+    /// Only the node with the contained id applies the command.
+    Node(UniqueNodeId),
+}
+
+impl LogControlTarget {
+    /// Returns true when the target addresses the node with the provided id.
+    pub fn addresses(&self, node_id: &UniqueNodeId) -> bool {
+        match self {
+            LogControlTarget::AllNodes => true,
+            LogControlTarget::Node(id) => id == node_id,
+        }
+    }
+}
+
+/// Instructs the addressed nodes to replace their log filter, see
+/// [`iceoryx2_log::set_log_filter()`].
+#[derive(Debug, Clone, Copy, ZeroCopySend)]
+#[repr(C)]
+pub struct LogControlCommand {
+    /// The nodes that shall apply the log filter.
+    pub target: LogControlTarget,
+
+    /// The new log filter.
+    pub log_filter: StaticString<MAX_LOG_FILTER_LENGTH>,
+}
+
+/// The response of a controlled process to a [`LogControlCommand`] that addressed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct LogControlAcknowledgment {
+    /// The node that received the command.
+    pub node_id: UniqueNodeId,
+
+    /// True when the log filter was applied, false when the node rejected it.
+    pub is_applied: bool,
+}
+
+/// Configuration for the log control.
+///
+/// Must be identical for all [`LogControl`](crate::log_control::LogControl)s and
+/// [`LogController`](crate::log_control::LogController)s since all of them open or create
+/// the log control service.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The maximum number of processes that can be controlled at the same time.
+    pub max_controlled_nodes: usize,
+
+    /// The maximum number of operators that can send commands at the same time.
+    pub max_controllers: usize,
+
+    /// The maximum number of commands a controlled process buffers between two spins. It
+    /// is also the maximum number of commands an operator can send before the previous
+    /// ones were released.
+    pub command_buffer_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_controlled_nodes: 64,
+            max_controllers: 2,
+            command_buffer_size: 4,
+        }
+    }
+}
+
+pub(crate) type CommandService<S> =
+    RequestResponsePortFactory<S, LogControlCommand, (), LogControlAcknowledgment, ()>;
+
+pub(crate) fn open_or_create_command_service<S: ServiceType>(
+    node: &Node<S>,
+    config: &Config,
+) -> Result<CommandService<S>, RequestResponseOpenOrCreateError> {
+    node.service_builder(service_name())
+        .request_response::<LogControlCommand, LogControlAcknowledgment>()
+        .max_clients(config.max_controllers)
+        .max_servers(config.max_controlled_nodes)
+        .max_nodes(config.max_controlled_nodes + config.max_controllers)
+        .max_active_requests_per_client(config.command_buffer_size)
+        .max_response_buffer_size(1)
+        .enable_safe_overflow_for_requests(true)
+        .open_or_create()
+}
+
+pub(crate) fn open_or_create_event_service<S: ServiceType>(
+    node: &Node<S>,
+    config: &Config,
+) -> Result<EventPortFactory<S>, EventOpenOrCreateError> {
+    node.service_builder(service_name())
+        .event()
+        .max_notifiers(config.max_controllers)
+        .max_listeners(config.max_controlled_nodes)
+        .max_nodes(config.max_controlled_nodes + config.max_controllers)
+        .open_or_create()
+}
+
+/// Returns the service name of the log control.
+///
+/// # Panics
+///
+/// This function will panic during the first call if the service name is invalid,
+/// which should never happen with the predefined constants.
+pub fn service_name() -> &'static ServiceName {
+    static SERVICE_NAME_INSTANCE: LazyLock<ServiceName> = LazyLock::new(|| {
+        ServiceName::__internal_new_prefixed(SERVICE_NAME)
+            .expect("shouldn't occur: invalid service name for log control")
+    });
+
+    &SERVICE_NAME_INSTANCE
+}
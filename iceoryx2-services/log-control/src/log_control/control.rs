@@ -0,0 +1,201 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::Node;
+use iceoryx2::port::ReceiveError;
+use iceoryx2::port::listener::{Listener, ListenerCreateError, ListenerWaitError};
+use iceoryx2::port::server::Server;
+use iceoryx2::service::Service as ServiceType;
+use iceoryx2::service::builder::event::EventOpenOrCreateError;
+use iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError;
+use iceoryx2::service::port_factory::server::ServerCreateError;
+use iceoryx2_bb_container::string::String as _;
+use iceoryx2_log::{set_log_filter, warn};
+
+use crate::log_control::command::{open_or_create_command_service, open_or_create_event_service};
+use crate::log_control::{Config, LogControlAcknowledgment, LogControlCommand};
+
+/// Errors that can occur when creating a [`LogControl`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    /// Failed to open or create the log control services.
+    ServiceCreationFailure,
+
+    /// The log control service already has the maximum number of controlled nodes.
+    ExceedsMaxControlledNodes,
+
+    /// Failed to create the server that receives the commands for reasons other than the
+    /// maximum number of controlled nodes.
+    ServerCreationFailure,
+
+    /// Failed to create the listener that is woken up on new commands.
+    ListenerCreationFailure,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+impl From<RequestResponseOpenOrCreateError> for CreationError {
+    fn from(_: RequestResponseOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<EventOpenOrCreateError> for CreationError {
+    fn from(_: EventOpenOrCreateError) -> Self {
+        CreationError::ServiceCreationFailure
+    }
+}
+
+impl From<ServerCreateError> for CreationError {
+    fn from(error: ServerCreateError) -> Self {
+        match error {
+            ServerCreateError::ExceedsMaxSupportedServers => {
+                CreationError::ExceedsMaxControlledNodes
+            }
+            ServerCreateError::UnableToCreateDataSegment
+            | ServerCreateError::DataSegmentHugePagesNotSupported
+            | ServerCreateError::UnableToLockDataSegment
+            | ServerCreateError::FailedToDeployThreadsafetyPolicy
+            | ServerCreateError::UnableToCreatePortTag => CreationError::ServerCreationFailure,
+        }
+    }
+}
+
+impl From<ListenerCreateError> for CreationError {
+    fn from(_: ListenerCreateError) -> Self {
+        CreationError::ListenerCreationFailure
+    }
+}
+
+/// Errors that can occur during the spin operation of a [`LogControl`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpinError {
+    /// Failed to receive commands.
+    ReceiveFailure,
+
+    /// Failed to consume the notifications of the listener.
+    ListenerFailure,
+}
+
+impl core::fmt::Display for SpinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SpinError::{self:?}")
+    }
+}
+
+impl core::error::Error for SpinError {}
+
+impl From<ReceiveError> for SpinError {
+    fn from(_: ReceiveError) -> Self {
+        SpinError::ReceiveFailure
+    }
+}
+
+impl From<ListenerWaitError> for SpinError {
+    fn from(_: ListenerWaitError) -> Self {
+        SpinError::ListenerFailure
+    }
+}
+
+/// Makes the log filter of a process controllable at runtime.
+///
+/// Receives the [`LogControlCommand`]s of all
+/// [`LogController`](crate::log_control::LogController)s and applies the log filter of
+/// every command that addresses its [`Node`] with [`iceoryx2_log::set_log_filter()`].
+/// Every addressed command is answered with a [`LogControlAcknowledgment`].
+///
+/// # Type Parameters
+///
+/// * `S` - The service type of the controlled [`Node`].
+#[derive(Debug)]
+pub struct LogControl<S: ServiceType> {
+    node_id: UniqueNodeId,
+    server: Server<S, LogControlCommand, (), LogControlAcknowledgment, ()>,
+    listener: Listener<S>,
+}
+
+impl<S: ServiceType> LogControl<S> {
+    /// Creates a [`LogControl`] for the provided [`Node`].
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - The node whose process log filter shall be controllable.
+    /// * `config` - Configuration for the log control.
+    pub fn create(node: &Node<S>, config: &Config) -> Result<Self, CreationError> {
+        let commands = open_or_create_command_service(node, config)?;
+        let server = commands.server_builder().create()?;
+
+        let event = open_or_create_event_service(node, config)?;
+        let listener = event.listener_builder().create()?;
+
+        Ok(Self {
+            node_id: *node.id(),
+            server,
+            listener,
+        })
+    }
+
+    /// Applies the log filter of all received [`LogControlCommand`]s that address the
+    /// [`Node`] and acknowledges them. Commands with an invalid log filter are reported,
+    /// ignored and acknowledged as not applied.
+    ///
+    /// Returns the number of applied log filters.
+    pub fn spin(&self) -> Result<usize, SpinError> {
+        self.listener.try_wait(|_| {})?;
+
+        let mut number_of_applied_filters = 0;
+        while let Some(request) = self.server.receive()? {
+            let command = request.payload();
+            if !command.target.addresses(&self.node_id) {
+                continue;
+            }
+
+            let is_applied = match set_log_filter(command.log_filter.as_str()) {
+                Ok(()) => {
+                    number_of_applied_filters += 1;
+                    true
+                }
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to apply the received log filter \"{}\" ({e}).",
+                        command.log_filter.as_str());
+                    false
+                }
+            };
+
+            if let Err(e) = request.send_copy(LogControlAcknowledgment {
+                node_id: self.node_id,
+                is_applied,
+            }) {
+                warn!(from self,
+                    "Unable to acknowledge the received log filter \"{}\" ({e}).",
+                    command.log_filter.as_str());
+            }
+        }
+
+        Ok(number_of_applied_filters)
+    }
+
+    /// Returns the [`Listener`] that is notified whenever a new [`LogControlCommand`] was
+    /// sent. It can be attached to a [`WaitSet`](iceoryx2::waitset::WaitSet) to call
+    /// [`LogControl::spin()`] only when required.
+    pub fn listener(&self) -> &Listener<S> {
+        &self.listener
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::node::Node;
+use iceoryx2::pending_response::PendingResponse;
+use iceoryx2::port::ReceiveError;
+use iceoryx2::port::client::{Client, RequestSendError};
+use iceoryx2::port::notifier::{Notifier, NotifierCreateError, NotifierNotifyError};
+use iceoryx2::service::Service as ServiceType;
+use iceoryx2::service::builder::event::EventOpenOrCreateError;
+use iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError;
+use iceoryx2::service::port_factory::client::ClientCreateError;
+use iceoryx2_bb_container::string::StaticString;
+use iceoryx2_log::{LogFilterError, validate_log_filter};
+
+use crate::log_control::command::{open_or_create_command_service, open_or_create_event_service};
+use crate::log_control::{Config, LogControlAcknowledgment, LogControlCommand, LogControlTarget};
+
+/// Errors that can occur when creating a [`LogController`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ControllerCreationError {
+    /// Failed to open or create the log control services.
+    ServiceCreationFailure,
+
+    /// The log control service already has the maximum number of controllers.
+    ExceedsMaxControllers,
+
+    /// Failed to create the client or the notifier for reasons other than the maximum
+    /// number of controllers.
+    PortCreationFailure,
+}
+
+impl core::fmt::Display for ControllerCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ControllerCreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ControllerCreationError {}
+
+impl From<RequestResponseOpenOrCreateError> for ControllerCreationError {
+    fn from(_: RequestResponseOpenOrCreateError) -> Self {
+        ControllerCreationError::ServiceCreationFailure
+    }
+}
+
+impl From<EventOpenOrCreateError> for ControllerCreationError {
+    fn from(_: EventOpenOrCreateError) -> Self {
+        ControllerCreationError::ServiceCreationFailure
+    }
+}
+
+impl From<ClientCreateError> for ControllerCreationError {
+    fn from(error: ClientCreateError) -> Self {
+        match error {
+            ClientCreateError::ExceedsMaxSupportedClients => {
+                ControllerCreationError::ExceedsMaxControllers
+            }
+            ClientCreateError::UnableToCreateDataSegment
+            | ClientCreateError::DataSegmentHugePagesNotSupported
+            | ClientCreateError::UnableToLockDataSegment
+            | ClientCreateError::FailedToDeployThreadsafetyPolicy
+            | ClientCreateError::UnableToCreatePortTag
+            | ClientCreateError::MaxActiveRequestsExceedsMaxSupportedActiveRequestsOfService => {
+                ControllerCreationError::PortCreationFailure
+            }
+        }
+    }
+}
+
+impl From<NotifierCreateError> for ControllerCreationError {
+    fn from(error: NotifierCreateError) -> Self {
+        match error {
+            NotifierCreateError::ExceedsMaxSupportedNotifiers => {
+                ControllerCreationError::ExceedsMaxControllers
+            }
+            NotifierCreateError::FailedToDeployThreadsafetyPolicy
+            | NotifierCreateError::UnableToCreatePortTag => {
+                ControllerCreationError::PortCreationFailure
+            }
+        }
+    }
+}
+
+/// Errors that can occur when a [`LogController`] sends a log filter.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ControlError {
+    /// The log filter is invalid and would be rejected by the controlled processes.
+    InvalidLogFilter(LogFilterError),
+
+    /// The log filter exceeds [`MAX_LOG_FILTER_LENGTH`](crate::log_control::MAX_LOG_FILTER_LENGTH).
+    LogFilterTooLong,
+
+    /// Failed to send the [`LogControlCommand`].
+    SendFailure,
+
+    /// The [`LogController`] has already sent
+    /// [`Config::command_buffer_size`](crate::log_control::Config::command_buffer_size)
+    /// commands whose [`PendingLogFilter`]s were not yet released.
+    ExceedsMaxPendingLogFilters,
+
+    /// The [`LogControlCommand`] was sent but the controlled processes could not be woken up.
+    NotifyFailure,
+
+    /// Failed to receive the [`LogControlAcknowledgment`]s.
+    ReceiveFailure,
+}
+
+impl core::fmt::Display for ControlError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ControlError::{self:?}")
+    }
+}
+
+impl core::error::Error for ControlError {}
+
+impl From<LogFilterError> for ControlError {
+    fn from(error: LogFilterError) -> Self {
+        ControlError::InvalidLogFilter(error)
+    }
+}
+
+impl From<RequestSendError> for ControlError {
+    fn from(error: RequestSendError) -> Self {
+        match error {
+            RequestSendError::ExceedsMaxActiveRequests => ControlError::ExceedsMaxPendingLogFilters,
+            RequestSendError::SendError(_) => ControlError::SendFailure,
+        }
+    }
+}
+
+impl From<ReceiveError> for ControlError {
+    fn from(_: ReceiveError) -> Self {
+        ControlError::ReceiveFailure
+    }
+}
+
+impl From<NotifierNotifyError> for ControlError {
+    fn from(_: NotifierNotifyError) -> Self {
+        ControlError::NotifyFailure
+    }
+}
+
+/// A log filter that was sent by a [`LogController`]. It collects the
+/// [`LogControlAcknowledgment`]s of the controlled processes that received it.
+///
+/// The log filter is lost for all controlled processes that did not receive it until the
+/// [`PendingLogFilter`] goes out of scope.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type of the controlled nodes.
+#[derive(Debug)]
+pub struct PendingLogFilter<S: ServiceType> {
+    pending_response: PendingResponse<S, LogControlCommand, (), LogControlAcknowledgment, ()>,
+}
+
+impl<S: ServiceType> PendingLogFilter<S> {
+    /// Returns the number of controlled processes the log filter was delivered to,
+    /// including the ones that are not addressed by the [`LogControlTarget`].
+    pub fn number_of_receivers(&self) -> usize {
+        self.pending_response.number_of_server_connections()
+    }
+
+    /// Receives the next [`LogControlAcknowledgment`] of an addressed controlled process.
+    /// Returns [`None`] when no new acknowledgment is available.
+    pub fn receive(&self) -> Result<Option<LogControlAcknowledgment>, ControlError> {
+        Ok(self
+            .pending_response
+            .receive()?
+            .map(|response| *response.payload()))
+    }
+}
+
+/// Sends log filters to processes with a [`LogControl`](crate::log_control::LogControl).
+///
+/// A controlled process connects to the [`LogController`] in its next
+/// [`LogControl::spin()`](crate::log_control::LogControl::spin()). Sent log filters that were
+/// not received until the [`LogController`] or the [`PendingLogFilter`] goes out of scope
+/// are lost, therefore both must outlive the spin cycle of the controlled processes.
+///
+/// # Type Parameters
+///
+/// * `S` - The service type of the controlled nodes.
+#[derive(Debug)]
+pub struct LogController<S: ServiceType> {
+    client: Client<S, LogControlCommand, (), LogControlAcknowledgment, ()>,
+    notifier: Notifier<S>,
+}
+
+impl<S: ServiceType> LogController<S> {
+    /// Creates a [`LogController`].
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - The node of the operator.
+    /// * `config` - Configuration for the log control.
+    pub fn create(node: &Node<S>, config: &Config) -> Result<Self, ControllerCreationError> {
+        let commands = open_or_create_command_service(node, config)?;
+        let client = commands.client_builder().create()?;
+
+        let event = open_or_create_event_service(node, config)?;
+        let notifier = event.notifier_builder().create()?;
+
+        Ok(Self { client, notifier })
+    }
+
+    /// Sends the log filter to the addressed nodes. The filter is validated before it is
+    /// sent, see [`iceoryx2_log::filter`] for the syntax. The returned [`PendingLogFilter`]
+    /// receives the [`LogControlAcknowledgment`]s of the addressed nodes.
+    ///
+    /// # Parameters
+    ///
+    /// * `target` - The nodes that shall apply the log filter.
+    /// * `log_filter` - The new log filter.
+    pub fn set_log_filter(
+        &self,
+        target: LogControlTarget,
+        log_filter: &str,
+    ) -> Result<PendingLogFilter<S>, ControlError> {
+        validate_log_filter(log_filter)?;
+        let log_filter =
+            StaticString::try_from(log_filter).map_err(|_| ControlError::LogFilterTooLong)?;
+
+        let pending_response = self
+            .client
+            .send_copy(LogControlCommand { target, log_filter })?;
+        self.notifier.notify()?;
+
+        Ok(PendingLogFilter { pending_response })
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Log Control
+//!
+//! This module allows to change the log filter, see [`iceoryx2_log::filter`], of running
+//! processes.
+//!
+//! The log control consists of two components:
+//!
+//! 1. **LogControl**: Owned by the controllable process. It receives
+//!    [`LogControlCommand`]s and applies the contained log filter when they address
+//!    its [`Node`](iceoryx2::node::Node).
+//!
+//! 2. **LogController**: Used by an operator to send a log filter to a specific node or to
+//!    all nodes.
+//!
+//! Both communicate via a well-known request-response service, where every addressed
+//! node acknowledges a received log filter, and wake up waiting processes with an event
+//! service of the same name.
+//!
+//! ## Usage
+//!
+//! The controllable process calls [`LogControl::spin()`] periodically or whenever the
+//! [`LogControl::listener()`] is triggered.
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2_services_log_control::log_control::{Config as LogControlConfig, LogControl};
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!     let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!     let log_control = LogControl::create(&node, &LogControlConfig::default())?;
+//!
+//!     while node.wait(Duration::from_millis(100)).is_ok() {
+//!         log_control.spin()?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The operator sends the new log filter.
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2_services_log_control::log_control::{
+//!     Config as LogControlConfig, LogControlTarget, LogController
+//! };
+//! use iceoryx2::prelude::*;
+//!
+//! fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!     let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!     let controller = LogController::create(&node, &LogControlConfig::default())?;
+//!
+//!     let pending = controller.set_log_filter(LogControlTarget::AllNodes, "warn,iceoryx2::port=trace")?;
+//!
+//!     while node.wait(Duration::from_millis(100)).is_ok() {
+//!         while let Some(acknowledgment) = pending.receive()? {
+//!             println!("{:?} applied the filter: {}", acknowledgment.node_id, acknowledgment.is_applied);
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// The command exchanged between operator and controllable processes.
+mod command;
+
+/// The port of a controllable process.
+mod control;
+
+/// The port of an operator.
+mod controller;
+
+pub use command::*;
+pub use control::*;
+pub use controller::*;
@@ -0,0 +1,194 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod log_control {
+
+    use std::sync::{Mutex, MutexGuard};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_log::{LogFilterError, clear_log_filter, get_log_level_for};
+    use iceoryx2_services_log_control::log_control::{
+        Config, ControlError, ControllerCreationError, LogControl, LogControlAcknowledgment,
+        LogControlTarget, LogController, MAX_LOG_FILTER_LENGTH,
+    };
+
+    // the log filter is process global, the tests must not run concurrently
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock() -> MutexGuard<'static, ()> {
+        let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        clear_log_filter();
+        guard
+    }
+
+    #[test]
+    fn log_filter_for_all_nodes_is_applied<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let operator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = LogControl::create(&node, &Config::default()).unwrap();
+        let controller = LogController::create(&operator, &Config::default()).unwrap();
+
+        assert_that!(sut.spin(), eq Ok(0));
+
+        let _pending = controller
+            .set_log_filter(LogControlTarget::AllNodes, "log_control_tests=debug")
+            .unwrap();
+
+        assert_that!(sut.spin(), eq Ok(1));
+        assert_that!(get_log_level_for("log_control_tests::some_module"), eq LogLevel::Debug);
+        assert_that!(sut.spin(), eq Ok(0));
+
+        clear_log_filter();
+    }
+
+    #[test]
+    fn log_filter_for_another_node_is_ignored<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let node_a = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_b = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let operator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut_a = LogControl::create(&node_a, &Config::default()).unwrap();
+        let sut_b = LogControl::create(&node_b, &Config::default()).unwrap();
+        let controller = LogController::create(&operator, &Config::default()).unwrap();
+
+        let _pending = controller
+            .set_log_filter(
+                LogControlTarget::Node(*node_a.id()),
+                "log_control_tests=error",
+            )
+            .unwrap();
+
+        assert_that!(sut_b.spin(), eq Ok(0));
+        assert_that!(sut_a.spin(), eq Ok(1));
+        assert_that!(get_log_level_for("log_control_tests"), eq LogLevel::Error);
+
+        clear_log_filter();
+    }
+
+    #[test]
+    fn listener_is_notified_on_new_log_filter<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = LogControl::create(&node, &Config::default()).unwrap();
+        let controller = LogController::create(&node, &Config::default()).unwrap();
+
+        assert_that!(sut.listener().try_wait(|_| {}).unwrap(), eq 0);
+
+        let _pending = controller
+            .set_log_filter(LogControlTarget::AllNodes, "log_control_tests=trace")
+            .unwrap();
+
+        assert_that!(sut.listener().try_wait(|_| {}).unwrap(), ge 1);
+        assert_that!(sut.spin(), eq Ok(1));
+
+        clear_log_filter();
+    }
+
+    #[test]
+    fn addressed_nodes_acknowledge_the_log_filter<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let node_a = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_b = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let operator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut_a = LogControl::create(&node_a, &Config::default()).unwrap();
+        let sut_b = LogControl::create(&node_b, &Config::default()).unwrap();
+        let controller = LogController::create(&operator, &Config::default()).unwrap();
+
+        let pending = controller
+            .set_log_filter(
+                LogControlTarget::Node(*node_b.id()),
+                "log_control_tests=info",
+            )
+            .unwrap();
+        assert_that!(pending.number_of_receivers(), eq 2);
+        assert_that!(pending.receive(), eq Ok(None));
+
+        assert_that!(sut_a.spin(), eq Ok(0));
+        assert_that!(pending.receive(), eq Ok(None));
+
+        assert_that!(sut_b.spin(), eq Ok(1));
+        assert_that!(pending.receive(), eq Ok(Some(LogControlAcknowledgment {
+            node_id: *node_b.id(),
+            is_applied: true,
+        })));
+        assert_that!(pending.receive(), eq Ok(None));
+
+        clear_log_filter();
+    }
+
+    #[test]
+    fn log_filter_without_controlled_nodes_has_no_receivers<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let operator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let controller = LogController::create(&operator, &Config::default()).unwrap();
+
+        let pending = controller
+            .set_log_filter(LogControlTarget::AllNodes, "log_control_tests=info")
+            .unwrap();
+
+        assert_that!(pending.number_of_receivers(), eq 0);
+        assert_that!(pending.receive(), eq Ok(None));
+    }
+
+    #[test]
+    fn invalid_log_filter_is_not_sent<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = LogControl::create(&node, &Config::default()).unwrap();
+        let controller = LogController::create(&node, &Config::default()).unwrap();
+
+        let too_long_filter = ["x=info"; MAX_LOG_FILTER_LENGTH / 6 + 1].join(",");
+
+        assert_that!(controller.set_log_filter(LogControlTarget::AllNodes, "x=verbose").err(),
+            eq Some(ControlError::InvalidLogFilter(LogFilterError::InvalidLogLevel)));
+        assert_that!(controller.set_log_filter(LogControlTarget::AllNodes, &too_long_filter).err(),
+            eq Some(ControlError::LogFilterTooLong));
+        assert_that!(sut.spin(), eq Ok(0));
+    }
+
+    #[test]
+    fn number_of_controllers_is_limited<S: Service>() {
+        let _guard = lock();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let log_control_config = Config {
+            max_controllers: 1,
+            ..Config::default()
+        };
+
+        let _controller = LogController::create(&node, &log_control_config).unwrap();
+
+        assert_that!(LogController::create(&node, &log_control_config).err(),
+            eq Some(ControllerCreationError::ExceedsMaxControllers));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -15,10 +15,14 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[allow(clippy::module_inception)]
 #[conformance_tests]
 pub mod active_request {
+    use core::time::Duration;
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
     use iceoryx2::service::port_factory::request_response::PortFactory;
+
+    use iceoryx2::port::SendError;
     use iceoryx2::{node::Node, prelude::ZeroCopySend, service::Service};
+    use iceoryx2_bb_posix::clock::{ClockType, Time, nanosleep};
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_testing::*;
@@ -310,4 +314,73 @@ pub mod active_request {
         assert_that!(*sut.user_header(), eq USER_HEADER);
         assert_that!(*sut.payload(), eq PAYLOAD);
     }
+
+    #[conformance_test]
+    pub fn request_without_deadline_never_expires<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let _pending_response = test.client.send_copy(123).unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+
+        assert_that!(sut.header().deadline(), is_none);
+        assert_that!(sut.remaining_time().unwrap(), is_none);
+        assert_that!(sut.has_expired().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn remaining_time_is_bounded_by_deadline<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(3600);
+        let test = TestFixture::<Sut>::new();
+        let mut request = test.client.loan().unwrap();
+        request.set_deadline(TIMEOUT).unwrap();
+        let _pending_response = request.send().unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+
+        let now = Time::now_with_clock(ClockType::Monotonic)
+            .unwrap()
+            .as_duration();
+        assert_that!(sut.header().deadline().unwrap(), gt now);
+        assert_that!(sut.header().deadline().unwrap(), le now + TIMEOUT);
+        assert_that!(sut.remaining_time().unwrap().unwrap(), le TIMEOUT);
+        assert_that!(sut.remaining_time().unwrap().unwrap(), gt Duration::ZERO);
+        assert_that!(sut.has_expired().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn responses_sent_after_deadline_are_discarded<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let test = TestFixture::<Sut>::new();
+        let mut request = test.client.loan().unwrap();
+        request.set_deadline(TIMEOUT).unwrap();
+        let pending_response = request.send().unwrap();
+
+        let sut = test.server.receive().unwrap().unwrap();
+        nanosleep(TIMEOUT * 2).unwrap();
+
+        assert_that!(sut.has_expired().unwrap(), eq true);
+        assert_that!(sut.remaining_time().unwrap(), eq Some(Duration::ZERO));
+        assert_that!(sut.send_copy(456).err(), eq Some(SendError::RequestExpired));
+        assert_that!(sut.is_connected(), eq true);
+        assert_that!(pending_response.has_response(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn request_timeout_of_client_sets_deadline<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(3600);
+        let test = TestFixture::<Sut>::new();
+        let client = test
+            .service
+            .client_builder()
+            .request_timeout(TIMEOUT)
+            .create()
+            .unwrap();
+        assert_that!(client.request_timeout(), eq Some(TIMEOUT));
+
+        let _pending_response = client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        assert_that!(sut.remaining_time().unwrap().unwrap(), le TIMEOUT);
+        assert_that!(sut.has_expired().unwrap(), eq false);
+    }
 }
@@ -21,6 +21,7 @@ pub mod client {
     use iceoryx2::port::update_connections::UpdateConnections;
 
     use iceoryx2::port::client::RequestSendError;
+    use iceoryx2::port::request_dispatch_policy::RequestDispatchPolicy;
     use iceoryx2::port::{BackpressureAction, LoanError, SendError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::port_factory::client::PortFactoryClient;
@@ -1387,4 +1388,209 @@ pub mod client {
 
         Ok(())
     }
+
+    #[conformance_test]
+    pub fn default_request_dispatch_policy_is_broadcast<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        assert_that!(sut.request_dispatch_policy(), eq RequestDispatchPolicy::Broadcast);
+
+        let sut = service
+            .client_builder()
+            .request_dispatch_policy(RequestDispatchPolicy::RoundRobin)
+            .create()
+            .unwrap();
+        assert_that!(sut.request_dispatch_policy(), eq RequestDispatchPolicy::RoundRobin);
+    }
+
+    #[conformance_test]
+    pub fn round_robin_delivers_every_request_to_one_server_in_turn<Sut: Service>() {
+        const NUMBER_OF_SERVERS: usize = 3;
+        const NUMBER_OF_ROUNDS: usize = 4;
+
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(NUMBER_OF_SERVERS)
+            .max_active_requests_per_client(NUMBER_OF_SERVERS * NUMBER_OF_ROUNDS)
+            .create()
+            .unwrap();
+
+        let servers: vec::Vec<_> = (0..NUMBER_OF_SERVERS)
+            .map(|_| service.server_builder().create().unwrap())
+            .collect();
+        let sut = service
+            .client_builder()
+            .request_dispatch_policy(RequestDispatchPolicy::RoundRobin)
+            .create()
+            .unwrap();
+
+        let mut pending_responses = vec![];
+        let mut active_requests = vec![];
+        for n in 0..NUMBER_OF_SERVERS * NUMBER_OF_ROUNDS {
+            let pending_response = sut.send_copy(n as u64).unwrap();
+            assert_that!(pending_response.number_of_server_connections(), eq 1);
+            pending_responses.push(pending_response);
+
+            // the servers are served in the order they were connected: 0 -> 1 -> 2 -> 0 ...
+            let expected_server = n % NUMBER_OF_SERVERS;
+            for (i, server) in servers.iter().enumerate() {
+                assert_that!(server.has_requests().unwrap(), eq i == expected_server);
+            }
+            let active_request = servers[expected_server].receive().unwrap().unwrap();
+            assert_that!(*active_request, eq n as u64);
+            active_requests.push(active_request);
+        }
+
+        for server in &servers {
+            assert_that!(server.has_requests().unwrap(), eq false);
+        }
+    }
+
+    #[conformance_test]
+    pub fn least_outstanding_requests_prefers_server_with_fewer_unreleased_requests<
+        Sut: Service,
+    >() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_a = service.server_builder().create().unwrap();
+        let server_b = service.server_builder().create().unwrap();
+        let sut = service
+            .client_builder()
+            .request_dispatch_policy(RequestDispatchPolicy::LeastOutstandingRequests)
+            .create()
+            .unwrap();
+
+        let _pending_response_1 = sut.send_copy(1).unwrap();
+        let (busy_server, idle_server) = if server_a.has_requests().unwrap() {
+            (&server_a, &server_b)
+        } else {
+            (&server_b, &server_a)
+        };
+        let active_request_1 = busy_server.receive().unwrap().unwrap();
+
+        let pending_response_2 = sut.send_copy(2).unwrap();
+        assert_that!(pending_response_2.number_of_server_connections(), eq 1);
+        assert_that!(busy_server.has_requests().unwrap(), eq false);
+        let active_request_2 = idle_server.receive().unwrap().unwrap();
+        assert_that!(*active_request_2, eq 2);
+
+        // the idle server releases its request, the busy server still holds one
+        drop(active_request_2);
+        drop(pending_response_2);
+
+        let _pending_response_3 = sut.send_copy(3).unwrap();
+        assert_that!(busy_server.has_requests().unwrap(), eq false);
+        assert_that!(*idle_server.receive().unwrap().unwrap(), eq 3);
+        assert_that!(*active_request_1, eq 1);
+    }
+
+    #[conformance_test]
+    pub fn targeted_request_is_delivered_only_to_target_server<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(3)
+            .create()
+            .unwrap();
+
+        let other_server_1 = service.server_builder().create().unwrap();
+        let target_server = service.server_builder().create().unwrap();
+        let other_server_2 = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let request = sut.loan_uninit().unwrap().write_payload(42);
+        let pending_response = request
+            .send_with_dispatch_policy(RequestDispatchPolicy::Target(target_server.id()))
+            .unwrap();
+
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+        assert_that!(other_server_1.has_requests().unwrap(), eq false);
+        assert_that!(other_server_2.has_requests().unwrap(), eq false);
+        assert_that!(*target_server.receive().unwrap().unwrap(), eq 42);
+
+        // the client policy is still used for all other requests
+        let pending_response = sut.send_copy(43).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 3);
+    }
+
+    #[conformance_test]
+    pub fn targeted_request_to_unknown_server_is_delivered_to_no_one<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let gone_server_id = service.server_builder().create().unwrap().id();
+        let sut = service
+            .client_builder()
+            .request_dispatch_policy(RequestDispatchPolicy::Target(gone_server_id))
+            .create()
+            .unwrap();
+
+        let pending_response = sut.send_copy(1).unwrap();
+
+        assert_that!(pending_response.number_of_server_connections(), eq 0);
+        assert_that!(pending_response.is_connected(), eq false);
+        assert_that!(server.has_requests().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn pending_response_of_targeted_request_disconnects_when_target_releases_request<
+        Sut: Service,
+    >() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let _other_server = service.server_builder().create().unwrap();
+        let target_server = service.server_builder().create().unwrap();
+        let sut = service
+            .client_builder()
+            .request_dispatch_policy(RequestDispatchPolicy::Target(target_server.id()))
+            .create()
+            .unwrap();
+
+        let pending_response = sut.send_copy(1).unwrap();
+        assert_that!(pending_response.is_connected(), eq true);
+
+        let active_request = target_server.receive().unwrap().unwrap();
+        active_request.send_copy(2).unwrap();
+        drop(active_request);
+
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 2);
+        assert_that!(pending_response.is_connected(), eq false);
+    }
 }
@@ -37,6 +37,7 @@ pub mod service_publish_subscribe_flatbuffer;
 pub mod service_request_response;
 pub mod service_request_response_builder;
 pub mod service_request_response_flatbuffer;
+pub mod service_request_response_rpc;
 pub mod subscriber;
 pub mod waitset;
 pub mod writer;
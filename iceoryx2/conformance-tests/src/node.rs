@@ -21,7 +21,7 @@ pub mod node {
     use core::time::Duration;
     use iceoryx2::identifiers::UniqueNodeId;
 
-    use iceoryx2::config::Config;
+    use iceoryx2::config::{Config, ServiceNameRemapping};
     use iceoryx2::node::{
         NodeCleanupFailure, NodeCreationFailure, NodeListFailure, NodeState, NodeView,
     };
@@ -86,6 +86,19 @@ pub mod node {
         }
     }
 
+    fn original_name_of<S: Service>(
+        service_name: &ServiceName,
+        config: &Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Option<ServiceName> {
+        S::details(service_name, config, messaging_pattern)
+            .unwrap()
+            .unwrap()
+            .static_details
+            .original_name()
+            .copied()
+    }
+
     fn generate_node_name(i: usize, prefix: &str) -> NodeName {
         NodeName::new(&(prefix.to_string() + &i.to_string())).unwrap()
     }
@@ -418,4 +431,143 @@ pub mod node {
 
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
+
+    #[conformance_test]
+    pub fn service_names_are_remapped_with_the_config_remappings<S: Service>() {
+        let test = Test::<S>::new();
+        let original_name = generate_service_name();
+        let remapped_name = generate_service_name();
+        let mut config = test.config().clone();
+        config
+            .remappings
+            .push(ServiceNameRemapping::new(&original_name, &remapped_name));
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = sut
+            .service_builder(&original_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(*service.name(), eq remapped_name);
+        assert_that!(original_name_of::<S>(&remapped_name, &config, MessagingPattern::PublishSubscribe), eq Some(original_name));
+        assert_that!(S::does_exist(&remapped_name, &config, MessagingPattern::PublishSubscribe), eq Ok(true));
+        assert_that!(S::does_exist(&original_name, &config, MessagingPattern::PublishSubscribe), eq Ok(false));
+    }
+
+    #[conformance_test]
+    pub fn namespace_is_prefixed_to_service_names<S: Service>() {
+        let test = Test::<S>::new();
+        let namespace = ServiceName::new("robot_1").unwrap();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(test.config())
+            .namespace(&namespace)
+            .create::<S>()
+            .unwrap();
+
+        let service = sut.service_builder(&service_name).event().create().unwrap();
+
+        let expected_name = ServiceName::new(&format!("robot_1/{service_name}")).unwrap();
+        assert_that!(sut.namespace(), eq Some(&namespace));
+        assert_that!(*service.name(), eq expected_name);
+        assert_that!(original_name_of::<S>(&expected_name, test.config(), MessagingPattern::Event), eq Some(service_name));
+    }
+
+    #[conformance_test]
+    pub fn remapping_is_applied_before_namespace<S: Service>() {
+        let test = Test::<S>::new();
+        let namespace = ServiceName::new("robot_2").unwrap();
+        let original_name = generate_service_name();
+        let remapped_name = generate_service_name();
+        let mut config = test.config().clone();
+        config
+            .remappings
+            .push(ServiceNameRemapping::new(&original_name, &remapped_name));
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .namespace(&namespace)
+            .create::<S>()
+            .unwrap();
+
+        let service = sut
+            .service_builder(&original_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let expected_name = ServiceName::new(&format!("robot_2/{remapped_name}")).unwrap();
+        assert_that!(*service.name(), eq expected_name);
+        assert_that!(original_name_of::<S>(&expected_name, &config, MessagingPattern::RequestResponse), eq Some(original_name));
+    }
+
+    #[conformance_test]
+    pub fn service_builder_without_remapping_uses_the_provided_name<S: Service>() {
+        let test = Test::<S>::new();
+        let namespace = ServiceName::new("robot_3").unwrap();
+        let service_name = generate_service_name();
+        let mut config = test.config().clone();
+        config.remappings.push(ServiceNameRemapping::new(
+            &service_name,
+            &generate_service_name(),
+        ));
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .namespace(&namespace)
+            .create::<S>()
+            .unwrap();
+
+        let service = sut
+            .service_builder_without_remapping(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(*service.name(), eq service_name);
+        assert_that!(original_name_of::<S>(&service_name, &config, MessagingPattern::PublishSubscribe), eq None);
+    }
+
+    #[conformance_test]
+    pub fn service_without_remapping_has_no_original_name<S: Service>() {
+        let test = Test::<S>::new();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new()
+            .config(test.config())
+            .create::<S>()
+            .unwrap();
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.namespace(), eq None);
+        assert_that!(*service.name(), eq service_name);
+        assert_that!(original_name_of::<S>(&service_name, test.config(), MessagingPattern::PublishSubscribe), eq None);
+    }
+
+    #[conformance_test]
+    pub fn node_details_contain_the_namespace<S: Service>() {
+        let test = Test::<S>::new();
+        let namespace = ServiceName::new("robot_4").unwrap();
+        let sut = NodeBuilder::new()
+            .config(test.config())
+            .namespace(&namespace)
+            .create::<S>()
+            .unwrap();
+
+        let mut namespaces = vec![];
+        Node::<S>::list(test.config(), |node_state| {
+            if let NodeState::Alive(view) = node_state
+                && view.id() == sut.id()
+            {
+                namespaces.push(view.details().as_ref().and_then(|d| d.namespace().copied()));
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(namespaces, eq vec![Some(namespace)]);
+    }
 }
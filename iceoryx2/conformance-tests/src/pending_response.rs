@@ -15,10 +15,15 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[allow(clippy::module_inception)]
 #[conformance_tests]
 pub mod pending_response {
+    use core::time::Duration;
+
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
+    use iceoryx2::prelude::{CallbackProgression, WaitSetBuilder};
     use iceoryx2::service::port_factory::request_response::PortFactory;
+    use iceoryx2::waitset::WaitSetAttachmentError;
     use iceoryx2::{node::Node, service::Service};
+    use iceoryx2_bb_posix::clock::nanosleep;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_testing::*;
@@ -140,4 +145,80 @@ pub mod pending_response {
         assert_that!(sut.has_response(), eq false);
         assert_that!(sut.receive().unwrap(), is_none);
     }
+
+    #[conformance_test]
+    pub fn request_without_deadline_never_expires<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(9).unwrap();
+
+        assert_that!(sut.remaining_time().unwrap(), is_none);
+        assert_that!(sut.has_expired().unwrap(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn responses_received_after_deadline_are_discarded<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let test = TestFixture::<Sut>::new();
+        let mut request = test.client.loan().unwrap();
+        request.set_deadline(TIMEOUT).unwrap();
+        let sut = request.send().unwrap();
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        active_request.send_copy(8).unwrap();
+        assert_that!(sut.has_response(), eq true);
+
+        nanosleep(TIMEOUT * 2).unwrap();
+
+        assert_that!(sut.has_expired().unwrap(), eq true);
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.has_response(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn responses_received_before_deadline_are_delivered<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(3600);
+        let test = TestFixture::<Sut>::new();
+        let mut request = test.client.loan().unwrap();
+        request.set_deadline(TIMEOUT).unwrap();
+        let sut = request.send().unwrap();
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        active_request.send_copy(8).unwrap();
+
+        assert_that!(sut.has_expired().unwrap(), eq false);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 8);
+    }
+
+    #[conformance_test]
+    pub fn attaching_request_without_deadline_to_waitset_fails<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let sut = test.client.send_copy(9).unwrap();
+
+        assert_that!(waitset.attach_request_deadline(&sut).err(), eq Some(WaitSetAttachmentError::NoDeadline));
+    }
+
+    #[conformance_test]
+    pub fn attached_request_deadline_wakes_up_waitset<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let test = TestFixture::<Sut>::new();
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let mut request = test.client.loan().unwrap();
+        request.set_deadline(TIMEOUT).unwrap();
+        let sut = request.send().unwrap();
+
+        let guard = waitset.attach_request_deadline(&sut).unwrap();
+
+        let mut callback_called = false;
+        waitset
+            .wait_and_process_once(|id| {
+                callback_called = true;
+                assert_that!(id.has_event_from(&guard), eq true);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(callback_called, eq true);
+        assert_that!(sut.has_expired().unwrap(), eq true);
+    }
 }
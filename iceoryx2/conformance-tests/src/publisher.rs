@@ -19,7 +19,7 @@ pub mod publisher {
     use alloc::{format, vec};
     use core::time::Duration;
     use iceoryx2::port::update_connections::UpdateConnections;
-    use iceoryx2::port::{LoanError, publisher::PublisherCreateError};
+    use iceoryx2::port::{LoanError, SendError, publisher::PublisherCreateError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::service::marker::{CustomHeaderMarker, CustomPayloadMarker};
@@ -693,6 +693,49 @@ pub mod publisher {
         Ok(())
     }
 
+    #[conformance_test]
+    pub fn backpressure_strategy_with_timeout_fails_when_subscriber_is_not_served_in_time<
+        Sut: Service,
+    >() -> core::result::Result<(), alloc::boxed::Box<dyn core::error::Error>> {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let strategy = BackpressureStrategy::RetryUntilDeliveredOrTimeout(TIMEOUT);
+        let sut = service
+            .publisher_builder()
+            .backpressure_strategy(strategy)
+            .create()?;
+        assert_that!(sut.backpressure_strategy(), eq strategy);
+
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        assert_that!(sut.subscribers_not_served_in_time(), len 0);
+
+        let now = Time::now().unwrap();
+        let result = sut.send_copy(2);
+        assert_that!(now.elapsed().unwrap(), time_at_least TIMEOUT);
+        assert_that!(result, eq Err(SendError::DeliveryTimeout));
+        assert_that!(sut.subscribers_not_served_in_time(), eq vec![subscriber.id()]);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 1);
+        drop(sample);
+
+        sut.send_copy(3)?;
+        assert_that!(sut.subscribers_not_served_in_time(), len 0);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+
+        Ok(())
+    }
+
     #[conformance_test]
     pub fn create_error_display_works<S: Service>() {
         assert_that!(
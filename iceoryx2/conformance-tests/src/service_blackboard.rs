@@ -20,11 +20,13 @@ pub mod service_blackboard {
     use alloc::{format, vec, vec::Vec};
     use core::alloc::Layout;
     use core::ptr::copy_nonoverlapping;
+    use iceoryx2::config::ServiceProfile;
     use iceoryx2::constants::MAX_BLACKBOARD_KEY_SIZE;
     use iceoryx2::port::reader::*;
     use iceoryx2::port::writer::*;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
+    use iceoryx2::service::blackboard_snapshot::BlackboardSnapshot;
     use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
     use iceoryx2::service::marker::CustomKeyMarker;
     use iceoryx2::service::resource::blackboard::{KeyMemory, KeyMemoryError};
@@ -32,10 +34,13 @@ pub mod service_blackboard {
     use iceoryx2_bb_concurrency::atomic::Ordering;
     use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64};
     use iceoryx2_bb_container::string::*;
+    use iceoryx2_bb_container::vector::*;
     use iceoryx2_bb_posix::barrier::BarrierBuilder;
     use iceoryx2_bb_posix::barrier::BarrierHandle;
+    use iceoryx2_bb_posix::file::File;
     use iceoryx2_bb_posix::ipc_capable::Handle;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_posix::testing::generate_file_path;
     use iceoryx2_bb_posix::thread::thread_scope;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
@@ -429,6 +434,30 @@ pub mod service_blackboard {
         assert_that!(sut2.static_config().max_readers(), eq 9);
     }
 
+    #[conformance_test]
+    pub fn settings_can_be_modified_via_service_profile<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let mut custom_config = test.config().clone();
+        let mut profile = ServiceProfile::new(service_name.as_str());
+        profile.blackboard.max_readers = Some(3);
+        custom_config.services.push(profile);
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_readers(), eq 3);
+        assert_that!(sut.static_config().max_nodes(), eq custom_config.defaults.blackboard.max_nodes);
+    }
+
     #[conformance_test]
     pub fn type_information_are_correct<Sut: Service>() {
         type KeyType = u64;
@@ -2572,4 +2601,570 @@ pub mod service_blackboard {
         assert_that!(read_value_1, eq new_value_1);
         assert_that!(read_value_2, eq new_value_2);
     }
+
+    #[conformance_test]
+    pub fn blackboard_with_only_dynamic_entries_can_be_created<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(32)
+            .create();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.static_config().max_dynamic_entries(), eq 2);
+        assert_that!(sut.static_config().max_dynamic_value_size(), eq 32);
+    }
+
+    #[conformance_test]
+    pub fn added_entries_with_non_copy_values_can_be_read<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(128)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let vec = StaticVec::<u32, 8>::try_from([1, 2, 3].as_slice()).unwrap();
+        let string = StaticString::<64>::try_from("hypnotoad").unwrap();
+        assert_that!(writer.add_entry(&1, vec.clone()), is_ok);
+        assert_that!(writer.add_entry(&2, string), is_ok);
+
+        let vec_handle = reader.dynamic_entry::<StaticVec<u32, 8>>(&1).unwrap();
+        let string_handle = reader.dynamic_entry::<StaticString<64>>(&2).unwrap();
+        assert_that!(*vec_handle.get().unwrap(), eq vec);
+        assert_that!(*string_handle.get().unwrap(), eq string);
+    }
+
+    #[conformance_test]
+    pub fn added_entries_can_be_updated<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(128)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        writer
+            .add_entry(&7, StaticString::<64>::try_from("fuu").unwrap())
+            .unwrap();
+
+        let entry_handle = reader.dynamic_entry::<StaticString<64>>(&7).unwrap();
+        let mut entry_handle_mut = writer.dynamic_entry::<StaticString<64>>(&7).unwrap();
+        assert_that!(entry_handle_mut.entry_id(), eq entry_handle.entry_id());
+
+        let value = entry_handle.get().unwrap();
+        assert_that!(entry_handle.is_up_to_date(&value), eq true);
+
+        entry_handle_mut.update(StaticString::<64>::try_from("fuu bar").unwrap());
+        assert_that!(entry_handle.is_up_to_date(&value), eq false);
+        assert_that!(*entry_handle.get().unwrap(), eq StaticString::<64>::try_from("fuu bar").unwrap());
+    }
+
+    #[conformance_test]
+    pub fn dynamic_entry_handle_mut_can_be_created_only_once<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        writer.add_entry(&7, 12u64).unwrap();
+
+        let entry_handle_mut = writer.dynamic_entry::<u64>(&7);
+        assert_that!(entry_handle_mut, is_ok);
+        let sut = writer.dynamic_entry::<u64>(&7);
+        assert_that!(sut.err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+
+        drop(entry_handle_mut);
+        assert_that!(writer.dynamic_entry::<u64>(&7), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn dynamic_entry_with_wrong_value_type_does_not_exist<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        writer.add_entry(&7, 12u64).unwrap();
+
+        assert_that!(reader.dynamic_entry::<i64>(&7).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.dynamic_entry::<u64>(&8).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(writer.dynamic_entry::<i64>(&7).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+    }
+
+    #[conformance_test]
+    pub fn adding_entry_with_existing_key_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        assert_that!(writer.add_entry(&0, 1u8).err(), eq Some(EntryAddError::KeyAlreadyExists));
+        assert_that!(writer.add_entry(&1, 1u8), is_ok);
+        assert_that!(writer.add_entry(&1, 2u16).err(), eq Some(EntryAddError::KeyAlreadyExists));
+    }
+
+    #[conformance_test]
+    pub fn adding_more_than_max_dynamic_entries_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        const MAX_DYNAMIC_ENTRIES: u64 = 4;
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(MAX_DYNAMIC_ENTRIES as usize)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        for key in 0..MAX_DYNAMIC_ENTRIES {
+            assert_that!(writer.add_entry(&key, key), is_ok);
+        }
+        assert_that!(writer.add_entry(&MAX_DYNAMIC_ENTRIES, 0u64).err(), eq Some(EntryAddError::ExceedsMaxDynamicEntries));
+
+        assert_that!(writer.remove_entry(&1), is_ok);
+        assert_that!(writer.add_entry(&MAX_DYNAMIC_ENTRIES, 0u64), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn adding_entry_with_too_large_value_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        assert_that!(writer.add_entry(&0, 0u128).err(), eq Some(EntryAddError::ValueTooLarge));
+        assert_that!(writer.add_entry(&0, [0u8; 8]), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn removed_entry_cannot_be_read_anymore<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        writer.add_entry(&3, 33u32).unwrap();
+
+        let entry_handle = reader.dynamic_entry::<u32>(&3).unwrap();
+        let value = entry_handle.get().unwrap();
+        assert_that!(*value, eq 33);
+        assert_that!(entry_handle.exists(), eq true);
+
+        assert_that!(writer.remove_entry(&3), is_ok);
+        assert_that!(entry_handle.exists(), eq false);
+        assert_that!(entry_handle.is_up_to_date(&value), eq false);
+        assert_that!(entry_handle.get().err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.dynamic_entry::<u32>(&3).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+
+        // the slot is reused with another key but the old handle stays invalid
+        writer.add_entry(&4, 44u32).unwrap();
+        assert_that!(entry_handle.get().err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(*reader.dynamic_entry::<u32>(&4).unwrap().get().unwrap(), eq 44);
+    }
+
+    #[conformance_test]
+    pub fn removing_entries_fails_for_static_missing_and_used_entries<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        writer.add_entry(&1, 1u8).unwrap();
+
+        assert_that!(writer.remove_entry(&0).err(), eq Some(EntryRemoveError::EntryIsNotRemovable));
+        assert_that!(writer.remove_entry(&2).err(), eq Some(EntryRemoveError::EntryDoesNotExist));
+
+        let entry_handle_mut = writer.dynamic_entry::<u8>(&1).unwrap();
+        assert_that!(writer.remove_entry(&1).err(), eq Some(EntryRemoveError::HandleStillInUse));
+        drop(entry_handle_mut);
+        assert_that!(writer.remove_entry(&1), is_ok);
+        assert_that!(writer.remove_entry(&1).err(), eq Some(EntryRemoveError::EntryDoesNotExist));
+    }
+
+    #[conformance_test]
+    pub fn list_keys_contains_added_and_not_removed_entries<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_dynamic_entries(3)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        writer.add_entry(&1, 1u8).unwrap();
+        writer.add_entry(&2, 2u8).unwrap();
+        writer.add_entry(&3, 3u8).unwrap();
+        writer.remove_entry(&2).unwrap();
+
+        let mut keys = vec![];
+        sut.list_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+        keys.sort();
+        assert_that!(keys, eq vec![0, 1, 3]);
+
+        let mut number_of_keys = 0;
+        sut.list_keys(|_| {
+            number_of_keys += 1;
+            CallbackProgression::Stop
+        });
+        assert_that!(number_of_keys, eq 1);
+    }
+
+    #[conformance_test]
+    pub fn readers_discover_added_keys_via_event<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+        let writer = blackboard.writer_builder().create().unwrap();
+        let reader = blackboard.reader_builder().create().unwrap();
+        assert_that!(writer.key_set_event_id(), eq reader.key_set_event_id());
+
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .event_id_max_value(writer.key_set_event_id().as_value())
+            .create()
+            .unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        writer.add_entry(&5, 55u64).unwrap();
+        notifier
+            .notify_with_custom_event_id(writer.key_set_event_id())
+            .unwrap();
+
+        let mut keys_changed = false;
+        listener
+            .try_wait(|event| keys_changed |= event.id == reader.key_set_event_id())
+            .unwrap();
+        assert_that!(keys_changed, eq true);
+
+        let mut keys = vec![];
+        blackboard.list_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+        assert_that!(keys, eq vec![5]);
+        assert_that!(*reader.dynamic_entry::<u64>(&5).unwrap().get().unwrap(), eq 55);
+    }
+
+    #[conformance_test]
+    pub fn opener_can_read_added_entries<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut_creator = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(64)
+            .create()
+            .unwrap();
+        let writer = sut_creator.writer_builder().create().unwrap();
+        writer
+            .add_entry(&9, StaticString::<32>::try_from("all glory").unwrap())
+            .unwrap();
+
+        let sut_opener = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let reader = sut_opener.reader_builder().create().unwrap();
+        assert_that!(*reader.dynamic_entry::<StaticString<32>>(&9).unwrap().get().unwrap(), eq StaticString::<32>::try_from("all glory").unwrap());
+    }
+
+    #[conformance_test]
+    pub fn snapshot_contains_static_and_dynamic_entries<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 11)
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+        let writer = blackboard.writer_builder().create().unwrap();
+        writer.entry::<u32>(&1).unwrap().update_with_copy(111);
+        writer.add_entry(&2, 22u64).unwrap();
+
+        let snapshot = blackboard.snapshot();
+        assert_that!(snapshot.entries(), len 2);
+
+        let static_entry = snapshot.entries().iter().find(|e| !e.is_dynamic()).unwrap();
+        assert_that!(static_entry.key(), eq 1u64.to_ne_bytes());
+        assert_that!(*static_entry.value_type_details(), eq TypeDetail::new::<u32>(TypeVariant::FixedSize));
+        assert_that!(static_entry.value(), eq 111u32.to_ne_bytes());
+
+        let dynamic_entry = snapshot.entries().iter().find(|e| e.is_dynamic()).unwrap();
+        assert_that!(dynamic_entry.key(), eq 2u64.to_ne_bytes());
+        assert_that!(*dynamic_entry.value_type_details(), eq TypeDetail::new::<u64>(TypeVariant::FixedSize));
+        assert_that!(dynamic_entry.value(), eq 22u64.to_ne_bytes());
+    }
+
+    #[conformance_test]
+    pub fn create_from_snapshot_restores_all_entries<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 11)
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+        let writer = blackboard.writer_builder().create().unwrap();
+        writer.entry::<u32>(&1).unwrap().update_with_copy(111);
+        writer.add_entry(&2, 22u64).unwrap();
+        let snapshot = blackboard.snapshot();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(8)
+            .from_snapshot(&snapshot)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(*reader.entry::<u32>(&1).unwrap().get(), eq 111);
+        assert_that!(*reader.dynamic_entry::<u64>(&2).unwrap().get().unwrap(), eq 22);
+
+        let writer = sut.writer_builder().create().unwrap();
+        writer.remove_entry(&2).unwrap();
+        writer.add_entry(&3, 33u64).unwrap();
+        writer.add_entry(&4, 44u64).unwrap();
+        assert_that!(*reader.dynamic_entry::<u64>(&4).unwrap().get().unwrap(), eq 44);
+    }
+
+    #[conformance_test]
+    pub fn snapshot_value_replaces_value_of_added_entry<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 11)
+            .create()
+            .unwrap();
+        let writer = blackboard.writer_builder().create().unwrap();
+        writer.entry::<u32>(&1).unwrap().update_with_copy(111);
+        let snapshot = blackboard.snapshot();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 0)
+            .add::<u16>(5, 55)
+            .from_snapshot(&snapshot)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(*reader.entry::<u32>(&1).unwrap().get(), eq 111);
+        assert_that!(*reader.entry::<u16>(&5).unwrap().get(), eq 55);
+    }
+
+    #[conformance_test]
+    pub fn create_from_snapshot_with_different_key_type_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 11)
+            .create()
+            .unwrap();
+        let snapshot = blackboard.snapshot();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u32>()
+            .from_snapshot(&snapshot)
+            .create();
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::IncompatibleSnapshot));
+    }
+
+    #[conformance_test]
+    pub fn create_from_snapshot_with_different_value_type_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 11)
+            .create()
+            .unwrap();
+        let snapshot = blackboard.snapshot();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<i32>(1, 0)
+            .from_snapshot(&snapshot)
+            .create();
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::IncompatibleSnapshot));
+    }
+
+    #[conformance_test]
+    pub fn create_from_snapshot_with_too_few_dynamic_entries_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+        let writer = blackboard.writer_builder().create().unwrap();
+        writer.add_entry(&1, 11u64).unwrap();
+        writer.add_entry(&2, 22u64).unwrap();
+        let snapshot = blackboard.snapshot();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .from_snapshot(&snapshot)
+            .create();
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::IncompatibleSnapshot));
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .max_dynamic_entries(2)
+            .max_dynamic_value_size(4)
+            .from_snapshot(&snapshot)
+            .create();
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::IncompatibleSnapshot));
+    }
+
+    #[conformance_test]
+    pub fn snapshot_can_be_written_to_and_read_from_file<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let file_path = generate_file_path();
+
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 11)
+            .add::<i8>(2, -2)
+            .max_dynamic_entries(1)
+            .max_dynamic_value_size(8)
+            .create()
+            .unwrap();
+        let writer = blackboard.writer_builder().create().unwrap();
+        writer.add_entry(&3, 33u64).unwrap();
+        let snapshot = blackboard.snapshot();
+
+        assert_that!(snapshot.write_to_file(&file_path), is_ok);
+        let sut = BlackboardSnapshot::from_file(&file_path);
+        File::remove(&file_path).unwrap();
+
+        assert_that!(sut, eq Ok(snapshot));
+    }
 }
@@ -18,6 +18,7 @@ pub mod service_event {
     use alloc::collections::BTreeSet;
     use alloc::{format, vec};
     use core::time::Duration;
+    use iceoryx2::config::ServiceProfile;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
     use iceoryx2_bb_posix::clock::Time;
     use iceoryx2_bb_posix::clock::nanosleep;
@@ -333,6 +334,39 @@ pub mod service_event {
         assert_that!(sut2.static_config().max_listeners(), eq 10);
     }
 
+    #[conformance_test]
+    pub fn settings_can_be_modified_via_service_profile<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let other_service_name = generate_service_name();
+        let mut custom_config = test.config().clone();
+        let mut profile = ServiceProfile::new(service_name.as_str());
+        profile.event.max_listeners = Some(3);
+        profile.event.notifier_created_event = Some(7);
+        custom_config.services.push(profile);
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().max_listeners(), eq 3);
+        assert_that!(sut.static_config().notifier_created_event(), eq Some(EventId::new(7)));
+        assert_that!(sut.static_config().max_notifiers(), eq custom_config.defaults.event.max_notifiers);
+
+        let sut2 = node
+            .service_builder(&other_service_name)
+            .event()
+            .create()
+            .unwrap();
+        assert_that!(sut2.static_config().max_listeners(), eq custom_config.defaults.event.max_listeners);
+        assert_that!(sut2.static_config().notifier_created_event(), eq None);
+    }
+
     #[conformance_test]
     pub fn simple_communication_works_listener_created_first<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -19,7 +19,10 @@ pub mod service_publish_subscribe {
     use core::time::Duration;
     use iceoryx2::service::header::payload_header::PayloadHeader;
 
-    use iceoryx2::config::Config;
+    use iceoryx2::config::{Config, ServiceProfile};
+    use iceoryx2::dynamic_payload::{
+        DynamicPayload, DynamicPayloadAccessError, DynamicPayloadMut, PrimitiveValue, TypeLayout,
+    };
     use iceoryx2::port::publisher::PublisherCreateError;
     use iceoryx2::port::subscriber::SubscriberCreateError;
     use iceoryx2::port::update_connections::UpdateConnections;
@@ -60,6 +63,67 @@ pub mod service_publish_subscribe {
         }
     }
 
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[type_name("Position")]
+    #[repr(C)]
+    struct Position {
+        x: u64,
+        y: u64,
+    }
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[type_name("Position")]
+    #[repr(C)]
+    struct PositionWithSwappedFields {
+        y: u64,
+        x: u64,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Position")]
+    #[type_version(2)]
+    #[repr(C)]
+    struct PositionV2 {
+        x: u64,
+        y: u64,
+        #[since_version(2)]
+        z: u64,
+    }
+
+    impl Default for PositionV2 {
+        fn default() -> Self {
+            Self { x: 0, y: 0, z: 42 }
+        }
+    }
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[type_name("Position")]
+    #[type_version(2)]
+    #[repr(C)]
+    struct PositionV2WithSwappedFields {
+        y: u64,
+        x: u64,
+        #[since_version(2)]
+        z: u64,
+    }
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[type_layout]
+    #[repr(C)]
+    struct DescribedPosition {
+        x: u64,
+        y: u64,
+    }
+
+    #[derive(Debug, Default, ZeroCopySend)]
+    #[type_layout]
+    #[repr(C)]
+    struct Vehicle {
+        position: DescribedPosition,
+        wheel_pressure: [f32; 4],
+        is_parked: bool,
+    }
+
     #[conformance_test]
     pub fn open_or_create_with_attributes_succeeds_when_service_does_exist<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -314,6 +378,343 @@ pub mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[conformance_test]
+    pub fn open_fails_when_service_payload_has_different_type_structure<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Position>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PositionWithSwappedFields>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeStructure);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[PositionWithSwappedFields]>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Position>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_service_user_header_has_different_type_structure<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<Position>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<PositionWithSwappedFields>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeStructure);
+    }
+
+    #[conformance_test]
+    pub fn newer_subscriber_receives_defaulted_fields_from_older_service<Sut: Service>() {
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let older_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Position>()
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .subscriber_max_buffer_size(MAX_BORROWED_SAMPLES * 2)
+            .create()
+            .unwrap();
+        let publisher = older_service.publisher_builder().create().unwrap();
+
+        let newer_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PositionV2>()
+            .open();
+        assert_that!(newer_service, is_ok);
+        let newer_service = newer_service.unwrap();
+        let subscriber = newer_service.subscriber_builder().create().unwrap();
+
+        for n in 0..MAX_BORROWED_SAMPLES as u64 * 2 {
+            publisher.send_copy(Position { x: n, y: n + 1 }).unwrap();
+        }
+
+        for _ in 0..2 {
+            let mut samples = vec![];
+            for _ in 0..MAX_BORROWED_SAMPLES {
+                samples.push(subscriber.receive().unwrap().unwrap());
+            }
+
+            for sample in &samples {
+                assert_that!(sample.y, eq sample.x + 1);
+                assert_that!(sample.z, eq 42);
+            }
+        }
+
+        let mut payload_versions = vec![];
+        newer_service.dynamic_config().list_subscribers(|details| {
+            payload_versions.push(details.payload_version);
+            CallbackProgression::Continue
+        });
+        assert_that!(payload_versions, eq vec![1]);
+    }
+
+    #[conformance_test]
+    pub fn older_subscriber_receives_the_known_fields_from_newer_service<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let newer_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PositionV2>()
+            .create()
+            .unwrap();
+        let publisher = newer_service.publisher_builder().create().unwrap();
+
+        let older_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Position>()
+            .open();
+        assert_that!(older_service, is_ok);
+        let older_service = older_service.unwrap();
+        let subscriber = older_service.subscriber_builder().create().unwrap();
+
+        publisher
+            .send_copy(PositionV2 { x: 3, y: 5, z: 7 })
+            .unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.x, eq 3);
+        assert_that!(sample.y, eq 5);
+
+        let mut payload_versions = vec![];
+        older_service.dynamic_config().list_subscribers(|details| {
+            payload_versions.push(details.payload_version);
+            CallbackProgression::Continue
+        });
+        assert_that!(payload_versions, eq vec![1]);
+    }
+
+    #[conformance_test]
+    pub fn publisher_creation_fails_when_payload_version_differs_from_service<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let older_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Position>()
+            .create()
+            .unwrap();
+
+        let newer_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PositionV2>()
+            .open()
+            .unwrap();
+
+        let sut = newer_service.publisher_builder().create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublisherCreateError::IncompatiblePayloadVersion);
+
+        let sut = older_service.publisher_builder().create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[conformance_test]
+    pub fn open_fails_when_payload_versions_have_incompatible_fields<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let _older_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Position>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PositionV2WithSwappedFields>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[PositionV2]>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[conformance_test]
+    pub fn type_layout_is_only_available_for_described_payload_types<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<Vehicle>()
+            .create()
+            .unwrap();
+        assert_that!(sut.type_layout(), eq Some(TypeLayout::new::<Vehicle>()));
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<Position>()
+            .create()
+            .unwrap();
+        assert_that!(sut.type_layout(), eq None);
+    }
+
+    #[conformance_test]
+    pub fn custom_payload_service_reads_received_fields_with_the_type_layout<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Vehicle>()
+            .create()
+            .unwrap();
+
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::new::<Vehicle>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+                .unwrap()
+        };
+        let layout = sut.type_layout().unwrap();
+        assert_that!(layout, eq TypeLayout::new::<Vehicle>());
+
+        let publisher = typed_service.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher
+            .send_copy(Vehicle {
+                position: DescribedPosition { x: 12, y: 34 },
+                wheel_pressure: [2.0, 2.25, 2.5, 2.75],
+                is_parked: true,
+            })
+            .unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                sample.payload().as_ptr().cast::<u8>(),
+                sample.payload().len(),
+            )
+        };
+        let payload = DynamicPayload::new(&layout, bytes).unwrap();
+
+        assert_that!(payload.get("position.x"), eq Ok(PrimitiveValue::U64(12)));
+        assert_that!(payload.get("position.y"), eq Ok(PrimitiveValue::U64(34)));
+        assert_that!(payload.get("wheel_pressure[2]"), eq Ok(PrimitiveValue::F32(2.5)));
+        assert_that!(payload.get("is_parked"), eq Ok(PrimitiveValue::Bool(true)));
+        assert_that!(payload.get("position"), eq Err(DynamicPayloadAccessError::NotAPrimitive));
+        assert_that!(payload.get("position.z"), eq Err(DynamicPayloadAccessError::UnknownField));
+        assert_that!(
+            payload.get("wheel_pressure[4]"),
+            eq Err(DynamicPayloadAccessError::IndexOutOfBounds)
+        );
+        assert_that!(payload.get("is_parked[0"), eq Err(DynamicPayloadAccessError::InvalidPath));
+
+        let mut paths = vec![];
+        payload
+            .for_each_value(|path, _| paths.push(format!("{path}")))
+            .unwrap();
+        assert_that!(paths, eq vec![
+            "position.x",
+            "position.y",
+            "wheel_pressure[0]",
+            "wheel_pressure[1]",
+            "wheel_pressure[2]",
+            "wheel_pressure[3]",
+            "is_parked"
+        ]);
+    }
+
+    #[conformance_test]
+    pub fn custom_payload_service_writes_fields_with_the_type_layout<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<Vehicle>()
+            .create()
+            .unwrap();
+
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .user_header::<CustomHeaderMarker>()
+                .__internal_set_payload_type_details(&TypeDetail::new::<Vehicle>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_user_header_type_details(&TypeDetail::new::<()>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+                .unwrap()
+        };
+        let layout = sut.type_layout().unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = typed_service.subscriber_builder().create().unwrap();
+
+        let mut sample = unsafe { publisher.loan_custom_payload(1).unwrap() };
+        let payload = sample.payload_mut();
+        let bytes = unsafe {
+            core::ptr::write_bytes(payload.as_mut_ptr().cast::<u8>(), 0, payload.len());
+            core::slice::from_raw_parts_mut(payload.as_mut_ptr().cast::<u8>(), payload.len())
+        };
+        let mut payload = DynamicPayloadMut::new(&layout, bytes).unwrap();
+
+        assert_that!(payload.set("position.x", PrimitiveValue::U64(56)), is_ok);
+        assert_that!(payload.set("position.y", PrimitiveValue::U64(78)), is_ok);
+        assert_that!(
+            payload.set("wheel_pressure[1]", PrimitiveValue::F32(1.5)),
+            is_ok
+        );
+        assert_that!(payload.set("is_parked", PrimitiveValue::Bool(true)), is_ok);
+        assert_that!(
+            payload.set("position.x", PrimitiveValue::U32(56)),
+            eq Err(DynamicPayloadAccessError::TypeMismatch)
+        );
+
+        unsafe { sample.assume_init() }.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.position.x, eq 56);
+        assert_that!(sample.position.y, eq 78);
+        assert_that!(sample.wheel_pressure, eq [0.0, 1.5, 0.0, 0.0]);
+        assert_that!(sample.is_parked, eq true);
+    }
+
     #[conformance_test]
     pub fn open_fails_when_service_does_not_satisfy_max_nodes_requirement<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -786,6 +1187,69 @@ pub mod service_publish_subscribe {
         assert_that!(sut2.static_config().subscriber_max_buffer_size(), eq 13);
     }
 
+    #[conformance_test]
+    pub fn settings_can_be_modified_via_service_profile<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let other_service_name = generate_service_name();
+        let mut custom_config = test.config().clone();
+        let mut profile = ServiceProfile::new(service_name.as_str());
+        profile.publish_subscribe.max_publishers = Some(9);
+        profile.publish_subscribe.subscriber_max_buffer_size = Some(13);
+        profile.publish_subscribe.enable_safe_overflow = Some(false);
+        custom_config.services.push(profile);
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_publishers(), eq 9);
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 13);
+        assert_that!(sut.static_config().has_safe_overflow(), eq false);
+        assert_that!(sut.static_config().max_subscribers(), eq custom_config.defaults.publish_subscribe.max_subscribers);
+
+        let sut2 = node
+            .service_builder(&other_service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut2.static_config().max_publishers(), eq custom_config.defaults.publish_subscribe.max_publishers);
+        assert_that!(sut2.static_config().subscriber_max_buffer_size(), eq custom_config.defaults.publish_subscribe.subscriber_max_buffer_size);
+        assert_that!(sut2.static_config().has_safe_overflow(), eq custom_config.defaults.publish_subscribe.enable_safe_overflow);
+    }
+
+    #[conformance_test]
+    pub fn builder_settings_take_precedence_over_service_profile<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let mut custom_config = test.config().clone();
+        let mut profile = ServiceProfile::new("*");
+        profile.publish_subscribe.max_publishers = Some(9);
+        profile.publish_subscribe.publisher_history_size = Some(4);
+        custom_config.services.push(profile);
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_publishers(), eq 3);
+        assert_that!(sut.static_config().history_size(), eq 4);
+    }
+
     #[conformance_test]
     pub fn number_of_publishers_works<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -3038,44 +3502,117 @@ pub mod service_publish_subscribe {
         let test = Test::<Sut>::new();
         let node = test.create_node();
         let service_name = generate_service_name();
-
-        let service_pub = node
+
+        let service_pub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
+            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
+
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open()
+            .unwrap();
+
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+
+        let mut samples = vec![];
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
+            }
+            samples.push(recv_sample);
+        }
+    }
+
+    #[conformance_test]
+    pub fn sliced_service_with_buddy_allocator_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        const NUMBER_OF_ROUNDS: usize = 3;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
+            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .data_segment_allocator(DataSegmentAllocator::Buddy)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for round in 0..NUMBER_OF_ROUNDS {
+            // all samples are held at once so that differently sized chunks coexist
+            let mut samples = vec![];
+            for n in (0..=MAX_ELEMENTS).rev() {
+                let sample = publisher.loan_slice_uninit(n).unwrap();
+                sample
+                    .write_from_fn(|i| (i * n + round) as u64)
+                    .send()
+                    .unwrap();
+
+                samples.push(subscriber.receive().unwrap().unwrap());
+            }
+
+            for (sample, n) in samples.iter().zip((0..=MAX_ELEMENTS).rev()) {
+                assert_that!(sample.payload(), len n);
+                for (i, element) in sample.payload().iter().enumerate() {
+                    assert_that!(*element, eq(i * n + round) as u64);
+                }
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn buddy_allocator_ignores_allocation_strategy<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 16;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
-            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
-            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .publish_subscribe::<[u8]>()
             .create()
             .unwrap();
 
-        let service_sub = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .open()
-            .unwrap();
-
-        let publisher = service_pub
+        let publisher = sut
             .publisher_builder()
             .initial_max_slice_len(MAX_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .data_segment_allocator(DataSegmentAllocator::Buddy)
             .create()
             .unwrap();
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
-
-        let mut samples = vec![];
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+        let sample = publisher.loan_slice(MAX_ELEMENTS);
+        assert_that!(sample, is_ok);
+        drop(sample);
 
-            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
-            }
-            samples.push(recv_sample);
-        }
+        let sample = publisher.loan_slice(MAX_ELEMENTS + 1);
+        assert_that!(sample.err(), eq Some(LoanError::ExceedsMaxLoanSize));
     }
 
     #[conformance_test]
@@ -3679,6 +4216,47 @@ pub mod service_publish_subscribe {
         send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
     }
 
+    #[conformance_test]
+    pub fn send_and_receive_alternating_sample_sizes_with_segment_idle_timeout_works<
+        Sut: Service,
+    >() {
+        const ITERATIONS: usize = 300;
+        const LARGE_SAMPLE_SIZE: usize = 4096;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .segment_idle_timeout(Duration::ZERO)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..ITERATIONS {
+            let sample_size = if n % 2 == 0 { LARGE_SAMPLE_SIZE } else { 1 };
+            let mut sample = publisher.loan_slice(sample_size).unwrap();
+            for byte in sample.payload_mut() {
+                *byte = n as u8;
+            }
+            sample.send().unwrap();
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len sample_size);
+            for byte in sample.payload() {
+                assert_that!(*byte, eq n as u8);
+            }
+        }
+    }
+
     fn send_and_receives_increasing_samples_with_overflow_works<Sut: Service>(
         allocation_strategy: AllocationStrategy,
     ) {
@@ -4104,4 +4682,277 @@ pub mod service_publish_subscribe {
         assert_that!(second, is_some);
         assert_that!(*second.unwrap(), eq 4567);
     }
+
+    #[conformance_test]
+    pub fn data_segment_memory_is_applied_on_creation_and_adopted_on_open<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .data_segment_lock_memory(true)
+            .data_segment_prefault_memory(true)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        for static_config in [sut.static_config(), sut2.static_config()] {
+            let data_segment_memory = static_config.data_segment_memory();
+            assert_that!(data_segment_memory.use_huge_pages(), eq false);
+            assert_that!(data_segment_memory.lock_memory(), eq true);
+            assert_that!(data_segment_memory.prefault_memory(), eq true);
+        }
+    }
+
+    #[conformance_test]
+    pub fn locked_and_prefaulted_data_segments_deliver_samples<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .data_segment_lock_memory(true)
+            .data_segment_prefault_memory(true)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(8192), is_ok);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8192);
+    }
+
+    #[conformance_test]
+    pub fn huge_page_data_segments_are_either_created_or_reported_as_not_supported<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .data_segment_use_huge_pages(true)
+            .create()
+            .unwrap();
+
+        match sut.publisher_builder().create() {
+            Ok(publisher) => {
+                let subscriber = sut.subscriber_builder().create().unwrap();
+                assert_that!(publisher.send_copy(2048), is_ok);
+                assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2048);
+            }
+            Err(e) => {
+                assert_that!(e, eq PublisherCreateError::DataSegmentHugePagesNotSupported)
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn slice_builder_appends_up_to_max_slice_len_with_static_allocation_strategy<
+        Sut: Service,
+    >() {
+        const MAX_SLICE_LEN: usize = 32;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice_builder().unwrap();
+        assert_that!(sample, is_empty);
+        assert_that!(sample.capacity(), ge MAX_SLICE_LEN);
+
+        let capacity = sample.capacity();
+        for n in 0..capacity {
+            assert_that!(sample.push(n as u64), is_ok);
+        }
+        assert_that!(sample.push(0).err(), eq Some(LoanError::ExceedsMaxLoanSize));
+        assert_that!(sample, len capacity);
+
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().number_of_elements(), eq capacity as u64);
+        for (n, value) in sample.payload().iter().enumerate() {
+            assert_that!(*value, eq n as u64);
+        }
+    }
+
+    fn slice_builder_grows_payload_in_data_segment<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) {
+        const NUMBER_OF_ELEMENTS: usize = 1000;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(allocation_strategy)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for iteration in 0..3 {
+            let mut sample = publisher.loan_slice_builder().unwrap();
+            *sample.user_header_mut() = 4711 + iteration;
+            for n in 0..NUMBER_OF_ELEMENTS {
+                assert_that!(sample.push((n as u64) * iteration), is_ok);
+            }
+            assert_that!(sample.capacity(), ge NUMBER_OF_ELEMENTS);
+            assert_that!(*sample.user_header(), eq 4711 + iteration);
+
+            let sample = sample.finish();
+            assert_that!(sample.payload(), len NUMBER_OF_ELEMENTS);
+            assert_that!(sample.send(), eq Ok(1));
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample.user_header(), eq 4711 + iteration);
+            assert_that!(sample.payload(), len NUMBER_OF_ELEMENTS);
+            for (n, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(n as u64) * iteration);
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn slice_builder_grows_payload_with_best_fit_allocation_strategy<Sut: Service>() {
+        slice_builder_grows_payload_in_data_segment::<Sut>(AllocationStrategy::BestFit);
+    }
+
+    #[conformance_test]
+    pub fn slice_builder_grows_payload_with_power_of_two_allocation_strategy<Sut: Service>() {
+        slice_builder_grows_payload_in_data_segment::<Sut>(AllocationStrategy::PowerOfTwo);
+    }
+
+    #[conformance_test]
+    pub fn slice_builder_reserve_applies_allocation_strategy_once<Sut: Service>() {
+        const RESERVED_LEN: usize = 50;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()
+            .unwrap();
+
+        let mut sample = publisher.loan_slice_builder().unwrap();
+        assert_that!(sample.reserve(RESERVED_LEN), is_ok);
+        assert_that!(sample.capacity(), ge RESERVED_LEN);
+
+        assert_that!(sample.reserve(RESERVED_LEN + 1), is_ok);
+        assert_that!(sample.capacity(), ge RESERVED_LEN + 1);
+        assert_that!(sample.capacity(), lt 2 * RESERVED_LEN);
+    }
+
+    #[conformance_test]
+    pub fn slice_builder_writes_strings<Sut: Service>() {
+        use core::fmt::Write;
+
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice_builder().unwrap();
+        assert_that!(sample.push_str("the answer is "), is_ok);
+        assert_that!(write!(sample, "{} and not {}", 42, 73), is_ok);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq b"the answer is 42 and not 73".as_slice());
+    }
+
+    #[conformance_test]
+    pub fn dropping_slice_builder_releases_the_loan<Sut: Service>() {
+        const MAX_LOANS: usize = 2;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .max_loaned_samples(MAX_LOANS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        for _ in 0..10 {
+            let mut samples = vec![];
+            for _ in 0..MAX_LOANS {
+                let mut sample = publisher.loan_slice_builder().unwrap();
+                for n in 0..100 {
+                    assert_that!(sample.push(n), is_ok);
+                }
+                samples.push(sample);
+            }
+
+            assert_that!(
+                publisher.loan_slice_builder().err(),
+                eq Some(LoanError::ExceedsMaxLoans)
+            );
+        }
+    }
 }
@@ -17,6 +17,7 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 pub mod service_request_response_builder {
     use alloc::vec;
 
+    use iceoryx2::config::ServiceProfile;
     use iceoryx2::prelude::*;
     use iceoryx2::service::attribute::*;
     use iceoryx2::service::builder::request_response::{
@@ -25,10 +26,36 @@ pub mod service_request_response_builder {
     use iceoryx2::service::port_factory::client::ClientCreateError;
     use iceoryx2::service::port_factory::server::ServerCreateError;
     use iceoryx2::service::static_config::message_type_details::TypeVariant;
+    use iceoryx2_bb_derive_macros::ZeroCopySend;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_testing::*;
 
+    #[conformance_test]
+    pub fn settings_can_be_modified_via_service_profile<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let mut custom_config = test.config().clone();
+        let mut profile = ServiceProfile::new(service_name.as_str());
+        profile.request_response.max_servers = Some(3);
+        profile.request_response.max_response_buffer_size = Some(5);
+        custom_config.services.push(profile);
+        let node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_servers(), eq 3);
+        assert_that!(sut.static_config().max_response_buffer_size(), eq 5);
+        assert_that!(sut.static_config().max_clients(), eq custom_config.defaults.request_response.max_clients);
+    }
+
     #[conformance_test]
     pub fn open_existing_service_works<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -200,6 +227,57 @@ pub mod service_request_response_builder {
         assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestOrResponseType));
     }
 
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Position")]
+    #[repr(C)]
+    struct Position {
+        x: u64,
+        y: u64,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Position")]
+    #[repr(C)]
+    struct PositionWithSwappedFields {
+        y: u64,
+        x: u64,
+    }
+
+    #[conformance_test]
+    pub fn opening_service_with_different_request_or_response_type_structure_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<Position, Position>()
+            .create();
+
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<PositionWithSwappedFields, Position>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<Position, PositionWithSwappedFields>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<Position, Position>()
+            .open();
+
+        assert_that!(sut_open, is_ok);
+    }
+
     #[conformance_test]
     pub fn opening_service_with_incompatible_request_type_alignment_fails<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -0,0 +1,140 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing_macros::conformance_tests;
+
+#[allow(clippy::module_inception)]
+#[conformance_tests]
+pub mod service_request_response_rpc {
+    use iceoryx2::node::Node;
+    use iceoryx2::prelude::rpc_service;
+    use iceoryx2::service::Service;
+    use iceoryx2::service::port_factory::request_response::PortFactory;
+    use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_testing::*;
+
+    #[rpc_service]
+    pub trait Calculator {
+        fn add(&self, lhs: u64, rhs: u64) -> u64;
+        fn negate(&self, value: i64) -> i64;
+        fn reset(&self);
+    }
+
+    #[derive(Default)]
+    struct TestCalculator {
+        number_of_resets: AtomicU64,
+    }
+
+    impl Calculator for TestCalculator {
+        fn add(&self, lhs: u64, rhs: u64) -> u64 {
+            lhs + rhs
+        }
+
+        fn negate(&self, value: i64) -> i64 {
+            -value
+        }
+
+        fn reset(&self) {
+            self.number_of_resets.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    struct TestFixture<Sut: Service> {
+        _context: Test<Sut>,
+        _node: Node<Sut>,
+        _service: PortFactory<Sut, CalculatorRequest, (), CalculatorResponse, ()>,
+        client: CalculatorClient<Sut>,
+        server: CalculatorServer<Sut>,
+    }
+
+    impl<Sut: Service> TestFixture<Sut> {
+        fn new() -> Self {
+            let context = Test::new();
+            let service_name = generate_service_name();
+            let node = context.create_node();
+            let service = node
+                .service_builder(&service_name)
+                .request_response::<CalculatorRequest, CalculatorResponse>()
+                .create()
+                .unwrap();
+
+            Self {
+                client: CalculatorClient::new(service.client_builder().create().unwrap()),
+                server: CalculatorServer::new(service.server_builder().create().unwrap()),
+                _context: context,
+                _node: node,
+                _service: service,
+            }
+        }
+    }
+
+    #[conformance_test]
+    pub fn handle_next_returns_false_without_requests<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let calculator = TestCalculator::default();
+
+        assert_that!(test.server.handle_next(&calculator), eq Ok(false));
+        assert_that!(test.server.handle_all(&calculator), eq Ok(0));
+    }
+
+    #[conformance_test]
+    pub fn call_is_dispatched_to_implementation<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let calculator = TestCalculator::default();
+
+        let pending_response = test.client.add(12, 30).unwrap();
+        assert_that!(test.server.handle_next(&calculator), eq Ok(true));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.as_add(), eq Some(&42));
+        assert_that!(response.as_negate(), is_none);
+    }
+
+    #[conformance_test]
+    pub fn calls_of_different_methods_are_dispatched_to_the_corresponding_method<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let calculator = TestCalculator::default();
+
+        let add_response = test.client.add(1, 2).unwrap();
+        let negate_response = test.client.negate(7).unwrap();
+        let reset_response = test.client.reset().unwrap();
+        assert_that!(test.server.handle_all(&calculator), eq Ok(3));
+
+        let response = add_response.receive().unwrap().unwrap();
+        assert_that!(response.as_add(), eq Some(&3));
+
+        let response = negate_response.receive().unwrap().unwrap();
+        assert_that!(response.as_negate(), eq Some(&-7));
+
+        let response = reset_response.receive().unwrap().unwrap();
+        assert_that!(response.as_reset(), eq Some(&()));
+        assert_that!(calculator.number_of_resets.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[conformance_test]
+    pub fn request_payload_contains_method_arguments<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+
+        let _pending_response = test.client.add(5, 6).unwrap();
+        let active_request = test.server.server().receive().unwrap().unwrap();
+
+        match active_request.payload() {
+            CalculatorRequest::Add { lhs, rhs } => {
+                assert_that!(*lhs, eq 5);
+                assert_that!(*rhs, eq 6);
+            }
+            request => panic!("received unexpected request {request:?}"),
+        }
+    }
+}
@@ -660,4 +660,220 @@ pub mod waitset {
 
         assert_that!(sut.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
+
+    #[conformance_test]
+    pub fn attachments_have_the_lowest_priority_by_default<S: Service>()
+    where
+        <S::Event as Event<RelocatableCountingBitSet>>::Listener: SynchronousMultiplexing,
+    {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _notifier) = create_event::<S>(&node);
+        let notification_guard = sut.attach_notification(&listener).unwrap();
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        assert_that!(notification_guard.priority(), eq 0);
+        assert_that!(tick_guard.priority(), eq 0);
+
+        notification_guard.set_priority(12);
+        tick_guard.set_priority(255);
+
+        assert_that!(notification_guard.priority(), eq 12);
+        assert_that!(tick_guard.priority(), eq 255);
+    }
+
+    #[conformance_test]
+    pub fn wait_and_process_once_dispatches_in_order_of_priority<S: Service>()
+    where
+        <S::Event as Event<RelocatableCountingBitSet>>::Listener: SynchronousMultiplexing,
+    {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+        let (listener_3, notifier_3) = create_event::<S>(&node);
+
+        let guards = [
+            sut.attach_notification(&listener_1).unwrap(),
+            sut.attach_notification(&listener_2).unwrap(),
+            sut.attach_notification(&listener_3).unwrap(),
+        ];
+        guards[1].set_priority(200);
+        guards[2].set_priority(100);
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+        notifier_3.notify().unwrap();
+
+        let mut dispatch_order = vec![];
+        sut.wait_and_process_once(|attachment_id| {
+            match guards
+                .iter()
+                .position(|guard| attachment_id.has_event_from(guard))
+            {
+                Some(n) => dispatch_order.push(n),
+                None => test_fail!("only attachments shall trigger"),
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(dispatch_order, eq vec![1, 2, 0]);
+    }
+
+    #[conformance_test]
+    pub fn priority_of_deadline_applies_to_missed_deadline<S: Service>()
+    where
+        <S::Event as Event<RelocatableCountingBitSet>>::Listener: SynchronousMultiplexing,
+    {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _notifier) = create_event::<S>(&node);
+        let tick_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        let deadline_guard = sut
+            .attach_deadline(&listener, Duration::from_nanos(1))
+            .unwrap();
+        deadline_guard.set_priority(1);
+
+        nanosleep(TIMEOUT).unwrap();
+
+        let mut missed_deadline_first = None;
+        sut.wait_and_process_once(|attachment_id| {
+            if missed_deadline_first.is_none() {
+                missed_deadline_first = Some(attachment_id.has_missed_deadline(&deadline_guard));
+            } else {
+                assert_that!(attachment_id.has_event_from(&tick_guard), eq true);
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadline_first, eq Some(true));
+    }
+
+    #[conformance_test]
+    pub fn max_callbacks_per_cycle_defers_remaining_events<S: Service>()
+    where
+        <S::Event as Event<RelocatableCountingBitSet>>::Listener: SynchronousMultiplexing,
+    {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let sut = WaitSetBuilder::new()
+            .max_callbacks_per_cycle(1)
+            .create::<S>()
+            .unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+
+        let listener_1_guard = sut.attach_notification(&listener_1).unwrap();
+        let listener_2_guard = sut.attach_notification(&listener_2).unwrap();
+        listener_2_guard.set_priority(10);
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut number_of_callbacks = 0;
+        sut.wait_and_process_once(|attachment_id| {
+            number_of_callbacks += 1;
+            assert_that!(attachment_id.has_event_from(&listener_2_guard), eq true);
+            listener_2.try_wait(|_| {}).unwrap();
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_callbacks, eq 1);
+
+        sut.wait_and_process_once(|attachment_id| {
+            number_of_callbacks += 1;
+            assert_that!(attachment_id.has_event_from(&listener_1_guard), eq true);
+            listener_1.try_wait(|_| {}).unwrap();
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_callbacks, eq 2);
+    }
+
+    #[conformance_test]
+    pub fn deferred_missed_deadlines_are_dispatched_in_the_next_cycle<S: Service>()
+    where
+        <S::Event as Event<RelocatableCountingBitSet>>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new()
+            .max_callbacks_per_cycle(1)
+            .create::<S>()
+            .unwrap();
+
+        let tick_1_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        let tick_2_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+
+        nanosleep(TIMEOUT).unwrap();
+
+        let mut tick_1_triggered = 0;
+        let mut tick_2_triggered = 0;
+        let mut on_event = |attachment_id: WaitSetAttachmentId<S>| {
+            if attachment_id.has_event_from(&tick_1_guard) {
+                tick_1_triggered += 1;
+            } else if attachment_id.has_event_from(&tick_2_guard) {
+                tick_2_triggered += 1;
+            } else {
+                test_fail!("only attachments shall trigger");
+            }
+            CallbackProgression::Continue
+        };
+
+        sut.wait_and_process_once(&mut on_event).unwrap();
+        sut.wait_and_process_once(&mut on_event).unwrap();
+
+        assert_that!(tick_1_triggered, eq 1);
+        assert_that!(tick_2_triggered, eq 1);
+    }
+
+    #[conformance_test]
+    pub fn deferred_events_of_detached_attachments_are_discarded<S: Service>()
+    where
+        <S::Event as Event<RelocatableCountingBitSet>>::Listener: SynchronousMultiplexing,
+    {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let sut = WaitSetBuilder::new()
+            .max_callbacks_per_cycle(1)
+            .create::<S>()
+            .unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+
+        let listener_1_guard = sut.attach_notification(&listener_1).unwrap();
+        let listener_2_guard = sut.attach_notification(&listener_2).unwrap();
+        listener_1_guard.set_priority(10);
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+
+        sut.wait_and_process_once(|_| {
+            listener_1.try_wait(|_| {}).unwrap();
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        drop(listener_2_guard);
+
+        let mut number_of_callbacks = 0;
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                number_of_callbacks += 1;
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(number_of_callbacks, eq 0);
+    }
 }
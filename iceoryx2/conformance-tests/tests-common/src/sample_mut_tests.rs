@@ -35,3 +35,9 @@ instantiate_conformance_tests_with_module!(
     iceoryx2_conformance_tests::sample_mut,
     iceoryx2::service::local_threadsafe::Service
 );
+
+instantiate_conformance_tests_with_module!(
+    ipc_copy,
+    iceoryx2_conformance_tests::sample_mut,
+    iceoryx2::service::ipc_copy::Service
+);
@@ -40,6 +40,7 @@
 //! ```
 
 use alloc::sync::Arc;
+use core::time::Duration;
 use core::{any::TypeId, fmt::Debug, marker::PhantomData, mem::MaybeUninit, ops::Deref};
 use flatbuffers::InvalidFlatbuffer;
 use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
@@ -58,6 +59,7 @@ use iceoryx2_log::fail;
 use crate::payload::number_of_elements;
 use crate::port::details::chunk::Chunk;
 use crate::port::details::chunk::ChunkMut;
+use crate::service::header::request_response::DeadlineError;
 use crate::service::marker::CustomHeaderMarker;
 use crate::service::marker::CustomPayloadMarker;
 use crate::service::marker::Flatbuffer;
@@ -328,6 +330,20 @@ impl<
         }
     }
 
+    /// Returns the time that is left until the deadline of the received
+    /// [`RequestMut`](crate::request_mut::RequestMut) is reached. If the
+    /// [`RequestMut`](crate::request_mut::RequestMut) has no deadline, it returns [`None`].
+    pub fn remaining_time(&self) -> Result<Option<Duration>, DeadlineError> {
+        service::header::request_response::remaining_time(self.header().deadline)
+    }
+
+    /// Returns [`true`] when the deadline of the received
+    /// [`RequestMut`](crate::request_mut::RequestMut) was reached. All [`ResponseMut`]s that are
+    /// sent afterwards are discarded.
+    pub fn has_expired(&self) -> Result<bool, DeadlineError> {
+        service::header::request_response::has_expired(self.header().deadline)
+    }
+
     /// Returns a reference to the user_header of the received
     /// [`RequestMut`](crate::request_mut::RequestMut)
     pub fn user_header(&self) -> &RequestHeader {
@@ -384,6 +400,7 @@ impl<
         let user_header_ptr: *mut ResponseHeader = chunk.user_header.cast();
         unsafe {
             header_ptr.write(service::header::request_response::ResponseHeader {
+                sequence_number: 0,
                 node_id: *shared_state.response_sender.shared_node.id(),
                 server_id: UniqueServerId(UniqueSystemId::from(
                     shared_state.response_sender.sender_port_id,
@@ -391,6 +408,7 @@ impl<
                 request_id: self.request_id,
                 number_of_elements: slice_len as _,
                 payload_offset: 0,
+                request_deadline: self.header().deadline,
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
@@ -68,10 +68,50 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Layered Config
+//!
+//! The global config is merged from multiple layers, each layer overrides only the entries it
+//! contains:
+//!
+//!  1. the default values
+//!  2. the global config file, e.g. `/etc/iceoryx2/iceoryx2.toml`
+//!  3. the user config file, e.g. `$HOME/.config/iceoryx2/iceoryx2.toml`
+//!  4. the project local config file `config/iceoryx2.toml`
+//!  5. the config file provided with
+//!     [`Config::setup_global_config_from_file()`](crate::config::Config::setup_global_config_from_file())
+//!  6. environment variables (only with the `std` feature), the name of the variable is the
+//!     key of the entry in upper case with the prefix `IOX2_` where `.` and `-` are replaced
+//!     with `_`, e.g. `IOX2_GLOBAL_PREFIX` for `global.prefix` or
+//!     `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS` for
+//!     `defaults.publish-subscribe.max-subscribers`
+//!
+//! The [`LayeredConfig`](crate::config::LayeredConfig) reports from which layer every entry
+//! originates.
+//!
+//! ```no_run
+//! use iceoryx2::config::{Config, ConfigValueOrigin, LayeredConfig};
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let mut layered_config = LayeredConfig::new();
+//! layered_config.add_file(&FilePath::new(b"my/base/config.toml")?)?;
+//! layered_config.add_file(&FilePath::new(b"my/partial/overrides.toml")?)?;
+//!
+//! let config: Config = layered_config.config()?;
+//! println!("prefix originates from: {}",
+//!     layered_config.origin("global.prefix").unwrap_or(&ConfigValueOrigin::Default));
+//! # Ok(())
+//! # }
+//! ```
 
 use core::time::Duration;
 
-use alloc::string::String;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::{
@@ -90,6 +130,7 @@ use serde::{Deserialize, Serialize};
 use iceoryx2_log::{debug, fail, fatal_panic, info, trace, warn};
 
 use crate::port::backpressure_strategy::BackpressureStrategy;
+use crate::service::service_name::ServiceName;
 
 use iceoryx2_pal_configuration::ICEORYX2_ROOT_PATH;
 
@@ -130,6 +171,31 @@ impl core::fmt::Display for ConfigCreationError {
 
 impl core::error::Error for ConfigCreationError {}
 
+/// Describes from which layer of a [`LayeredConfig`] the effective value of a config entry
+/// originates.
+#[allow(clippy::large_enum_variant)] // `Box` is not allowed in a mission-critical context
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ConfigValueOrigin {
+    /// The default value of the entry.
+    Default,
+    /// The config file at the contained path.
+    File(FilePath),
+    /// The environment variable with the contained name.
+    EnvironmentVariable(String),
+}
+
+impl core::fmt::Display for ConfigValueOrigin {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfigValueOrigin::Default => write!(f, "default"),
+            ConfigValueOrigin::File(path) => write!(f, "file \"{path}\""),
+            ConfigValueOrigin::EnvironmentVariable(name) => {
+                write!(f, "environment variable {name}")
+            }
+        }
+    }
+}
+
 /// All configurable settings of a [`Service`](crate::service::Service).
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -344,6 +410,15 @@ pub struct PublishSubscribe {
     /// [`Publisher`](crate::port::publisher::Publisher) when the initially preallocated memory is
     /// insufficient.
     pub publisher_allocation_strategy: AllocationStrategy,
+    /// Backs the data segments of the [`Publisher`](crate::port::publisher::Publisher)s with
+    /// transparent huge pages. The creation fails when the system does not provide them for
+    /// shared memory.
+    pub data_segment_use_huge_pages: bool,
+    /// Locks the data segments in memory so that they are never swapped out.
+    pub data_segment_lock_memory: bool,
+    /// Touches every page of a data segment when it is mapped so that no page fault occurs
+    /// when a [`Sample`](crate::sample::Sample) is accessed.
+    pub data_segment_prefault_memory: bool,
 }
 
 impl Default for PublishSubscribe {
@@ -360,6 +435,9 @@ impl Default for PublishSubscribe {
             backpressure_strategy: BackpressureStrategy::RetryUntilDelivered,
             subscriber_expired_connection_buffer: 128,
             publisher_allocation_strategy: AllocationStrategy::Static,
+            data_segment_use_huge_pages: false,
+            data_segment_lock_memory: false,
+            data_segment_prefault_memory: false,
         }
     }
 }
@@ -484,6 +562,16 @@ pub struct RequestResonse {
     /// [`Server`](crate::port::server::Server) when the initially preallocated memory is
     /// insufficient.
     pub server_allocation_strategy: AllocationStrategy,
+    /// Backs the data segments of the [`Client`](crate::port::client::Client)s and
+    /// [`Server`](crate::port::server::Server)s with transparent huge pages. The creation fails
+    /// when the system does not provide them for shared memory.
+    pub data_segment_use_huge_pages: bool,
+    /// Locks the data segments in memory so that they are never swapped out.
+    pub data_segment_lock_memory: bool,
+    /// Touches every page of a data segment when it is mapped so that no page fault occurs
+    /// when a [`RequestMut`](crate::request_mut::RequestMut) or
+    /// [`Response`](crate::response::Response) is accessed.
+    pub data_segment_prefault_memory: bool,
 }
 
 impl Default for RequestResonse {
@@ -506,6 +594,9 @@ impl Default for RequestResonse {
             enable_fire_and_forget_requests: true,
             client_allocation_strategy: AllocationStrategy::Static,
             server_allocation_strategy: AllocationStrategy::Static,
+            data_segment_use_huge_pages: false,
+            data_segment_lock_memory: false,
+            data_segment_prefault_memory: false,
         }
     }
 }
@@ -533,6 +624,591 @@ impl Default for Blackboard {
     }
 }
 
+/// Overrides of the [`PublishSubscribe`] defaults for all services that match the name
+/// pattern of a [`ServiceProfile`]. Every entry that is not set keeps its default value.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct PublishSubscribeProfile {
+    /// Overrides [`PublishSubscribe::max_subscribers`]
+    pub max_subscribers: Option<usize>,
+    /// Overrides [`PublishSubscribe::max_publishers`]
+    pub max_publishers: Option<usize>,
+    /// Overrides [`PublishSubscribe::max_nodes`]
+    pub max_nodes: Option<usize>,
+    /// Overrides [`PublishSubscribe::subscriber_max_buffer_size`]
+    pub subscriber_max_buffer_size: Option<usize>,
+    /// Overrides [`PublishSubscribe::subscriber_max_borrowed_samples`]
+    pub subscriber_max_borrowed_samples: Option<usize>,
+    /// Overrides [`PublishSubscribe::publisher_max_loaned_samples`]
+    pub publisher_max_loaned_samples: Option<usize>,
+    /// Overrides [`PublishSubscribe::publisher_history_size`]
+    pub publisher_history_size: Option<usize>,
+    /// Overrides [`PublishSubscribe::enable_safe_overflow`]
+    pub enable_safe_overflow: Option<bool>,
+    /// Overrides [`PublishSubscribe::backpressure_strategy`]
+    pub backpressure_strategy: Option<BackpressureStrategy>,
+    /// Overrides [`PublishSubscribe::subscriber_expired_connection_buffer`]
+    pub subscriber_expired_connection_buffer: Option<usize>,
+    /// Overrides [`PublishSubscribe::publisher_allocation_strategy`]
+    pub publisher_allocation_strategy: Option<AllocationStrategy>,
+    /// Overrides [`PublishSubscribe::data_segment_use_huge_pages`]
+    pub data_segment_use_huge_pages: Option<bool>,
+    /// Overrides [`PublishSubscribe::data_segment_lock_memory`]
+    pub data_segment_lock_memory: Option<bool>,
+    /// Overrides [`PublishSubscribe::data_segment_prefault_memory`]
+    pub data_segment_prefault_memory: Option<bool>,
+}
+
+impl PublishSubscribeProfile {
+    fn apply(&self, defaults: &mut PublishSubscribe) {
+        override_with(&mut defaults.max_subscribers, self.max_subscribers);
+        override_with(&mut defaults.max_publishers, self.max_publishers);
+        override_with(&mut defaults.max_nodes, self.max_nodes);
+        override_with(
+            &mut defaults.subscriber_max_buffer_size,
+            self.subscriber_max_buffer_size,
+        );
+        override_with(
+            &mut defaults.subscriber_max_borrowed_samples,
+            self.subscriber_max_borrowed_samples,
+        );
+        override_with(
+            &mut defaults.publisher_max_loaned_samples,
+            self.publisher_max_loaned_samples,
+        );
+        override_with(
+            &mut defaults.publisher_history_size,
+            self.publisher_history_size,
+        );
+        override_with(
+            &mut defaults.enable_safe_overflow,
+            self.enable_safe_overflow,
+        );
+        override_with(
+            &mut defaults.backpressure_strategy,
+            self.backpressure_strategy,
+        );
+        override_with(
+            &mut defaults.subscriber_expired_connection_buffer,
+            self.subscriber_expired_connection_buffer,
+        );
+        override_with(
+            &mut defaults.publisher_allocation_strategy,
+            self.publisher_allocation_strategy,
+        );
+        override_with(
+            &mut defaults.data_segment_use_huge_pages,
+            self.data_segment_use_huge_pages,
+        );
+        override_with(
+            &mut defaults.data_segment_lock_memory,
+            self.data_segment_lock_memory,
+        );
+        override_with(
+            &mut defaults.data_segment_prefault_memory,
+            self.data_segment_prefault_memory,
+        );
+    }
+}
+
+/// Overrides of the [`Event`] defaults for all services that match the name pattern of a
+/// [`ServiceProfile`]. Every entry that is not set keeps its default value.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct EventProfile {
+    /// Overrides [`Event::max_listeners`]
+    pub max_listeners: Option<usize>,
+    /// Overrides [`Event::max_notifiers`]
+    pub max_notifiers: Option<usize>,
+    /// Overrides [`Event::max_nodes`]
+    pub max_nodes: Option<usize>,
+    /// Overrides [`Event::event_id_max_value`]
+    pub event_id_max_value: Option<usize>,
+    /// Sets [`Event::deadline`]
+    pub deadline: Option<Duration>,
+    /// Sets [`Event::notifier_created_event`]
+    pub notifier_created_event: Option<usize>,
+    /// Sets [`Event::notifier_dropped_event`]
+    pub notifier_dropped_event: Option<usize>,
+    /// Sets [`Event::notifier_dead_event`]
+    pub notifier_dead_event: Option<usize>,
+}
+
+impl EventProfile {
+    fn apply(&self, defaults: &mut Event) {
+        override_with(&mut defaults.max_listeners, self.max_listeners);
+        override_with(&mut defaults.max_notifiers, self.max_notifiers);
+        override_with(&mut defaults.max_nodes, self.max_nodes);
+        override_with(&mut defaults.event_id_max_value, self.event_id_max_value);
+        if self.deadline.is_some() {
+            defaults.deadline = self.deadline;
+        }
+        if self.notifier_created_event.is_some() {
+            defaults.notifier_created_event = self.notifier_created_event;
+        }
+        if self.notifier_dropped_event.is_some() {
+            defaults.notifier_dropped_event = self.notifier_dropped_event;
+        }
+        if self.notifier_dead_event.is_some() {
+            defaults.notifier_dead_event = self.notifier_dead_event;
+        }
+    }
+}
+
+/// Overrides of the [`RequestResonse`] defaults for all services that match the name pattern
+/// of a [`ServiceProfile`]. Every entry that is not set keeps its default value.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct RequestResponseProfile {
+    /// Overrides [`RequestResonse::enable_safe_overflow_for_requests`]
+    pub enable_safe_overflow_for_requests: Option<bool>,
+    /// Overrides [`RequestResonse::enable_safe_overflow_for_responses`]
+    pub enable_safe_overflow_for_responses: Option<bool>,
+    /// Overrides [`RequestResonse::max_active_requests_per_client`]
+    pub max_active_requests_per_client: Option<usize>,
+    /// Overrides [`RequestResonse::max_response_buffer_size`]
+    pub max_response_buffer_size: Option<usize>,
+    /// Overrides [`RequestResonse::max_servers`]
+    pub max_servers: Option<usize>,
+    /// Overrides [`RequestResonse::max_clients`]
+    pub max_clients: Option<usize>,
+    /// Overrides [`RequestResonse::max_nodes`]
+    pub max_nodes: Option<usize>,
+    /// Overrides [`RequestResonse::max_borrowed_responses_per_pending_response`]
+    pub max_borrowed_responses_per_pending_response: Option<usize>,
+    /// Overrides [`RequestResonse::max_loaned_requests`]
+    pub max_loaned_requests: Option<usize>,
+    /// Overrides [`RequestResonse::server_max_loaned_responses_per_request`]
+    pub server_max_loaned_responses_per_request: Option<usize>,
+    /// Overrides [`RequestResonse::client_backpressure_strategy`]
+    pub client_backpressure_strategy: Option<BackpressureStrategy>,
+    /// Overrides [`RequestResonse::server_backpressure_strategy`]
+    pub server_backpressure_strategy: Option<BackpressureStrategy>,
+    /// Overrides [`RequestResonse::client_expired_connection_buffer`]
+    pub client_expired_connection_buffer: Option<usize>,
+    /// Overrides [`RequestResonse::enable_fire_and_forget_requests`]
+    pub enable_fire_and_forget_requests: Option<bool>,
+    /// Overrides [`RequestResonse::server_expired_connection_buffer`]
+    pub server_expired_connection_buffer: Option<usize>,
+    /// Overrides [`RequestResonse::client_allocation_strategy`]
+    pub client_allocation_strategy: Option<AllocationStrategy>,
+    /// Overrides [`RequestResonse::server_allocation_strategy`]
+    pub server_allocation_strategy: Option<AllocationStrategy>,
+    /// Overrides [`RequestResonse::data_segment_use_huge_pages`]
+    pub data_segment_use_huge_pages: Option<bool>,
+    /// Overrides [`RequestResonse::data_segment_lock_memory`]
+    pub data_segment_lock_memory: Option<bool>,
+    /// Overrides [`RequestResonse::data_segment_prefault_memory`]
+    pub data_segment_prefault_memory: Option<bool>,
+}
+
+impl RequestResponseProfile {
+    fn apply(&self, defaults: &mut RequestResonse) {
+        override_with(
+            &mut defaults.enable_safe_overflow_for_requests,
+            self.enable_safe_overflow_for_requests,
+        );
+        override_with(
+            &mut defaults.enable_safe_overflow_for_responses,
+            self.enable_safe_overflow_for_responses,
+        );
+        override_with(
+            &mut defaults.max_active_requests_per_client,
+            self.max_active_requests_per_client,
+        );
+        override_with(
+            &mut defaults.max_response_buffer_size,
+            self.max_response_buffer_size,
+        );
+        override_with(&mut defaults.max_servers, self.max_servers);
+        override_with(&mut defaults.max_clients, self.max_clients);
+        override_with(&mut defaults.max_nodes, self.max_nodes);
+        override_with(
+            &mut defaults.max_borrowed_responses_per_pending_response,
+            self.max_borrowed_responses_per_pending_response,
+        );
+        override_with(&mut defaults.max_loaned_requests, self.max_loaned_requests);
+        override_with(
+            &mut defaults.server_max_loaned_responses_per_request,
+            self.server_max_loaned_responses_per_request,
+        );
+        override_with(
+            &mut defaults.client_backpressure_strategy,
+            self.client_backpressure_strategy,
+        );
+        override_with(
+            &mut defaults.server_backpressure_strategy,
+            self.server_backpressure_strategy,
+        );
+        override_with(
+            &mut defaults.client_expired_connection_buffer,
+            self.client_expired_connection_buffer,
+        );
+        override_with(
+            &mut defaults.enable_fire_and_forget_requests,
+            self.enable_fire_and_forget_requests,
+        );
+        override_with(
+            &mut defaults.server_expired_connection_buffer,
+            self.server_expired_connection_buffer,
+        );
+        override_with(
+            &mut defaults.client_allocation_strategy,
+            self.client_allocation_strategy,
+        );
+        override_with(
+            &mut defaults.server_allocation_strategy,
+            self.server_allocation_strategy,
+        );
+        override_with(
+            &mut defaults.data_segment_use_huge_pages,
+            self.data_segment_use_huge_pages,
+        );
+        override_with(
+            &mut defaults.data_segment_lock_memory,
+            self.data_segment_lock_memory,
+        );
+        override_with(
+            &mut defaults.data_segment_prefault_memory,
+            self.data_segment_prefault_memory,
+        );
+    }
+}
+
+/// Overrides of the [`Blackboard`] defaults for all services that match the name pattern of a
+/// [`ServiceProfile`]. Every entry that is not set keeps its default value.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct BlackboardProfile {
+    /// Overrides [`Blackboard::max_readers`]
+    pub max_readers: Option<usize>,
+    /// Overrides [`Blackboard::max_nodes`]
+    pub max_nodes: Option<usize>,
+}
+
+impl BlackboardProfile {
+    fn apply(&self, defaults: &mut Blackboard) {
+        override_with(&mut defaults.max_readers, self.max_readers);
+        override_with(&mut defaults.max_nodes, self.max_nodes);
+    }
+}
+
+/// Per-service QoS settings that override the [`Defaults`] for all services whose
+/// [`ServiceName`] matches the [`ServiceProfile::name`] pattern, see
+/// [`ServiceName::matches()`]. In the config file every profile is a `[[services]]` entry:
+///
+/// ```toml
+/// [[services]]
+/// name = "sensor/*"
+/// publish-subscribe.subscriber-max-buffer-size = 16
+/// publish-subscribe.backpressure-strategy = "DiscardData"
+/// ```
+///
+/// The settings are applied when a service builder or port builder is created, explicit
+/// builder calls like
+/// [`Builder::subscriber_max_buffer_size()`](crate::service::builder::publish_subscribe::Builder::subscriber_max_buffer_size())
+/// still take precedence.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct ServiceProfile {
+    /// The pattern of the service names the profile applies to. `*` matches any sequence of
+    /// characters and `?` matches exactly one character.
+    pub name: String,
+    /// Overrides for publish-subscribe based services
+    pub publish_subscribe: PublishSubscribeProfile,
+    /// Overrides for event based services
+    pub event: EventProfile,
+    /// Overrides for request-response based services
+    pub request_response: RequestResponseProfile,
+    /// Overrides for blackboard based services
+    pub blackboard: BlackboardProfile,
+}
+
+impl ServiceProfile {
+    /// Creates a new [`ServiceProfile`] without any overrides for all services that match the
+    /// provided name pattern.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    fn apply(&self, defaults: &mut Defaults) {
+        self.publish_subscribe
+            .apply(&mut defaults.publish_subscribe);
+        self.event.apply(&mut defaults.event);
+        self.request_response.apply(&mut defaults.request_response);
+        self.blackboard.apply(&mut defaults.blackboard);
+    }
+}
+
+/// Rewrites the [`ServiceName`] [`ServiceNameRemapping::from`] into
+/// [`ServiceNameRemapping::to`] whenever a service is created or opened with
+/// [`Node::service_builder()`](crate::node::Node::service_builder()). It allows to run the same
+/// binary multiple times with different service names. In the config file every remapping is a
+/// `[[remappings]]` entry:
+///
+/// ```toml
+/// [[remappings]]
+/// from = "camera/image"
+/// to = "left_camera/image"
+/// ```
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ServiceNameRemapping {
+    /// The [`ServiceName`] that is used in the code
+    pub from: ServiceName,
+    /// The [`ServiceName`] that is used instead
+    pub to: ServiceName,
+}
+
+impl ServiceNameRemapping {
+    /// Creates a new [`ServiceNameRemapping`] that rewrites `from` into `to`.
+    pub fn new(from: &ServiceName, to: &ServiceName) -> Self {
+        Self {
+            from: *from,
+            to: *to,
+        }
+    }
+}
+
+fn override_with<T>(value: &mut T, profile_value: Option<T>) {
+    if let Some(profile_value) = profile_value {
+        *value = profile_value;
+    }
+}
+
+/// A [`Config`] that is merged from multiple layers. Every layer, a config file or the
+/// environment variables, overrides only the entries it contains and the origin of every
+/// effective value is tracked.
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    entries: toml::Table,
+    origins: BTreeMap<String, ConfigValueOrigin>,
+}
+
+impl Default for LayeredConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LayeredConfig {
+    /// Creates a new [`LayeredConfig`] that contains only the default values.
+    pub fn new() -> Self {
+        let entries = fatal_panic!(from "LayeredConfig::new()",
+            when toml::Table::try_from(Config::default()),
+            "This should never happen! The default config could not be serialized.");
+
+        let mut origins = BTreeMap::new();
+        Self::record_origins("", &entries, &ConfigValueOrigin::Default, &mut origins);
+
+        Self { entries, origins }
+    }
+
+    /// Returns the name of the environment variable that overrides the config entry with the
+    /// provided key, e.g. `IOX2_GLOBAL_PREFIX` for `global.prefix`.
+    pub fn environment_variable_name(key: &str) -> String {
+        format!("IOX2_{}", key.replace(['.', '-'], "_").to_uppercase())
+    }
+
+    /// Merges the content of the config file on top of the current layers. The file may
+    /// contain only a subset of all entries.
+    pub fn add_file(&mut self, config_file: &FilePath) -> Result<(), ConfigCreationError> {
+        let msg = "Unable to add config file";
+        let origin = "LayeredConfig::add_file()";
+        let contents = Config::read_config_file(config_file)?;
+
+        if let Err(e) = toml::from_str::<Config>(&contents) {
+            fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                "{} since the contents of \"{}\" could not be deserialized ({}).", msg, config_file, e);
+        }
+
+        let layer = match toml::from_str::<toml::Table>(&contents) {
+            Ok(layer) => layer,
+            Err(e) => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "{} since the contents of \"{}\" could not be deserialized ({}).", msg, config_file, e);
+            }
+        };
+
+        Self::merge(
+            &mut self.entries,
+            layer,
+            "",
+            &ConfigValueOrigin::File(*config_file),
+            &mut self.origins,
+        );
+
+        trace!(from origin, "Added config file \"{}\".", config_file);
+        Ok(())
+    }
+
+    /// Overrides every entry for which `lookup` returns a value when it is called with the
+    /// [`LayeredConfig::environment_variable_name()`] of the entry. Values that cannot be
+    /// converted into the type of the entry are ignored with a warning.
+    pub fn add_environment_overrides_from<F: FnMut(&str) -> Option<String>>(
+        &mut self,
+        mut lookup: F,
+    ) {
+        let origin = "LayeredConfig::add_environment_overrides_from()";
+        let keys: Vec<String> = self.origins.keys().cloned().collect();
+        for key in keys {
+            let name = Self::environment_variable_name(&key);
+            let raw_value = match lookup(&name) {
+                Some(raw_value) => raw_value,
+                None => continue,
+            };
+
+            let mut entries = self.entries.clone();
+            let entry = match Self::entry_mut(&mut entries, &key) {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            let value = match entry {
+                toml::Value::String(_) => Some(toml::Value::String(raw_value.clone())),
+                _ => toml::from_str::<toml::Table>(&format!("value = {raw_value}"))
+                    .ok()
+                    .and_then(|mut v| v.remove("value")),
+            };
+
+            match value {
+                Some(value) if value.same_type(entry) => {
+                    *entry = value;
+                }
+                _ => {
+                    warn!(from origin,
+                        "The value \"{}\" of the environment variable {} is not valid for the config entry \"{}\" and is ignored.",
+                        raw_value, name, key);
+                    continue;
+                }
+            }
+
+            if let Err(e) = toml::Value::Table(entries.clone()).try_into::<Config>() {
+                warn!(from origin,
+                    "The value \"{}\" of the environment variable {} is not valid for the config entry \"{}\" ({}) and is ignored.",
+                    raw_value, name, key, e);
+                continue;
+            }
+
+            self.entries = entries;
+            self.origins
+                .insert(key, ConfigValueOrigin::EnvironmentVariable(name));
+        }
+    }
+
+    /// Overrides every entry for which the environment variable with the name
+    /// [`LayeredConfig::environment_variable_name()`] is set.
+    #[cfg(feature = "std")]
+    pub fn add_environment_overrides(&mut self) {
+        self.add_environment_overrides_from(|name| std::env::var(name).ok());
+    }
+
+    /// Returns the origin of the effective value of the config entry with the provided key,
+    /// e.g. `defaults.publish-subscribe.max-subscribers`. If the entry does not exist it returns
+    /// [`None`].
+    pub fn origin(&self, key: &str) -> Option<&ConfigValueOrigin> {
+        self.origins.get(key)
+    }
+
+    /// Returns an iterator over the keys of all config entries, sorted by name, together with
+    /// the origin of their effective value.
+    pub fn origins(&self) -> impl Iterator<Item = (&str, &ConfigValueOrigin)> {
+        self.origins
+            .iter()
+            .map(|(key, origin)| (key.as_str(), origin))
+    }
+
+    /// Returns the [`Config`] that contains the effective values of all layers.
+    pub fn config(&self) -> Result<Config, ConfigCreationError> {
+        let origin = "LayeredConfig::config()";
+        match toml::Value::Table(self.entries.clone()).try_into::<Config>() {
+            Ok(config) => Ok(config),
+            Err(e) => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "Unable to create config since the layers could not be deserialized ({}).", e);
+            }
+        }
+    }
+
+    fn merge(
+        entries: &mut toml::Table,
+        layer: toml::Table,
+        prefix: &str,
+        origin: &ConfigValueOrigin,
+        origins: &mut BTreeMap<String, ConfigValueOrigin>,
+    ) {
+        for (key, value) in layer {
+            let path = Self::join(prefix, &key);
+            match (entries.get_mut(&key), value) {
+                (Some(toml::Value::Table(entries)), toml::Value::Table(layer)) => {
+                    Self::merge(entries, layer, &path, origin, origins)
+                }
+                (_, value) => {
+                    let sub_entries = format!("{path}.");
+                    origins.retain(|k, _| *k != path && !k.starts_with(&sub_entries));
+                    match &value {
+                        toml::Value::Table(table) => {
+                            Self::record_origins(&path, table, origin, origins)
+                        }
+                        _ => {
+                            origins.insert(path, origin.clone());
+                        }
+                    }
+                    entries.insert(key, value);
+                }
+            }
+        }
+    }
+
+    fn record_origins(
+        prefix: &str,
+        table: &toml::Table,
+        origin: &ConfigValueOrigin,
+        origins: &mut BTreeMap<String, ConfigValueOrigin>,
+    ) {
+        for (key, value) in table {
+            let path = Self::join(prefix, key);
+            match value {
+                toml::Value::Table(table) => Self::record_origins(&path, table, origin, origins),
+                _ => {
+                    origins.insert(path, origin.clone());
+                }
+            }
+        }
+    }
+
+    fn entry_mut<'a>(entries: &'a mut toml::Table, key: &str) -> Option<&'a mut toml::Value> {
+        let mut path = key.split('.');
+        let mut entry = entries.get_mut(path.next()?)?;
+        for name in path {
+            entry = entry.as_table_mut()?.get_mut(name)?;
+        }
+        Some(entry)
+    }
+
+    fn join(prefix: &str, key: &str) -> String {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        }
+    }
+}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [`Global`] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [`Defaults`] for communication within that iceoryx2 instance. The user has the
@@ -546,6 +1222,15 @@ pub struct Config {
     pub global: Global,
     /// Default settings
     pub defaults: Defaults,
+    /// Per-service QoS profiles that override the [`Defaults`] for all services with a
+    /// matching name. When multiple profiles match, they are applied in order, so a later
+    /// profile overrides the entries of an earlier one.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub services: Vec<ServiceProfile>,
+    /// Remappings of [`ServiceName`]s, when multiple remappings match the first one is
+    /// applied. The entry is always present so that it can be overridden with the
+    /// `IOX2_REMAPPINGS` environment variable.
+    pub remappings: Vec<ServiceNameRemapping>,
 }
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
@@ -557,6 +1242,31 @@ impl Config {
             "This should never happen! The relative local config path contains invalid symbols.")
     }
 
+    /// Returns the [`Defaults`] of the service with the provided name, all matching
+    /// [`ServiceProfile`]s of [`Config::services`] are applied in order on top of
+    /// [`Config::defaults`].
+    pub fn defaults_for(&self, service_name: &ServiceName) -> Defaults {
+        let mut defaults = self.defaults.clone();
+        for profile in self
+            .services
+            .iter()
+            .filter(|profile| service_name.matches(&profile.name))
+        {
+            profile.apply(&mut defaults);
+        }
+        defaults
+    }
+
+    /// Returns the [`ServiceName`] the provided name is remapped to by the first matching
+    /// [`ServiceNameRemapping`] of [`Config::remappings`]. If no remapping matches, [`None`]
+    /// is returned.
+    pub fn remapped_service_name(&self, service_name: &ServiceName) -> Option<&ServiceName> {
+        self.remappings
+            .iter()
+            .find(|remapping| remapping.from == *service_name)
+            .map(|remapping| &remapping.to)
+    }
+
     /// The name of the default iceoryx2 config file
     pub fn default_config_file_name() -> FileName {
         fatal_panic!(from "Config::default_config_file",
@@ -636,51 +1346,50 @@ impl Config {
         let msg = "Unable to consider all possible config file paths";
         let origin = "Config::iterate_over_config_files";
 
-        // prio 1: handle project local config file first
-        let local_project_config = Self::default_config_file_path();
-        if callback(local_project_config) == CallbackProgression::Stop {
+        // the files are provided with ascending priority so that later files override the
+        // entries of earlier ones
+
+        // layer 1: lookup global config file
+        if let Ok(global_config) = Self::load_global_config_path(origin, msg)
+            && callback(global_config) == CallbackProgression::Stop
+        {
             return Ok(());
         }
 
-        // prio 2: lookup user config file
+        // layer 2: lookup user config file
         if let Ok(user_config) = Self::load_user_config_path(origin, msg)
             && callback(user_config) == CallbackProgression::Stop
         {
             return Ok(());
         }
 
-        // prio 3: lookup global config file
-        if let Ok(global_config) = Self::load_global_config_path(origin, msg)
-            && callback(global_config) == CallbackProgression::Stop
-        {
-            return Ok(());
-        }
+        // layer 3: handle project local config file last
+        let local_project_config = Self::default_config_file_path();
+        callback(local_project_config);
 
         Ok(())
     }
 
-    /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
-    /// [`ConfigCreationError`] describing the failure.
-    pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
-        let msg = "Failed to create config";
-        let mut new_config = Self::default();
+    fn read_config_file(config_file: &FilePath) -> Result<String, ConfigCreationError> {
+        let msg = "Failed to read config file";
+        let origin = "Config::read_config_file()";
 
         let file = match FileBuilder::new(config_file).open_existing(AccessMode::Read) {
             Ok(file) => file,
             Err(FileOpenError::InsufficientPermissions) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::InsufficientPermissions,
                       "{} since the config file \"{}\" could not be opened due to insufficient permissions.",
                       msg, config_file);
             }
             Err(FileOpenError::FileDoesNotExist) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::ConfigFileDoesNotExist,
                       "{} since the config file \"{}\" does not exist.",
                       msg, config_file);
             }
             Err(e) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::UnableToOpenConfigFile,
                       "{} since the config file \"{}\" could not be open due to an internal error ({:?}).",
                       msg, config_file, e);
@@ -688,10 +1397,74 @@ impl Config {
         };
 
         let mut contents = String::new();
-        fail!(from new_config, when file.read_to_string(&mut contents),
+        fail!(from origin, when file.read_to_string(&mut contents),
                 with ConfigCreationError::FailedToReadConfigFileContents,
                 "{} since the config file contents could not be read.", msg);
 
+        Ok(contents)
+    }
+
+    /// Loads the config from all layers: the default values, the global, user and project local
+    /// config files, the optionally provided `config_file` and, with the `std` feature, the
+    /// environment variables. Every layer overrides only the entries it contains. Config files
+    /// that do not exist are skipped, config files that cannot be read are skipped with a
+    /// warning. Only a failure of the provided `config_file` is returned as
+    /// [`ConfigCreationError`].
+    pub fn load_layered(
+        config_file: Option<&FilePath>,
+    ) -> Result<LayeredConfig, ConfigCreationError> {
+        let origin = "Config::load_layered()";
+        let mut layered_config = LayeredConfig::new();
+        let mut is_config_file_loaded = false;
+
+        if let Err(e) = Self::iterate_over_config_files(|config_file_path| {
+            match layered_config.add_file(&config_file_path) {
+                Ok(()) => {
+                    info!(from origin, "Using config file at \"{}\"", config_file_path);
+                    is_config_file_loaded = true;
+                }
+                Err(ConfigCreationError::ConfigFileDoesNotExist) => {
+                    debug!(from origin, "No config file found at \"{}\"", config_file_path);
+                }
+                Err(e) => {
+                    warn!(from origin,
+                        "Config file found \"{}\" but a failure occurred ({:?}) while reading the content.",
+                        config_file_path, e);
+                }
+            }
+            CallbackProgression::Continue
+        }) {
+            warn!(from origin,
+                "A failure occurred ({:?}) while looking up the available config files.", e);
+        }
+
+        if let Some(config_file) = config_file {
+            layered_config.add_file(config_file)?;
+            info!(from origin, "Using config file at \"{}\"", config_file);
+            is_config_file_loaded = true;
+        }
+
+        if !is_config_file_loaded {
+            warn!(from origin,
+                "No config file was loaded, the default values will be used for all entries that are not overridden by environment variables.");
+        }
+
+        #[cfg(feature = "std")]
+        layered_config.add_environment_overrides();
+
+        Ok(layered_config)
+    }
+
+    /// Loads a configuration from a single file, entries that are not contained in the file keep
+    /// their default value. Other config files and environment variables are not considered,
+    /// see [`Config::load_layered()`]. On success it returns a [`Config`] object otherwise a
+    /// [`ConfigCreationError`] describing the failure.
+    pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
+        let msg = "Failed to create config";
+        let mut new_config = Self::default();
+
+        let contents = Self::read_config_file(config_file)?;
+
         match toml::from_str(&contents) {
             Ok(v) => new_config = v,
             Err(e) => {
@@ -704,7 +1477,9 @@ impl Config {
         Ok(new_config)
     }
 
-    /// Sets up the global configuration from a file. If the global configuration was already setup
+    /// Sets up the global configuration from a file. The file is the layer with the highest
+    /// priority of all config files, only environment variables override its entries, see
+    /// [`Config::load_layered()`]. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
     pub fn setup_global_config_from_file(
@@ -714,7 +1489,8 @@ impl Config {
             return Ok(ICEORYX2_CONFIG.get());
         }
 
-        if !ICEORYX2_CONFIG.set_value(Config::from_file(config_file)?) {
+        let config = Self::load_layered(Some(config_file))?.config()?;
+        if !ICEORYX2_CONFIG.set_value(config) {
             warn!(
                 from ICEORYX2_CONFIG.get(),
                 "Configuration already loaded and set up, cannot load another one. This may happen when this function is called from multiple threads."
@@ -727,43 +1503,23 @@ impl Config {
     }
 
     /// Returns the global configuration. If the global configuration was not yet loaded it will
-    /// load a default config by looking it up in the system. First it checks if a project local config file
-    /// exists, then if a config file in the user directory exist and then if a global config file exist. If
+    /// merge the global, user and project local config files and the environment variables on
+    /// top of the default values, see [`Config::load_layered()`]. If
     /// [`Config::setup_global_config_from_file()`]
     /// is called after this function was called, no file will be loaded since the global default
     /// config was already populated.
     pub fn global_config() -> &'static Config {
         let origin = "Config::global_config()";
         if !ICEORYX2_CONFIG.is_initialized() {
-            let mut is_config_file_set = false;
-            if let Err(e) = Self::iterate_over_config_files(|config_file_path| {
-                match Config::setup_global_config_from_file(&config_file_path) {
-                    Ok(_) => {
-                        info!(from origin, "Using config file at \"{}\"", config_file_path);
-                        is_config_file_set = true;
-                        CallbackProgression::Stop
-                    }
-                    Err(ConfigCreationError::ConfigFileDoesNotExist) => {
-                        debug!(from origin, "No config file found at \"{}\"", config_file_path);
-                        CallbackProgression::Continue
-                    }
-                    Err(e) => {
-                        warn!(from origin,
-                            "Config file found \"{}\" but a failure occurred ({:?}) while reading the content.",
-                            config_file_path, e);
-                        CallbackProgression::Continue
-                    }
+            let config = match Self::load_layered(None).and_then(|layered| layered.config()) {
+                Ok(config) => config,
+                Err(e) => {
+                    warn!(from origin,
+                        "A failure occurred ({:?}) while merging the config layers, a config with default values will be used.", e);
+                    Config::default()
                 }
-            }) {
-                warn!(from origin,
-                    "A failure occurred ({:?}) while looking up the available config files.", e);
-            }
-
-            if !is_config_file_set {
-                warn!(from origin,
-                    "No config file was loaded, a config with default values will be used.");
-                ICEORYX2_CONFIG.set_value(Config::default());
-            }
+            };
+            ICEORYX2_CONFIG.set_value(config);
         }
         ICEORYX2_CONFIG.get()
     }
@@ -32,6 +32,15 @@ pub const MAX_PORT_NAME_LENGTH: usize = 64;
 /// Defines the maximum length of a [`TypeName`](crate::service::static_config::message_type_details::TypeName)
 pub const MAX_TYPE_NAME_LENGTH: usize = 256;
 
+/// Defines the maximum length of a [`FieldName`](crate::service::static_config::message_type_details::FieldName).
+/// Longer field names are truncated.
+pub const MAX_FIELD_NAME_LENGTH: usize = 32;
+
+/// Defines how many fields of a type are recorded individually in the
+/// [`TypeStructure`](crate::service::static_config::message_type_details::TypeStructure).
+/// Further fields are covered only by the structural fingerprint of the type.
+pub const MAX_NUMBER_OF_RECORDED_FIELDS: usize = 16;
+
 /// The maximum size the [`MessagingPattern::Blackboard`](crate::service::static_config::messaging_pattern::MessagingPattern::Blackboard)
 /// supports for the keytype.
 pub const MAX_BLACKBOARD_KEY_SIZE: usize = 64;
@@ -39,3 +48,7 @@ pub const MAX_BLACKBOARD_KEY_SIZE: usize = 64;
 /// The maximum alignment the [`MessagingPattern::Blackboard`](crate::service::static_config::messaging_pattern::MessagingPattern::Blackboard)
 /// supports for the keytype.
 pub const MAX_BLACKBOARD_KEY_ALIGNMENT: usize = 8;
+
+/// The maximum alignment the [`MessagingPattern::Blackboard`](crate::service::static_config::messaging_pattern::MessagingPattern::Blackboard)
+/// supports for values of entries that are added at runtime.
+pub const MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT: usize = 8;
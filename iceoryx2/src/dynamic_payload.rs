@@ -0,0 +1,702 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Payload types that are annotated with `#[type_layout]` in the
+//! [`ZeroCopySend`](crate::prelude::ZeroCopySend) derive describe their
+//! [`TypeLayout`](crate::dynamic_payload::TypeLayout). The layout is stored in the type
+//! definition of the service and can be acquired with
+//! [`PortFactory::type_layout()`](crate::service::port_factory::publish_subscribe::PortFactory::type_layout()),
+//! so that processes that do not know the payload type at compile time can read and write
+//! its fields by path with [`DynamicPayload`](crate::dynamic_payload::DynamicPayload) and
+//! [`DynamicPayloadMut`](crate::dynamic_payload::DynamicPayloadMut).
+//!
+//! A path consists of field names that are separated by `.` and array indices in brackets,
+//! like `position.x` or `wheels[2].pressure`.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::dynamic_payload::{DynamicPayload, PrimitiveValue, TypeLayout};
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[type_layout]
+//! #[repr(C)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! #[derive(Debug, ZeroCopySend)]
+//! #[type_layout]
+//! #[repr(C)]
+//! struct Vehicle {
+//!     position: Position,
+//!     wheel_pressure: [f32; 4],
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let vehicle = Vehicle {
+//!     position: Position { x: 1.5, y: 2.5 },
+//!     wheel_pressure: [2.1, 2.2, 2.3, 2.4],
+//! };
+//! let bytes = unsafe {
+//!     core::slice::from_raw_parts(
+//!         (&vehicle as *const Vehicle).cast::<u8>(),
+//!         core::mem::size_of::<Vehicle>(),
+//!     )
+//! };
+//!
+//! let layout = TypeLayout::new::<Vehicle>();
+//! let payload = DynamicPayload::new(&layout, bytes)?;
+//!
+//! assert_eq!(payload.get("position.y")?, PrimitiveValue::F32(2.5));
+//! assert_eq!(payload.get("wheel_pressure[3]")?, PrimitiveValue::F32(2.4));
+//!
+//! payload.for_each_value(|path, value| println!("{path} = {value:?}"))?;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_elementary_traits::type_layout::{PrimitiveType, TypeLayoutVisitor};
+use iceoryx2_bb_elementary_traits::type_name::TypeName;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use serde::{Deserialize, Serialize};
+
+/// Failures that can occur when a field of a [`DynamicPayload`] or [`DynamicPayloadMut`]
+/// is accessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynamicPayloadAccessError {
+    /// The path is not well formed.
+    InvalidPath,
+    /// The path contains a field that does not exist.
+    UnknownField,
+    /// The path contains an array index that is out of bounds.
+    IndexOutOfBounds,
+    /// The path does not lead to a primitive value.
+    NotAPrimitive,
+    /// The provided value does not have the primitive type of the field.
+    TypeMismatch,
+    /// The field contains a value that is not valid for its primitive type.
+    InvalidValue,
+    /// The provided payload is smaller than the size of the [`TypeLayout`].
+    InsufficientPayloadSize,
+}
+
+impl core::fmt::Display for DynamicPayloadAccessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DynamicPayloadAccessError::{self:?}")
+    }
+}
+
+impl core::error::Error for DynamicPayloadAccessError {}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "PrimitiveType")]
+enum PrimitiveTypeDef {
+    Bool,
+    Char,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    F32,
+    F64,
+}
+
+/// Describes how a [`TypeLayout`] is composed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TypeLayoutKind {
+    /// A primitive type.
+    Primitive(#[serde(with = "PrimitiveTypeDef")] PrimitiveType),
+    /// An array of `len` elements.
+    Array {
+        /// The number of elements.
+        len: usize,
+        /// The layout of one element.
+        element: Box<TypeLayout>,
+    },
+    /// A struct with named fields.
+    Struct {
+        /// The fields of the struct in declaration order.
+        fields: Vec<FieldLayout>,
+    },
+    /// A type whose fields are unknown.
+    Opaque,
+}
+
+/// Describes a field of a struct.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldLayout {
+    name: String,
+    offset: usize,
+    layout: TypeLayout,
+}
+
+impl FieldLayout {
+    /// Returns the name of the field. Fields of tuple structs are named by their index.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the offset of the field in bytes relative to the start of the struct.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the [`TypeLayout`] of the field.
+    pub fn layout(&self) -> &TypeLayout {
+        &self.layout
+    }
+}
+
+/// The memory layout of a payload type, see the [module documentation](crate::dynamic_payload).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeLayout {
+    type_name: String,
+    size: usize,
+    alignment: usize,
+    kind: TypeLayoutKind,
+}
+
+impl TypeLayout {
+    /// Creates the [`TypeLayout`] of the provided type. Types that are not annotated with
+    /// `#[type_layout]` are described as [`TypeLayoutKind::Opaque`].
+    pub fn new<T: ZeroCopySend>() -> Self {
+        Self::describe::<T>()
+    }
+
+    /// Returns the [`TypeLayout`] of the type when it describes its fields, otherwise
+    /// [`None`].
+    pub(crate) fn from_described_type<T: TypeName>() -> Option<Self> {
+        let layout = Self::describe::<T>();
+        if layout.contains_struct() {
+            Some(layout)
+        } else {
+            None
+        }
+    }
+
+    fn describe<T: TypeName>() -> Self {
+        let mut collector = TypeLayoutCollector::default();
+        T::__describe_layout(&mut collector);
+        match collector.layout {
+            Some(layout) => layout,
+            None => Self {
+                type_name: String::from(core::any::type_name::<T>()),
+                size: core::mem::size_of::<T>(),
+                alignment: core::mem::align_of::<T>(),
+                kind: TypeLayoutKind::Opaque,
+            },
+        }
+    }
+
+    fn contains_struct(&self) -> bool {
+        match &self.kind {
+            TypeLayoutKind::Struct { .. } => true,
+            TypeLayoutKind::Array { element, .. } => element.contains_struct(),
+            TypeLayoutKind::Primitive(_) | TypeLayoutKind::Opaque => false,
+        }
+    }
+
+    /// Returns the name of the type.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the size of the type in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the alignment of the type in bytes.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// Returns the [`TypeLayoutKind`] of the type.
+    pub fn kind(&self) -> &TypeLayoutKind {
+        &self.kind
+    }
+
+    /// Returns the offset in bytes and the [`TypeLayout`] of the element at the provided
+    /// path. An empty path refers to the type itself.
+    pub fn find(&self, path: &str) -> Result<(usize, &TypeLayout), DynamicPayloadAccessError> {
+        let mut offset = 0;
+        let mut current = self;
+
+        if path.is_empty() {
+            return Ok((offset, current));
+        }
+
+        for (n, segment) in path.split('.').enumerate() {
+            let (name, mut indices) = match segment.find('[') {
+                Some(position) => segment.split_at(position),
+                None => (segment, ""),
+            };
+
+            if !name.is_empty() {
+                let fields = match &current.kind {
+                    TypeLayoutKind::Struct { fields } => fields,
+                    _ => return Err(DynamicPayloadAccessError::UnknownField),
+                };
+                let field = fields
+                    .iter()
+                    .find(|field| field.name == name)
+                    .ok_or(DynamicPayloadAccessError::UnknownField)?;
+                offset += field.offset;
+                current = &field.layout;
+            } else if n != 0 || indices.is_empty() {
+                return Err(DynamicPayloadAccessError::InvalidPath);
+            }
+
+            while !indices.is_empty() {
+                let end = indices
+                    .find(']')
+                    .ok_or(DynamicPayloadAccessError::InvalidPath)?;
+                if !indices.starts_with('[') {
+                    return Err(DynamicPayloadAccessError::InvalidPath);
+                }
+                let index: usize = indices[1..end]
+                    .parse()
+                    .map_err(|_| DynamicPayloadAccessError::InvalidPath)?;
+
+                match &current.kind {
+                    TypeLayoutKind::Array { len, element } => {
+                        if *len <= index {
+                            return Err(DynamicPayloadAccessError::IndexOutOfBounds);
+                        }
+                        offset += index * element.size;
+                        current = element;
+                    }
+                    _ => return Err(DynamicPayloadAccessError::InvalidPath),
+                }
+
+                indices = &indices[end + 1..];
+            }
+        }
+
+        Ok((offset, current))
+    }
+
+    fn visit_values<E, F: FnMut(&str, usize, PrimitiveType) -> Result<(), E>>(
+        &self,
+        path: &mut String,
+        offset: usize,
+        callback: &mut F,
+    ) -> Result<(), E> {
+        match &self.kind {
+            TypeLayoutKind::Primitive(primitive) => callback(path, offset, *primitive),
+            TypeLayoutKind::Array { len, element } => {
+                let path_len = path.len();
+                for index in 0..*len {
+                    path.push_str(&format!("[{index}]"));
+                    element.visit_values(path, offset + index * element.size, callback)?;
+                    path.truncate(path_len);
+                }
+                Ok(())
+            }
+            TypeLayoutKind::Struct { fields } => {
+                let path_len = path.len();
+                for field in fields {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(&field.name);
+                    field
+                        .layout
+                        .visit_values(path, offset + field.offset, callback)?;
+                    path.truncate(path_len);
+                }
+                Ok(())
+            }
+            TypeLayoutKind::Opaque => Ok(()),
+        }
+    }
+}
+
+enum PendingLayout {
+    Array {
+        len: usize,
+    },
+    Struct {
+        type_name: &'static str,
+        size: usize,
+        alignment: usize,
+        fields: Vec<FieldLayout>,
+        next_field: Option<(&'static str, usize)>,
+    },
+}
+
+#[derive(Default)]
+struct TypeLayoutCollector {
+    pending: Vec<PendingLayout>,
+    layout: Option<TypeLayout>,
+}
+
+impl TypeLayoutCollector {
+    fn add(&mut self, layout: TypeLayout) {
+        match self.pending.last_mut() {
+            None => self.layout = Some(layout),
+            Some(PendingLayout::Array { len }) => {
+                let len = *len;
+                self.pending.pop();
+                self.add(TypeLayout {
+                    type_name: format!("[{}; {len}]", layout.type_name),
+                    size: layout.size * len,
+                    alignment: layout.alignment,
+                    kind: TypeLayoutKind::Array {
+                        len,
+                        element: Box::new(layout),
+                    },
+                });
+            }
+            Some(PendingLayout::Struct {
+                fields, next_field, ..
+            }) => {
+                if let Some((name, offset)) = next_field.take() {
+                    fields.push(FieldLayout {
+                        name: String::from(name),
+                        offset,
+                        layout,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl TypeLayoutVisitor for TypeLayoutCollector {
+    fn primitive(&mut self, primitive: PrimitiveType) {
+        self.add(TypeLayout {
+            type_name: String::from(primitive.as_str()),
+            size: primitive.size(),
+            alignment: primitive.alignment(),
+            kind: TypeLayoutKind::Primitive(primitive),
+        });
+    }
+
+    fn opaque(&mut self, type_name: &'static str, size: usize, alignment: usize) {
+        self.add(TypeLayout {
+            type_name: String::from(type_name),
+            size,
+            alignment,
+            kind: TypeLayoutKind::Opaque,
+        });
+    }
+
+    fn array(&mut self, len: usize) {
+        self.pending.push(PendingLayout::Array { len });
+    }
+
+    fn begin_struct(&mut self, type_name: &'static str, size: usize, alignment: usize) {
+        self.pending.push(PendingLayout::Struct {
+            type_name,
+            size,
+            alignment,
+            fields: Vec::new(),
+            next_field: None,
+        });
+    }
+
+    fn field(&mut self, name: &'static str, offset: usize) {
+        if let Some(PendingLayout::Struct { next_field, .. }) = self.pending.last_mut() {
+            *next_field = Some((name, offset));
+        }
+    }
+
+    fn end_struct(&mut self) {
+        if let Some(PendingLayout::Struct {
+            type_name,
+            size,
+            alignment,
+            fields,
+            ..
+        }) = self.pending.pop()
+        {
+            self.add(TypeLayout {
+                type_name: String::from(type_name),
+                size,
+                alignment,
+                kind: TypeLayoutKind::Struct { fields },
+            });
+        }
+    }
+}
+
+/// The value of a primitive field of a [`DynamicPayload`] or [`DynamicPayloadMut`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrimitiveValue {
+    /// A value of type `bool`.
+    Bool(bool),
+    /// A value of type `char`.
+    Char(char),
+    /// A value of type `u8`.
+    U8(u8),
+    /// A value of type `u16`.
+    U16(u16),
+    /// A value of type `u32`.
+    U32(u32),
+    /// A value of type `u64`.
+    U64(u64),
+    /// A value of type `u128`.
+    U128(u128),
+    /// A value of type `usize`.
+    Usize(usize),
+    /// A value of type `i8`.
+    I8(i8),
+    /// A value of type `i16`.
+    I16(i16),
+    /// A value of type `i32`.
+    I32(i32),
+    /// A value of type `i64`.
+    I64(i64),
+    /// A value of type `i128`.
+    I128(i128),
+    /// A value of type `isize`.
+    Isize(isize),
+    /// A value of type `f32`.
+    F32(f32),
+    /// A value of type `f64`.
+    F64(f64),
+}
+
+impl PrimitiveValue {
+    /// Returns the [`PrimitiveType`] of the value.
+    pub fn primitive_type(&self) -> PrimitiveType {
+        match self {
+            PrimitiveValue::Bool(_) => PrimitiveType::Bool,
+            PrimitiveValue::Char(_) => PrimitiveType::Char,
+            PrimitiveValue::U8(_) => PrimitiveType::U8,
+            PrimitiveValue::U16(_) => PrimitiveType::U16,
+            PrimitiveValue::U32(_) => PrimitiveType::U32,
+            PrimitiveValue::U64(_) => PrimitiveType::U64,
+            PrimitiveValue::U128(_) => PrimitiveType::U128,
+            PrimitiveValue::Usize(_) => PrimitiveType::Usize,
+            PrimitiveValue::I8(_) => PrimitiveType::I8,
+            PrimitiveValue::I16(_) => PrimitiveType::I16,
+            PrimitiveValue::I32(_) => PrimitiveType::I32,
+            PrimitiveValue::I64(_) => PrimitiveType::I64,
+            PrimitiveValue::I128(_) => PrimitiveType::I128,
+            PrimitiveValue::Isize(_) => PrimitiveType::Isize,
+            PrimitiveValue::F32(_) => PrimitiveType::F32,
+            PrimitiveValue::F64(_) => PrimitiveType::F64,
+        }
+    }
+
+    fn read(primitive: PrimitiveType, bytes: &[u8]) -> Result<Self, DynamicPayloadAccessError> {
+        macro_rules! read {
+            ($t:ty) => {
+                <$t>::from_ne_bytes(
+                    bytes[..core::mem::size_of::<$t>()]
+                        .try_into()
+                        .expect("The slice has the size of the primitive type."),
+                )
+            };
+        }
+
+        Ok(match primitive {
+            PrimitiveType::Bool => match bytes[0] {
+                0 => PrimitiveValue::Bool(false),
+                1 => PrimitiveValue::Bool(true),
+                _ => return Err(DynamicPayloadAccessError::InvalidValue),
+            },
+            PrimitiveType::Char => PrimitiveValue::Char(
+                char::from_u32(read!(u32)).ok_or(DynamicPayloadAccessError::InvalidValue)?,
+            ),
+            PrimitiveType::U8 => PrimitiveValue::U8(read!(u8)),
+            PrimitiveType::U16 => PrimitiveValue::U16(read!(u16)),
+            PrimitiveType::U32 => PrimitiveValue::U32(read!(u32)),
+            PrimitiveType::U64 => PrimitiveValue::U64(read!(u64)),
+            PrimitiveType::U128 => PrimitiveValue::U128(read!(u128)),
+            PrimitiveType::Usize => PrimitiveValue::Usize(read!(usize)),
+            PrimitiveType::I8 => PrimitiveValue::I8(read!(i8)),
+            PrimitiveType::I16 => PrimitiveValue::I16(read!(i16)),
+            PrimitiveType::I32 => PrimitiveValue::I32(read!(i32)),
+            PrimitiveType::I64 => PrimitiveValue::I64(read!(i64)),
+            PrimitiveType::I128 => PrimitiveValue::I128(read!(i128)),
+            PrimitiveType::Isize => PrimitiveValue::Isize(read!(isize)),
+            PrimitiveType::F32 => PrimitiveValue::F32(read!(f32)),
+            PrimitiveType::F64 => PrimitiveValue::F64(read!(f64)),
+        })
+    }
+
+    fn write(&self, bytes: &mut [u8]) {
+        macro_rules! write {
+            ($v:expr) => {{
+                let value = $v.to_ne_bytes();
+                bytes[..value.len()].copy_from_slice(&value);
+            }};
+        }
+
+        match self {
+            PrimitiveValue::Bool(v) => bytes[0] = *v as u8,
+            PrimitiveValue::Char(v) => write!(*v as u32),
+            PrimitiveValue::U8(v) => write!(v),
+            PrimitiveValue::U16(v) => write!(v),
+            PrimitiveValue::U32(v) => write!(v),
+            PrimitiveValue::U64(v) => write!(v),
+            PrimitiveValue::U128(v) => write!(v),
+            PrimitiveValue::Usize(v) => write!(v),
+            PrimitiveValue::I8(v) => write!(v),
+            PrimitiveValue::I16(v) => write!(v),
+            PrimitiveValue::I32(v) => write!(v),
+            PrimitiveValue::I64(v) => write!(v),
+            PrimitiveValue::I128(v) => write!(v),
+            PrimitiveValue::Isize(v) => write!(v),
+            PrimitiveValue::F32(v) => write!(v),
+            PrimitiveValue::F64(v) => write!(v),
+        }
+    }
+}
+
+fn find_primitive(
+    layout: &TypeLayout,
+    path: &str,
+) -> Result<(usize, PrimitiveType), DynamicPayloadAccessError> {
+    match layout.find(path)? {
+        (
+            offset,
+            TypeLayout {
+                kind: TypeLayoutKind::Primitive(primitive),
+                ..
+            },
+        ) => Ok((offset, *primitive)),
+        _ => Err(DynamicPayloadAccessError::NotAPrimitive),
+    }
+}
+
+fn for_each_value<F: FnMut(&str, PrimitiveValue)>(
+    layout: &TypeLayout,
+    bytes: &[u8],
+    mut callback: F,
+) -> Result<(), DynamicPayloadAccessError> {
+    layout.visit_values(&mut String::new(), 0, &mut |path, offset, primitive| {
+        callback(path, PrimitiveValue::read(primitive, &bytes[offset..])?);
+        Ok(())
+    })
+}
+
+/// Read-only access to the fields of a payload whose type is only known by its
+/// [`TypeLayout`].
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicPayload<'a> {
+    layout: &'a TypeLayout,
+    bytes: &'a [u8],
+}
+
+impl<'a> DynamicPayload<'a> {
+    /// Creates a new [`DynamicPayload`]. Fails when the payload is smaller than the
+    /// [`TypeLayout`].
+    pub fn new(layout: &'a TypeLayout, bytes: &'a [u8]) -> Result<Self, DynamicPayloadAccessError> {
+        if bytes.len() < layout.size {
+            return Err(DynamicPayloadAccessError::InsufficientPayloadSize);
+        }
+
+        Ok(Self { layout, bytes })
+    }
+
+    /// Returns the [`TypeLayout`] of the payload.
+    pub fn layout(&self) -> &TypeLayout {
+        self.layout
+    }
+
+    /// Returns the value of the primitive field at the provided path.
+    pub fn get(&self, path: &str) -> Result<PrimitiveValue, DynamicPayloadAccessError> {
+        let (offset, primitive) = find_primitive(self.layout, path)?;
+        PrimitiveValue::read(primitive, &self.bytes[offset..])
+    }
+
+    /// Calls the callback with the path and the value of every primitive field of the
+    /// payload. Fields with an [`TypeLayoutKind::Opaque`] layout are skipped.
+    pub fn for_each_value<F: FnMut(&str, PrimitiveValue)>(
+        &self,
+        callback: F,
+    ) -> Result<(), DynamicPayloadAccessError> {
+        for_each_value(self.layout, self.bytes, callback)
+    }
+}
+
+/// Read and write access to the fields of a payload whose type is only known by its
+/// [`TypeLayout`].
+#[derive(Debug)]
+pub struct DynamicPayloadMut<'a> {
+    layout: &'a TypeLayout,
+    bytes: &'a mut [u8],
+}
+
+impl<'a> DynamicPayloadMut<'a> {
+    /// Creates a new [`DynamicPayloadMut`]. Fails when the payload is smaller than the
+    /// [`TypeLayout`].
+    pub fn new(
+        layout: &'a TypeLayout,
+        bytes: &'a mut [u8],
+    ) -> Result<Self, DynamicPayloadAccessError> {
+        if bytes.len() < layout.size {
+            return Err(DynamicPayloadAccessError::InsufficientPayloadSize);
+        }
+
+        Ok(Self { layout, bytes })
+    }
+
+    /// Returns the [`TypeLayout`] of the payload.
+    pub fn layout(&self) -> &TypeLayout {
+        self.layout
+    }
+
+    /// Returns the value of the primitive field at the provided path.
+    pub fn get(&self, path: &str) -> Result<PrimitiveValue, DynamicPayloadAccessError> {
+        let (offset, primitive) = find_primitive(self.layout, path)?;
+        PrimitiveValue::read(primitive, &self.bytes[offset..])
+    }
+
+    /// Sets the primitive field at the provided path to the value. Fails with
+    /// [`DynamicPayloadAccessError::TypeMismatch`] when the value does not have the
+    /// [`PrimitiveType`] of the field.
+    pub fn set(
+        &mut self,
+        path: &str,
+        value: PrimitiveValue,
+    ) -> Result<(), DynamicPayloadAccessError> {
+        let (offset, primitive) = find_primitive(self.layout, path)?;
+        if value.primitive_type() != primitive {
+            return Err(DynamicPayloadAccessError::TypeMismatch);
+        }
+
+        value.write(&mut self.bytes[offset..]);
+        Ok(())
+    }
+
+    /// Calls the callback with the path and the value of every primitive field of the
+    /// payload. Fields with an [`TypeLayoutKind::Opaque`] layout are skipped.
+    pub fn for_each_value<F: FnMut(&str, PrimitiveValue)>(
+        &self,
+        callback: F,
+    ) -> Result<(), DynamicPayloadAccessError> {
+        for_each_value(self.layout, self.bytes, callback)
+    }
+}
@@ -554,15 +554,27 @@ pub mod response_mut;
 /// [`Client`](crate::port::client::Client)
 pub mod response_mut_uninit;
 
+/// Reads and writes the fields of payloads whose type is only known by its recorded
+/// [`TypeLayout`](crate::dynamic_payload::TypeLayout).
+pub mod dynamic_payload;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
 /// The payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut;
 
+/// The payload with a growing slice length that is sent by a
+/// [`Publisher`](crate::port::publisher::Publisher).
+pub mod sample_mut_builder;
+
 /// The uninitialized payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut_uninit;
 
+/// Typed remote procedure calls on top of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
+pub mod rpc;
+
 /// The foundation of communication the service with its
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
@@ -577,8 +589,18 @@ pub mod prelude;
 #[doc(hidden)]
 pub mod testing;
 
+/// Trace points of the communication hot path and a collector that exports them in the Chrome
+/// trace event format.
+#[cfg(feature = "std")]
+pub mod trace;
+
 /// Event handling mechanism to wait on multiple [`Listener`](crate::port::listener::Listener)s
 /// in one call, realizing the reactor pattern. (Event multiplexer)
 pub mod waitset;
 
+/// Dispatches the events of a [`WaitSet`](crate::waitset::WaitSet) to callbacks that are
+/// registered per attachment and executed on a pool of worker threads.
+#[cfg(feature = "std")]
+pub mod waitset_executor;
+
 pub(crate) mod payload;
@@ -316,16 +316,18 @@ enum NodeReadPortTagsFailure {
 pub struct NodeDetails {
     executable: FileName,
     name: NodeName,
+    #[serde(default)]
+    namespace: Option<ServiceName>,
     config: Config,
 }
 
 impl NodeDetails {
     #[doc(hidden)]
     pub fn __internal_new(node_name: &Option<NodeName>, config: &Config) -> Self {
-        Self::new(node_name, config)
+        Self::new(node_name, &None, config)
     }
 
-    fn new(node_name: &Option<NodeName>, config: &Config) -> Self {
+    fn new(node_name: &Option<NodeName>, namespace: &Option<ServiceName>, config: &Config) -> Self {
         let executable = match Process::from_self().executable() {
             Ok(n) => n.file_name(),
             Err(e) => {
@@ -342,6 +344,7 @@ impl NodeDetails {
             } else {
                 NodeName::new("").expect("An empty NodeName is always valid.")
             },
+            namespace: *namespace,
             config: config.clone(),
         }
     }
@@ -357,6 +360,12 @@ impl NodeDetails {
         &self.name
     }
 
+    /// Returns the namespace that the [`Node`] prefixes to the name of every service, see
+    /// [`NodeBuilder::namespace()`].
+    pub fn namespace(&self) -> Option<&ServiceName> {
+        self.namespace.as_ref()
+    }
+
     /// Returns the [`Config`] the [`Node`] uses to create all entities.
     pub fn config(&self) -> &Config {
         &self.config
@@ -1056,6 +1065,32 @@ impl<Service: service::Service> SharedNode<Service> {
         &self.state.details.name
     }
 
+    pub(crate) fn namespace(&self) -> Option<&ServiceName> {
+        self.state.details.namespace.as_ref()
+    }
+
+    /// Applies the [`ServiceNameRemapping`](crate::config::ServiceNameRemapping)s of the
+    /// [`Config`] and prefixes the namespace afterwards. Internal services are never remapped.
+    pub(crate) fn resolve_service_name(&self, name: &ServiceName) -> ServiceName {
+        if ServiceName::has_iox2_prefix(name) {
+            return *name;
+        }
+
+        let remapped_name = *self.config().remapped_service_name(name).unwrap_or(name);
+        match self.namespace() {
+            None => remapped_name,
+            Some(namespace) => match ServiceName::new(&format!("{namespace}/{remapped_name}")) {
+                Ok(namespaced_name) => namespaced_name,
+                Err(e) => {
+                    warn!(from "Node::service_builder()",
+                        "Unable to prefix the service name \"{}\" with the namespace \"{}\" ({:?}), the service name is used without namespace.",
+                        remapped_name, namespace, e);
+                    remapped_name
+                }
+            },
+        }
+    }
+
     pub(crate) fn create_port_tag(
         &self,
         origin: &str,
@@ -1140,9 +1175,27 @@ impl<Service: service::Service> Node<Service> {
         self.shared.id()
     }
 
-    /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
+    /// Returns the namespace that is prefixed to the name of every service, see
+    /// [`NodeBuilder::namespace()`].
+    pub fn namespace(&self) -> Option<&ServiceName> {
+        self.shared.namespace()
+    }
+
+    /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name. The name
+    /// is rewritten by the first matching [`ServiceNameRemapping`](crate::config::ServiceNameRemapping)
+    /// of the [`Config`] and prefixed with the namespace of the [`Node`] afterwards.
     pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
-        Builder::new(name, self.shared.clone())
+        let resolved_name = self.shared.resolve_service_name(name);
+        let original_name = (resolved_name != *name).then_some(*name);
+        Builder::new(&resolved_name, original_name, self.shared.clone())
+    }
+
+    /// Instantiates a [`ServiceBuilder`](Builder) for a service with exactly the provided name,
+    /// neither the remappings of the [`Config`] nor the namespace of the [`Node`] are applied.
+    /// Intended for services that were discovered at runtime, e.g. with
+    /// [`Service::list()`](crate::service::Service::list()), whose names are already resolved.
+    pub fn service_builder_without_remapping(&self, name: &ServiceName) -> Builder<Service> {
+        Builder::new(name, None, self.shared.clone())
     }
 
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
@@ -1514,6 +1567,7 @@ impl<Service: service::Service> Node<Service> {
 #[derive(Debug, Default, Clone)]
 pub struct NodeBuilder {
     name: Option<NodeName>,
+    namespace: Option<ServiceName>,
     signal_handling_mode: SignalHandlingMode,
     config: Option<Config>,
 }
@@ -1530,6 +1584,14 @@ impl NodeBuilder {
         self
     }
 
+    /// Sets the namespace of the to be created [`Node`]. It is prefixed, separated with `/`, to
+    /// the name of every service that is created or opened with [`Node::service_builder()`]
+    /// after the [`ServiceNameRemapping`](crate::config::ServiceNameRemapping)s were applied.
+    pub fn namespace(mut self, value: &ServiceName) -> Self {
+        self.namespace = Some(*value);
+        self
+    }
+
     /// Defines the [`SignalHandlingMode`] for the [`Node`]. It affects the [`Node::wait()`] call
     /// that returns any received [`Signal`](iceoryx2_bb_posix::signal::Signal) via its
     /// [`NodeWaitFailure`]
@@ -1597,6 +1659,10 @@ impl NodeBuilder {
             shared: SharedNode { state },
         };
 
+        if let Some(name) = &self.name {
+            iceoryx2_bb_loggers::__internal_set_node_name(name.as_str());
+        }
+
         trace!(from new_node, "created");
         Ok(new_node)
     }
@@ -1638,7 +1704,7 @@ impl NodeBuilder {
         node_id: &UniqueNodeId,
     ) -> Result<(Service::StaticStorage, NodeDetails), NodeCreationFailure> {
         let msg = "Unable to create node details storage";
-        let details = NodeDetails::new(&self.name, config);
+        let details = NodeDetails::new(&self.name, &self.namespace, config);
 
         let details_config = node_details_config::<Service>(&details.config, node_id);
         let serialized_details = match <Service::ConfigSerializer>::serialize(&details) {
@@ -56,6 +56,7 @@
 //! ```
 
 use core::ops::Deref;
+use core::time::Duration;
 use core::{fmt::Debug, marker::PhantomData};
 
 use flatbuffers::InvalidFlatbuffer;
@@ -64,11 +65,12 @@ use iceoryx2_bb_elementary_traits::iceoryx_send::IceoryxSend;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_flatbuffers::FlatbufferError;
-use iceoryx2_log::fail;
+use iceoryx2_log::{fail, warn};
 
 use crate::port::client::ClientSharedState;
 use crate::port::details::chunk::Chunk;
 use crate::port::details::chunk_details::ChunkDetails;
+use crate::service::header::request_response::DeadlineError;
 use crate::service::marker::{CustomPayloadMarker, Flatbuffer};
 use crate::{port::ReceiveError, request_mut::RequestMut, response::Response, service};
 
@@ -253,6 +255,31 @@ impl<
         self.request.user_header()
     }
 
+    /// Returns the time that is left until the deadline of the corresponding [`RequestMut`] is
+    /// reached. If the [`RequestMut`] has no deadline, it returns [`None`].
+    pub fn remaining_time(&self) -> Result<Option<Duration>, DeadlineError> {
+        service::header::request_response::remaining_time(self.request.header().deadline)
+    }
+
+    /// Returns [`true`] when the deadline of the corresponding [`RequestMut`] was reached.
+    /// All [`Response`]s that are received afterwards are discarded.
+    pub fn has_expired(&self) -> Result<bool, DeadlineError> {
+        service::header::request_response::has_expired(self.request.header().deadline)
+    }
+
+    // when the clock cannot be read the deadline cannot be evaluated, the response is handed
+    // to the user who is informed about the failure by [`PendingResponse::has_expired()`]
+    fn discards_responses(&self) -> bool {
+        match self.has_expired() {
+            Ok(has_expired) => has_expired,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to evaluate the deadline of the request, the response is received anyway ({e}).");
+                false
+            }
+        }
+    }
+
     /// Returns how many [`Server`](crate::port::server::Server)s received the corresponding
     /// [`RequestMut`] initially.
     pub fn number_of_server_connections(&self) -> usize {
@@ -396,7 +423,9 @@ impl<
                         _response_payload: PhantomData,
                     };
 
-                    if response.header().request_id != self.request.header().request_id {
+                    if response.header().request_id != self.request.header().request_id
+                        || self.discards_responses()
+                    {
                         continue;
                     }
 
@@ -461,7 +490,9 @@ impl<
                         _response_payload: PhantomData,
                     };
 
-                    if response.header().request_id != self.request.header().request_id {
+                    if response.header().request_id != self.request.header().request_id
+                        || self.discards_responses()
+                    {
                         continue;
                     }
 
@@ -503,7 +534,9 @@ impl<
                         _response_payload: PhantomData,
                     };
 
-                    if response.header().request_id != self.request.header().request_id {
+                    if response.header().request_id != self.request.header().request_id
+                        || self.discards_responses()
+                    {
                         continue;
                     }
 
@@ -10,8 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use serde::{Deserialize, Serialize, de::Visitor};
 
+const RETRY_UNTIL_DELIVERED_OR_TIMEOUT: &str = "RetryUntilDeliveredOrTimeout";
+
 /// Defines the strategy a sender shall pursue when the buffer of the receiver is full
 /// and the service does not overflow.
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -19,6 +23,20 @@ pub enum BackpressureStrategy {
     /// Retries until the receiver has consumed some
     /// data from the full buffer and there is space again
     RetryUntilDelivered,
+    /// Retries until the receiver has consumed some data from the full buffer and there is
+    /// space again or until the provided timeout has passed. The timeout bounds the whole
+    /// send operation over all receivers. Receivers that could not be served in time do not
+    /// receive the data and the send operation fails with
+    /// [`SendError::DeliveryTimeout`](crate::port::SendError::DeliveryTimeout).
+    ///
+    /// [`SendError`](crate::port::SendError) is a `Copy` type that is returned on the
+    /// hot path without any heap allocation, therefore it does not carry the list of
+    /// receivers that were not served in time. The list is stored in the sending port
+    /// instead and remains available until the next send operation, see
+    /// [`Publisher::subscribers_not_served_in_time()`](crate::port::publisher::Publisher::subscribers_not_served_in_time()),
+    /// [`Client::servers_not_served_in_time()`](crate::port::client::Client::servers_not_served_in_time())
+    /// and [`Server::clients_not_served_in_time()`](crate::port::server::Server::clients_not_served_in_time()).
+    RetryUntilDeliveredOrTimeout(Duration),
     /// Do not deliver the data to receiver with a full buffer
     DiscardData,
 }
@@ -28,7 +46,41 @@ impl Serialize for BackpressureStrategy {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&alloc::format!("{self:?}"))
+        match self {
+            BackpressureStrategy::RetryUntilDeliveredOrTimeout(timeout) => serializer
+                .serialize_str(&alloc::format!(
+                    "{RETRY_UNTIL_DELIVERED_OR_TIMEOUT}({})",
+                    duration_to_string(timeout)
+                )),
+            _ => serializer.serialize_str(&alloc::format!("{self:?}")),
+        }
+    }
+}
+
+fn duration_to_string(value: &Duration) -> alloc::string::String {
+    let nanos = value.as_nanos();
+    if nanos.is_multiple_of(1_000_000_000) {
+        alloc::format!("{}s", nanos / 1_000_000_000)
+    } else if nanos.is_multiple_of(1_000_000) {
+        alloc::format!("{}ms", nanos / 1_000_000)
+    } else if nanos.is_multiple_of(1_000) {
+        alloc::format!("{}us", nanos / 1_000)
+    } else {
+        alloc::format!("{nanos}ns")
+    }
+}
+
+fn duration_from_str(value: &str) -> Option<Duration> {
+    let unit_start = value.find(|c: char| !c.is_ascii_digit())?;
+    let (number, unit) = value.split_at(unit_start);
+    let number = number.parse::<u64>().ok()?;
+
+    match unit {
+        "s" => Some(Duration::from_secs(number)),
+        "ms" => Some(Duration::from_millis(number)),
+        "us" => Some(Duration::from_micros(number)),
+        "ns" => Some(Duration::from_nanos(number)),
+        _ => None,
     }
 }
 
@@ -38,7 +90,9 @@ impl Visitor<'_> for BackpressureStrategyVisitor {
     type Value = BackpressureStrategy;
 
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-        formatter.write_str("a string containing either 'RetryUntilDelivered' or 'DiscardData'")
+        formatter.write_str(
+            "a string containing either 'RetryUntilDelivered', 'RetryUntilDeliveredOrTimeout(<duration>)' or 'DiscardData'",
+        )
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -48,6 +102,17 @@ impl Visitor<'_> for BackpressureStrategyVisitor {
         match v {
             "DiscardData" => Ok(BackpressureStrategy::DiscardData),
             "RetryUntilDelivered" => Ok(BackpressureStrategy::RetryUntilDelivered),
+            v if v.starts_with(RETRY_UNTIL_DELIVERED_OR_TIMEOUT) => v
+                [RETRY_UNTIL_DELIVERED_OR_TIMEOUT.len()..]
+                .strip_prefix('(')
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(|v| duration_from_str(v.trim()))
+                .map(BackpressureStrategy::RetryUntilDeliveredOrTimeout)
+                .ok_or_else(|| {
+                    E::custom(alloc::format!(
+                        "Invalid BackpressureStrategy provided: \"{v:?}\". The timeout must be an integer with one of the units 's', 'ms', 'us' or 'ns', e.g. '{RETRY_UNTIL_DELIVERED_OR_TIMEOUT}(10ms)'."
+                    ))
+                }),
             v => Err(E::custom(alloc::format!(
                 "Invalid BackpressureStrategy provided: \"{v:?}\"."
             ))),
@@ -63,3 +128,43 @@ impl<'de> Deserialize<'de> for BackpressureStrategy {
         deserializer.deserialize_str(BackpressureStrategyVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iceoryx2_bb_testing::assert_that;
+    use serde::de::IntoDeserializer;
+    use serde::de::value::{Error, StrDeserializer};
+
+    use super::*;
+
+    fn deserialize(value: &str) -> Result<BackpressureStrategy, Error> {
+        let deserializer: StrDeserializer<'_, Error> = value.into_deserializer();
+        BackpressureStrategy::deserialize(deserializer)
+    }
+
+    #[test]
+    fn timeout_strategy_can_be_deserialized_with_all_units() {
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(2s)"), eq Ok(BackpressureStrategy::RetryUntilDeliveredOrTimeout(Duration::from_secs(2))));
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(10ms)"), eq Ok(BackpressureStrategy::RetryUntilDeliveredOrTimeout(Duration::from_millis(10))));
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(7us)"), eq Ok(BackpressureStrategy::RetryUntilDeliveredOrTimeout(Duration::from_micros(7))));
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(3ns)"), eq Ok(BackpressureStrategy::RetryUntilDeliveredOrTimeout(Duration::from_nanos(3))));
+    }
+
+    #[test]
+    fn timeout_strategy_with_invalid_duration_fails_to_deserialize() {
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout"), is_err);
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout()"), is_err);
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(10)"), is_err);
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(10min)"), is_err);
+        assert_that!(deserialize("RetryUntilDeliveredOrTimeout(-1ms)"), is_err);
+    }
+
+    #[test]
+    fn duration_is_serialized_with_the_largest_exact_unit() {
+        assert_that!(duration_to_string(&Duration::from_secs(5)), eq "5s");
+        assert_that!(duration_to_string(&Duration::from_millis(1500)), eq "1500ms");
+        assert_that!(duration_to_string(&Duration::from_micros(12)), eq "12us");
+        assert_that!(duration_to_string(&Duration::from_nanos(1001)), eq "1001ns");
+        assert_that!(duration_to_string(&Duration::ZERO), eq "0s");
+    }
+}
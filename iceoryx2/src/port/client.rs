@@ -80,10 +80,11 @@ use crate::service::marker::{CustomHeaderMarker, CustomPayloadMarker, Flatbuffer
 use crate::service::resource::request_response::RequestResponseResources;
 use crate::service::static_config::message_type_details::MessageTypeDetails;
 use crate::{
-    identifiers::UniqueClientId,
+    identifiers::{UniqueClientId, UniqueServerId},
     pending_response::PendingResponse,
     port::{
-        details::data_segment::DataSegment, port_name::PortName,
+        details::data_segment::{DataSegment, ReceiveAreaConfig},
+        port_name::PortName,
         update_connections::UpdateConnections,
     },
     prelude::{BackpressureStrategy, PortFactory},
@@ -97,8 +98,10 @@ use crate::{
         static_config::message_type_details::TypeVariant,
     },
 };
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::ptr::NonNull;
+use core::time::Duration;
 use core::{any::TypeId, fmt::Debug, marker::PhantomData, mem::MaybeUninit};
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_concurrency::atomic::{AtomicU64, AtomicUsize};
@@ -112,7 +115,8 @@ use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
-use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::shared_memory::{SharedMemoryCreateError, ShmPointer};
 use iceoryx2_cal::shm_allocator::PointerOffset;
 use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_CLOSED, CHANNEL_STATE_OPEN};
 use iceoryx2_cal::{
@@ -124,11 +128,12 @@ use iceoryx2_log::{fail, fatal_panic, warn};
 use super::{
     LoanError, SendError,
     details::{
-        data_segment::DataSegmentType,
+        data_segment::{DataSegmentAllocator, DataSegmentType},
         receiver::{Receiver, SenderDetails},
         segment_state::SegmentState,
         sender::{ReceiverDetails, Sender},
     },
+    request_dispatch_policy::RequestDispatchPolicy,
     update_connections::ConnectionFailure,
 };
 
@@ -183,6 +188,7 @@ pub struct ClientSharedState<Service: service::Service> {
     pub(crate) max_active_requests: usize,
     pub(crate) loan_counter: AtomicUsize,
     max_loans: usize,
+    next_round_robin_connection_id: AtomicUsize,
     // IMPORTANT!
     // Fields of a rust struct are dropped in declaration order. Since this tag is our marker that the
     // port exists and might require cleanup after a crash, the tag must be defined as last member of
@@ -278,6 +284,7 @@ impl<Service: service::Service> ClientSharedState<Service> {
         chunk: &ChunkMut,
         channel_id: ChannelId,
         request_id: RequestId,
+        dispatch_policy: RequestDispatchPolicy,
     ) -> Result<usize, RequestSendError> {
         let msg = "Unable to send request";
 
@@ -303,12 +310,50 @@ impl<Service: service::Service> ClientSharedState<Service> {
         self.prepare_channel_to_receive_responses(channel_id, request_id);
 
         self.active_request_counter.fetch_add(1, Ordering::Relaxed);
-        Ok(self.request_sender.deliver_offset(
-            chunk,
-            // All requests are delivered on the same channel, therefore we can use
-            // ChannelId::new(0).
-            ChannelId::new(0),
-        )?)
+
+        // All requests are delivered on the same channel, therefore we can use
+        // ChannelId::new(0).
+        let request_channel_id = ChannelId::new(0);
+        let connection_id = match dispatch_policy {
+            RequestDispatchPolicy::Broadcast => {
+                return Ok(self
+                    .request_sender
+                    .deliver_offset(chunk, request_channel_id)?);
+            }
+            RequestDispatchPolicy::RoundRobin => {
+                let connection_id = self.request_sender.next_connection_id(
+                    self.next_round_robin_connection_id.load(Ordering::Relaxed),
+                );
+                if let Some(id) = connection_id {
+                    self.next_round_robin_connection_id
+                        .store(id + 1, Ordering::Relaxed);
+                }
+                connection_id
+            }
+            RequestDispatchPolicy::LeastOutstandingRequests => self
+                .request_sender
+                .connection_id_with_least_outstanding_chunks(),
+            RequestDispatchPolicy::Target(server_id) => {
+                self.request_sender.get_connection_id_of(server_id.value())
+            }
+        };
+
+        // the servers that do not receive the request will never respond, their channels
+        // must be closed otherwise the pending response stays connected to them
+        self.response_receiver.close_channel_of_all_senders_except(
+            channel_id,
+            request_id,
+            connection_id.and_then(|id| self.request_sender.receiver_port_id_of(id)),
+        );
+
+        match connection_id {
+            Some(connection_id) => Ok(self.request_sender.deliver_offset_to_connection(
+                chunk,
+                request_channel_id,
+                connection_id,
+            )?),
+            None => Ok(0),
+        }
     }
 
     pub(crate) fn update_connections(
@@ -344,7 +389,9 @@ impl<Service: service::Service> ClientSharedState<Service> {
                         port_id: port.server_id.value(),
                         max_number_of_segments: port.max_number_of_segments,
                         data_segment_type: port.data_segment_type,
+                        data_segment_allocator: DataSegmentAllocator::Pool,
                         number_of_chunks: port.number_of_responses,
+                        payload_delivery: port.payload_delivery,
                     },
                 );
                 result = result.and(inner_result);
@@ -509,6 +556,7 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_requests,
+                static_config.data_segment_memory.memory_properties(),
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
@@ -516,13 +564,26 @@ impl<
                 global_config,
                 number_of_requests,
                 client_factory.config.allocation_strategy,
+                None,
+                static_config.data_segment_memory.memory_properties(),
             ),
         };
 
-        let data_segment = fail!(from origin,
-            when data_segment,
-            with ClientCreateError::UnableToCreateDataSegment,
-            "{} since the client data segment could not be created.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(SharedMemoryCreateError::HugePagesNotSupported) => {
+                fail!(from origin, with ClientCreateError::DataSegmentHugePagesNotSupported,
+                    "{} since the client data segment cannot be backed by huge pages on this system.", msg);
+            }
+            Err(SharedMemoryCreateError::MemoryLockFailed) => {
+                fail!(from origin, with ClientCreateError::UnableToLockDataSegment,
+                    "{} since the client data segment could not be locked in memory.", msg);
+            }
+            Err(_) => {
+                fail!(from origin, with ClientCreateError::UnableToCreateDataSegment,
+                    "{} since the client data segment could not be created.", msg);
+            }
+        };
 
         let max_active_requests = match client_factory.config.max_active_requests {
             Some(requests) => {
@@ -536,6 +597,18 @@ impl<
             None => static_config.max_active_requests_per_client,
         };
 
+        let receive_area_config = ReceiveAreaConfig::new::<Service>(
+            sample_layout,
+            static_config.max_active_requests_per_client,
+            static_config.max_active_requests_per_client,
+            1,
+            static_config.data_segment_memory.memory_properties(),
+        );
+        let number_of_requests = data_segment.number_of_tracked_chunks_per_connection(
+            number_of_requests,
+            receive_area_config.as_ref(),
+        );
+
         let client_details = ClientDetails {
             client_id,
             node_id: *service.shared_node().id(),
@@ -546,11 +619,13 @@ impl<
             max_number_of_segments,
             max_active_requests,
             client_name: client_factory.config.port_name,
+            payload_delivery: Service::__internal_payload_delivery(),
         };
 
         let sender_max_borrowed_chunks = static_config.required_max_borrowed_chunks_per_client();
         let request_sender = Sender {
             data_segment,
+            receive_area_config,
             segment_states: {
                 let mut v =
                     alloc::vec::Vec::<SegmentState>::with_capacity(max_number_of_segments as usize);
@@ -584,12 +659,16 @@ impl<
             // one channel suffices
             number_of_channels: 1,
             initial_channel_state: CHANNEL_STATE_OPEN,
+            unserved_receivers: UnsafeCell::new(alloc::vec::Vec::with_capacity(
+                server_list.capacity(),
+            )),
+            sequence_number: AtomicU64::new(0),
         };
 
         let number_of_to_be_removed_connections = service
             .shared_node()
             .config()
-            .defaults
+            .defaults_for(service.static_config().name())
             .request_response
             .client_expired_connection_buffer;
         let number_of_active_connections = server_list.capacity();
@@ -618,6 +697,7 @@ impl<
             number_of_channels: number_of_requests_with_max_service_setting,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
             initial_channel_state: CHANNEL_STATE_CLOSED,
+            data_segment_memory: static_config.data_segment_memory.memory_properties(),
         };
 
         let client_shared_state = Service::ArcThreadSafetyPolicy::new(ClientSharedState {
@@ -638,6 +718,7 @@ impl<
             max_active_requests,
             max_loans: static_config.max_loaned_requests,
             loan_counter: AtomicUsize::new(0),
+            next_round_robin_connection_id: AtomicUsize::new(0),
         });
 
         let client_shared_state = match client_shared_state {
@@ -715,6 +796,35 @@ impl<
             .backpressure_strategy
     }
 
+    /// Returns the [`UniqueServerId`]s of all [`Server`](crate::port::server::Server)s that
+    /// did not receive the last sent [`RequestMut`] since they could not be served within the
+    /// timeout of [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`]. When the last send
+    /// operation did not fail with [`SendError::DeliveryTimeout`] the list is empty.
+    pub fn servers_not_served_in_time(&self) -> Vec<UniqueServerId> {
+        self.client_shared_state
+            .lock()
+            .request_sender
+            .unserved_receivers()
+            .iter()
+            .map(|id| UniqueServerId(UniqueSystemId::from(*id)))
+            .collect()
+    }
+
+    /// Returns the [`RequestDispatchPolicy`] that defines to which
+    /// [`Server`](crate::port::server::Server)s a [`RequestMut`] is delivered.
+    pub fn request_dispatch_policy(&self) -> RequestDispatchPolicy {
+        self.client_shared_state
+            .lock()
+            .config
+            .request_dispatch_policy
+    }
+
+    /// Returns the timeout after which a [`RequestMut`] of the [`Client`] expires. If the
+    /// requests have no deadline, it returns [`None`].
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.client_shared_state.lock().config.request_timeout
+    }
+
     /// Returns the maximal active requests a [`Client`] can send.
     pub fn max_active_requests(&self) -> usize {
         self.client_shared_state.lock().max_active_requests
@@ -730,6 +840,16 @@ impl<
             fail!(from self, with LoanError::ExceedsMaxLoans,
                 "Unable to loan request since it would exceed the max number of loaned requests ({}).", client_shared_state.max_loans);
         }
+        let deadline = match client_shared_state.config.request_timeout {
+            Some(timeout) => {
+                fail!(from self,
+                    when service::header::request_response::deadline_from_timeout(timeout),
+                    with LoanError::InternalFailure,
+                    "Unable to loan request since its deadline could not be acquired.")
+            }
+            None => service::header::request_response::NO_DEADLINE,
+        };
+
         let chunk = client_shared_state
             .request_sender
             .allocate(client_shared_state.request_sender.chunk_layout(slice_len))?;
@@ -751,12 +871,14 @@ impl<
         let user_header_ptr: *mut RequestHeader = chunk.user_header.cast();
         unsafe {
             header_ptr.write(service::header::request_response::RequestHeader {
+                sequence_number: 0,
                 node_id: *client_shared_state.request_sender.shared_node.id(),
                 client_id: self.id(),
                 channel_id,
                 request_id: self.next_request_id(),
                 number_of_elements: slice_len as _,
                 payload_offset: 0,
+                deadline,
             })
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
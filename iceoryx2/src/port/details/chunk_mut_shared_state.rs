@@ -149,14 +149,16 @@ impl<Service: crate::service::Service, T: DataSegmentSharedState> ChunkMutShared
             .initial_layout(unsafe {
                 Layout::from_size_align_unchecked(
                     chunk.size(),
-                    port_state.message_type_details().header.alignment,
+                    port_state.message_type_details().max_alignment(),
                 )
             })
             .reserved_header_len(reserved_header_len)
             .create(self.clone())
     }
 
-    pub fn memory_structure(&self, payload_ptr: *const u8) -> MemoryStructure {
+    /// Returns the [`ChunkMut`] that is currently owned. It may change whenever the
+    /// underlying memory is grown.
+    pub fn current_chunk(&self) -> ChunkMut {
         let state = self.state.lock();
         let port_state = state.port_shared_state.lock();
 
@@ -170,15 +172,18 @@ impl<Service: crate::service::Service, T: DataSegmentSharedState> ChunkMutShared
             .cast_mut();
         let header_len = message_type_details.all_headers_len();
 
-        let payload_size = state.slice_len.load(Ordering::Relaxed);
-        let chunk = ChunkMut {
+        ChunkMut {
             offset: PointerOffset::from_value(state.offset_to_chunk.load(Ordering::Relaxed)),
-            size: payload_size + header_len,
+            size: state.slice_len.load(Ordering::Relaxed) + header_len,
             header,
             user_header,
             payload,
-        };
+        }
+    }
 
+    pub fn memory_structure(&self, payload_ptr: *const u8) -> MemoryStructure {
+        let chunk = self.current_chunk();
+        let payload_size = self.state.lock().slice_len.load(Ordering::Relaxed);
         let payload_offset = payload_ptr as usize - chunk.payload_ptr() as usize;
 
         MemoryStructure {
@@ -200,12 +205,8 @@ impl<Service: crate::service::Service, T: DataSegmentSharedState> Grow<ShmPointe
         content_placement: ContentPlacement,
     ) -> Result<ShmPointer, AllocationGrowError> {
         let state = self.state.lock();
-        let ptr = unsafe {
-            state
-                .port_shared_state
-                .lock()
-                .grow(ptr, old_layout, new_layout, content_placement)
-        }?;
+        let port_state = state.port_shared_state.lock();
+        let ptr = unsafe { port_state.grow(ptr, old_layout, new_layout, content_placement) }?;
 
         state
             .offset_to_chunk
@@ -213,7 +214,10 @@ impl<Service: crate::service::Service, T: DataSegmentSharedState> Grow<ShmPointe
         state
             .shm_raw_ptr
             .store(ptr.data_ptr as usize, Ordering::Relaxed);
-        state.slice_len.store(new_layout.size(), Ordering::Relaxed);
+        state.slice_len.store(
+            new_layout.size() - port_state.header_len(),
+            Ordering::Relaxed,
+        );
 
         Ok(ptr)
     }
@@ -12,6 +12,7 @@
 
 use core::alloc::Layout;
 use core::ptr::NonNull;
+use core::time::Duration;
 
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
@@ -24,13 +25,16 @@ use iceoryx2_bb_posix::file::AccessMode;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::{
     event::NamedConceptBuilder,
+    named_concept::{NamedConceptMgmt, NamedConceptRemoveError},
     resizable_shared_memory::*,
     shared_memory::{
-        SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, SharedMemoryForPoolAllocator,
-        SharedMemoryOpenError, ShmPointer,
+        MemoryProperties, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError,
+        SharedMemoryForBuddyAllocator, SharedMemoryForPoolAllocator, SharedMemoryOpenError,
+        ShmPointer,
     },
     shm_allocator::{
-        self, AllocationError, PointerOffset, SegmentId, pool_allocator::PoolAllocator,
+        self, AllocationError, PointerOffset, SegmentId, ShmAllocator,
+        buddy_allocator::BuddyAllocator, pool_allocator::PoolAllocator,
     },
 };
 use iceoryx2_log::fail;
@@ -39,7 +43,9 @@ use crate::{
     config,
     service::{
         self,
-        config_scheme::{data_segment_config, resizable_data_segment_config},
+        config_scheme::{
+            buddy_data_segment_config, data_segment_config, resizable_data_segment_config,
+        },
     },
 };
 
@@ -62,10 +68,71 @@ impl DataSegmentType {
     }
 }
 
+/// Defines the allocator that manages the chunks in the data segment of a zero copy capable
+/// sender port.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, ZeroCopySend)]
+pub enum DataSegmentAllocator {
+    /// Every chunk has the size of the largest chunk. Allocation and deallocation take
+    /// constant time.
+    #[default]
+    Pool,
+    /// Every chunk is sized to the loaned payload and rounded up to the next power of two
+    /// multiple of the minimum block size. Allocation and deallocation take logarithmic time.
+    /// The data segment is always a [`DataSegmentType::Static`] data segment.
+    Buddy,
+}
+
+/// Defines how a sender port delivers its payload to the receiver ports.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, ZeroCopySend)]
+pub enum PayloadDelivery {
+    /// The receivers map the data segment of the sender and read the payload in place.
+    #[default]
+    ZeroCopy,
+    /// The sender copies the payload into a receive area that it maintains for every receiver.
+    /// The receivers map only their own receive area and never the data segment of the sender.
+    Copy,
+}
+
+/// The setup of the receive areas of a sender port that delivers copies, see
+/// [`PayloadDelivery::Copy`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReceiveAreaConfig {
+    chunk_layout: Layout,
+    number_of_chunks: usize,
+    memory_properties: MemoryProperties,
+}
+
+impl ReceiveAreaConfig {
+    /// Returns [`None`] when the [`service::Service`] delivers its payload without copying it.
+    pub(crate) fn new<Service: service::Service>(
+        chunk_layout: Layout,
+        receiver_max_buffer_size: usize,
+        receiver_max_borrowed_chunks: usize,
+        number_of_channels: usize,
+        memory_properties: MemoryProperties,
+    ) -> Option<Self> {
+        match Service::__internal_payload_delivery() {
+            PayloadDelivery::ZeroCopy => None,
+            // every chunk that is buffered or borrowed by the receiver plus the one that is
+            // copied while the receiver's buffer is full
+            PayloadDelivery::Copy => Some(Self {
+                chunk_layout,
+                number_of_chunks: number_of_channels
+                    * (receiver_max_buffer_size + receiver_max_borrowed_chunks)
+                    + 1,
+                memory_properties,
+            }),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum MemoryType<Service: service::Service> {
     Static(Service::SharedMemory),
     Dynamic(Service::ResizableSharedMemory),
+    Buddy(Service::BuddySharedMemory),
 }
 
 #[derive(Debug)]
@@ -83,6 +150,9 @@ impl<Service: service::Service> Abandonable for DataSegment<Service> {
             MemoryType::Dynamic(shm) => unsafe {
                 Service::ResizableSharedMemory::abandon_in_place(NonNull::from_mut(shm));
             },
+            MemoryType::Buddy(shm) => unsafe {
+                Service::BuddySharedMemory::abandon_in_place(NonNull::from_mut(shm));
+            },
         }
     }
 }
@@ -103,6 +173,9 @@ impl<Service: service::Service> Grow<ShmPointer> for DataSegment<Service> {
             MemoryType::Dynamic(memory) => Ok(fail!(from self,
                     when unsafe { memory.grow(ptr, old_layout, new_layout, content_placement) },
                     "{msg}.")),
+            MemoryType::Buddy(memory) => Ok(fail!(from self,
+                    when unsafe { memory.grow(ptr, old_layout, new_layout, content_placement) },
+                    "{msg}.")),
         }
     }
 }
@@ -113,6 +186,7 @@ impl<Service: service::Service> DataSegment<Service> {
         chunk_layout: Layout,
         global_config: &config::Config,
         number_of_chunks: usize,
+        memory_properties: MemoryProperties,
     ) -> Result<Self, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: chunk_layout,
@@ -127,6 +201,7 @@ impl<Service: service::Service> DataSegment<Service> {
                                     >>::new(segment_name)
                                     .config(&segment_config)
                                     .size(chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1)
+                                    .memory_properties(memory_properties)
                                     .create(&allocator_config),
                                 "{msg}");
 
@@ -141,30 +216,109 @@ impl<Service: service::Service> DataSegment<Service> {
         global_config: &config::Config,
         number_of_chunks: usize,
         allocation_strategy: AllocationStrategy,
+        segment_idle_timeout: Option<Duration>,
+        memory_properties: MemoryProperties,
     ) -> Result<Self, SharedMemoryCreateError> {
         let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_dynamic_segment()";
 
         let segment_config = resizable_data_segment_config::<Service>(global_config);
-        let memory = fail!(from origin,
-                    when <<Service::ResizableSharedMemory as ResizableSharedMemory<
-                        PoolAllocator,
-                        Service::SharedMemory,
-                    >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
-                        segment_name,
-                    )
-                    .config(&segment_config)
-                    .max_number_of_chunks_hint(number_of_chunks)
-                    .max_chunk_layout_hint(chunk_layout)
-                    .allocation_strategy(allocation_strategy)
-                    .create(),
-                    "{msg}");
+        let mut builder = <<Service::ResizableSharedMemory as ResizableSharedMemory<
+            PoolAllocator,
+            Service::SharedMemory,
+        >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
+            segment_name,
+        )
+        .config(&segment_config)
+        .max_number_of_chunks_hint(number_of_chunks)
+        .max_chunk_layout_hint(chunk_layout)
+        .allocation_strategy(allocation_strategy)
+        .memory_properties(memory_properties)
+        .max_number_of_segments(Self::max_number_of_segments(DataSegmentType::Dynamic) as usize);
+
+        if let Some(segment_idle_timeout) = segment_idle_timeout {
+            builder = builder.segment_idle_timeout(segment_idle_timeout);
+        }
+
+        let memory = fail!(from origin, when builder.create(), "{msg}");
 
         Ok(Self {
             memory: MemoryType::Dynamic(memory),
         })
     }
 
+    pub(crate) fn create_buddy_segment(
+        segment_name: &FileName,
+        chunk_layout: Layout,
+        global_config: &config::Config,
+        number_of_chunks: usize,
+        memory_properties: MemoryProperties,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let setup_hint = BuddyAllocator::initial_setup_hint(chunk_layout, number_of_chunks);
+        let msg = "Unable to create the buddy allocated data segment since the underlying shared memory could not be created.";
+        let origin = "DataSegment::create_buddy_segment()";
+
+        let segment_config = buddy_data_segment_config::<Service>(global_config);
+        let memory = fail!(from origin,
+                                when <<Service::BuddySharedMemory as SharedMemory<BuddyAllocator>>::Builder as NamedConceptBuilder<
+                                Service::BuddySharedMemory,
+                                    >>::new(segment_name)
+                                    .config(&segment_config)
+                                    .size(setup_hint.payload_size + setup_hint.config.max_alignment - 1)
+                                    .memory_properties(memory_properties)
+                                    .create(&setup_hint.config),
+                                "{msg}");
+
+        Ok(Self {
+            memory: MemoryType::Buddy(memory),
+        })
+    }
+
+    /// Creates the receive area of a single receiver. It uses the same kind of memory and
+    /// allocator as the data segment of the sender.
+    pub(crate) fn create_receive_area(
+        &self,
+        segment_name: &FileName,
+        global_config: &config::Config,
+        config: &ReceiveAreaConfig,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        match &self.memory {
+            MemoryType::Static(_) => Self::create_static_segment(
+                segment_name,
+                config.chunk_layout,
+                global_config,
+                config.number_of_chunks,
+                config.memory_properties,
+            ),
+            MemoryType::Dynamic(memory) => Self::create_dynamic_segment(
+                segment_name,
+                config.chunk_layout,
+                global_config,
+                config.number_of_chunks,
+                memory.allocation_strategy(),
+                None,
+                config.memory_properties,
+            ),
+            MemoryType::Buddy(_) => Self::create_buddy_segment(
+                segment_name,
+                config.chunk_layout,
+                global_config,
+                config.number_of_chunks,
+                config.memory_properties,
+            ),
+        }
+    }
+
+    /// Releases the ownership of the underlying memory so that it is not removed when the
+    /// [`DataSegment`] goes out of scope.
+    pub(crate) fn release_ownership(&self) {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.release_ownership(),
+            MemoryType::Dynamic(memory) => memory.release_ownership(),
+            MemoryType::Buddy(memory) => memory.release_ownership(),
+        }
+    }
+
     pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, AllocationError> {
         let msg = "Unable to allocate memory from the data segment";
         match &self.memory {
@@ -173,12 +327,57 @@ impl<Service: service::Service> DataSegment<Service> {
             MemoryType::Dynamic(memory) => {
                 Ok(fail!(from self, when memory.allocate(layout), "{msg}."))
             }
+            MemoryType::Buddy(memory) => {
+                Ok(fail!(from self, when memory.allocate(layout), "{msg}."))
+            }
+        }
+    }
+
+    pub(crate) fn allocator(&self) -> DataSegmentAllocator {
+        match &self.memory {
+            MemoryType::Static(_) | MemoryType::Dynamic(_) => DataSegmentAllocator::Pool,
+            MemoryType::Buddy(_) => DataSegmentAllocator::Buddy,
+        }
+    }
+
+    /// Returns the number of chunks that must be tracked per segment. A buddy allocated segment
+    /// is tracked in blocks of the minimum block size since its chunks have different sizes.
+    pub(crate) fn number_of_tracked_chunks(&self, number_of_chunks: usize) -> usize {
+        match &self.memory {
+            MemoryType::Static(_) | MemoryType::Dynamic(_) => number_of_chunks,
+            MemoryType::Buddy(memory) => memory.number_of_blocks(),
         }
     }
 
+    /// Returns the number of chunks that must be tracked per segment of a connection. When the
+    /// connection delivers copies, the chunks of its receive area must be tracked as well.
+    pub(crate) fn number_of_tracked_chunks_per_connection(
+        &self,
+        number_of_chunks: usize,
+        receive_area: Option<&ReceiveAreaConfig>,
+    ) -> usize {
+        let own_chunks = self.number_of_tracked_chunks(number_of_chunks);
+        let receive_area_chunks = match (receive_area, &self.memory) {
+            (None, _) => 0,
+            (Some(config), MemoryType::Static(_) | MemoryType::Dynamic(_)) => {
+                config.number_of_chunks
+            }
+            (Some(config), MemoryType::Buddy(memory)) => {
+                let hint = BuddyAllocator::initial_setup_hint(
+                    config.chunk_layout,
+                    config.number_of_chunks,
+                );
+                (hint.payload_size + hint.config.max_alignment - 1)
+                    .div_ceil(memory.min_block_size())
+            }
+        };
+
+        own_chunks.max(receive_area_chunks)
+    }
+
     pub(crate) fn allocation_strategy(&self) -> AllocationStrategy {
         match &self.memory {
-            MemoryType::Static(_) => AllocationStrategy::Static,
+            MemoryType::Static(_) | MemoryType::Buddy(_) => AllocationStrategy::Static,
             MemoryType::Dynamic(memory) => memory.allocation_strategy(),
         }
     }
@@ -188,6 +387,7 @@ impl<Service: service::Service> DataSegment<Service> {
             match &self.memory {
                 MemoryType::Static(memory) => memory.deallocate_bucket(offset),
                 MemoryType::Dynamic(memory) => memory.deallocate_bucket(offset),
+                MemoryType::Buddy(memory) => memory.deallocate_block(offset),
             }
         }
     }
@@ -196,6 +396,7 @@ impl<Service: service::Service> DataSegment<Service> {
         match &self.memory {
             MemoryType::Static(memory) => memory.bucket_size(),
             MemoryType::Dynamic(memory) => memory.bucket_size(segment_id),
+            MemoryType::Buddy(memory) => memory.min_block_size(),
         }
     }
 
@@ -212,6 +413,7 @@ impl<Service: service::Service> DataSegment<Service> {
 #[derive(Debug)]
 enum MemoryViewType<Service: service::Service> {
     Static(Service::SharedMemory),
+    Buddy(Service::BuddySharedMemory),
     Dynamic(
         <Service::ResizableSharedMemory as ResizableSharedMemory<
             PoolAllocator,
@@ -238,6 +440,9 @@ impl<Service: service::Service> Abandonable for DataSegmentView<Service> {
             MemoryViewType::Static(shm) => unsafe {
                 Service::SharedMemory::abandon_in_place(NonNull::from_mut(shm));
             },
+            MemoryViewType::Buddy(shm) => unsafe {
+                Service::BuddySharedMemory::abandon_in_place(NonNull::from_mut(shm));
+            },
         }
     }
 }
@@ -246,6 +451,7 @@ impl<Service: service::Service> DataSegmentView<Service> {
     pub(crate) fn open_static_segment(
         segment_name: &FileName,
         global_config: &config::Config,
+        memory_properties: MemoryProperties,
     ) -> Result<Self, SharedMemoryOpenError> {
         let origin = "DataSegment::open()";
         let msg =
@@ -257,6 +463,7 @@ impl<Service: service::Service> DataSegmentView<Service> {
                                 Builder::new(segment_name)
                                 .config(&segment_config)
                                 .timeout(global_config.global.creation_timeout)
+                                .memory_properties(memory_properties)
                                 .open(AccessMode::Read),
                             "{msg}");
 
@@ -265,9 +472,34 @@ impl<Service: service::Service> DataSegmentView<Service> {
         })
     }
 
+    pub(crate) fn open_buddy_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        memory_properties: MemoryProperties,
+    ) -> Result<Self, SharedMemoryOpenError> {
+        let origin = "DataSegment::open()";
+        let msg =
+            "Unable to open data segment since the underlying shared memory could not be opened.";
+
+        let segment_config = buddy_data_segment_config::<Service>(global_config);
+        let memory = fail!(from origin,
+                            when <Service::BuddySharedMemory as SharedMemory<BuddyAllocator>>::
+                                Builder::new(segment_name)
+                                .config(&segment_config)
+                                .timeout(global_config.global.creation_timeout)
+                                .memory_properties(memory_properties)
+                                .open(AccessMode::Read),
+                            "{msg}");
+
+        Ok(Self {
+            memory: MemoryViewType::Buddy(memory),
+        })
+    }
+
     pub(crate) fn open_dynamic_segment(
         segment_name: &FileName,
         global_config: &config::Config,
+        memory_properties: MemoryProperties,
     ) -> Result<Self, SharedMemoryOpenError> {
         let origin = "DataSegment::open()";
         let msg =
@@ -282,6 +514,7 @@ impl<Service: service::Service> DataSegmentView<Service> {
                         segment_name,
                     )
                     .config(&segment_config)
+                    .memory_properties(memory_properties)
                     .open(AccessMode::Read),
                     "{msg}");
 
@@ -290,12 +523,48 @@ impl<Service: service::Service> DataSegmentView<Service> {
         })
     }
 
+    /// Removes a segment whose creator released the ownership.
+    ///
+    /// # Safety
+    ///
+    ///  * the creator of the segment must no longer use it
+    pub(crate) unsafe fn remove_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        data_segment_type: DataSegmentType,
+        data_segment_allocator: DataSegmentAllocator,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        unsafe {
+            match (data_segment_type, data_segment_allocator) {
+                (DataSegmentType::Static, DataSegmentAllocator::Pool) => {
+                    <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                        segment_name,
+                        &data_segment_config::<Service>(global_config),
+                    )
+                }
+                (_, DataSegmentAllocator::Buddy) => {
+                    <Service::BuddySharedMemory as NamedConceptMgmt>::remove_cfg(
+                        segment_name,
+                        &buddy_data_segment_config::<Service>(global_config),
+                    )
+                }
+                (DataSegmentType::Dynamic, DataSegmentAllocator::Pool) => {
+                    <Service::ResizableSharedMemory as NamedConceptMgmt>::remove_cfg(
+                        segment_name,
+                        &resizable_data_segment_config::<Service>(global_config),
+                    )
+                }
+            }
+        }
+    }
+
     pub(crate) fn register_and_translate_offset(
         &self,
         offset: PointerOffset,
     ) -> Result<usize, SharedMemoryOpenError> {
         match &self.memory {
             MemoryViewType::Static(memory) => Ok(offset.offset() + memory.payload_start_address()),
+            MemoryViewType::Buddy(memory) => Ok(offset.offset() + memory.payload_start_address()),
             MemoryViewType::Dynamic(memory) => unsafe {
                 match memory.register_and_translate_offset(offset) {
                     Ok(ptr) => Ok(ptr as usize),
@@ -317,6 +586,12 @@ impl<Service: service::Service> DataSegmentView<Service> {
         }
     }
 
+    pub(crate) fn release_unused_segments(&self) {
+        if let MemoryViewType::Dynamic(memory) = &self.memory {
+            memory.release_unused_segments();
+        }
+    }
+
     pub(crate) fn is_dynamic(&self) -> bool {
         matches!(&self.memory, MemoryViewType::Dynamic(_))
     }
@@ -0,0 +1,98 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::alloc::Layout;
+use core::ptr::NonNull;
+
+use iceoryx2_bb_concurrency::cell::UnsafeCell;
+use iceoryx2_bb_container::vector::polymorphic_vec::*;
+use iceoryx2_bb_elementary_traits::allocator::{Allocate, Deallocate};
+use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_log::fatal_panic;
+
+/// Process local memory into which received payloads of an older version are upgraded. The
+/// shared memory of the service contains only the smaller older payload, therefore the newer
+/// payload cannot be read in place.
+#[derive(Debug)]
+pub(crate) struct PayloadUpgradeSlots {
+    memory: NonNull<u8>,
+    layout: Layout,
+    slot_size: usize,
+    free_slots: UnsafeCell<PolymorphicVec<'static, usize, HeapAllocator>>,
+}
+
+// the slots are only accessed under the lock of the `SubscriberSharedState`
+unsafe impl Send for PayloadUpgradeSlots {}
+unsafe impl Sync for PayloadUpgradeSlots {}
+
+impl Drop for PayloadUpgradeSlots {
+    fn drop(&mut self) {
+        unsafe { HeapAllocator::global().deallocate(self.memory, self.layout) };
+    }
+}
+
+impl PayloadUpgradeSlots {
+    pub(crate) fn new(
+        payload_size: usize,
+        payload_alignment: usize,
+        number_of_slots: usize,
+    ) -> Self {
+        let origin = "PayloadUpgradeSlots::new()";
+        let alignment = payload_alignment.max(1);
+        let slot_size = payload_size.next_multiple_of(alignment).max(alignment);
+        let number_of_slots = number_of_slots.max(1);
+
+        let layout = match Layout::from_size_align(slot_size * number_of_slots, alignment) {
+            Ok(layout) => layout,
+            Err(e) => {
+                fatal_panic!(from origin,
+                    "This should never happen! The payload layout ({payload_size}, {payload_alignment}) is invalid ({e:?}).");
+            }
+        };
+
+        Self {
+            memory: HeapAllocator::global()
+                .allocate(layout)
+                .expect("Heap allocator provides memory."),
+            layout,
+            slot_size,
+            free_slots: UnsafeCell::new(
+                PolymorphicVec::from_fn(HeapAllocator::global(), number_of_slots, |n| n)
+                    .expect("Heap allocator provides memory."),
+            ),
+        }
+    }
+
+    /// Returns a free slot or [`None`] when all slots are in use.
+    pub(crate) fn acquire(&self) -> Option<*mut u8> {
+        let index = unsafe { &mut *self.free_slots.get() }.pop()?;
+        Some(unsafe { self.memory.as_ptr().add(index * self.slot_size) })
+    }
+
+    /// Returns the slot to the free slots when `ptr` points to one of the slots, otherwise
+    /// false is returned.
+    pub(crate) fn release(&self, ptr: *const u8) -> bool {
+        let start = self.memory.as_ptr() as usize;
+        let ptr = ptr as usize;
+        if ptr < start || start + self.layout.size() <= ptr {
+            return false;
+        }
+
+        let free_slots = unsafe { &mut *self.free_slots.get() };
+        if free_slots.push((ptr - start) / self.slot_size).is_err() {
+            fatal_panic!(from self,
+                "This should never happen! A payload upgrade slot was released twice.");
+        }
+
+        true
+    }
+}
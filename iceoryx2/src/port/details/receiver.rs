@@ -22,6 +22,7 @@ use iceoryx2_bb_elementary::cyclic_tagger::*;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{MemoryProperties, SharedMemoryOpenError};
 use iceoryx2_cal::zero_copy_connection::*;
 use iceoryx2_log::fatal_panic;
 use iceoryx2_log::{error, fail, warn};
@@ -31,27 +32,36 @@ use crate::port::DegradationInfo;
 use crate::port::update_connections::ConnectionFailure;
 use crate::port::{DegradationAction, DegradationHandler, ReceiveError};
 use crate::service::SharedServiceState;
-use crate::service::naming_scheme::data_segment_name;
+use crate::service::naming_scheme::{data_segment_name, receive_area_name};
 use crate::service::resource::ServiceResource;
 use crate::service::static_config::message_type_details::MessageTypeDetails;
 use crate::service::{self, config_scheme::connection_config, naming_scheme::connection_name};
+#[cfg(feature = "trace_points")]
+use crate::trace::TracePointKind;
 
 use super::chunk::Chunk;
 use super::chunk_details::ChunkDetails;
-use super::data_segment::{DataSegmentType, DataSegmentView};
+use super::data_segment::{
+    DataSegmentAllocator, DataSegmentType, DataSegmentView, PayloadDelivery,
+};
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct SenderDetails {
     pub(crate) port_id: u128,
     pub(crate) number_of_chunks: usize,
     pub(crate) max_number_of_segments: u8,
     pub(crate) data_segment_type: DataSegmentType,
+    pub(crate) data_segment_allocator: DataSegmentAllocator,
+    pub(crate) payload_delivery: PayloadDelivery,
 }
 
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service, Resource: ServiceResource> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: DataSegmentView<Service>,
+    /// The receive area of a sender that delivers copies is created when the sender connects,
+    /// therefore it is mapped with the first chunk that is received.
+    data_segment: UnsafeCell<Option<DataSegmentView<Service>>>,
+    sender_details: SenderDetails,
     pub(crate) sender_port_id: u128,
     tag: Tag,
     _resource: PhantomData<Resource>,
@@ -68,7 +78,9 @@ impl<Service: service::Service, Resource: ServiceResource> Abandonable
                 NonNull::from_mut(&mut this.receiver),
             )
         };
-        unsafe { DataSegmentView::abandon_in_place(NonNull::from_mut(&mut this.data_segment)) };
+        if let Some(data_segment) = this.data_segment.get_mut() {
+            unsafe { DataSegmentView::abandon_in_place(NonNull::from_mut(data_segment)) };
+        }
     }
 }
 
@@ -83,13 +95,11 @@ impl<Service: service::Service, Resource: ServiceResource> Taggable
 impl<Service: service::Service, Resource: ServiceResource> Connection<Service, Resource> {
     fn new(
         this: &Receiver<Service, Resource>,
-        data_segment_type: DataSegmentType,
-        sender_port_id: u128,
-        number_of_chunks: usize,
-        max_number_of_segments: u8,
+        sender_details: &SenderDetails,
         cyclic_tagger: &CyclicTagger,
         initial_channel_state: ChannelState,
     ) -> Result<Self, ConnectionFailure> {
+        let sender_port_id = sender_details.port_id;
         let msg = format!(
             "Unable to establish connection to sender port {:?} from receiver port {:?}.",
             sender_port_id, this.receiver_port_id
@@ -103,36 +113,89 @@ impl<Service: service::Service, Resource: ServiceResource> Connection<Service, R
                                     .buffer_size(this.buffer_size)
                                     .receiver_max_borrowed_chunks_per_channel(this.receiver_max_borrowed_chunks)
                                     .enable_safe_overflow(this.enable_safe_overflow)
-                                    .number_of_chunks_per_segment(number_of_chunks)
+                                    .number_of_chunks_per_segment(sender_details.number_of_chunks)
                                     .number_of_channels(this.number_of_channels)
                                     .initial_channel_state(initial_channel_state)
-                                    .max_supported_shared_memory_segments(max_number_of_segments)
+                                    .max_supported_shared_memory_segments(sender_details.max_number_of_segments)
                                     .timeout(global_config.global.creation_timeout)
                                     .create_receiver(),
                         "{} since the zero copy connection could not be established.", msg);
 
-        let segment_name = data_segment_name(sender_port_id);
-        let data_segment = match data_segment_type {
-            DataSegmentType::Static => {
-                DataSegmentView::open_static_segment(&segment_name, global_config)
-            }
-            DataSegmentType::Dynamic => {
-                DataSegmentView::open_dynamic_segment(&segment_name, global_config)
-            }
+        let data_segment = match sender_details.payload_delivery {
+            PayloadDelivery::ZeroCopy => Some(fail!(from this,
+                                 when Self::open_data_segment(this, sender_details),
+                                "{} since the sender data segment could not be opened.", msg)),
+            PayloadDelivery::Copy => Self::open_data_segment(this, sender_details).ok(),
         };
 
-        let data_segment = fail!(from this,
-                                 when data_segment,
-                                "{} since the sender data segment could not be opened.", msg);
-
         Ok(Self {
             receiver,
-            data_segment,
+            data_segment: UnsafeCell::new(data_segment),
+            sender_details: *sender_details,
             sender_port_id,
             tag: cyclic_tagger.create_tag(),
             _resource: PhantomData,
         })
     }
+
+    fn open_data_segment(
+        this: &Receiver<Service, Resource>,
+        sender_details: &SenderDetails,
+    ) -> Result<DataSegmentView<Service>, SharedMemoryOpenError> {
+        let global_config = this.service_state.shared_node().config();
+        let segment_name = match sender_details.payload_delivery {
+            PayloadDelivery::ZeroCopy => data_segment_name(sender_details.port_id),
+            PayloadDelivery::Copy => {
+                receive_area_name(sender_details.port_id, this.receiver_port_id)
+            }
+        };
+
+        match (
+            sender_details.data_segment_type,
+            sender_details.data_segment_allocator,
+        ) {
+            (DataSegmentType::Static, DataSegmentAllocator::Pool) => {
+                DataSegmentView::open_static_segment(
+                    &segment_name,
+                    global_config,
+                    this.data_segment_memory,
+                )
+            }
+            (_, DataSegmentAllocator::Buddy) => DataSegmentView::open_buddy_segment(
+                &segment_name,
+                global_config,
+                this.data_segment_memory,
+            ),
+            (DataSegmentType::Dynamic, DataSegmentAllocator::Pool) => {
+                DataSegmentView::open_dynamic_segment(
+                    &segment_name,
+                    global_config,
+                    this.data_segment_memory,
+                )
+            }
+        }
+    }
+
+    fn mapped_data_segment(&self) -> Option<&DataSegmentView<Service>> {
+        unsafe { (*self.data_segment.get()).as_ref() }
+    }
+
+    fn data_segment(
+        &self,
+        this: &Receiver<Service, Resource>,
+    ) -> Result<&DataSegmentView<Service>, SharedMemoryOpenError> {
+        let data_segment = unsafe { &mut *self.data_segment.get() };
+        if data_segment.is_none() {
+            *data_segment = Some(Self::open_data_segment(this, &self.sender_details)?);
+        }
+
+        match data_segment.as_ref() {
+            Some(data_segment) => Ok(data_segment),
+            None => {
+                fatal_panic!(from this, "This should never happen! The data segment of the sender was not mapped.")
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -151,6 +214,7 @@ pub(crate) struct Receiver<Service: service::Service, Resource: ServiceResource>
     pub(crate) number_of_channels: usize,
     pub(crate) connection_storage: UnsafeCell<SlotMap<Connection<Service, Resource>>>,
     pub(crate) initial_channel_state: ChannelState,
+    pub(crate) data_segment_memory: MemoryProperties,
 }
 
 impl<Service: service::Service, Resource: ServiceResource> Abandonable
@@ -165,15 +229,31 @@ impl<Service: service::Service, Resource: ServiceResource> Abandonable
 
 impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Resource> {
     pub(crate) fn release_offset(&self, chunk: &ChunkDetails, channel_id: ChannelId) {
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
         if let Some(connection) = connection_storage.get(chunk.connection_key) {
             if connection.sender_port_id != chunk.origin {
                 return;
             }
 
-            unsafe { connection.data_segment.unregister_offset(chunk.offset) };
+            if let Some(data_segment) = connection.mapped_data_segment() {
+                unsafe { data_segment.unregister_offset(chunk.offset) };
+            }
             match connection.receiver.release(chunk.offset, channel_id) {
-                Ok(()) => (),
+                Ok(()) =>
+                {
+                    #[cfg(feature = "trace_points")]
+                    if let Some(span) = span {
+                        span.finish(
+                            TracePointKind::Release,
+                            self.receiver_port_id,
+                            chunk.origin,
+                            chunk.offset.as_value(),
+                        );
+                    }
+                }
                 Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
                     error!(from self, "This should never happen! The publishers retrieve channel is full and the chunk cannot be returned.");
                 }
@@ -226,6 +306,23 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
         }
     }
 
+    /// Closes the channel of all connections except the one to the provided sender.
+    pub(crate) fn close_channel_of_all_senders_except(
+        &self,
+        channel_id: ChannelId,
+        expected_state: ChannelState,
+        sender_port_id: Option<u128>,
+    ) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            if Some(connection.sender_port_id) != sender_port_id {
+                connection
+                    .receiver
+                    .close_channel(channel_id, expected_state);
+            }
+        }
+    }
+
     pub(crate) fn receiver_port_id(&self) -> u128 {
         self.receiver_port_id
     }
@@ -238,10 +335,7 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
         let key = connection_storage.insert(Connection::new(
             self,
-            sender_details.data_segment_type,
-            sender_details.port_id,
-            sender_details.number_of_chunks,
-            sender_details.max_number_of_segments,
+            sender_details,
             &self.tagger,
             self.initial_channel_state,
         )?);
@@ -291,7 +385,7 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
                     if let Some((index, key)) = index_and_key_to_remove {
                         // we found a connection without data and borrows which can be removed from the container
                         to_be_removed_connections.remove(index);
-                        connection_storage.remove(key);
+                        self.discard_connection(connection_storage, key);
                     } else if connection_has_borrows {
                         // we did not find a connection that can safely be removed to create some space for the connection with borrows;
                         // removing a connection with borrows might lead to segfaults -> try to remove a connection without borrows
@@ -304,7 +398,7 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
                             // we found a connection without borrows which can be removed from the container
                             warn!(from self, "{} {} {}", msg_begin, msg_disconnect_with_data, msg_end);
                             to_be_removed_connections.remove(index);
-                            connection_storage.remove(key);
+                            self.discard_connection(connection_storage, key);
                         }
                     }
 
@@ -314,11 +408,44 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
                         } else {
                             warn!(from self, "{} {} {}", msg_begin, msg_disconnect_with_data, msg_end);
                         }
-                        connection_storage.remove(key);
+                        self.discard_connection(connection_storage, key);
                     }
                 }
             } else {
-                connection_storage.remove(key);
+                self.discard_connection(connection_storage, key);
+            }
+        }
+    }
+
+    /// Removes the connection of a disconnected sender. A sender that delivers copies hands
+    /// its receive area over when the receiver still holds copies, therefore it is removed
+    /// together with the connection.
+    fn discard_connection(
+        &self,
+        connection_storage: &mut SlotMap<Connection<Service, Resource>>,
+        key: SlotMapKey,
+    ) {
+        let connection = match connection_storage.remove(key) {
+            Some(connection) => connection,
+            None => return,
+        };
+
+        if connection.sender_details.payload_delivery == PayloadDelivery::Copy {
+            let sender_details = connection.sender_details;
+            drop(connection);
+
+            // # SAFETY: the sender is disconnected and no longer uses the receive area
+            if let Err(e) = unsafe {
+                DataSegmentView::<Service>::remove_segment(
+                    &receive_area_name(sender_details.port_id, self.receiver_port_id),
+                    self.service_state.shared_node().config(),
+                    sender_details.data_segment_type,
+                    sender_details.data_segment_allocator,
+                )
+            } {
+                warn!(from self,
+                    "Unable to remove the receive area of the disconnected sender {:?} ({:?}).",
+                    sender_details.port_id, e);
             }
         }
     }
@@ -436,13 +563,22 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
                         origin: connection.sender_port_id,
                     };
 
-                    let offset = match connection
-                        .data_segment
-                        .register_and_translate_offset(offset)
-                    {
+                    let data_segment = match connection.data_segment(self) {
+                        Ok(data_segment) => data_segment,
+                        Err(e) => {
+                            warn!(from self, "Lost chunk. This only happens when a sender that delivers copies has gone out of scope before the receiver has mapped its receive area. [{e:?}]");
+                            if let Err(e) = connection.receiver.release(offset, channel_id) {
+                                error!(from self,
+                                    "This should never happen! Failed to return the lost chunk that was received before. [{e:?}]");
+                            }
+                            return Ok(None);
+                        }
+                    };
+
+                    let offset = match data_segment.register_and_translate_offset(offset) {
                         Ok(offset) => offset,
                         Err(e) => {
-                            if connection.data_segment.is_dynamic() {
+                            if data_segment.is_dynamic() {
                                 warn!(from self, "Lost chunk. This only happens in the dynamic use case when a sender has reallocated its data segment and gone out of scope before the receiver has mapped the realloacted data segment. To circumvent this, you could either use static memory or increase the initial max slice len.");
                                 if let Err(e) = connection.receiver.release(offset, channel_id) {
                                     error!(from self,
@@ -521,7 +657,7 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
                 // there is a connection which has neither data nor borrows nor is it present in the 'connection_storage'
                 if let Some((index, key)) = index_and_key {
                     to_be_removed_connections.remove(index);
-                    connection_storage.remove(key);
+                    self.discard_connection(connection_storage, key);
                     indices_to_skip = index;
 
                     continue;
@@ -538,6 +674,30 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
     pub(crate) fn receive(
         &self,
         channel_id: ChannelId,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
+        let result = self.receive_impl(channel_id);
+
+        #[cfg(feature = "trace_points")]
+        if let (Some(span), Ok(Some((details, chunk)))) = (span, &result) {
+            span.finish(
+                TracePointKind::Receive,
+                self.receiver_port_id,
+                details.origin,
+                unsafe {
+                    crate::service::header::payload_header::read_sequence_number(chunk.header)
+                },
+            );
+        }
+
+        result
+    }
+
+    fn receive_impl(
+        &self,
+        channel_id: ChannelId,
     ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         if let Some(data) = self.receive_from_to_be_removed_connections(channel_id)? {
             return Ok(Some(data));
@@ -549,6 +709,9 @@ impl<Service: service::Service, Resource: ServiceResource> Receiver<Service, Res
         let connection_storage = unsafe { &*self.connection_storage.get() };
         for (connection_key, connection) in connection_storage.iter() {
             if !connection.receiver.has_data(channel_id) {
+                if let Some(data_segment) = connection.mapped_data_segment() {
+                    data_segment.release_unused_segments();
+                }
                 continue;
             }
 
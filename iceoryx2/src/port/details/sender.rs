@@ -13,18 +13,20 @@
 use core::alloc::Layout;
 use core::marker::PhantomData;
 use core::ptr::NonNull;
+use core::time::Duration;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 
 use alloc::format;
 use alloc::vec::Vec;
 
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
-use iceoryx2_bb_concurrency::cell::UnsafeCell;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, AtomicUsize};
+use iceoryx2_bb_concurrency::cell::{Cell, UnsafeCell};
 use iceoryx2_bb_elementary::cyclic_tagger::*;
 use iceoryx2_bb_elementary_traits::allocator::{
     AllocationError, AllocationGrowError, ContentPlacement, Grow,
 };
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shared_memory::ShmPointer;
 use iceoryx2_cal::shm_allocator::PointerOffset;
@@ -43,12 +45,16 @@ use crate::port::{
 use crate::prelude::BackpressureStrategy;
 use crate::service::SharedServiceState;
 use crate::service::config_scheme::connection_config;
+use crate::service::header::payload_header::write_sequence_number;
+use crate::service::naming_scheme::receive_area_name;
 use crate::service::resource::ServiceResource;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
+#[cfg(feature = "trace_points")]
+use crate::trace::TracePointKind;
 use crate::{service, service::naming_scheme::connection_name};
 
 use super::chunk::ChunkMut;
-use super::data_segment::DataSegment;
+use super::data_segment::{DataSegment, DataSegmentAllocator, ReceiveAreaConfig};
 use super::segment_state::SegmentState;
 
 #[derive(Clone, Copy)]
@@ -61,6 +67,11 @@ pub(crate) struct ReceiverDetails {
 pub(crate) struct Connection<Service: service::Service, Resource: ServiceResource> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    /// the chunks are copied into the receive area before they are delivered when the service
+    /// delivers copies
+    receive_area: Option<DataSegment<Service>>,
+    /// number of chunks that were delivered to the receiver but not yet returned
+    outstanding_chunks: AtomicUsize,
     tag: Tag,
     _resource: PhantomData<Resource>,
 }
@@ -75,6 +86,10 @@ impl<Service: service::Service, Resource: ServiceResource> Abandonable
                 NonNull::from_mut(&mut this.sender),
             )
         };
+
+        if let Some(receive_area) = &mut this.receive_area {
+            unsafe { DataSegment::<Service>::abandon_in_place(NonNull::from_mut(receive_area)) };
+        }
     }
 }
 
@@ -87,6 +102,14 @@ impl<Service: service::Service, Resource: ServiceResource> Taggable
 }
 
 impl<Service: service::Service, Resource: ServiceResource> Connection<Service, Resource> {
+    fn release_outstanding_chunk(&self) {
+        let _ =
+            self.outstanding_chunks
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| {
+                    value.checked_sub(1)
+                });
+    }
+
     fn new(
         this: &Sender<Service, Resource>,
         receiver_port_id: u128,
@@ -105,6 +128,18 @@ impl<Service: service::Service, Resource: ServiceResource> Connection<Service, R
                 msg, buffer_size, this.receiver_max_buffer_size);
         }
 
+        // the receive area must exist before the receiver can see the connection
+        let receive_area = match &this.receive_area_config {
+            Some(config) => Some(fail!(from this,
+                        when this.data_segment.create_receive_area(
+                            &receive_area_name(this.sender_port_id, receiver_port_id),
+                            this.shared_node.config(),
+                            config),
+                        with ZeroCopyCreationError::InternalError,
+                        "{} since the receive area could not be created.", msg)),
+            None => None,
+        };
+
         let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.sender_port_id, receiver_port_id))
                                 .config(&connection_config::<Service>(this.shared_node.config()))
@@ -122,6 +157,8 @@ impl<Service: service::Service, Resource: ServiceResource> Connection<Service, R
         Ok(Self {
             sender,
             receiver_port_id,
+            receive_area,
+            outstanding_chunks: AtomicUsize::new(0),
             tag,
             _resource: PhantomData,
         })
@@ -132,6 +169,7 @@ impl<Service: service::Service, Resource: ServiceResource> Connection<Service, R
 pub(crate) struct Sender<Service: service::Service, Resource: ServiceResource> {
     pub(crate) segment_states: Vec<SegmentState>,
     pub(crate) data_segment: DataSegment<Service>,
+    pub(crate) receive_area_config: Option<ReceiveAreaConfig>,
     pub(crate) connections: Vec<UnsafeCell<Option<Connection<Service, Resource>>>>,
     pub(crate) sender_port_id: u128,
     pub(crate) shared_node: SharedNode<Service>,
@@ -150,6 +188,8 @@ pub(crate) struct Sender<Service: service::Service, Resource: ServiceResource> {
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) number_of_channels: usize,
     pub(crate) initial_channel_state: ChannelState,
+    pub(crate) unserved_receivers: UnsafeCell<Vec<u128>>,
+    pub(crate) sequence_number: AtomicU64,
 }
 
 impl<Service: service::Service, Resource: ServiceResource> Grow<ShmPointer>
@@ -197,6 +237,23 @@ impl<Service: service::Service, Resource: ServiceResource> Abandonable
     }
 }
 
+impl<Service: service::Service, Resource: ServiceResource> Drop for Sender<Service, Resource> {
+    fn drop(&mut self) {
+        self.retrieve_returned_chunks();
+
+        for connection in &self.connections {
+            if let Some(connection) = unsafe { &*connection.get() }
+                && let Some(receive_area) = &connection.receive_area
+                && connection.outstanding_chunks.load(Ordering::Relaxed) != 0
+            {
+                // the receiver still holds copies, it takes over the receive area and removes
+                // it when the last copy was consumed
+                receive_area.release_ownership();
+            }
+        }
+    }
+}
+
 impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resource> {
     fn get(&self, index: usize) -> &Option<Connection<Service, Resource>> {
         unsafe { &(*self.connections[index].get()) }
@@ -223,19 +280,77 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
         None
     }
 
+    pub(crate) fn receiver_port_id_of(&self, connection_id: usize) -> Option<u128> {
+        self.get(connection_id)
+            .as_ref()
+            .map(|connection| connection.receiver_port_id)
+    }
+
+    /// Returns the id of the first connection, starting at `start_id` in cyclic order.
+    pub(crate) fn next_connection_id(&self, start_id: usize) -> Option<usize> {
+        let len = self.len();
+        (0..len)
+            .map(|n| (start_id + n) % len)
+            .find(|id| self.get(*id).is_some())
+    }
+
+    /// Returns the id of the connection with the least chunks that were delivered but not yet
+    /// returned by the receiver.
+    pub(crate) fn connection_id_with_least_outstanding_chunks(&self) -> Option<usize> {
+        self.retrieve_returned_chunks();
+        (0..self.len())
+            .filter_map(|id| {
+                self.get(id)
+                    .as_ref()
+                    .map(|connection| (id, connection.outstanding_chunks.load(Ordering::Relaxed)))
+            })
+            .min_by_key(|(_, outstanding_chunks)| *outstanding_chunks)
+            .map(|(id, _)| id)
+    }
+
     fn deliver_offset_to_connection_impl(
         &self,
         chunk: &ChunkMut,
         channel_id: ChannelId,
         connection_id: usize,
+        send_start: Option<Time>,
     ) -> Result<usize, SendError> {
         let msg = "While delivering the chunk:";
 
         let mut number_of_recipients = 0;
         if let Some(connection) = self.get(connection_id) {
+            let (offset, sample_size) = match self.prepare_delivery(connection, chunk) {
+                Ok(v) => v,
+                Err(_) => {
+                    warn!(from self,
+                        "{msg} {:?} could not be copied into the exhausted receive area of receiver {:?}.",
+                        chunk, connection.receiver_port_id);
+                    return Ok(0);
+                }
+            };
+            // the timeout covers the whole send operation, every receiver gets only the
+            // time that is left
+            let remaining_time = match (self.backpressure_strategy, send_start) {
+                (BackpressureStrategy::RetryUntilDeliveredOrTimeout(timeout), Some(start)) => {
+                    timeout.saturating_sub(start.elapsed().unwrap_or(Duration::MAX))
+                }
+                (BackpressureStrategy::RetryUntilDeliveredOrTimeout(timeout), None) => timeout,
+                _ => Duration::MAX,
+            };
+            let has_timed_out = Cell::new(false);
+            let retry_until_timeout = |elapsed_time: Duration| {
+                if elapsed_time >= remaining_time {
+                    has_timed_out.set(true);
+                    BackpressureToReceiverAction::DiscardPointerOffsetAndFail
+                } else {
+                    BackpressureToReceiverAction::Retry
+                }
+            };
+
             let delivery_call_result = if let Some(handler) = self.backpressure_handler.as_ref() {
                 let backpressure_action_for_strategy = match self.backpressure_strategy {
-                    BackpressureStrategy::RetryUntilDelivered => {
+                    BackpressureStrategy::RetryUntilDelivered
+                    | BackpressureStrategy::RetryUntilDeliveredOrTimeout(_) => {
                         BackpressureToReceiverAction::Retry
                     }
                     BackpressureStrategy::DiscardData => {
@@ -245,11 +360,11 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
 
                 <Service::Connection as ZeroCopyConnection>::Sender::blocking_send(
                     &connection.sender,
-                    chunk.offset(),
-                    chunk.size(),
+                    offset,
+                    sample_size,
                     channel_id,
                     |retries, elapsed_time| {
-                        handler
+                        let action = handler
                             .call(&BackpressureInfo {
                                 service_id: self
                                     .service_state
@@ -261,7 +376,15 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
                                 retries,
                                 elapsed_time,
                             })
-                            .into()
+                            .into();
+
+                        match (action, self.backpressure_strategy) {
+                            (
+                                BackpressureToReceiverAction::FollowBackpressureyStrategy,
+                                BackpressureStrategy::RetryUntilDeliveredOrTimeout(_),
+                            ) => retry_until_timeout(elapsed_time),
+                            (action, _) => action,
+                        }
                     },
                     backpressure_action_for_strategy,
                 )
@@ -270,25 +393,46 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
                     BackpressureStrategy::DiscardData => {
                         <Service::Connection as ZeroCopyConnection>::Sender::try_send(
                             &connection.sender,
-                            chunk.offset(),
-                            chunk.size(),
+                            offset,
+                            sample_size,
                             channel_id,
                         )
                     }
                     BackpressureStrategy::RetryUntilDelivered => {
                         <Service::Connection as ZeroCopyConnection>::Sender::blocking_send(
                             &connection.sender,
-                            chunk.offset(),
-                            chunk.size(),
+                            offset,
+                            sample_size,
                             channel_id,
                             |_, _| BackpressureToReceiverAction::FollowBackpressureyStrategy,
                             BackpressureToReceiverAction::Retry,
                         )
                     }
+                    BackpressureStrategy::RetryUntilDeliveredOrTimeout(_) => {
+                        <Service::Connection as ZeroCopyConnection>::Sender::blocking_send(
+                            &connection.sender,
+                            offset,
+                            sample_size,
+                            channel_id,
+                            |_, elapsed_time| retry_until_timeout(elapsed_time),
+                            BackpressureToReceiverAction::Retry,
+                        )
+                    }
                 }
             };
 
+            if delivery_call_result.is_err() {
+                self.release_undelivered_chunk(connection, offset);
+            }
+
             match delivery_call_result {
+                Err(ZeroCopySendError::UnableToDeliver) if has_timed_out.get() => {
+                    unsafe { &mut *self.unserved_receivers.get() }
+                        .push(connection.receiver_port_id);
+                    fail!(from self, with SendError::DeliveryTimeout,
+                          "{msg} {:?} could not be delivered to receiver {:?} within the timeout.",
+                          chunk, connection.receiver_port_id);
+                }
                 Err(ZeroCopySendError::UnableToDeliver) => {
                     // can only happen with blocking send and the degradation handler triggered a failure
                     fail!(from self, with SendError::UnableToDeliver,
@@ -340,11 +484,15 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
                     }
                 }
                 Ok(overflow) => {
-                    self.borrow_chunk(chunk.offset());
+                    self.borrow_delivered_chunk(connection, offset);
                     number_of_recipients += 1;
+                    connection
+                        .outstanding_chunks
+                        .fetch_add(1, Ordering::Relaxed);
 
                     if let Some(old) = overflow {
-                        self.release_chunk(old)
+                        connection.release_outstanding_chunk();
+                        self.release_delivered_chunk(connection, old)
                     }
                 }
             }
@@ -352,6 +500,98 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
         Ok(number_of_recipients)
     }
 
+    /// Returns the offset and the sample size under which the chunk is delivered to the
+    /// connection. When the connection has a receive area, the chunk is copied into it.
+    fn prepare_delivery(
+        &self,
+        connection: &Connection<Service, Resource>,
+        chunk: &ChunkMut,
+    ) -> Result<(PointerOffset, usize), AllocationError> {
+        let receive_area = match &connection.receive_area {
+            Some(receive_area) => receive_area,
+            None => return Ok((chunk.offset(), self.sample_size_of(chunk))),
+        };
+
+        let layout = match Layout::from_size_align(chunk.size(), self.chunk_layout(1).align()) {
+            Ok(layout) => layout,
+            Err(_) => {
+                fail!(from self, with AllocationError::SizeTooLarge,
+                    "Unable to copy {:?} into the receive area since the chunk size exceeds the maximum supported size.",
+                    chunk);
+            }
+        };
+        let copy = receive_area.allocate(layout)?;
+        unsafe { core::ptr::copy_nonoverlapping(chunk.header_ptr(), copy.data_ptr, chunk.size()) };
+
+        Ok((
+            copy.offset,
+            receive_area.bucket_size(copy.offset.segment_id()),
+        ))
+    }
+
+    fn borrow_delivered_chunk(
+        &self,
+        connection: &Connection<Service, Resource>,
+        offset: PointerOffset,
+    ) {
+        // a copy in the receive area is owned by the connection and not tracked
+        if connection.receive_area.is_none() {
+            self.borrow_chunk(offset);
+        }
+    }
+
+    fn release_delivered_chunk(
+        &self,
+        connection: &Connection<Service, Resource>,
+        offset: PointerOffset,
+    ) {
+        match &connection.receive_area {
+            Some(receive_area) => unsafe { receive_area.deallocate_bucket(offset) },
+            None => self.release_chunk(offset),
+        }
+    }
+
+    fn release_undelivered_chunk(
+        &self,
+        connection: &Connection<Service, Resource>,
+        offset: PointerOffset,
+    ) {
+        if let Some(receive_area) = &connection.receive_area {
+            unsafe { receive_area.deallocate_bucket(offset) };
+        }
+    }
+
+    /// Delivers a chunk that was already sent, like a sample of the history, to a single
+    /// connection without blocking.
+    pub(crate) fn try_redeliver_to_connection(
+        &self,
+        connection: &Connection<Service, Resource>,
+        chunk: &ChunkMut,
+        channel_id: ChannelId,
+    ) -> Result<(), ZeroCopySendError> {
+        self.retrieve_returned_chunks();
+        let (offset, sample_size) = match self.prepare_delivery(connection, chunk) {
+            Ok(v) => v,
+            // the receiver holds too many chunks of its receive area
+            Err(_) => return Err(ZeroCopySendError::UsedChunkListFull),
+        };
+
+        match connection.sender.try_send(offset, sample_size, channel_id) {
+            Ok(overflow) => {
+                self.borrow_delivered_chunk(connection, offset);
+
+                if let Some(old) = overflow {
+                    self.release_delivered_chunk(connection, old);
+                }
+                Ok(())
+            }
+            Err(e) => {
+                self.release_undelivered_chunk(connection, offset);
+                Err(e)
+            }
+        }
+    }
+
     pub(crate) fn has_disconnect_hint(
         &self,
         channel_id: ChannelId,
@@ -389,14 +629,56 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
         }
     }
 
+    /// Returns the port ids of all receivers that could not be served within the timeout of
+    /// [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`] in the last send operation.
+    pub(crate) fn unserved_receivers(&self) -> &[u128] {
+        unsafe { &*self.unserved_receivers.get() }
+    }
+
+    fn start_send_operation(&self) -> Option<Time> {
+        unsafe { &mut *self.unserved_receivers.get() }.clear();
+
+        match self.backpressure_strategy {
+            BackpressureStrategy::RetryUntilDeliveredOrTimeout(_) => Time::now().ok(),
+            _ => None,
+        }
+    }
+
+    // every delivered chunk gets a unique sequence number, in contrast to the offset of the
+    // chunk it is never reused and identifies the delivery also on the receiver side
+    fn assign_sequence_number(&self, chunk: &ChunkMut) -> u64 {
+        let sequence_number = self.sequence_number.fetch_add(1, Ordering::Relaxed);
+        unsafe { write_sequence_number(chunk.header, sequence_number) };
+        sequence_number
+    }
+
     pub(crate) fn deliver_offset_to_connection(
         &self,
         chunk: &ChunkMut,
         channel_id: ChannelId,
         connection_id: usize,
     ) -> Result<usize, SendError> {
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
+        #[cfg_attr(not(feature = "trace_points"), allow(unused_variables))]
+        let sequence_number = self.assign_sequence_number(chunk);
+        let send_start = self.start_send_operation();
         self.retrieve_returned_chunks();
-        self.deliver_offset_to_connection_impl(chunk, channel_id, connection_id)
+        let result =
+            self.deliver_offset_to_connection_impl(chunk, channel_id, connection_id, send_start);
+
+        #[cfg(feature = "trace_points")]
+        if let Some(span) = span {
+            span.finish(
+                TracePointKind::Send,
+                self.sender_port_id,
+                self.sender_port_id,
+                sequence_number,
+            );
+        }
+
+        result
     }
 
     pub(crate) fn deliver_offset(
@@ -404,12 +686,18 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
         chunk: &ChunkMut,
         channel_id: ChannelId,
     ) -> Result<usize, SendError> {
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
+        #[cfg_attr(not(feature = "trace_points"), allow(unused_variables))]
+        let sequence_number = self.assign_sequence_number(chunk);
+        let send_start = self.start_send_operation();
         self.retrieve_returned_chunks();
 
         let mut number_of_recipients = 0;
         let mut delivery_error = None;
         for i in 0..self.len() {
-            match self.deliver_offset_to_connection_impl(chunk, channel_id, i) {
+            match self.deliver_offset_to_connection_impl(chunk, channel_id, i, send_start) {
                 Ok(n) => number_of_recipients += n,
                 Err(error) => match error {
                     SendError::ConnectionCorrupted => {
@@ -417,12 +705,15 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
                         // an existing `UnableToDeliver` error
                         delivery_error = Some(error)
                     }
-                    SendError::UnableToDeliver if delivery_error.is_none() => {
-                        // only store the `UnableToDeliver` if there is no existing error
-                        // to prevent overriding a higher priority `ConnectionCorrupted` error
+                    SendError::UnableToDeliver | SendError::DeliveryTimeout
+                        if delivery_error.is_none() =>
+                    {
+                        // only store the `UnableToDeliver` or `DeliveryTimeout` if there is no
+                        // existing error to prevent overriding a higher priority
+                        // `ConnectionCorrupted` error
                         delivery_error = Some(error)
                     }
-                    SendError::UnableToDeliver => {
+                    SendError::UnableToDeliver | SendError::DeliveryTimeout => {
                         // there is already an error stored; nothing to do
                     }
                     e => {
@@ -436,6 +727,17 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
                 },
             }
         }
+
+        #[cfg(feature = "trace_points")]
+        if let Some(span) = span {
+            span.finish(
+                TracePointKind::Send,
+                self.sender_port_id,
+                self.sender_port_id,
+                sequence_number,
+            );
+        }
+
         if let Some(e) = delivery_error {
             Err(e)
         } else {
@@ -470,6 +772,9 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
     }
 
     pub(crate) fn allocate(&self, layout: Layout) -> Result<ChunkMut, LoanError> {
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
         self.retrieve_returned_chunks();
         let msg = "Unable to allocate data";
 
@@ -494,13 +799,30 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
             }
         };
 
-        let (ref_count, chunk_size) = self.borrow_chunk(shm_pointer.offset);
+        let (ref_count, bucket_size) = self.borrow_chunk(shm_pointer.offset);
         if ref_count != 0 {
             fatal_panic!(from self,
                 "{} since the allocated chunk is already in use! This should never happen!", msg);
         }
 
         self.loan_counter.fetch_add(1, Ordering::Relaxed);
+
+        #[cfg(feature = "trace_points")]
+        if let Some(span) = span {
+            span.finish(
+                TracePointKind::Loan,
+                self.sender_port_id,
+                self.sender_port_id,
+                shm_pointer.offset.as_value(),
+            );
+        }
+
+        // a buddy allocated chunk provides exactly the requested memory
+        let chunk_size = match self.data_segment.allocator() {
+            DataSegmentAllocator::Pool => bucket_size,
+            DataSegmentAllocator::Buddy => layout.size(),
+        };
+
         Ok(ChunkMut::new(
             &self.message_type_details,
             shm_pointer,
@@ -508,14 +830,24 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
         ))
     }
 
+    /// Returns the size the receivers derive the chunk index from. It is the size of the chunk
+    /// for a pool allocated data segment and the minimum block size for a buddy allocated one.
+    pub(crate) fn sample_size_of(&self, chunk: &ChunkMut) -> usize {
+        match self.data_segment.allocator() {
+            DataSegmentAllocator::Pool => chunk.size(),
+            DataSegmentAllocator::Buddy => {
+                self.data_segment.bucket_size(chunk.offset().segment_id())
+            }
+        }
+    }
+
     pub(crate) fn borrow_chunk(&self, offset: PointerOffset) -> (u64, usize) {
         let segment_id = offset.segment_id();
         let segment_state = &self.segment_states[segment_id.value() as usize];
-        let mut payload_size = segment_state.payload_size();
-        if segment_state.payload_size() == 0 {
-            payload_size = self.data_segment.bucket_size(segment_id);
-            segment_state.set_payload_size(payload_size);
-        }
+        // the ids of released segments are reused by segments with a different bucket size,
+        // therefore the payload size cannot be cached for the lifetime of the sender
+        let payload_size = self.data_segment.bucket_size(segment_id);
+        segment_state.set_payload_size(payload_size);
         (segment_state.borrow_chunk(offset.offset()), payload_size)
     }
 
@@ -527,7 +859,8 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
                     loop {
                         match connection.sender.reclaim(id) {
                             Ok(Some(ptr_dist)) => {
-                                self.release_chunk(ptr_dist);
+                                connection.release_outstanding_chunk();
+                                self.release_delivered_chunk(connection, ptr_dist);
                             }
                             Ok(None) => break,
                             Err(e) => {
@@ -554,13 +887,16 @@ impl<Service: service::Service, Resource: ServiceResource> Sender<Service, Resou
 
     fn remove_connection(&self, i: usize) {
         if let Some(connection) = self.get(i) {
-            // # SAFETY: the receiver no longer exist, therefore we can
-            //           reacquire all delivered chunks
-            unsafe {
-                connection
-                    .sender
-                    .acquire_used_offsets(|offset| self.release_chunk(offset))
-            };
+            // the copies in a receive area are released together with the receive area
+            if connection.receive_area.is_none() {
+                // # SAFETY: the receiver no longer exist, therefore we can
+                //           reacquire all delivered chunks
+                unsafe {
+                    connection
+                        .sender
+                        .acquire_used_offsets(|offset| self.release_chunk(offset))
+                };
+            }
 
             *self.get_mut(i) = None;
         }
@@ -20,7 +20,7 @@ pub use iceoryx2_cal::event::EventId;
 pub use iceoryx2_cal::event::event_state::EventActivation;
 
 pub(crate) mod details;
-pub use details::data_segment::DataSegmentType;
+pub use details::data_segment::{DataSegmentAllocator, DataSegmentType, PayloadDelivery};
 
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
@@ -49,6 +49,9 @@ pub mod writer;
 /// Defines the strategy a sender shall pursue when the buffer of a
 /// receiver is full and the service does not overflow.
 pub mod backpressure_strategy;
+/// Defines to which [`Server`](crate::port::server::Server)s a
+/// [`Client`](crate::port::client::Client) delivers a request.
+pub mod request_dispatch_policy;
 
 pub use iceoryx2_cal::zero_copy_connection::BackpressureToReceiverAction;
 
@@ -249,6 +252,14 @@ pub enum SendError {
     UnableToDeliver,
     /// An internal mechanisms failed and the data could not be delivered to all receivers.
     InternalError,
+    /// The data could not be delivered to all receivers within the timeout of
+    /// [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`](crate::port::backpressure_strategy::BackpressureStrategy::RetryUntilDeliveredOrTimeout).
+    /// The receivers that were not served in time can be acquired from the sending port, e.g.
+    /// [`Publisher::subscribers_not_served_in_time()`](crate::port::publisher::Publisher::subscribers_not_served_in_time()).
+    DeliveryTimeout,
+    /// The response was not sent since the deadline of the corresponding request was already
+    /// reached. The client would discard it anyway.
+    RequestExpired,
 }
 
 impl From<LoanError> for SendError {
@@ -676,6 +676,9 @@ impl<Service: service::Service> Notifier<Service> {
         value: EventId,
         skip_self_deliver: bool,
     ) -> Result<usize, NotifierNotifyError> {
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
         let msg = "Unable to notify event";
         let listener_connections = self.listener_connections.lock();
         listener_connections.update_connections();
@@ -710,6 +713,16 @@ impl<Service: service::Service> Notifier<Service> {
 
         self.handle_deadline(&listener_connections)?;
 
+        #[cfg(feature = "trace_points")]
+        if let Some(span) = span {
+            span.finish(
+                crate::trace::TracePointKind::Notify,
+                self.notifier_details.notifier_id.value(),
+                0,
+                value.as_value() as u64,
+            );
+        }
+
         Ok(number_of_triggered_listeners)
     }
 
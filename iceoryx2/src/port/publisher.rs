@@ -110,7 +110,7 @@ use core::{marker::PhantomData, mem::MaybeUninit};
 use alloc::vec::Vec;
 
 use iceoryx2_bb_concurrency::atomic::Ordering;
-use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicUsize};
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, AtomicUsize};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::CallbackProgression;
@@ -121,12 +121,13 @@ use iceoryx2_bb_elementary_traits::iceoryx_send::IceoryxSend;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shared_memory::{SharedMemoryCreateError, ShmPointer};
 use iceoryx2_cal::shm_allocator::PointerOffset;
 use iceoryx2_cal::zero_copy_connection::{
-    CHANNEL_STATE_OPEN, ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySender,
+    CHANNEL_STATE_OPEN, ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails,
 };
 use iceoryx2_log::{fail, warn};
 
@@ -137,6 +138,7 @@ use crate::port::port_name::PortName;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::prelude::{BackpressureStrategy, Flatbuffer};
 use crate::sample_mut::SampleMut;
+use crate::sample_mut_builder::SampleMutBuilder;
 use crate::sample_mut_uninit::SampleMutUninit;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
@@ -147,10 +149,12 @@ use crate::service::resource::publish_subscribe::PublishSubscribeResources;
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::{self};
 
-use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::data_segment::{
+    DataSegment, DataSegmentAllocator, DataSegmentType, ReceiveAreaConfig,
+};
 use super::details::segment_state::SegmentState;
 use super::{LoanError, SendError};
-use crate::identifiers::UniquePublisherId;
+use crate::identifiers::{UniquePublisherId, UniqueSubscriberId};
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
@@ -163,11 +167,21 @@ pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     /// The datasegment in which the payload of the [`Publisher`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The data segment of the [`Publisher`] shall be backed by huge pages but the system does
+    /// not support them.
+    DataSegmentHugePagesNotSupported,
+    /// The data segment of the [`Publisher`] could not be locked in memory, for instance since
+    /// the memory lock limit of the process is exceeded.
+    UnableToLockDataSegment,
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
     /// The tracking port tag, required for cleanup, could not be created.
     UnableToCreatePortTag,
+    /// The version of the payload type differs from the version of the
+    /// [`Service`](crate::service::Service). Only [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// can use a different but compatible version.
+    IncompatiblePayloadVersion,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -179,15 +193,17 @@ impl core::fmt::Display for PublisherCreateError {
 impl core::error::Error for PublisherCreateError {}
 
 #[derive(Debug, Clone, Copy)]
-struct OffsetAndSize {
+struct HistoryEntry {
     offset: u64,
+    address: usize,
     size: usize,
 }
 
-impl OffsetAndSize {
+impl HistoryEntry {
     fn new(chunk: &ChunkMut) -> Self {
         Self {
             offset: chunk.offset.as_value(),
+            address: chunk.header_ptr() as usize,
             size: chunk.size(),
         }
     }
@@ -199,7 +215,7 @@ pub struct PublisherSharedState<Service: service::Service> {
     config: LocalPublisherConfig,
     pub(crate) sender: Sender<Service, PublishSubscribeResources<Service>>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
-    history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
+    history: Option<UnsafeCell<Queue<HistoryEntry>>>,
     is_active: AtomicBool,
     // IMPORTANT!
     // Fields of a rust struct are dropped in declaration order. Since this tag is our marker that the
@@ -274,7 +290,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
                 self.sender.borrow_chunk(chunk.offset());
-                match history.push_with_overflow(OffsetAndSize::new(chunk)) {
+                match history.push_with_overflow(HistoryEntry::new(chunk)) {
                     None => (),
                     Some(old) => self
                         .sender
@@ -343,23 +359,21 @@ impl<Service: service::Service> PublisherSharedState<Service> {
 
                 for i in history_start..history.len() {
                     let old_sample = unsafe { history.get_unchecked(i) };
-                    self.sender.retrieve_returned_chunks();
-
-                    let offset = PointerOffset::from_value(old_sample.offset);
-                    match connection
-                        .sender
-                        .try_send(offset, old_sample.size, ChannelId::new(0))
-                    {
-                        Ok(overflow) => {
-                            self.sender.borrow_chunk(offset);
-
-                            if let Some(old) = overflow {
-                                self.sender.release_chunk(old);
-                            }
-                        }
-                        Err(e) => {
-                            warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
-                        }
+                    let chunk = ChunkMut::new(
+                        &self.sender.message_type_details,
+                        ShmPointer {
+                            offset: PointerOffset::from_value(old_sample.offset),
+                            data_ptr: old_sample.address as *mut u8,
+                        },
+                        old_sample.size,
+                    );
+
+                    if let Err(e) = self.sender.try_redeliver_to_connection(
+                        connection,
+                        &chunk,
+                        ChannelId::new(0),
+                    ) {
+                        warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                     }
                 }
             }
@@ -463,8 +477,25 @@ impl<
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
         let port_id = UniquePublisherId::new();
-        let config = &publisher_factory.config;
+        let mut config = publisher_factory.config;
+        if config.data_segment_allocator == DataSegmentAllocator::Buddy {
+            // a buddy allocated data segment is never reallocated
+            config.allocation_strategy = AllocationStrategy::Static;
+        }
+        let config = &config;
         let service = &publisher_factory.factory.service;
+
+        let local_payload_version = publisher_factory.factory.local_payload_type.version;
+        let service_payload_version = service
+            .static_config()
+            .publish_subscribe()
+            .message_type_details
+            .payload_version;
+        if local_payload_version != service_payload_version {
+            fail!(from origin, with PublisherCreateError::IncompatiblePayloadVersion,
+                "{msg} since the payload type has the version {local_payload_version} but the service uses the version {service_payload_version}.");
+        }
+
         // !MUST! be the first thing that is created when a new port is instantiated otherwise the
         // port resources might leak if this process is killed in between.
         let port_tag = match service
@@ -511,38 +542,77 @@ impl<
         let max_slice_len = config.initial_max_slice_len;
         let max_number_of_segments =
             DataSegment::<Service>::max_number_of_segments(data_segment_type);
-        let publisher_details = PublisherDetails {
-            data_segment_type,
-            publisher_id: port_id,
-            publisher_name: config.port_name,
-            number_of_samples,
-            max_slice_len,
-            node_id: *service.shared_node().id(),
-            max_number_of_segments,
-        };
         let global_config = service.shared_node().config();
 
-        let segment_name = data_segment_name(publisher_details.publisher_id.value());
-        let data_segment = match data_segment_type {
-            DataSegmentType::Static => DataSegment::create_static_segment(
-                &segment_name,
-                sample_layout,
-                global_config,
-                number_of_samples,
-            ),
-            DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
+        let segment_name = data_segment_name(port_id.value());
+        let data_segment = match (data_segment_type, config.data_segment_allocator) {
+            (DataSegmentType::Static, DataSegmentAllocator::Pool) => {
+                DataSegment::create_static_segment(
+                    &segment_name,
+                    sample_layout,
+                    global_config,
+                    number_of_samples,
+                    static_config.data_segment_memory.memory_properties(),
+                )
+            }
+            (_, DataSegmentAllocator::Buddy) => DataSegment::create_buddy_segment(
                 &segment_name,
                 sample_layout,
                 global_config,
                 number_of_samples,
-                config.allocation_strategy,
+                static_config.data_segment_memory.memory_properties(),
             ),
+            (DataSegmentType::Dynamic, DataSegmentAllocator::Pool) => {
+                DataSegment::create_dynamic_segment(
+                    &segment_name,
+                    sample_layout,
+                    global_config,
+                    number_of_samples,
+                    config.allocation_strategy,
+                    config.segment_idle_timeout,
+                    static_config.data_segment_memory.memory_properties(),
+                )
+            }
         };
 
-        let data_segment = fail!(from origin,
-                when data_segment,
-                with PublisherCreateError::UnableToCreateDataSegment,
-                "{} since the data segment could not be acquired.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(SharedMemoryCreateError::HugePagesNotSupported) => {
+                fail!(from origin, with PublisherCreateError::DataSegmentHugePagesNotSupported,
+                    "{} since the data segment cannot be backed by huge pages on this system.", msg);
+            }
+            Err(SharedMemoryCreateError::MemoryLockFailed) => {
+                fail!(from origin, with PublisherCreateError::UnableToLockDataSegment,
+                    "{} since the data segment could not be locked in memory.", msg);
+            }
+            Err(_) => {
+                fail!(from origin, with PublisherCreateError::UnableToCreateDataSegment,
+                    "{} since the data segment could not be acquired.", msg);
+            }
+        };
+
+        let receive_area_config = ReceiveAreaConfig::new::<Service>(
+            sample_layout,
+            static_config.subscriber_max_buffer_size,
+            static_config.subscriber_max_borrowed_samples,
+            1,
+            static_config.data_segment_memory.memory_properties(),
+        );
+        let number_of_chunks = data_segment.number_of_tracked_chunks_per_connection(
+            number_of_samples,
+            receive_area_config.as_ref(),
+        );
+        let publisher_details = PublisherDetails {
+            data_segment_type,
+            data_segment_allocator: config.data_segment_allocator,
+            publisher_id: port_id,
+            publisher_name: config.port_name,
+            number_of_samples: number_of_chunks,
+            max_slice_len,
+            node_id: *service.shared_node().id(),
+            max_number_of_segments,
+            payload_delivery: Service::__internal_payload_delivery(),
+        };
 
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
@@ -550,11 +620,12 @@ impl<
                 is_active: AtomicBool::new(true),
                 sender: Sender {
                     data_segment,
+                    receive_area_config,
                     segment_states: {
                         let mut v: Vec<SegmentState> =
                             Vec::with_capacity(max_number_of_segments as usize);
                         for _ in 0..max_number_of_segments {
-                            v.push(SegmentState::new(number_of_samples))
+                            v.push(SegmentState::new(number_of_chunks))
                         }
                         v
                     },
@@ -566,7 +637,7 @@ impl<
                     receiver_max_buffer_size: static_config.subscriber_max_buffer_size,
                     receiver_max_borrowed_chunks: static_config.subscriber_max_borrowed_samples,
                     enable_safe_overflow: static_config.enable_safe_overflow,
-                    number_of_chunks: number_of_samples,
+                    number_of_chunks,
                     max_number_of_segments,
                     degradation_handler: publisher_factory.degradation_handler,
                     backpressure_handler: publisher_factory.backpressure_handler,
@@ -578,6 +649,10 @@ impl<
                     message_type_details: static_config.message_type_details,
                     number_of_channels: 1,
                     initial_channel_state: CHANNEL_STATE_OPEN,
+                    unserved_receivers: UnsafeCell::new(Vec::with_capacity(
+                        subscriber_list.capacity(),
+                    )),
+                    sequence_number: AtomicU64::new(0),
                 },
                 config: *config,
                 subscriber_list_state: UnsafeCell::new(unsafe { subscriber_list.get_state() }),
@@ -647,6 +722,21 @@ impl<
             .backpressure_strategy
     }
 
+    /// Returns the [`UniqueSubscriberId`]s of all
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s that did not receive the last sent
+    /// [`SampleMut`] since they could not be served within the timeout of
+    /// [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`]. When the last send operation
+    /// did not fail with [`SendError::DeliveryTimeout`] the list is empty.
+    pub fn subscribers_not_served_in_time(&self) -> Vec<UniqueSubscriberId> {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .unserved_receivers()
+            .iter()
+            .map(|id| UniqueSubscriberId(UniqueSystemId::from(*id)))
+            .collect()
+    }
+
     fn loan_chunk(&self, slice_len: usize) -> Result<ChunkMut, LoanError>
     where
         UserHeader: Default,
@@ -896,6 +986,47 @@ impl<
         self.loan_slice_uninit_impl(number_of_elements)
     }
 
+    /// Loans/allocates a [`SampleMutBuilder`] from the underlying data segment of the
+    /// [`Publisher`] to which elements can be appended without knowing the final slice length
+    /// in advance. When the loaned memory is exhausted it grows inside the data segment
+    /// according to the [`AllocationStrategy`] of the [`Publisher`]. The slice length is
+    /// finalized when the sample is sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    /// #                        .initial_max_slice_len(8)
+    /// #                        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+    /// #                        .create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_builder()?;
+    /// for n in 0..20 {
+    ///     sample.push(n * 2)?;
+    /// }
+    ///
+    /// sample.send()?;
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn loan_slice_builder(
+        &self,
+    ) -> Result<SampleMutBuilder<Service, Payload, UserHeader>, LoanError> {
+        // required since Rust does not support generic specializations or negative traits
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        SampleMutBuilder::new(&self.publisher_shared_state, self.loan_chunk(1)?)
+    }
+
     fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
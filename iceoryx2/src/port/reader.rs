@@ -35,20 +35,23 @@
 //! # }
 //! ```
 
-use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
+use crate::constants::{MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT, MAX_BLACKBOARD_KEY_SIZE};
 use crate::identifiers::UniqueReaderId;
 use crate::port::port_name::PortName;
 use crate::prelude::EventId;
 use crate::service::dynamic_config::blackboard::ReaderDetails;
 use crate::service::marker::CustomKeyMarker;
 use crate::service::port_factory::reader::ReaderConfig;
-use crate::service::resource::blackboard::{BlackboardResources, KeyMemory};
+use crate::service::resource::blackboard::{
+    BlackboardResources, DynamicEntry, KeyMemory, dynamic_value_cell_size, dynamic_value_ptrs,
+};
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self, SharedServiceState};
 use core::alloc::Layout;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use core::mem::MaybeUninit;
 use core::ops::Deref;
 use core::ptr::NonNull;
 use iceoryx2_bb_concurrency::atomic::Ordering;
@@ -64,26 +67,26 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::shared_memory::SharedMemory;
 use iceoryx2_log::{fail, fatal_panic};
 
-/// A wrapper for the value returned by [`EntryHandle::get()`].
-pub struct BlackboardValue<ValueType: Copy> {
+/// A wrapper for the value returned by [`EntryHandle::get()`] and [`DynamicEntryHandle::get()`].
+pub struct BlackboardValue<ValueType> {
     value: ValueType,
     generation_counter: u64,
 }
 
-impl<ValueType: Copy> Deref for BlackboardValue<ValueType> {
+impl<ValueType> Deref for BlackboardValue<ValueType> {
     type Target = ValueType;
     fn deref(&self) -> &Self::Target {
         &self.value
     }
 }
 
-impl<ValueType: Copy + core::fmt::Display> core::fmt::Display for BlackboardValue<ValueType> {
+impl<ValueType: core::fmt::Display> core::fmt::Display for BlackboardValue<ValueType> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.value)
     }
 }
 
-impl<ValueType: Copy + Debug> Debug for BlackboardValue<ValueType> {
+impl<ValueType: Debug> Debug for BlackboardValue<ValueType> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
@@ -372,6 +375,98 @@ impl<
 
         Ok(offset)
     }
+
+    /// Creates a [`DynamicEntryHandle`] for direct read access to the value of an entry that
+    /// was added at runtime with
+    /// [`Writer::add_entry()`](crate::port::writer::Writer::add_entry).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # use iceoryx2_bb_container::string::StaticString;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .max_dynamic_entries(1)
+    /// #     .max_dynamic_value_size(128)
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// # writer.add_entry(&1, StaticString::<64>::from_bytes(b"some parameter")?)?;
+    /// # let reader = service.reader_builder().create()?;
+    /// let entry_handle = reader.dynamic_entry::<StaticString<64>>(&1)?;
+    /// let value = entry_handle.get()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dynamic_entry<ValueType: ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<DynamicEntryHandle<Service, KeyType, ValueType>, EntryHandleError> {
+        let msg = "Unable to create dynamic entry handle";
+
+        let key_mem = match KeyMemory::try_from(key) {
+            Ok(mem) => mem,
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! Key with invalid layout passed.");
+            }
+        };
+
+        let shared_state = self.shared_state.lock();
+        let resources = shared_state.service_state.additional_resource();
+        let mgmt = resources.mgmt.get();
+
+        let (index, snapshot) =
+            match mgmt.find_dynamic_entry(&key_mem, resources.key_eq_func.as_ref()) {
+                Some(v) => v,
+                None => {
+                    fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                        "{} since no dynamic entry with the given key exists.", msg);
+                }
+            };
+
+        if snapshot.type_details != TypeDetail::new::<ValueType>(TypeVariant::FixedSize) {
+            fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                "{} since no dynamic entry with the given key and value type exists.", msg);
+        }
+
+        let entry = &mgmt.dynamic_entries[index];
+        let (atomic_mgmt_ptr, data_ptr) =
+            dynamic_value_ptrs(resources.data.payload_start_address(), entry.offset);
+
+        Ok(DynamicEntryHandle {
+            entry: entry as *const DynamicEntry,
+            sequence_number: snapshot.sequence_number,
+            atomic_mgmt_ptr,
+            data_ptr,
+            cell_size: dynamic_value_cell_size(
+                shared_state
+                    .service_state
+                    .static_config()
+                    .blackboard()
+                    .max_dynamic_value_size,
+            ),
+            entry_id: EventId::new(entry.offset as _),
+            _value: PhantomData,
+            _shared_state: self.shared_state.clone(),
+        })
+    }
+
+    /// Returns the [`EventId`] that the writer uses in an event based communication setup to
+    /// inform readers that entries were added or removed.
+    pub fn key_set_event_id(&self) -> EventId {
+        EventId::new(
+            self.shared_state
+                .lock()
+                .service_state
+                .additional_resource()
+                .mgmt
+                .get()
+                .key_set_event_id,
+        )
+    }
 }
 
 /// Defines a failure that can occur when a [`EntryHandle`] is created with [`Reader::entry()`].
@@ -500,6 +595,112 @@ impl<
     }
 }
 
+/// A handle for direct read access to the value of an entry that was added at runtime with
+/// [`Writer::add_entry()`](crate::port::writer::Writer::add_entry).
+pub struct DynamicEntryHandle<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> {
+    entry: *const DynamicEntry,
+    sequence_number: u64,
+    atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+    data_ptr: *const u8,
+    cell_size: usize,
+    entry_id: EventId,
+    _value: PhantomData<ValueType>,
+    _shared_state: Service::ArcThreadSafetyPolicy<ReaderSharedState<Service, KeyType>>,
+}
+
+// Safe since the pointers into the blackboard segments don't change, the pointees implement
+// Send + Sync, and shared_state ensures their lifetime
+unsafe impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Send for DynamicEntryHandle<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Sync for DynamicEntryHandle<Service, KeyType, ValueType>
+{
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Debug for DynamicEntryHandle<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DynamicEntryHandle<{}, {}, {}> {{ entry_id: {:?}, sequence_number: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<KeyType>(),
+            core::any::type_name::<ValueType>(),
+            self.entry_id,
+            self.sequence_number
+        )
+    }
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> DynamicEntryHandle<Service, KeyType, ValueType>
+{
+    /// Returns a bitwise copy of the value wrapped in a [`BlackboardValue`]. When the entry was
+    /// removed in the meantime, [`EntryHandleError::EntryDoesNotExist`] is returned.
+    pub fn get(&self) -> Result<BlackboardValue<ValueType>, EntryHandleError> {
+        let mut value: MaybeUninit<ValueType> = MaybeUninit::uninit();
+        let generation_counter = unsafe { (*self.atomic_mgmt_ptr).__internal_get_write_cell() };
+        unsafe {
+            (*self.atomic_mgmt_ptr).__internal_load_from_cell(
+                value.as_mut_ptr().cast(),
+                core::mem::size_of::<ValueType>(),
+                self.cell_size,
+                MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+                self.data_ptr,
+            )
+        };
+
+        // the cells may have been reused by another entry, therefore the value must not be
+        // touched before it is ensured that the entry still exists
+        if !self.exists() {
+            fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                "Unable to get the value since the entry was removed.");
+        }
+
+        Ok(BlackboardValue {
+            value: unsafe { value.assume_init() },
+            // The generation_counter may be outdated, see EntryHandle::get()
+            generation_counter,
+        })
+    }
+
+    /// Checks if the passed `value` is up-to-date. Returns false when the entry was removed.
+    pub fn is_up_to_date(&self, value: &BlackboardValue<ValueType>) -> bool {
+        let write_cell = unsafe { (*self.atomic_mgmt_ptr).__internal_get_write_cell() };
+        write_cell == value.generation_counter && self.exists()
+    }
+
+    /// Returns true as long as the entry was not removed by the writer.
+    pub fn exists(&self) -> bool {
+        unsafe { (*self.entry).is_unchanged_since(self.sequence_number) }
+    }
+
+    /// Returns an ID corresponding to the entry which can be used in an event based communication
+    /// setup.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
 impl<Service: service::Service> Reader<Service, CustomKeyMarker> {
     #[doc(hidden)]
     /// # Safety
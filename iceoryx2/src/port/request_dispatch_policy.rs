@@ -0,0 +1,40 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::identifiers::UniqueServerId;
+
+/// Defines to which of the connected [`Server`](crate::port::server::Server)s a
+/// [`Client`](crate::port::client::Client) delivers a
+/// [`RequestMut`](crate::request_mut::RequestMut).
+///
+/// The policy of a [`Client`](crate::port::client::Client) is set with
+/// [`PortFactoryClient::request_dispatch_policy()`](crate::service::port_factory::client::PortFactoryClient::request_dispatch_policy())
+/// and can be overridden for a single request with
+/// [`RequestMut::send_with_dispatch_policy()`](crate::request_mut::RequestMut::send_with_dispatch_policy()).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum RequestDispatchPolicy {
+    /// The request is delivered to every connected [`Server`](crate::port::server::Server).
+    #[default]
+    Broadcast,
+    /// The request is delivered to exactly one [`Server`](crate::port::server::Server). The
+    /// [`Client`](crate::port::client::Client) cycles through all connected
+    /// [`Server`](crate::port::server::Server)s with every request.
+    RoundRobin,
+    /// The request is delivered to the [`Server`](crate::port::server::Server) with the least
+    /// requests that were delivered by this [`Client`](crate::port::client::Client) but were
+    /// not yet released by the [`Server`](crate::port::server::Server).
+    LeastOutstandingRequests,
+    /// The request is delivered only to the [`Server`](crate::port::server::Server) with the
+    /// provided [`UniqueServerId`]. If the [`Server`](crate::port::server::Server) is not
+    /// connected, the request is delivered to no one.
+    Target(UniqueServerId),
+}
@@ -92,11 +92,12 @@ use crate::{
     },
 };
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::ptr::NonNull;
 use core::{fmt::Debug, marker::PhantomData};
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, AtomicUsize};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::vector::polymorphic_vec::*;
@@ -108,14 +109,15 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_flatbuffers::AllocationStrategy;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shared_memory::{SharedMemoryCreateError, ShmPointer};
 use iceoryx2_cal::shm_allocator::PointerOffset;
 use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_CLOSED, CHANNEL_STATE_OPEN, ChannelId};
 use iceoryx2_log::{fail, warn};
 
-use super::details::data_segment::DataSegment;
+use super::details::data_segment::{DataSegment, ReceiveAreaConfig};
 use super::details::segment_state::SegmentState;
 use super::details::sender::{ReceiverDetails, Sender};
 use super::{
@@ -123,12 +125,12 @@ use super::{
     details::{
         chunk::Chunk,
         chunk_details::ChunkDetails,
-        data_segment::DataSegmentType,
+        data_segment::{DataSegmentAllocator, DataSegmentType},
         receiver::{Receiver, SenderDetails},
     },
     update_connections::ConnectionFailure,
 };
-use crate::identifiers::UniqueServerId;
+use crate::identifiers::{UniqueClientId, UniqueServerId};
 
 // All requests are received via one channel with id 0
 const REQUEST_CHANNEL_ID: ChannelId = ChannelId::new(0);
@@ -252,6 +254,8 @@ impl<Service: service::Service> SharedServerState<Service> {
                         number_of_chunks: details.number_of_requests,
                         max_number_of_segments: details.max_number_of_segments,
                         data_segment_type: details.data_segment_type,
+                        data_segment_allocator: DataSegmentAllocator::Pool,
+                        payload_delivery: details.payload_delivery,
                     },
                 );
                 result = result.and(inner_result);
@@ -414,7 +418,7 @@ impl<
         let number_of_to_be_removed_connections = service
             .shared_node()
             .config()
-            .defaults
+            .defaults_for(service.static_config().name())
             .request_response
             .server_expired_connection_buffer;
         let number_of_active_connections = client_list.capacity();
@@ -443,6 +447,7 @@ impl<
             number_of_channels: 1,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
             initial_channel_state: CHANNEL_STATE_OPEN,
+            data_segment_memory: static_config.data_segment_memory.memory_properties(),
         };
 
         let global_config = service.shared_node().config();
@@ -461,6 +466,7 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_responses,
+                static_config.data_segment_memory.memory_properties(),
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
@@ -468,15 +474,41 @@ impl<
                 global_config,
                 number_of_responses,
                 server_factory.config.allocation_strategy,
+                None,
+                static_config.data_segment_memory.memory_properties(),
             ),
         };
 
-        let data_segment = fail!(from origin,
-            when data_segment,
-            with ServerCreateError::UnableToCreateDataSegment,
-            "{} since the server data segment could not be created.", msg);
+        let data_segment = match data_segment {
+            Ok(data_segment) => data_segment,
+            Err(SharedMemoryCreateError::HugePagesNotSupported) => {
+                fail!(from origin, with ServerCreateError::DataSegmentHugePagesNotSupported,
+                    "{} since the server data segment cannot be backed by huge pages on this system.", msg);
+            }
+            Err(SharedMemoryCreateError::MemoryLockFailed) => {
+                fail!(from origin, with ServerCreateError::UnableToLockDataSegment,
+                    "{} since the server data segment could not be locked in memory.", msg);
+            }
+            Err(_) => {
+                fail!(from origin, with ServerCreateError::UnableToCreateDataSegment,
+                    "{} since the server data segment could not be created.", msg);
+            }
+        };
+
+        let receive_area_config = ReceiveAreaConfig::new::<Service>(
+            sample_layout,
+            static_config.max_response_buffer_size,
+            static_config.max_borrowed_responses_per_pending_response,
+            number_of_requests_per_client,
+            static_config.data_segment_memory.memory_properties(),
+        );
+        let number_of_responses = data_segment.number_of_tracked_chunks_per_connection(
+            number_of_responses,
+            receive_area_config.as_ref(),
+        );
 
         let response_sender = Sender {
+            receive_area_config,
             segment_states: {
                 let mut v =
                     alloc::vec::Vec::<SegmentState>::with_capacity(max_number_of_segments as usize);
@@ -508,6 +540,10 @@ impl<
             message_type_details: static_config.response_message_type_details,
             number_of_channels: number_of_requests_per_client,
             initial_channel_state: CHANNEL_STATE_CLOSED,
+            unserved_receivers: UnsafeCell::new(alloc::vec::Vec::with_capacity(
+                client_list.capacity(),
+            )),
+            sequence_number: AtomicU64::new(0),
         };
 
         let shared_state = Service::ArcThreadSafetyPolicy::new(SharedServerState {
@@ -549,6 +585,7 @@ impl<
                 data_segment_type,
                 max_number_of_segments,
                 server_name: server_factory.config.port_name,
+                payload_delivery: Service::__internal_payload_delivery(),
             }) {
             Some(v) => v,
             None => {
@@ -612,6 +649,22 @@ impl<
             .backpressure_strategy
     }
 
+    /// Returns the [`UniqueClientId`]s of all [`Client`](crate::port::client::Client)s that
+    /// did not receive the last sent [`ResponseMut`](crate::response_mut::ResponseMut) since
+    /// they could not be served within the timeout of
+    /// [`BackpressureStrategy::RetryUntilDeliveredOrTimeout`]. When the last send
+    /// operation did not fail with
+    /// [`SendError::DeliveryTimeout`](crate::port::SendError::DeliveryTimeout) the list is empty.
+    pub fn clients_not_served_in_time(&self) -> Vec<UniqueClientId> {
+        self.shared_state
+            .lock()
+            .response_sender
+            .unserved_receivers()
+            .iter()
+            .map(|id| UniqueClientId(UniqueSystemId::from(*id)))
+            .collect()
+    }
+
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         let shared_state = self.shared_state.lock();
         if let Err(e) = shared_state.update_connections() {
@@ -49,12 +49,13 @@ use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::zero_copy_connection::{CHANNEL_STATE_OPEN, ChannelId};
-use iceoryx2_log::{fail, warn};
+use iceoryx2_log::{fail, fatal_panic, warn};
 
 use crate::port::port_name::PortName;
 use crate::port::update_connections::UpdateConnections;
 use crate::service::SharedServiceState;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::port_factory::publish_subscribe::LocalPayloadType;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::resource::publish_subscribe::PublishSubscribeResources;
 use crate::service::static_config::publish_subscribe::StaticConfig;
@@ -63,6 +64,7 @@ use crate::{sample::Sample, service};
 use super::ReceiveError;
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
+use super::details::payload_upgrade_slots::PayloadUpgradeSlots;
 use super::details::receiver::*;
 use super::update_connections::ConnectionFailure;
 use crate::identifiers::UniqueSubscriberId;
@@ -103,6 +105,8 @@ impl core::error::Error for SubscriberCreateError {}
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service, PublishSubscribeResources<Service>>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    // only available when the payload type of the subscriber is newer than the one of the service
+    pub(crate) payload_upgrade_slots: Option<PayloadUpgradeSlots>,
     // IMPORTANT!
     // Fields of a rust struct are dropped in declaration order. Since this tag is our marker that the
     // port exists and might require cleanup after a crash, the tag must be defined as last member of
@@ -199,6 +203,7 @@ impl<
         service: SharedServiceState<Service, PublishSubscribeResources<Service>>,
         static_config: &StaticConfig,
         config: SubscriberConfig,
+        local_payload_type: LocalPayloadType,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
         let origin = "Subscriber::new()";
@@ -258,7 +263,7 @@ impl<
         let subscriber_expired_connection_buffer = service
             .shared_node()
             .config()
-            .defaults
+            .defaults_for(service.static_config().name())
             .publish_subscribe
             .subscriber_expired_connection_buffer;
 
@@ -273,6 +278,16 @@ impl<
             subscriber_max_borrowed_samples
         };
 
+        let service_payload_version = static_config.message_type_details.payload_version;
+        let payload_upgrade_slots =
+            (local_payload_type.version > service_payload_version).then(|| {
+                PayloadUpgradeSlots::new(
+                    local_payload_type.size,
+                    local_payload_type.alignment,
+                    subscriber_max_borrowed_samples,
+                )
+            });
+
         let number_of_active_connections = publisher_list.capacity();
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
@@ -280,6 +295,7 @@ impl<
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
             port_tag,
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
+            payload_upgrade_slots,
             receiver: Receiver {
                 connections: PolymorphicVec::from_fn(
                     HeapAllocator::global(),
@@ -289,6 +305,7 @@ impl<
                 .expect("Heap allocator provides memory."),
                 receiver_port_id: subscriber_id.value(),
                 service_state: service.clone(),
+                data_segment_memory: static_config.data_segment_memory.memory_properties(),
                 message_type_details: static_config.message_type_details,
                 receiver_max_borrowed_chunks: subscriber_max_borrowed_samples,
                 enable_safe_overflow: static_config.enable_safe_overflow,
@@ -333,6 +350,7 @@ impl<
                 subscriber_id,
                 buffer_size,
                 history_request,
+                payload_version: local_payload_type.version.min(service_payload_version),
                 node_id: *service.shared_node().id(),
                 subscriber_name: config.port_name,
             }) {
@@ -370,6 +388,8 @@ impl<
                         number_of_chunks: details.number_of_samples,
                         max_number_of_segments: details.max_number_of_segments,
                         data_segment_type: details.data_segment_type,
+                        data_segment_allocator: details.data_segment_allocator,
+                        payload_delivery: details.payload_delivery,
                     },
                 );
 
@@ -455,15 +475,57 @@ impl<Service: service::Service, Payload: IceoryxSend + Debug, UserHeader: Debug
 {
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    ///
+    /// When the payload type of the [`Subscriber`] is a newer version than the payload type of
+    /// the service, the received payload is upgraded into a local copy and the fields that
+    /// were added in the newer version are defaulted.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
-            subscriber_shared_state: self.subscriber_shared_state.clone(),
-            details,
-            chunk,
-            _payload: PhantomData,
-            _user_header: PhantomData,
+        Ok(self.receive_impl()?.map(|(details, mut chunk)| {
+            self.upgrade_payload(&mut chunk);
+            Sample {
+                subscriber_shared_state: self.subscriber_shared_state.clone(),
+                details,
+                chunk,
+                _payload: PhantomData,
+                _user_header: PhantomData,
+            }
         }))
     }
+
+    fn upgrade_payload(&self, chunk: &mut Chunk) {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let slots = match &subscriber_shared_state.payload_upgrade_slots {
+            Some(slots) => slots,
+            None => return,
+        };
+
+        // the receiver already ensures that no more samples than slots are borrowed
+        let slot = match slots.acquire() {
+            Some(slot) => slot,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! No payload upgrade slot is available while the max borrowed samples are not exceeded.");
+            }
+        };
+
+        let service_version = subscriber_shared_state
+            .receiver
+            .message_type_details
+            .payload_version;
+        if !unsafe {
+            <Payload as iceoryx2_bb_elementary_traits::type_name::TypeName>::__upgrade_from_version(
+                service_version,
+                chunk.payload,
+                slot.cast(),
+            )
+        } {
+            fatal_panic!(from self,
+                "This should never happen! The payload cannot be upgraded from the version {} of the service.",
+                service_version);
+        }
+
+        chunk.payload = slot;
+    }
 }
 
 impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend>
@@ -40,14 +40,16 @@
 //! # }
 //! ```
 
-use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
+use crate::constants::{MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT, MAX_BLACKBOARD_KEY_SIZE};
 use crate::identifiers::UniqueWriterId;
 use crate::port::port_name::PortName;
 use crate::prelude::EventId;
 use crate::service::dynamic_config::blackboard::WriterDetails;
 use crate::service::marker::CustomKeyMarker;
 use crate::service::port_factory::writer::WriterConfig;
-use crate::service::resource::blackboard::{BlackboardResources, KeyMemory};
+use crate::service::resource::blackboard::{
+    BlackboardResources, KeyMemory, dynamic_value_cell_size, dynamic_value_ptrs,
+};
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self, SharedServiceState};
 use core::alloc::Layout;
@@ -346,6 +348,245 @@ impl<
 
         Ok(offset)
     }
+
+    /// Adds a new entry at runtime. In contrast to the entries that are provided with
+    /// [`Creator::add()`](crate::service::builder::blackboard::Creator::add), the value does
+    /// not need to be [`Copy`]. Any [`ZeroCopySend`] type, e.g. a
+    /// [`StaticString`](iceoryx2_bb_container::string::StaticString) or a
+    /// [`StaticVec`](iceoryx2_bb_container::vector::StaticVec), can be stored as long as it does
+    /// not exceed the
+    /// [`StaticConfig::max_dynamic_value_size()`](crate::service::static_config::blackboard::StaticConfig::max_dynamic_value_size).
+    ///
+    /// Readers discover the new key with
+    /// [`PortFactory::list_keys()`](crate::service::port_factory::blackboard::PortFactory::list_keys)
+    /// and can be informed about it by sending [`Writer::key_set_event_id()`] with an event
+    /// service.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # use iceoryx2_bb_container::string::StaticString;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard_creator::<u64>()
+    ///     .max_dynamic_entries(4)
+    ///     .max_dynamic_value_size(128)
+    ///     .create()?;
+    ///
+    /// let writer = service.writer_builder().create()?;
+    /// writer.add_entry(&1, StaticString::<64>::from_bytes(b"some parameter")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_entry<ValueType: ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+        value: ValueType,
+    ) -> Result<(), EntryAddError> {
+        let msg = "Unable to add entry";
+        let key_mem = self.key_memory(key);
+
+        let shared_state = self.shared_state.lock();
+        let resources = shared_state.service_state.additional_resource();
+        let max_dynamic_value_size = shared_state
+            .service_state
+            .static_config()
+            .blackboard()
+            .max_dynamic_value_size;
+        let mgmt = resources.mgmt.get();
+
+        if core::mem::size_of::<ValueType>() > max_dynamic_value_size {
+            fail!(from self, with EntryAddError::ValueTooLarge,
+                "{} since the size of the value ({}) exceeds the maximum supported size of {}.",
+                msg, core::mem::size_of::<ValueType>(), max_dynamic_value_size);
+        }
+
+        if core::mem::align_of::<ValueType>() > MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT {
+            fail!(from self, with EntryAddError::ValueAlignmentTooLarge,
+                "{} since the alignment of the value ({}) exceeds the maximum supported alignment of {}.",
+                msg, core::mem::align_of::<ValueType>(), MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT);
+        }
+
+        if unsafe {
+            mgmt.map
+                .__internal_get(&key_mem, resources.key_eq_func.as_ref())
+        }
+        .is_some()
+            || mgmt
+                .find_dynamic_entry(&key_mem, resources.key_eq_func.as_ref())
+                .is_some()
+        {
+            fail!(from self, with EntryAddError::KeyAlreadyExists,
+                "{} since an entry with the given key already exists.", msg);
+        }
+
+        // only the single writer modifies the dynamic entries
+        let entry = match mgmt
+            .dynamic_entries
+            .iter()
+            .find(|entry| !unsafe { entry.is_used() })
+        {
+            Some(entry) => entry,
+            None => {
+                fail!(from self, with EntryAddError::ExceedsMaxDynamicEntries,
+                    "{} since it would exceed the maximum supported amount of dynamic entries of {}.",
+                    msg, mgmt.dynamic_entries.len());
+            }
+        };
+
+        let (atomic_mgmt_ptr, data_ptr) =
+            dynamic_value_ptrs(resources.data.payload_start_address(), entry.offset);
+        unsafe {
+            if (*atomic_mgmt_ptr).__internal_acquire_producer().is_err() {
+                fatal_panic!(from self,
+                    "This should never happen! The value of an unused dynamic entry is still in use.");
+            }
+            let write_cell = (*atomic_mgmt_ptr).__internal_get_ptr_to_write_cell(
+                dynamic_value_cell_size(max_dynamic_value_size),
+                MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+                data_ptr,
+            );
+            (write_cell as *mut ValueType).write(value);
+            (*atomic_mgmt_ptr).__internal_update_write_cell();
+            (*atomic_mgmt_ptr).__internal_release_producer();
+
+            // the entry becomes visible for the readers after the value is written
+            entry.assign(
+                key_mem,
+                TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Removes an entry that was added with [`Writer::add_entry()`]. Entries that were provided
+    /// with [`Creator::add()`](crate::service::builder::blackboard::Creator::add) cannot be
+    /// removed.
+    ///
+    /// Readers that still hold a [`DynamicEntryHandle`](crate::port::reader::DynamicEntryHandle)
+    /// to the removed entry receive
+    /// [`EntryHandleError::EntryDoesNotExist`](crate::port::reader::EntryHandleError::EntryDoesNotExist)
+    /// on their next read access.
+    pub fn remove_entry(&self, key: &KeyType) -> Result<(), EntryRemoveError> {
+        let msg = "Unable to remove entry";
+        let key_mem = self.key_memory(key);
+
+        let shared_state = self.shared_state.lock();
+        let resources = shared_state.service_state.additional_resource();
+        let mgmt = resources.mgmt.get();
+
+        if unsafe {
+            mgmt.map
+                .__internal_get(&key_mem, resources.key_eq_func.as_ref())
+        }
+        .is_some()
+        {
+            fail!(from self, with EntryRemoveError::EntryIsNotRemovable,
+                "{} since the entry was provided when the service was created.", msg);
+        }
+
+        let entry = match mgmt.find_dynamic_entry(&key_mem, resources.key_eq_func.as_ref()) {
+            Some((index, _)) => &mgmt.dynamic_entries[index],
+            None => {
+                fail!(from self, with EntryRemoveError::EntryDoesNotExist,
+                    "{} since no entry with the given key exists.", msg);
+            }
+        };
+
+        let (atomic_mgmt_ptr, _) =
+            dynamic_value_ptrs(resources.data.payload_start_address(), entry.offset);
+        if unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() }.is_err() {
+            fail!(from self, with EntryRemoveError::HandleStillInUse,
+                "{} since a DynamicEntryHandleMut to the entry still exists.", msg);
+        }
+
+        unsafe {
+            entry.release();
+            (*atomic_mgmt_ptr).__internal_release_producer();
+        }
+
+        Ok(())
+    }
+
+    /// Creates a [`DynamicEntryHandleMut`] for direct write access to the value of an entry
+    /// that was added with [`Writer::add_entry()`]. There can be only one
+    /// [`DynamicEntryHandleMut`] per value.
+    pub fn dynamic_entry<ValueType: ZeroCopySend + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<DynamicEntryHandleMut<Service, KeyType, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to create dynamic entry handle";
+        let key_mem = self.key_memory(key);
+
+        let shared_state = self.shared_state.lock();
+        let resources = shared_state.service_state.additional_resource();
+        let mgmt = resources.mgmt.get();
+
+        let (index, snapshot) =
+            match mgmt.find_dynamic_entry(&key_mem, resources.key_eq_func.as_ref()) {
+                Some(v) => v,
+                None => {
+                    fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                        "{} since no dynamic entry with the given key exists.", msg);
+                }
+            };
+
+        if snapshot.type_details != TypeDetail::new::<ValueType>(TypeVariant::FixedSize) {
+            fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                "{} since no dynamic entry with the given key and value type exists.", msg);
+        }
+
+        let offset = mgmt.dynamic_entries[index].offset;
+        let (atomic_mgmt_ptr, data_ptr) =
+            dynamic_value_ptrs(resources.data.payload_start_address(), offset);
+        let cell_size = dynamic_value_cell_size(
+            shared_state
+                .service_state
+                .static_config()
+                .blackboard()
+                .max_dynamic_value_size,
+        );
+
+        match DynamicEntryHandleMut::new(
+            atomic_mgmt_ptr,
+            data_ptr,
+            cell_size,
+            EventId::new(offset as _),
+            self.shared_state.clone(),
+        ) {
+            Ok(handle) => Ok(handle),
+            Err(e) => {
+                fail!(from self, with e,
+                    "{} since a handle for the passed key and value type already exists.", msg);
+            }
+        }
+    }
+
+    /// Returns the [`EventId`] that can be used in an event based communication setup to
+    /// inform readers that entries were added or removed.
+    pub fn key_set_event_id(&self) -> EventId {
+        EventId::new(
+            self.shared_state
+                .lock()
+                .service_state
+                .additional_resource()
+                .mgmt
+                .get()
+                .key_set_event_id,
+        )
+    }
+
+    fn key_memory(&self, key: &KeyType) -> KeyMemory<MAX_BLACKBOARD_KEY_SIZE> {
+        match KeyMemory::try_from(key) {
+            Ok(mem) => mem,
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! Key with invalid layout passed.");
+            }
+        }
+    }
 }
 
 /// Defines a failure that can occur when a [`EntryHandleMut`] is created with [`Writer::entry()`].
@@ -365,6 +606,46 @@ impl core::fmt::Display for EntryHandleMutError {
 
 impl core::error::Error for EntryHandleMutError {}
 
+/// Defines a failure that can occur when an entry is added with [`Writer::add_entry()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EntryAddError {
+    /// An entry with the given key already exists.
+    KeyAlreadyExists,
+    /// All dynamic entries of the blackboard are already in use.
+    ExceedsMaxDynamicEntries,
+    /// The size of the value exceeds the maximum size of a dynamic value.
+    ValueTooLarge,
+    /// The alignment of the value exceeds [`MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT`].
+    ValueAlignmentTooLarge,
+}
+
+impl core::fmt::Display for EntryAddError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EntryAddError::{self:?}")
+    }
+}
+
+impl core::error::Error for EntryAddError {}
+
+/// Defines a failure that can occur when an entry is removed with [`Writer::remove_entry()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EntryRemoveError {
+    /// The entry with the given key does not exist.
+    EntryDoesNotExist,
+    /// The entry was provided when the blackboard was created and cannot be removed.
+    EntryIsNotRemovable,
+    /// A [`DynamicEntryHandleMut`] to the entry still exists.
+    HandleStillInUse,
+}
+
+impl core::fmt::Display for EntryRemoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EntryRemoveError::{self:?}")
+    }
+}
+
+impl core::error::Error for EntryRemoveError {}
+
 /// A handle for direct write access to a specific blackboard value.
 pub struct EntryHandleMut<
     Service: service::Service,
@@ -646,6 +927,115 @@ impl<
     }
 }
 
+/// A handle for direct write access to the value of an entry that was added with
+/// [`Writer::add_entry()`].
+pub struct DynamicEntryHandleMut<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> {
+    atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+    data_ptr: *mut u8,
+    cell_size: usize,
+    entry_id: EventId,
+    _value: PhantomData<ValueType>,
+    _shared_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, KeyType>>,
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Drop for DynamicEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        unsafe { (*self.atomic_mgmt_ptr).__internal_release_producer() };
+    }
+}
+
+// Safe since the pointers to the UnrestrictedAtomicMgmt and the data cells don't change, the
+// entry cannot be removed while the producer is acquired and shared_state ensures the lifetime
+// of the UnrestrictedAtomicMgmt
+unsafe impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Send for DynamicEntryHandleMut<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Sync for DynamicEntryHandleMut<Service, KeyType, ValueType>
+{
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> Debug for DynamicEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DynamicEntryHandleMut<{}, {}, {}> {{ entry_id: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<KeyType>(),
+            core::any::type_name::<ValueType>(),
+            self.entry_id
+        )
+    }
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: ZeroCopySend + 'static,
+> DynamicEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn new(
+        atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+        data_ptr: *mut u8,
+        cell_size: usize,
+        entry_id: EventId,
+        writer_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, KeyType>>,
+    ) -> Result<Self, EntryHandleMutError> {
+        match unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() } {
+            Ok(_) => Ok(Self {
+                atomic_mgmt_ptr,
+                data_ptr,
+                cell_size,
+                entry_id,
+                _value: PhantomData,
+                _shared_state: writer_state.clone(),
+            }),
+            Err(_) => Err(EntryHandleMutError::HandleAlreadyExists),
+        }
+    }
+
+    /// Moves the new value into the entry. The previous value is not dropped since readers
+    /// may still hold bitwise copies of it.
+    pub fn update(&mut self, value: ValueType) {
+        unsafe {
+            let write_cell = (*self.atomic_mgmt_ptr).__internal_get_ptr_to_write_cell(
+                self.cell_size,
+                MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+                self.data_ptr,
+            );
+            (write_cell as *mut ValueType).write(value);
+            (*self.atomic_mgmt_ptr).__internal_update_write_cell();
+        }
+    }
+
+    /// Returns an ID corresponding to the entry which can be used in an event based communication
+    /// setup.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
 impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
     #[doc(hidden)]
     /// # Safety
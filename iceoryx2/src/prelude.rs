@@ -13,15 +13,15 @@
 pub use crate::config::Config;
 pub use crate::node::{Node, NodeBuilder, NodeState, node_name::NodeName};
 pub use crate::port::{
-    EventActivation, backpressure_strategy::BackpressureStrategy, event_id::EventId,
-    port_name::PortName,
+    DataSegmentAllocator, EventActivation, backpressure_strategy::BackpressureStrategy,
+    event_id::EventId, port_name::PortName,
 };
 pub use crate::service::header::payload_header::PayloadHeader;
 pub use crate::service::marker::Flatbuffer;
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
     Service, ServiceDetails, attribute::AttributeSet, attribute::AttributeSpecifier,
-    attribute::AttributeVerifier, ipc, ipc_threadsafe, local, local_threadsafe,
+    attribute::AttributeVerifier, ipc, ipc_copy, ipc_threadsafe, local, local_threadsafe,
     port_factory::PortFactory, service_name::ServiceName,
 };
 pub use crate::signal_handling_mode::SignalHandlingMode;
@@ -30,11 +30,13 @@ pub use iceoryx2_bb_container::semantic_string::SemanticString;
 pub use iceoryx2_bb_container::semantic_string::SemanticStringError;
 pub use iceoryx2_bb_derive_macros::PlacementDefault;
 pub use iceoryx2_bb_derive_macros::ZeroCopySend;
+pub use iceoryx2_bb_derive_macros::rpc_service;
 pub use iceoryx2_bb_elementary::CallbackProgression;
 pub use iceoryx2_bb_elementary::alignment::Alignment;
 pub use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
 pub use iceoryx2_bb_elementary_traits::iceoryx_send::IceoryxSend;
 pub use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+pub use iceoryx2_bb_elementary_traits::type_layout::TypeLayoutVisitor;
 pub use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 pub use iceoryx2_bb_flatbuffers::FlatbufferError;
 pub use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
@@ -36,6 +36,7 @@
 //! ```
 
 use core::ops::{Deref, DerefMut};
+use core::time::Duration;
 use core::{fmt::Debug, marker::PhantomData};
 
 use flatbuffers::InvalidFlatbuffer;
@@ -49,10 +50,12 @@ use iceoryx2_cal::zero_copy_connection::ChannelId;
 use crate::payload::number_of_elements;
 use crate::port::details::chunk::ChunkMut;
 use crate::port::details::chunk_mut_shared_state::ChunkMutSharedState;
+use crate::service::header::request_response::DeadlineError;
 use crate::service::marker::Flatbuffer;
 use crate::{
     pending_response::PendingResponse,
     port::client::{ClientSharedState, RequestSendError},
+    port::request_dispatch_policy::RequestDispatchPolicy,
     service,
 };
 
@@ -235,18 +238,61 @@ impl<
         unsafe { &mut *self.chunk.user_header_mut_ptr().cast() }
     }
 
-    /// Sends the [`RequestMut`] to all connected
+    /// Sets the deadline of the request to now plus the provided timeout. It overrides
+    /// the timeout that was set with
+    /// [`PortFactoryClient::request_timeout()`](crate::service::port_factory::client::PortFactoryClient::request_timeout()).
+    /// Responses that are sent or received after the deadline are discarded.
+    pub fn set_deadline(&mut self, timeout: Duration) -> Result<(), DeadlineError> {
+        let header: &mut service::header::request_response::RequestHeader =
+            unsafe { &mut *self.chunk.header_mut_ptr().cast() };
+        header.deadline = service::header::request_response::deadline_from_timeout(timeout)?;
+        Ok(())
+    }
+
+    /// Sends the [`RequestMut`] to the connected
     /// [`Server`](crate::port::server::Server)s of the
-    /// [`Service`](crate::service::Service).
+    /// [`Service`](crate::service::Service) that are selected by the
+    /// [`RequestDispatchPolicy`] of the [`Client`](crate::port::client::Client).
     pub fn send(
+        self,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        self.send_impl(None)
+    }
+
+    /// Sends the [`RequestMut`] to the connected
+    /// [`Server`](crate::port::server::Server)s of the
+    /// [`Service`](crate::service::Service) that are selected by the provided
+    /// [`RequestDispatchPolicy`]. The [`RequestDispatchPolicy`] of the
+    /// [`Client`](crate::port::client::Client) is ignored for this request.
+    pub fn send_with_dispatch_policy(
+        self,
+        dispatch_policy: RequestDispatchPolicy,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        self.send_impl(Some(dispatch_policy))
+    }
+
+    fn send_impl(
         mut self,
+        dispatch_policy: Option<RequestDispatchPolicy>,
     ) -> Result<
         PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
         RequestSendError,
     > {
         let shared_state = self.shared_state.clone();
         shared_state.call(|s| {
-            match s.send_request(&self.chunk, self.channel_id, self.header().request_id) {
+            let dispatch_policy = dispatch_policy.unwrap_or(s.config.request_dispatch_policy);
+            match s.send_request(
+                &self.chunk,
+                self.channel_id,
+                self.header().request_id,
+                dispatch_policy,
+            ) {
                 Ok(number_of_server_connections) => {
                     s.loan_counter.fetch_sub(1, Ordering::Relaxed);
                     self.was_sample_sent = true;
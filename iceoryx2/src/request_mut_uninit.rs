@@ -43,6 +43,7 @@ use iceoryx2_bb_flatbuffers::ResizableMemory;
 use iceoryx2_cal::{shared_memory::ShmPointer, zero_copy_connection::ChannelId};
 use iceoryx2_log::fail;
 
+use crate::service::header::request_response::DeadlineError;
 use crate::{
     payload::number_of_elements,
     port::{
@@ -54,6 +55,7 @@ use crate::{
     service::{self, marker::Flatbuffer},
 };
 use core::marker::PhantomData;
+use core::time::Duration;
 use core::{fmt::Debug, mem::MaybeUninit};
 
 /// The memory used inside the [`FlatBufferBuilder`].
@@ -298,6 +300,17 @@ impl<
     pub fn user_header_mut(&mut self) -> &mut RequestHeader {
         unsafe { &mut *self.chunk.user_header_mut_ptr().cast() }
     }
+
+    /// Sets the deadline of the request to now plus the provided timeout. It overrides
+    /// the timeout that was set with
+    /// [`PortFactoryClient::request_timeout()`](crate::service::port_factory::client::PortFactoryClient::request_timeout()).
+    /// Responses that are sent or received after the deadline are discarded.
+    pub fn set_deadline(&mut self, timeout: Duration) -> Result<(), DeadlineError> {
+        let header: &mut service::header::request_response::RequestHeader =
+            unsafe { &mut *self.chunk.header_mut_ptr().cast() };
+        header.deadline = service::header::request_response::deadline_from_timeout(timeout)?;
+        Ok(())
+    }
 }
 
 impl<
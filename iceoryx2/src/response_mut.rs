@@ -278,6 +278,9 @@ impl<
     /// Sends a [`ResponseMut`] to the corresponding
     /// [`PendingResponse`](crate::pending_response::PendingResponse) of the
     /// [`Client`](crate::port::client::Client).
+    /// If the deadline of the corresponding [`RequestMut`](crate::request_mut::RequestMut)
+    /// was reached, the [`ResponseMut`] is discarded and [`SendError::RequestExpired`] is
+    /// returned.
     ///
     /// ```
     /// use iceoryx2::prelude::*;
@@ -306,6 +309,16 @@ impl<
             fail!(from self, when s.update_connections(),
                 "{} since the connections could not be updated.", msg);
 
+            // the client discards responses of expired requests anyway
+            let has_expired = fail!(from self,
+                when service::header::request_response::has_expired(self.header().request_deadline),
+                with SendError::InternalError,
+                "{} since the deadline of the corresponding request could not be evaluated.", msg);
+            if has_expired {
+                fail!(from self, with SendError::RequestExpired,
+                    "{} since the deadline of the corresponding request was already reached.", msg);
+            }
+
             if self.connection_id != INVALID_CONNECTION_ID {
                 s.response_sender.deliver_offset_to_connection(
                     &self.chunk,
@@ -0,0 +1,91 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`rpc_service`](crate::rpc::rpc_service) attribute turns a trait into a request-response
+//! service with a generated request and response payload, a typed client and a server-side
+//! dispatcher.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! #[rpc_service]
+//! pub trait Calculator {
+//!     fn add(&self, lhs: u64, rhs: u64) -> u64;
+//!     fn negate(&self, value: i64) -> i64;
+//! }
+//!
+//! struct MyCalculator;
+//!
+//! impl Calculator for MyCalculator {
+//!     fn add(&self, lhs: u64, rhs: u64) -> u64 {
+//!         lhs + rhs
+//!     }
+//!
+//!     fn negate(&self, value: i64) -> i64 {
+//!         -value
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node
+//!     .service_builder(&"My/Calculator".try_into()?)
+//!     .request_response::<CalculatorRequest, CalculatorResponse>()
+//!     .open_or_create()?;
+//!
+//! let server = CalculatorServer::new(service.server_builder().create()?);
+//! let client = CalculatorClient::new(service.client_builder().create()?);
+//!
+//! let pending_response = client.add(3, 4)?;
+//! server.handle_all(&MyCalculator)?;
+//!
+//! if let Some(response) = pending_response.receive()? {
+//!     println!("3 + 4 = {:?}", response.as_add());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub use iceoryx2_bb_derive_macros::rpc_service;
+
+use crate::port::{ReceiveError, SendError};
+
+/// Failure that can occur when a server generated by [`rpc_service`] dispatches a request.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum RpcDispatchError {
+    /// The request could not be received.
+    ReceiveError(ReceiveError),
+    /// The response could not be sent.
+    SendError(SendError),
+}
+
+impl From<ReceiveError> for RpcDispatchError {
+    fn from(value: ReceiveError) -> Self {
+        RpcDispatchError::ReceiveError(value)
+    }
+}
+
+impl From<SendError> for RpcDispatchError {
+    fn from(value: SendError) -> Self {
+        RpcDispatchError::SendError(value)
+    }
+}
+
+impl core::fmt::Display for RpcDispatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RpcDispatchError::{self:?}")
+    }
+}
+
+impl core::error::Error for RpcDispatchError {}
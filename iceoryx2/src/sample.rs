@@ -131,8 +131,12 @@ impl<
 > Drop for Sample<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        self.subscriber_shared_state
-            .lock()
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if let Some(slots) = &subscriber_shared_state.payload_upgrade_slots {
+            slots.release(self.chunk.payload);
+        }
+
+        subscriber_shared_state
             .receiver
             .release_offset(&self.details, ChannelId::new(0));
     }
@@ -0,0 +1,343 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Appending elements of unknown count
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<[u64]>()
+//! #     .create()?;
+//! #
+//! let publisher = service
+//!     .publisher_builder()
+//!     .initial_max_slice_len(16)
+//!     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+//!     .create()?;
+//!
+//! let mut sample = publisher.loan_slice_builder()?;
+//! // the payload grows inside the data segment when the initial memory is exhausted
+//! for n in 0..100 {
+//!     sample.push(n)?;
+//! }
+//!
+//! // the slice length is set to 100 when the sample is sent
+//! sample.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Writing a string
+//!
+//! ```
+//! use core::fmt::Write;
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<[u8]>()
+//! #     .create()?;
+//! #
+//! # let publisher = service
+//! #     .publisher_builder()
+//! #     .initial_max_slice_len(16)
+//! #     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+//! #     .create()?;
+//!
+//! let mut sample = publisher.loan_slice_builder()?;
+//! sample.push_str("hello ")?;
+//! write!(sample, "number {}", 42)?;
+//!
+//! sample.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_flatbuffers::{AllocationStrategy, ResizableMemory};
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_log::fail;
+
+use crate::port::details::chunk::ChunkMut;
+use crate::port::details::chunk_mut_shared_state::ChunkMutSharedState;
+use crate::port::details::data_segment_shared_state::DataSegmentSharedState;
+use crate::port::publisher::PublisherSharedState;
+use crate::port::{LoanError, SendError};
+use crate::sample_mut::SampleMut;
+use crate::service::header::publish_subscribe::Header;
+
+type PayloadMemory<Service> =
+    ResizableMemory<ShmPointer, ChunkMutSharedState<Service, PublisherSharedState<Service>>>;
+
+/// Acquired by a [`crate::port::publisher::Publisher`] via
+/// [`crate::port::publisher::Publisher::loan_slice_builder()`].
+///
+/// Stores a slice payload whose length is not known when it is loaned. Elements are
+/// appended in place and when the loaned memory is exhausted, it grows inside the data
+/// segment of the [`crate::port::publisher::Publisher`] according to its
+/// [`AllocationStrategy`]. The slice length is finalized
+/// with [`SampleMutBuilder::finish()`] or [`SampleMutBuilder::send()`]. If the
+/// [`SampleMutBuilder`] is not sent it will release the loaned memory when going out of scope.
+pub struct SampleMutBuilder<
+    Service: crate::service::Service,
+    Payload: Debug + ZeroCopySend,
+    UserHeader: ZeroCopySend,
+> {
+    shared_state: ChunkMutSharedState<Service, PublisherSharedState<Service>>,
+    memory: PayloadMemory<Service>,
+    len: usize,
+    _payload: PhantomData<Payload>,
+    _user_header: PhantomData<UserHeader>,
+}
+
+unsafe impl<
+    Service: crate::service::Service,
+    Payload: Debug + ZeroCopySend,
+    UserHeader: ZeroCopySend,
+> Send for SampleMutBuilder<Service, Payload, UserHeader>
+where
+    Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader: ZeroCopySend>
+    Debug for SampleMutBuilder<Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SampleMutBuilder<{}, {}, {}> {{ len: {}, capacity: {}, memory: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            self.len,
+            self.capacity(),
+            self.memory
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader: ZeroCopySend>
+    SampleMutBuilder<Service, Payload, UserHeader>
+{
+    pub(crate) fn new(
+        publisher_shared_state: &Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>,
+        chunk: ChunkMut,
+    ) -> Result<Self, LoanError> {
+        let shared_state = fail!(from "SampleMutBuilder::new()",
+            when ChunkMutSharedState::new(publisher_shared_state, &chunk),
+            with LoanError::InternalFailure,
+            "Unable to create the sample builder since the underlying shared state could not be initialized.");
+
+        let memory = shared_state.create_resizable_memory(&chunk);
+
+        Ok(Self {
+            shared_state,
+            memory,
+            len: 0,
+            _payload: PhantomData,
+            _user_header: PhantomData,
+        })
+    }
+
+    fn payload_ptr(&self) -> *const Payload {
+        self.memory.as_ptr().cast()
+    }
+
+    fn payload_mut_ptr(&mut self) -> *mut Payload {
+        self.memory.as_mut_ptr().cast()
+    }
+
+    /// Returns a reference to the [`Header`] of the [`SampleMutBuilder`].
+    pub fn header(&self) -> &Header {
+        unsafe { &*(self.shared_state.current_chunk().header_ptr().cast()) }
+    }
+
+    /// Returns a reference to the user_header of the [`SampleMutBuilder`].
+    pub fn user_header(&self) -> &UserHeader {
+        unsafe { &*(self.shared_state.current_chunk().user_header_ptr().cast()) }
+    }
+
+    /// Returns a mutable reference to the user_header of the [`SampleMutBuilder`].
+    pub fn user_header_mut(&mut self) -> &mut UserHeader {
+        unsafe {
+            &mut *(self
+                .shared_state
+                .current_chunk()
+                .user_header_mut_ptr()
+                .cast())
+        }
+    }
+
+    /// Returns the number of elements that were appended.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when no element was appended, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements that can be appended before the underlying memory
+    /// must grow.
+    pub fn capacity(&self) -> usize {
+        match core::mem::size_of::<Payload>() {
+            0 => usize::MAX,
+            size => self.memory.len() / size,
+        }
+    }
+
+    /// Ensures that at least `additional` elements can be appended without growing the
+    /// underlying memory. The memory may grow beyond the requested size according to the
+    /// [`AllocationStrategy`] of the [`crate::port::publisher::Publisher`]. When the memory
+    /// cannot grow, a [`LoanError`] describing the failure is returned.
+    pub fn reserve(&mut self, additional: usize) -> Result<(), LoanError> {
+        let msg = "Unable to reserve memory";
+        let required_len = match self.len.checked_add(additional) {
+            Some(v) => v,
+            None => {
+                fail!(from self, with LoanError::ExceedsMaxLoanSize,
+                    "{msg} for {additional} additional elements since the slice length would overflow.");
+            }
+        };
+
+        if required_len <= self.capacity() {
+            return Ok(());
+        }
+
+        let allocation_strategy = self
+            .shared_state
+            .call(|s| -> Result<AllocationStrategy, ()> { Ok(s.allocation_strategy()) })
+            .expect("Always returns ok.");
+        if allocation_strategy == AllocationStrategy::Static {
+            fail!(from self, with LoanError::ExceedsMaxLoanSize,
+                "{msg} for {required_len} elements since it would exceed the max slice length of {} with a static allocation strategy.",
+                self.capacity());
+        }
+
+        match self
+            .memory
+            .grow_upwards(required_len.saturating_mul(core::mem::size_of::<Payload>()))
+        {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{msg} for {required_len} elements since the underlying data segment could not grow. [{e:?}]");
+            }
+        }
+    }
+
+    /// Appends an element to the payload. When the underlying memory is exhausted it grows
+    /// inside the data segment. On failure a [`LoanError`] is returned and the element is
+    /// dropped.
+    pub fn push(&mut self, value: Payload) -> Result<(), LoanError> {
+        self.reserve(1)?;
+
+        let len = self.len;
+        unsafe { self.payload_mut_ptr().add(len).write(value) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends all elements of the provided slice to the payload. When the underlying memory
+    /// is exhausted it grows inside the data segment. On failure a [`LoanError`] is returned
+    /// and no element is appended.
+    pub fn extend_from_slice(&mut self, values: &[Payload]) -> Result<(), LoanError>
+    where
+        Payload: Clone,
+    {
+        self.reserve(values.len())?;
+
+        let len = self.len;
+        let payload = self.payload_mut_ptr();
+        for (n, value) in values.iter().enumerate() {
+            unsafe { payload.add(len + n).write(value.clone()) };
+        }
+        self.len += values.len();
+
+        Ok(())
+    }
+
+    /// Removes all appended elements. The already acquired memory is retained. Like the
+    /// payload of a [`SampleMut`], the elements are not dropped.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns a reference to the appended elements.
+    pub fn payload(&self) -> &[Payload] {
+        unsafe { core::slice::from_raw_parts(self.payload_ptr(), self.len) }
+    }
+
+    /// Returns a mutable reference to the appended elements.
+    pub fn payload_mut(&mut self) -> &mut [Payload] {
+        let len = self.len;
+        unsafe { core::slice::from_raw_parts_mut(self.payload_mut_ptr(), len) }
+    }
+
+    /// Finalizes the slice length with the number of appended elements and converts the
+    /// [`SampleMutBuilder`] into a [`SampleMut`].
+    pub fn finish(self) -> SampleMut<Service, [Payload], UserHeader> {
+        let mut chunk = self.shared_state.current_chunk();
+
+        let header = unsafe { &mut *chunk.header_mut_ptr().cast::<Header>() };
+        header.number_of_elements = self.len as u64;
+        header.payload_offset = 0;
+
+        SampleMut {
+            shared_state: self.shared_state,
+            chunk,
+            _payload: PhantomData,
+            _user_header: PhantomData,
+        }
+    }
+
+    /// Finalizes the slice length with the number of appended elements and sends the
+    /// payload to all connected [`crate::port::subscriber::Subscriber`]s. On success it
+    /// returns the number of [`crate::port::subscriber::Subscriber`]s that received the
+    /// data, otherwise a [`SendError`] describing the failure.
+    pub fn send(self) -> Result<usize, SendError> {
+        self.finish().send()
+    }
+}
+
+impl<Service: crate::service::Service, UserHeader: ZeroCopySend>
+    SampleMutBuilder<Service, u8, UserHeader>
+{
+    /// Appends the UTF-8 bytes of the provided string to the payload. When the underlying
+    /// memory is exhausted it grows inside the data segment. On failure a [`LoanError`] is
+    /// returned and no byte is appended.
+    pub fn push_str(&mut self, value: &str) -> Result<(), LoanError> {
+        self.extend_from_slice(value.as_bytes())
+    }
+}
+
+impl<Service: crate::service::Service, UserHeader: ZeroCopySend> core::fmt::Write
+    for SampleMutBuilder<Service, u8, UserHeader>
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s).map_err(|_| core::fmt::Error)
+    }
+}
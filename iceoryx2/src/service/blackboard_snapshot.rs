@@ -0,0 +1,242 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`BlackboardSnapshot`](crate::service::blackboard_snapshot::BlackboardSnapshot) contains
+//! all entries of a
+//! [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+//! based service, the key, the
+//! [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail) and the
+//! bytes of the value. It is acquired with
+//! [`PortFactory::snapshot()`](crate::service::port_factory::blackboard::PortFactory::snapshot())
+//! and can be used to initialize a new blackboard with
+//! [`Creator::from_snapshot()`](crate::service::builder::blackboard::Creator::from_snapshot()) so
+//! that the contents survive a restart when the snapshot is stored in a file.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::blackboard_snapshot::BlackboardSnapshot;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let snapshot_file = FilePath::new(b"parameters.toml")?;
+//!
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     .add::<i32>(0, 0)
+//!     .create()?;
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//! writer.entry::<i32>(&0)?.update_with_copy(42);
+//!
+//! blackboard.snapshot().write_to_file(&snapshot_file)?;
+//! drop(writer);
+//! drop(blackboard);
+//!
+//! // after a restart, the blackboard is created with the stored values
+//! let snapshot = BlackboardSnapshot::from_file(&snapshot_file)?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     .add::<i32>(0, 0)
+//!     .from_snapshot(&snapshot)
+//!     .create()?;
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//! assert_eq!(*reader.entry::<i32>(&0)?.get(), 42);
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use iceoryx2_bb_posix::file::{
+    AccessMode, CreationMode, FileBuilder, FileCreationError, FileOpenError, FileReadError,
+};
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_cal::serialize::Serialize as _;
+use iceoryx2_cal::serialize::recommended::Recommended;
+use iceoryx2_log::{fail, trace};
+use serde::{Deserialize, Serialize};
+
+use crate::service::static_config::message_type_details::TypeDetail;
+
+/// Errors that can occur when a [`BlackboardSnapshot`] is written to or read from a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardSnapshotError {
+    /// An interrupt signal was received.
+    Interrupt,
+    /// The snapshot file does not exist.
+    FileDoesNotExist,
+    /// The process has insufficient permissions to access the snapshot file.
+    InsufficientPermissions,
+    /// The snapshot could not be serialized.
+    SerializationFailed,
+    /// The content of the snapshot file is not a valid [`BlackboardSnapshot`].
+    DeserializationFailed,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for BlackboardSnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BlackboardSnapshotError::{self:?}")
+    }
+}
+
+impl core::error::Error for BlackboardSnapshotError {}
+
+/// A single entry of a [`BlackboardSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlackboardSnapshotEntry {
+    pub(crate) key: Vec<u8>,
+    pub(crate) value_type_details: TypeDetail,
+    pub(crate) value: Vec<u8>,
+    pub(crate) is_dynamic: bool,
+}
+
+impl BlackboardSnapshotEntry {
+    /// Returns the bytes of the key.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    /// Returns the [`TypeDetail`] of the value.
+    pub fn value_type_details(&self) -> &TypeDetail {
+        &self.value_type_details
+    }
+
+    /// Returns the bytes of the value.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Returns true when the entry was added at runtime with
+    /// [`Writer::add_entry()`](crate::port::writer::Writer::add_entry), otherwise false.
+    pub fn is_dynamic(&self) -> bool {
+        self.is_dynamic
+    }
+}
+
+/// Contains all entries of a blackboard at the time the snapshot was taken. The values are
+/// stored bitwise, therefore a snapshot can only be restored on a platform with the same
+/// memory layout of the key and value types.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlackboardSnapshot {
+    pub(crate) key_type_details: TypeDetail,
+    pub(crate) entries: Vec<BlackboardSnapshotEntry>,
+}
+
+impl BlackboardSnapshot {
+    /// Returns the [`TypeDetail`] of the key of the blackboard.
+    pub fn key_type_details(&self) -> &TypeDetail {
+        &self.key_type_details
+    }
+
+    /// Returns all entries of the snapshot.
+    pub fn entries(&self) -> &[BlackboardSnapshotEntry] {
+        &self.entries
+    }
+
+    /// Writes the snapshot into the provided file. An already existing file is replaced.
+    pub fn write_to_file(&self, file_path: &FilePath) -> Result<(), BlackboardSnapshotError> {
+        let msg = "Unable to write blackboard snapshot";
+
+        let content = fail!(from self, when Recommended::serialize(self),
+            with BlackboardSnapshotError::SerializationFailed,
+            "{} since the snapshot could not be serialized.", msg);
+
+        let mut file = match FileBuilder::new(file_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .permission(Permission::OWNER_READ_WRITE | Permission::GROUP_READ)
+            .create()
+        {
+            Ok(file) => file,
+            Err(FileCreationError::Interrupt) => {
+                fail!(from self, with BlackboardSnapshotError::Interrupt,
+                    "{} since an interrupt signal was received while creating the file \"{}\".", msg, file_path);
+            }
+            Err(FileCreationError::InsufficientPermissions) => {
+                fail!(from self, with BlackboardSnapshotError::InsufficientPermissions,
+                    "{} since the file \"{}\" could not be created due to insufficient permissions.", msg, file_path);
+            }
+            Err(e) => {
+                fail!(from self, with BlackboardSnapshotError::InternalFailure,
+                    "{} since the file \"{}\" could not be created due to an internal error ({:?}).", msg, file_path, e);
+            }
+        };
+
+        match file.write(&content) {
+            Ok(n) if n == content.len() as u64 => {
+                trace!(from self, "Written to \"{}\".", file_path);
+                Ok(())
+            }
+            Ok(n) => {
+                fail!(from self, with BlackboardSnapshotError::InternalFailure,
+                    "{} since only {} of {} bytes were written into the file \"{}\".", msg, n, content.len(), file_path);
+            }
+            Err(e) => {
+                fail!(from self, with BlackboardSnapshotError::InternalFailure,
+                    "{} since the file \"{}\" could not be written ({:?}).", msg, file_path, e);
+            }
+        }
+    }
+
+    /// Reads a snapshot from a file that was written with
+    /// [`BlackboardSnapshot::write_to_file()`].
+    pub fn from_file(file_path: &FilePath) -> Result<Self, BlackboardSnapshotError> {
+        let msg = "Unable to read blackboard snapshot";
+        let origin = "BlackboardSnapshot::from_file()";
+
+        let file = match FileBuilder::new(file_path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from origin, with BlackboardSnapshotError::FileDoesNotExist,
+                    "{} since the file \"{}\" does not exist.", msg, file_path);
+            }
+            Err(FileOpenError::Interrupt) => {
+                fail!(from origin, with BlackboardSnapshotError::Interrupt,
+                    "{} since an interrupt signal was received while opening the file \"{}\".", msg, file_path);
+            }
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from origin, with BlackboardSnapshotError::InsufficientPermissions,
+                    "{} since the file \"{}\" could not be opened due to insufficient permissions.", msg, file_path);
+            }
+            Err(e) => {
+                fail!(from origin, with BlackboardSnapshotError::InternalFailure,
+                    "{} since the file \"{}\" could not be opened due to an internal error ({:?}).", msg, file_path, e);
+            }
+        };
+
+        let mut content = Vec::new();
+        match file.read_to_vector(&mut content) {
+            Ok(_) => (),
+            Err(FileReadError::Interrupt) => {
+                fail!(from origin, with BlackboardSnapshotError::Interrupt,
+                    "{} since an interrupt signal was received while reading the file \"{}\".", msg, file_path);
+            }
+            Err(e) => {
+                fail!(from origin, with BlackboardSnapshotError::InternalFailure,
+                    "{} since the file \"{}\" could not be read ({:?}).", msg, file_path, e);
+            }
+        }
+
+        Ok(
+            fail!(from origin, when Recommended::deserialize::<Self>(&content),
+            with BlackboardSnapshotError::DeserializationFailed,
+            "{} since the content of the file \"{}\" is not a valid snapshot.", msg, file_path),
+        )
+    }
+}
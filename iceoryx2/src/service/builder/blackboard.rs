@@ -22,8 +22,9 @@ use alloc::boxed::Box;
 use alloc::format;
 use alloc::vec::Vec;
 
-use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
+use crate::constants::{MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT, MAX_BLACKBOARD_KEY_SIZE};
 use crate::service;
+use crate::service::blackboard_snapshot::BlackboardSnapshot;
 use crate::service::builder::{DynamicConfigCreationArgs, ServiceCreateError, ServiceOpenError};
 use crate::service::dynamic_config::MessagingPatternSettings;
 use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
@@ -92,7 +93,9 @@ impl From<ServiceState> for BlackboardOpenError {
     fn from(value: ServiceState) -> Self {
         match value {
             ServiceState::Interrupt => BlackboardOpenError::Interrupt,
-            ServiceState::IncompatiblePayload => BlackboardOpenError::IncompatibleKeys,
+            ServiceState::IncompatiblePayload | ServiceState::IncompatibleTypeStructure => {
+                BlackboardOpenError::IncompatibleKeys
+            }
             ServiceState::IncompatibleMessagingPattern => {
                 BlackboardOpenError::IncompatibleMessagingPattern
             }
@@ -117,7 +120,9 @@ impl From<ServiceOpenError> for BlackboardOpenError {
             ServiceOpenError::IncompatibleMessagingPattern => {
                 BlackboardOpenError::IncompatibleMessagingPattern
             }
-            ServiceOpenError::IncompatiblePayload => BlackboardOpenError::IncompatibleKeys,
+            ServiceOpenError::IncompatiblePayload | ServiceOpenError::IncompatibleTypeStructure => {
+                BlackboardOpenError::IncompatibleKeys
+            }
             ServiceOpenError::InsufficientPermissions => {
                 BlackboardOpenError::InsufficientPermissions
             }
@@ -154,12 +159,16 @@ pub enum BlackboardCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
-    /// No key-value pairs have been provided. At least one is required.
+    /// Neither key-value pairs nor dynamic entries have been provided. At least one is required.
     NoEntriesProvided,
     /// The [`Node`](crate::node::Node) service tag could not be created. Required to track resources of dead nodes when cleaning them up.
     UnableToCreateServiceTag,
     /// The [`Service`]s config could not be created and written to the static service configuration.
     ServiceConfigCouldNotBeCreated,
+    /// The [`BlackboardSnapshot`] provided with [`Creator::from_snapshot()`] does not fit the
+    /// [`Service`], e.g. the key type differs, an entry has a different value type than the one
+    /// provided with [`Creator::add()`] or the dynamic entries exceed the configured limits.
+    IncompatibleSnapshot,
 }
 
 impl core::fmt::Display for BlackboardCreateError {
@@ -175,6 +184,7 @@ impl From<ServiceState> for BlackboardCreateError {
         match value {
             ServiceState::Interrupt => BlackboardCreateError::Interrupt,
             ServiceState::IncompatiblePayload
+            | ServiceState::IncompatibleTypeStructure
             | ServiceState::IncompatibleMessagingPattern
             | ServiceState::VersionMismatch => BlackboardCreateError::AlreadyExists,
             ServiceState::InsufficientPermissions => BlackboardCreateError::InsufficientPermissions,
@@ -255,6 +265,14 @@ impl BuilderInternals {
     }
 }
 
+/// A dynamic entry of a [`BlackboardSnapshot`] that is restored when the blackboard is created.
+#[derive(Debug)]
+pub(crate) struct RestoredDynamicEntry {
+    pub(crate) key: KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+    pub(crate) value_type_details: TypeDetail,
+    pub(crate) value: Vec<u8>,
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct Verify {
     max_readers: bool,
@@ -265,6 +283,8 @@ pub(crate) struct BuilderConfig<ServiceType: service::Service> {
     pub(crate) base: builder::BuilderWithServiceType<ServiceType>,
     pub(crate) verify: Verify,
     pub(crate) internals: Vec<BuilderInternals>,
+    pub(crate) snapshot: Option<BlackboardSnapshot>,
+    pub(crate) restored_dynamic_entries: Vec<RestoredDynamicEntry>,
     pub(crate) override_key_type: Option<TypeDetail>,
     pub(crate) key_eq_func: Arc<dyn Fn(*const u8, *const u8) -> bool + Send + Sync>,
 }
@@ -339,6 +359,8 @@ impl<
                 base,
                 verify: Verify::default(),
                 internals: Vec::<BuilderInternals>::new(),
+                snapshot: None,
+                restored_dynamic_entries: Vec::new(),
                 override_key_type: None,
                 key_eq_func: Arc::new(|lhs: *const u8, rhs: *const u8| {
                     KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::default_key_eq_comparison::<KeyType>(
@@ -349,9 +371,15 @@ impl<
             _key: PhantomData,
         };
 
-        new_self.config.base.service_config.messaging_pattern = MessagingPattern::Blackboard(
-            static_config::blackboard::StaticConfig::new(new_self.config.base.shared_node.config()),
-        );
+        new_self.config.base.service_config.messaging_pattern =
+            MessagingPattern::Blackboard(static_config::blackboard::StaticConfig::new(
+                &new_self
+                    .config
+                    .base
+                    .shared_node
+                    .config()
+                    .defaults_for(new_self.config.base.service_config.name()),
+            ));
 
         new_self
     }
@@ -450,6 +478,21 @@ impl<
         self
     }
 
+    /// Defines how many entries can be added at runtime with
+    /// [`Writer::add_entry()`](crate::port::writer::Writer::add_entry) in addition to the
+    /// entries provided with [`Creator::add()`].
+    pub fn max_dynamic_entries(mut self, value: usize) -> Self {
+        self.builder.config_details_mut().max_dynamic_entries = value;
+        self
+    }
+
+    /// Defines the maximum size in bytes of a value of an entry that is added at runtime with
+    /// [`Writer::add_entry()`](crate::port::writer::Writer::add_entry).
+    pub fn max_dynamic_value_size(mut self, value: usize) -> Self {
+        self.builder.config_details_mut().max_dynamic_value_size = value;
+        self
+    }
+
     /// Adds key-value pairs to the blackboard.
     pub fn add<ValueType: ZeroCopySend + Copy + 'static>(
         mut self,
@@ -491,6 +534,143 @@ impl<
         self.add(key, ValueType::default())
     }
 
+    /// Initializes the blackboard with the entries of a [`BlackboardSnapshot`]. The values of the
+    /// snapshot replace the values of entries with the same key that were provided with
+    /// [`Creator::add()`]. Entries that were added at runtime with
+    /// [`Writer::add_entry()`](crate::port::writer::Writer::add_entry) are restored as dynamic
+    /// entries, therefore [`Creator::max_dynamic_entries()`] and
+    /// [`Creator::max_dynamic_value_size()`] must be large enough to hold them.
+    pub fn from_snapshot(mut self, snapshot: &BlackboardSnapshot) -> Self {
+        self.builder.config.snapshot = Some(snapshot.clone());
+        self
+    }
+
+    fn apply_snapshot(&mut self, origin: &str, msg: &str) -> Result<(), BlackboardCreateError> {
+        let snapshot = match self.builder.config.snapshot.take() {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let settings = *self.builder.config_details();
+        let key_type_details = settings.type_details;
+        if snapshot.key_type_details != key_type_details {
+            fail!(from origin, with BlackboardCreateError::IncompatibleSnapshot,
+                "{} since the snapshot has the key type {:?} but the key type {:?} is required.",
+                msg, snapshot.key_type_details, key_type_details);
+        }
+
+        let number_of_dynamic_entries = snapshot.entries.iter().filter(|e| e.is_dynamic).count();
+        if settings.max_dynamic_entries < number_of_dynamic_entries {
+            fail!(from origin, with BlackboardCreateError::IncompatibleSnapshot,
+                "{} since the snapshot contains {} dynamic entries but only {} dynamic entries are supported.",
+                msg, number_of_dynamic_entries, settings.max_dynamic_entries);
+        }
+
+        let key_layout = unsafe {
+            Layout::from_size_align_unchecked(key_type_details.size, key_type_details.alignment)
+        };
+        let key_eq_func = self.builder.config.key_eq_func.clone();
+        let is_same_key = |lhs: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+                           rhs: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
+            key_eq_func(lhs.data.as_ptr(), rhs.data.as_ptr())
+        };
+
+        for entry in snapshot.entries {
+            let value_type_details = entry.value_type_details;
+            if entry.key.len() != key_type_details.size
+                || entry.value.len() != value_type_details.size
+            {
+                fail!(from origin, with BlackboardCreateError::IncompatibleSnapshot,
+                    "{} since the snapshot contains an entry whose key or value size does not match its type.", msg);
+            }
+
+            let key = match unsafe { KeyMemory::try_from_ptr(entry.key.as_ptr(), key_layout) } {
+                Ok(key) => key,
+                Err(_) => {
+                    fatal_panic!(from origin, "This should never happen! Key with invalid layout set.")
+                }
+            };
+
+            if entry.is_dynamic {
+                if settings.max_dynamic_value_size < value_type_details.size
+                    || MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT < value_type_details.alignment
+                {
+                    fail!(from origin, with BlackboardCreateError::IncompatibleSnapshot,
+                        "{} since the dynamic entry value {:?} of the snapshot exceeds the supported size of {} or alignment of {}.",
+                        msg, value_type_details, settings.max_dynamic_value_size, MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT);
+                }
+
+                self.builder
+                    .config
+                    .restored_dynamic_entries
+                    .push(RestoredDynamicEntry {
+                        key,
+                        value_type_details,
+                        value: entry.value,
+                    });
+                continue;
+            }
+
+            let value = entry.value;
+            let value_writer = Box::new(move |raw_memory_ptr: *mut u8| unsafe {
+                let ptrs = __internal_calculate_atomic_mgmt_and_payload_ptr(
+                    raw_memory_ptr,
+                    value_type_details.alignment,
+                );
+                core::ptr::copy_nonoverlapping(
+                    value.as_ptr(),
+                    ptrs.atomic_payload_ptr,
+                    value.len(),
+                );
+            });
+
+            match self
+                .builder
+                .config
+                .internals
+                .iter_mut()
+                .find(|internals| is_same_key(&internals.key, &key))
+            {
+                Some(internals) => {
+                    if internals.value_type_details != value_type_details {
+                        fail!(from origin, with BlackboardCreateError::IncompatibleSnapshot,
+                            "{} since the snapshot contains the value type {:?} for an entry that was added with the value type {:?}.",
+                            msg, value_type_details, internals.value_type_details);
+                    }
+                    internals.value_writer = value_writer;
+                }
+                None => self.builder.config.internals.push(BuilderInternals::new(
+                    key,
+                    value_type_details,
+                    value_writer,
+                    UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+                        value_type_details.size,
+                        value_type_details.alignment,
+                    ),
+                    UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(
+                        value_type_details.alignment,
+                    ),
+                    Box::new(|| {}),
+                )),
+            }
+        }
+
+        for restored in self.builder.config.restored_dynamic_entries.iter() {
+            if self
+                .builder
+                .config
+                .internals
+                .iter()
+                .any(|internals| is_same_key(&internals.key, &restored.key))
+            {
+                fail!(from origin, with BlackboardCreateError::IncompatibleSnapshot,
+                    "{} since a dynamic entry of the snapshot has the same key as an entry that was added with Creator::add().", msg);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
         let origin = format!("{self:?}");
@@ -533,9 +713,12 @@ impl<
         let msg = "Unable to create blackboard service";
 
         self.adjust_configuration_to_meaningful_values();
-        if self.builder.config.internals.is_empty() {
+        self.apply_snapshot(&origin, msg)?;
+        if self.builder.config.internals.is_empty()
+            && self.builder.config_details().max_dynamic_entries == 0
+        {
             fail!(from origin,  with BlackboardCreateError::NoEntriesProvided,
-                "{} without entries. At least one key-value pair is required.", msg);
+                "{} without entries. At least one key-value pair or one dynamic entry is required.", msg);
         }
 
         let generate_dynamic_config = |service_config: &StaticConfig| {
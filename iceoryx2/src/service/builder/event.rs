@@ -100,7 +100,9 @@ impl From<ServiceState> for EventOpenError {
     fn from(value: ServiceState) -> Self {
         match value {
             ServiceState::Interrupt => EventOpenError::Interrupt,
-            ServiceState::IncompatibleMessagingPattern | ServiceState::IncompatiblePayload => {
+            ServiceState::IncompatibleMessagingPattern
+            | ServiceState::IncompatiblePayload
+            | ServiceState::IncompatibleTypeStructure => {
                 EventOpenError::IncompatibleMessagingPattern
             }
             ServiceState::InsufficientPermissions => EventOpenError::InsufficientPermissions,
@@ -122,7 +124,9 @@ impl From<ServiceOpenError> for EventOpenError {
             ServiceOpenError::IncompatibleMessagingPattern => {
                 EventOpenError::IncompatibleMessagingPattern
             }
-            ServiceOpenError::IncompatiblePayload => EventOpenError::IncompatibleMessagingPattern,
+            ServiceOpenError::IncompatiblePayload | ServiceOpenError::IncompatibleTypeStructure => {
+                EventOpenError::IncompatibleMessagingPattern
+            }
             ServiceOpenError::InsufficientPermissions => EventOpenError::InsufficientPermissions,
             ServiceOpenError::InternalFailure | ServiceOpenError::UnableToAcquireTypeDefinition => {
                 EventOpenError::InternalFailure
@@ -315,9 +319,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify: Verify::default(),
         };
 
-        new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
-            static_config::event::StaticConfig::new(new_self.base.shared_node.config()),
-        );
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::Event(static_config::event::StaticConfig::new(
+                &new_self
+                    .base
+                    .shared_node
+                    .config()
+                    .defaults_for(new_self.base.service_config.name()),
+            ));
 
         new_self
     }
@@ -89,6 +89,7 @@ enum ServiceState {
     HangsInCreation,
     Corrupted,
     IncompatiblePayload,
+    IncompatibleTypeStructure,
     VersionMismatch,
 }
 
@@ -118,6 +119,7 @@ enum_gen! {
 #[derive(Debug, Clone)]
 pub struct Builder<S: Service> {
     name: ServiceName,
+    original_name: Option<ServiceName>,
     shared_node: SharedNode<S>,
     _phantom_s: PhantomData<S>,
 }
@@ -142,6 +144,7 @@ impl From<ServiceState> for ServiceCreateError {
             ServiceState::IncompatibleMessagingPattern
             | ServiceState::HangsInCreation
             | ServiceState::IncompatiblePayload
+            | ServiceState::IncompatibleTypeStructure
             | ServiceState::VersionMismatch => ServiceCreateError::AlreadyExists,
             ServiceState::InsufficientPermissions => ServiceCreateError::InsufficientPermissions,
             ServiceState::Corrupted => ServiceCreateError::ServiceInCorruptedState,
@@ -164,6 +167,7 @@ pub enum ServiceOpenError {
     Interrupt,
     IncompatibleMessagingPattern,
     IncompatiblePayload,
+    IncompatibleTypeStructure,
     InsufficientPermissions,
     VersionMismatch,
     UnableToAcquireTypeDefinition,
@@ -178,6 +182,7 @@ impl From<ServiceState> for ServiceOpenError {
                 ServiceOpenError::IncompatibleMessagingPattern
             }
             ServiceState::IncompatiblePayload => ServiceOpenError::IncompatiblePayload,
+            ServiceState::IncompatibleTypeStructure => ServiceOpenError::IncompatibleTypeStructure,
             ServiceState::InsufficientPermissions => ServiceOpenError::InsufficientPermissions,
             ServiceState::InternalFailure => ServiceOpenError::InternalFailure,
             ServiceState::Interrupt => ServiceOpenError::Interrupt,
@@ -193,9 +198,14 @@ struct DynamicConfigCreationArgs {
 }
 
 impl<S: Service> Builder<S> {
-    pub(crate) fn new(name: &ServiceName, shared_node: SharedNode<S>) -> Self {
+    pub(crate) fn new(
+        name: &ServiceName,
+        original_name: Option<ServiceName>,
+        shared_node: SharedNode<S>,
+    ) -> Self {
         Self {
             name: *name,
+            original_name,
             shared_node,
             _phantom_s: PhantomData,
         }
@@ -210,10 +220,10 @@ impl<S: Service> Builder<S> {
         self,
     ) -> request_response::Builder<RequestPayload, (), ResponsePayload, (), S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_request_response::<S::ServiceNameHasher>(
+            self.static_config(StaticConfig::new_request_response::<S::ServiceNameHasher>(
                 &self.name,
                 self.shared_node.config(),
-            ),
+            )),
             self.shared_node,
         )
         .request_response::<RequestPayload, ResponsePayload>()
@@ -225,10 +235,10 @@ impl<S: Service> Builder<S> {
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
+            self.static_config(StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
                 &self.name,
                 self.shared_node.config(),
-            ),
+            )),
             self.shared_node,
         )
         .publish_subscribe()
@@ -238,7 +248,10 @@ impl<S: Service> Builder<S> {
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
+            self.static_config(StaticConfig::new_event::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            )),
             self.shared_node,
         )
         .event()
@@ -252,10 +265,10 @@ impl<S: Service> Builder<S> {
         self,
     ) -> blackboard::Creator<KeyType, S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+            self.static_config(StaticConfig::new_blackboard::<S::ServiceNameHasher>(
                 &self.name,
                 self.shared_node.config(),
-            ),
+            )),
             self.shared_node,
         )
         .blackboard_creator()
@@ -269,14 +282,19 @@ impl<S: Service> Builder<S> {
         self,
     ) -> blackboard::Opener<KeyType, S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+            self.static_config(StaticConfig::new_blackboard::<S::ServiceNameHasher>(
                 &self.name,
                 self.shared_node.config(),
-            ),
+            )),
             self.shared_node,
         )
         .blackboard_opener()
     }
+
+    fn static_config(&self, mut static_config: StaticConfig) -> StaticConfig {
+        static_config.set_original_name(self.original_name);
+        static_config
+    }
 }
 
 #[doc(hidden)]
@@ -394,6 +412,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         | ServiceOpenError::InternalFailure
                         | ServiceOpenError::IncompatibleMessagingPattern
                         | ServiceOpenError::IncompatiblePayload
+                        | ServiceOpenError::IncompatibleTypeStructure
                         | ServiceOpenError::UnableToCreateServiceTag
                         | ServiceOpenError::Interrupt
                         | ServiceOpenError::VersionMismatch
@@ -741,7 +760,11 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         fail!(from self, with ServiceCreateError::InternalFailure,
                             "{msg} since the dynamic service config initialization failed.");
                     }
-                    Err(DynamicStorageCreateError::InternalError) => {
+                    Err(
+                        DynamicStorageCreateError::InternalError
+                        | DynamicStorageCreateError::HugePagesNotSupported
+                        | DynamicStorageCreateError::MemoryLockFailed,
+                    ) => {
                         fail!(from self, with ServiceCreateError::InternalFailure,
                             "{} since the dynamic service segment could not be created due to an internal failure.", msg);
                     }
@@ -17,11 +17,13 @@
 use core::marker::PhantomData;
 
 use crate::config::Config;
+use crate::dynamic_payload::TypeLayout;
 use crate::service::resource::type_definition::TypeDefinition;
 use iceoryx2_bb_container::string::String;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_flatbuffers::TypeName;
+use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_log::{fail, fatal_panic, warn};
 
 use super::ServiceState;
@@ -30,6 +32,7 @@ use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::marker::{CustomHeaderMarker, CustomPayloadMarker, Flatbuffer};
 use crate::service::port_factory::publish_subscribe;
+use crate::service::port_factory::publish_subscribe::LocalPayloadType;
 use crate::service::resource::publish_subscribe::{
     PublishSubscribeResourceConfig, PublishSubscribeResources,
 };
@@ -40,7 +43,7 @@ use iceoryx2_bb_elementary_traits::iceoryx_send::IceoryxSend;
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{MessageTypeDetails, TypeDetail, TypeStructure, TypeVariant},
 };
 
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
@@ -54,6 +57,9 @@ pub enum PublishSubscribeOpenError {
     InternalFailure,
     /// The [`Service`] has the wrong payload type.
     IncompatibleTypes,
+    /// The [`Service`] has a payload or user header type with the same name and layout but a
+    /// different structure, for instance swapped fields.
+    IncompatibleTypeStructure,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
@@ -108,6 +114,9 @@ impl From<ServiceState> for PublishSubscribeOpenError {
     fn from(value: ServiceState) -> Self {
         match value {
             ServiceState::IncompatiblePayload => PublishSubscribeOpenError::IncompatibleTypes,
+            ServiceState::IncompatibleTypeStructure => {
+                PublishSubscribeOpenError::IncompatibleTypeStructure
+            }
             ServiceState::IncompatibleMessagingPattern => {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
@@ -135,6 +144,9 @@ impl From<ServiceOpenError> for PublishSubscribeOpenError {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
             ServiceOpenError::IncompatiblePayload => PublishSubscribeOpenError::IncompatibleTypes,
+            ServiceOpenError::IncompatibleTypeStructure => {
+                PublishSubscribeOpenError::IncompatibleTypeStructure
+            }
             ServiceOpenError::InsufficientPermissions => {
                 PublishSubscribeOpenError::InsufficientPermissions
             }
@@ -169,6 +181,9 @@ impl From<PublishSubscribeOpenError> for ServiceOpenError {
                 ServiceOpenError::IncompatibleMessagingPattern
             }
             PublishSubscribeOpenError::IncompatibleTypes => ServiceOpenError::IncompatiblePayload,
+            PublishSubscribeOpenError::IncompatibleTypeStructure => {
+                ServiceOpenError::IncompatibleTypeStructure
+            }
             PublishSubscribeOpenError::InsufficientPermissions => {
                 ServiceOpenError::InsufficientPermissions
             }
@@ -306,6 +321,7 @@ impl From<ServiceState> for PublishSubscribeCreateError {
     fn from(value: ServiceState) -> Self {
         match value {
             ServiceState::IncompatiblePayload
+            | ServiceState::IncompatibleTypeStructure
             | ServiceState::IncompatibleMessagingPattern
             | ServiceState::VersionMismatch => PublishSubscribeCreateError::AlreadyExists,
             ServiceState::InsufficientPermissions => {
@@ -384,6 +400,8 @@ pub struct Builder<
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    payload_size_of_version: fn(u32) -> Option<usize>,
+    payload_type_layout: fn() -> Option<TypeLayout>,
     flatbuffer_schema_path: Option<FilePath>,
     type_definition_name_hint: TypeName,
     skip_type_definition_verification: bool,
@@ -404,6 +422,8 @@ impl<
             override_alignment: self.override_alignment,
             override_payload_type: self.override_payload_type,
             override_user_header_type: self.override_user_header_type,
+            payload_size_of_version: self.payload_size_of_version,
+            payload_type_layout: self.payload_type_layout,
             flatbuffer_schema_path: self.flatbuffer_schema_path,
             type_definition_name_hint: self.type_definition_name_hint,
             verify: self.verify,
@@ -441,6 +461,8 @@ impl<
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            payload_size_of_version: |_| None,
+            payload_type_layout: || None,
             flatbuffer_schema_path: None,
             type_definition_name_hint: TypeName::new::<Payload>(),
             skip_type_definition_verification: false,
@@ -449,7 +471,13 @@ impl<
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
-            static_config::publish_subscribe::StaticConfig::new(new_self.base.shared_node.config()),
+            static_config::publish_subscribe::StaticConfig::new(
+                &new_self
+                    .base
+                    .shared_node
+                    .config()
+                    .defaults_for(new_self.base.service_config.name()),
+            ),
         );
 
         new_self
@@ -481,6 +509,29 @@ impl<
         let pubsub_service_config = self.config_details();
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
+                let local_details = &pubsub_service_config.message_type_details;
+                let service_details = &config.publish_subscribe().message_type_details;
+                if local_details.payload_version != service_details.payload_version {
+                    if !local_details.is_version_compatible_to(service_details) {
+                        fail!(from self, with ServiceState::IncompatiblePayload,
+                            "{} since the service offers the type \"{:?}\" in version {} which is not compatible to the requested type \"{:?}\" in version {}.",
+                            error_msg, service_details, service_details.payload_version, local_details, local_details.payload_version);
+                    }
+
+                    if local_details.payload_version > service_details.payload_version {
+                        match (self.payload_size_of_version)(service_details.payload_version) {
+                            Some(size) if size <= service_details.payload.size => (),
+                            _ => {
+                                fail!(from self, with ServiceState::IncompatiblePayload,
+                                    "{} since the requested type in version {} cannot be upgraded from the version {} of the service.",
+                                    error_msg, local_details.payload_version, service_details.payload_version);
+                            }
+                        }
+                    }
+
+                    return Ok(Some((config, storage)));
+                }
+
                 if !pubsub_service_config
                     .message_type_details
                     .is_compatible_to(&config.publish_subscribe().message_type_details)
@@ -490,6 +541,15 @@ impl<
                         error_msg, &config.publish_subscribe().message_type_details , pubsub_service_config.message_type_details);
                 }
 
+                if let Some((part, difference)) = pubsub_service_config
+                    .message_type_details
+                    .structural_difference_to(&config.publish_subscribe().message_type_details)
+                {
+                    fail!(from self, with ServiceState::IncompatibleTypeStructure,
+                        "{} since the {} type of the service has the same name and layout as the requested type but differs in the {}.",
+                        error_msg, part, difference);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -524,6 +584,34 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if the data segments of the
+    /// [`crate::port::publisher::Publisher`]s are backed by huge pages. When an existing
+    /// [`Service`] is opened the setting of the [`Service`] is used.
+    pub fn data_segment_use_huge_pages(mut self, value: bool) -> Self {
+        self.config_details_mut().data_segment_memory.use_huge_pages = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if the data segments of the
+    /// [`crate::port::publisher::Publisher`]s are locked in memory so that they are never
+    /// swapped out. When an existing [`Service`] is opened the setting of the [`Service`] is
+    /// used.
+    pub fn data_segment_lock_memory(mut self, value: bool) -> Self {
+        self.config_details_mut().data_segment_memory.lock_memory = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every page of the data segments of the
+    /// [`crate::port::publisher::Publisher`]s is touched when the segment is mapped so that no
+    /// page fault occurs in the hot path. When an existing [`Service`] is opened the setting of
+    /// the [`Service`] is used.
+    pub fn data_segment_prefault_memory(mut self, value: bool) -> Self {
+        self.config_details_mut()
+            .data_segment_memory
+            .prefault_memory = value;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -731,6 +819,19 @@ impl<
             }
         };
 
+        let type_layout = match (self.payload_type_layout)() {
+            Some(type_layout) => {
+                match <ServiceType::ConfigSerializer as Serialize>::serialize(&type_layout) {
+                    Ok(serialized_type_layout) => Some(serialized_type_layout),
+                    Err(e) => {
+                        fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                            "{} since the type layout of the payload could not be serialized. [{:?}]", msg, e);
+                    }
+                }
+            }
+            None => None,
+        };
+
         let service_state = self.base.create(
             msg,
             attributes,
@@ -746,6 +847,8 @@ impl<
                             schema_path: self.flatbuffer_schema_path,
                             type_name: self.type_definition_name_hint,
                             skip_type_definition_verification: false,
+                            type_layout: type_layout.clone(),
+                            open_type_layout: false,
                         },
                         shared_node: self.base.shared_node.clone(),
                     },
@@ -754,7 +857,10 @@ impl<
             |_| {},
         )?;
 
-        Ok(publish_subscribe::PortFactory::new(service_state))
+        Ok(publish_subscribe::PortFactory::new(
+            service_state,
+            self.local_payload_type(),
+        ))
     }
 
     fn open_impl(
@@ -782,6 +888,9 @@ impl<
                             type_name: self.type_definition_name_hint,
                             skip_type_definition_verification: self
                                 .skip_type_definition_verification,
+                            type_layout: None,
+                            open_type_layout: self.override_payload_type.is_some()
+                                || (self.payload_type_layout)().is_some(),
                         },
                         shared_node: self.base.shared_node.clone(),
                     },
@@ -789,7 +898,10 @@ impl<
             },
         )?;
 
-        Ok(publish_subscribe::PortFactory::new(service_state))
+        Ok(publish_subscribe::PortFactory::new(
+            service_state,
+            self.local_payload_type(),
+        ))
     }
 
     fn open_or_create_impl(
@@ -812,6 +924,15 @@ impl<
         )
     }
 
+    fn local_payload_type(&self) -> LocalPayloadType {
+        let details = &self.config_details().message_type_details;
+        LocalPayloadType {
+            version: details.payload_version,
+            size: details.payload.size,
+            alignment: details.payload.alignment,
+        }
+    }
+
     fn adjust_payload_alignment(&mut self) {
         if let Some(alignment) = self.override_alignment {
             self.config_details_mut()
@@ -871,13 +992,23 @@ impl<Payload: IceoryxSend + Debug, UserHeader: Debug + ZeroCopySend, ServiceType
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
             MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::FixedSize);
+        self.payload_size_of_version =
+            <Payload as iceoryx2_bb_elementary_traits::type_name::TypeName>::__size_of_version;
+        self.payload_type_layout = TypeLayout::from_described_type::<Payload>;
 
+        // overridden types are defined outside of Rust, their structure is unknown
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = *details;
+            self.config_details_mut()
+                .message_type_details
+                .payload_structure = TypeStructure::default();
         }
 
         if let Some(details) = &self.override_user_header_type {
             self.config_details_mut().message_type_details.user_header = *details;
+            self.config_details_mut()
+                .message_type_details
+                .user_header_structure = TypeStructure::default();
         }
 
         self.adjust_payload_alignment();
@@ -981,12 +1112,19 @@ impl<
         self.config_details_mut().message_type_details =
             MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::Dynamic);
 
+        // overridden types are defined outside of Rust, their structure is unknown
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = *details;
+            self.config_details_mut()
+                .message_type_details
+                .payload_structure = TypeStructure::default();
         }
 
         if let Some(details) = &self.override_user_header_type {
             self.config_details_mut().message_type_details.user_header = *details;
+            self.config_details_mut()
+                .message_type_details
+                .user_header_structure = TypeStructure::default();
         }
 
         self.adjust_payload_alignment();
@@ -41,7 +41,7 @@ use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::{Service, builder, dynamic_config};
 use crate::service::{header, static_config};
 
-use super::message_type_details::{MessageTypeDetails, TypeVariant};
+use super::message_type_details::{MessageTypeDetails, TypeStructure, TypeVariant};
 use crate::service::marker::{CustomHeaderMarker, CustomPayloadMarker, Flatbuffer};
 
 /// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall
@@ -74,6 +74,9 @@ pub enum RequestResponseOpenError {
     HangsInCreation,
     /// The [`Service`] has the wrong request payload type, request header type or type alignment.
     IncompatibleRequestOrResponseType,
+    /// The [`Service`] has a request or response type with the same name and layout but a
+    /// different structure, for instance swapped fields.
+    IncompatibleRequestOrResponseTypeStructure,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -120,6 +123,9 @@ impl From<ServiceState> for RequestResponseOpenError {
             ServiceState::IncompatiblePayload => {
                 RequestResponseOpenError::IncompatibleRequestOrResponseType
             }
+            ServiceState::IncompatibleTypeStructure => {
+                RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure
+            }
             ServiceState::IncompatibleMessagingPattern => {
                 RequestResponseOpenError::IncompatibleMessagingPattern
             }
@@ -148,6 +154,9 @@ impl From<ServiceOpenError> for RequestResponseOpenError {
             ServiceOpenError::IncompatiblePayload => {
                 RequestResponseOpenError::IncompatibleRequestOrResponseType
             }
+            ServiceOpenError::IncompatibleTypeStructure => {
+                RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure
+            }
             ServiceOpenError::InsufficientPermissions => {
                 RequestResponseOpenError::InsufficientPermissions
             }
@@ -184,6 +193,9 @@ impl From<RequestResponseOpenError> for ServiceOpenError {
             RequestResponseOpenError::IncompatibleRequestOrResponseType => {
                 ServiceOpenError::IncompatiblePayload
             }
+            RequestResponseOpenError::IncompatibleRequestOrResponseTypeStructure => {
+                ServiceOpenError::IncompatibleTypeStructure
+            }
             RequestResponseOpenError::InsufficientPermissions => {
                 ServiceOpenError::InsufficientPermissions
             }
@@ -258,6 +270,7 @@ impl From<ServiceState> for RequestResponseCreateError {
         match value {
             ServiceState::Interrupt => RequestResponseCreateError::Interrupt,
             ServiceState::IncompatiblePayload
+            | ServiceState::IncompatibleTypeStructure
             | ServiceState::IncompatibleMessagingPattern
             | ServiceState::VersionMismatch => RequestResponseCreateError::AlreadyExists,
             ServiceState::InsufficientPermissions => {
@@ -584,6 +597,35 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if the data segments of the
+    /// [`Client`](crate::port::client::Client)s and [`Server`](crate::port::server::Server)s are
+    /// backed by huge pages. When an existing [`Service`] is opened the setting of the
+    /// [`Service`] is used.
+    pub fn data_segment_use_huge_pages(mut self, value: bool) -> Self {
+        self.config_details_mut().data_segment_memory.use_huge_pages = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if the data segments of the
+    /// [`Client`](crate::port::client::Client)s and [`Server`](crate::port::server::Server)s are
+    /// locked in memory so that they are never swapped out. When an existing [`Service`] is
+    /// opened the setting of the [`Service`] is used.
+    pub fn data_segment_lock_memory(mut self, value: bool) -> Self {
+        self.config_details_mut().data_segment_memory.lock_memory = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every page of the data segments of the
+    /// [`Client`](crate::port::client::Client)s and [`Server`](crate::port::server::Server)s is
+    /// touched when the segment is mapped so that no page fault occurs in the hot path. When an
+    /// existing [`Service`] is opened the setting of the [`Service`] is used.
+    pub fn data_segment_prefault_memory(mut self, value: bool) -> Self {
+        self.config_details_mut()
+            .data_segment_memory
+            .prefault_memory = value;
+        self
+    }
+
     /// Defines how many active requests a [`Server`](crate::port::server::Server) can hold in
     /// parallel per [`Client`](crate::port::client::Client). The objects are used to send answers to a request that was received earlier
     /// from a [`Client`](crate::port::client::Client)
@@ -837,6 +879,28 @@ impl<
                         reqres_service_config.response_message_type_details);
                 }
 
+                if let Some((part, difference)) = reqres_service_config
+                    .request_message_type_details
+                    .structural_difference_to(
+                        &config.request_response().request_message_type_details,
+                    )
+                {
+                    fail!(from self, with ServiceState::IncompatibleTypeStructure,
+                        "{} since the request {} type of the service has the same name and layout as the requested type but differs in the {}.",
+                        error_msg, part, difference);
+                }
+
+                if let Some((part, difference)) = reqres_service_config
+                    .response_message_type_details
+                    .structural_difference_to(
+                        &config.request_response().response_message_type_details,
+                    )
+                {
+                    fail!(from self, with ServiceState::IncompatibleTypeStructure,
+                        "{} since the response {} type of the service has the same name and layout as the requested type but differs in the {}.",
+                        error_msg, part, difference);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -892,12 +956,16 @@ impl<
                             schema_path: self.request_flatbuffer_schema_path,
                             type_name: self.request_type_definition_name_hint,
                             skip_type_definition_verification: false,
+                            type_layout: None,
+                            open_type_layout: false,
                         },
                         response: TypeDefinition {
                             use_type_definition: self.response_has_flatbuffer_payload(),
                             schema_path: self.response_flatbuffer_schema_path,
                             type_name: self.response_type_definition_name_hint,
                             skip_type_definition_verification: false,
+                            type_layout: None,
+                            open_type_layout: false,
                         },
                         shared_node: self.base.shared_node.clone(),
                     },
@@ -940,6 +1008,8 @@ impl<
                             type_name: self.request_type_definition_name_hint,
                             skip_type_definition_verification: self
                                 .skip_type_definition_verification,
+                            type_layout: None,
+                            open_type_layout: false,
                         },
                         response: TypeDefinition {
                             use_type_definition: self.response_has_flatbuffer_payload(),
@@ -947,6 +1017,8 @@ impl<
                             type_name: self.response_type_definition_name_hint,
                             skip_type_definition_verification: self
                                 .skip_type_definition_verification,
+                            type_layout: None,
+                            open_type_layout: false,
                         },
                         shared_node: self.base.shared_node.clone(),
                     },
@@ -985,28 +1057,41 @@ impl<
     }
 
     fn prepare_message_type(&mut self) {
+        // overridden types are defined outside of Rust, their structure is unknown
         if let Some(details) = &self.override_request_payload_type {
             self.config_details_mut()
                 .request_message_type_details
                 .payload = *details;
+            self.config_details_mut()
+                .request_message_type_details
+                .payload_structure = TypeStructure::default();
         }
 
         if let Some(details) = &self.override_request_header_type {
             self.config_details_mut()
                 .request_message_type_details
                 .user_header = *details;
+            self.config_details_mut()
+                .request_message_type_details
+                .user_header_structure = TypeStructure::default();
         }
 
         if let Some(details) = &self.override_response_payload_type {
             self.config_details_mut()
                 .response_message_type_details
                 .payload = *details;
+            self.config_details_mut()
+                .response_message_type_details
+                .payload_structure = TypeStructure::default();
         }
 
         if let Some(details) = &self.override_response_header_type {
             self.config_details_mut()
                 .response_message_type_details
                 .user_header = *details;
+            self.config_details_mut()
+                .response_message_type_details
+                .user_header_structure = TypeStructure::default();
         }
 
         if let Some(alignment) = self.override_request_alignment {
@@ -77,6 +77,15 @@ pub(crate) fn resizable_data_segment_config<Service: crate::service::Service>(
         .path_hint(global_config.global.root_path())
 }
 
+pub(crate) fn buddy_data_segment_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::BuddySharedMemory as NamedConceptMgmt>::Configuration {
+    <<Service::BuddySharedMemory as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.service.data_segment_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Monitoring as NamedConceptMgmt>::Configuration {
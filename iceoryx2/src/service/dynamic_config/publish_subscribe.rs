@@ -28,7 +28,7 @@
 //! ```
 use crate::{
     identifiers::{UniqueNodeId, UniquePortId, UniquePublisherId, UniqueSubscriberId},
-    port::details::data_segment::DataSegmentType,
+    port::details::data_segment::{DataSegmentAllocator, DataSegmentType, PayloadDelivery},
     port::port_name::PortName,
 };
 use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -61,16 +61,24 @@ pub struct PublisherDetails {
     pub node_id: UniqueNodeId,
     /// The total number of samples contained in the
     /// [`Publisher`](crate::port::publisher::Publisher)s data segment.
+    /// With the [`DataSegmentAllocator::Buddy`] it is the number of the smallest blocks the
+    /// data segment is divided into.
     pub number_of_samples: usize,
     /// The current maximum length of a slice.
     pub max_slice_len: usize,
     /// The type of data segment the [`Publisher`](crate::port::publisher::Publisher)
     /// has.
     pub data_segment_type: DataSegmentType,
+    /// The [`DataSegmentAllocator`] that manages the chunks of the
+    /// [`Publisher`](crate::port::publisher::Publisher)s data segment.
+    pub data_segment_allocator: DataSegmentAllocator,
     /// If the [`Publisher`](crate::port::publisher::Publisher) has the
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Publisher`](crate::port::publisher::Publisher) can have at most.
     pub max_number_of_segments: u8,
+    /// Defines how the [`Publisher`](crate::port::publisher::Publisher) delivers its samples
+    /// to the [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    pub payload_delivery: PayloadDelivery,
 }
 
 /// Contains the communication settings of the connected
@@ -89,6 +97,10 @@ pub struct SubscriberDetails {
     pub buffer_size: usize,
     /// The requested amount of [`Sample`](crate::sample::Sample) to get as history.
     pub history_request: usize,
+    /// The payload version that was negotiated between the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) and the service. It is the older
+    /// version of both payload types.
+    pub payload_version: u32,
 }
 
 /// The dynamic configuration of an
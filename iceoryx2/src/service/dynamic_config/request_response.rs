@@ -23,7 +23,7 @@ use iceoryx2_log::{error, fatal_panic};
 
 use crate::{
     identifiers::{UniqueClientId, UniqueNodeId, UniquePortId, UniqueServerId},
-    port::details::data_segment::DataSegmentType,
+    port::details::data_segment::{DataSegmentType, PayloadDelivery},
     port::port_name::PortName,
 };
 
@@ -55,6 +55,9 @@ pub struct ServerDetails {
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Server`](crate::port::server::Server) can have at most.
     pub max_number_of_segments: u8,
+    /// Defines how the [`Server`](crate::port::server::Server) delivers its responses to the
+    /// [`Client`](crate::port::client::Client)s.
+    pub payload_delivery: PayloadDelivery,
 }
 
 /// Contains the communication settings of the connected
@@ -86,6 +89,9 @@ pub struct ClientDetails {
     /// The maximal amount of active request a
     /// [`Client`](crate::port::client::Client) can send.
     pub max_active_requests: usize,
+    /// Defines how the [`Client`](crate::port::client::Client) delivers its requests to the
+    /// [`Server`](crate::port::server::Server)s.
+    pub payload_delivery: PayloadDelivery,
 }
 
 #[repr(C)]
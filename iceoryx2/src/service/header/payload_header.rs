@@ -28,3 +28,32 @@ pub trait PayloadHeader {
     /// means that it contains 3 elements (3 * 40 == 120).
     fn number_of_elements(&self) -> u64;
 }
+
+/// Every payload header starts with the sequence number that the sender port assigns when the
+/// payload is delivered. Since it is located at the beginning of the header, the type-erased
+/// sender and receiver ports can access it without knowing the concrete header type.
+pub(crate) const SEQUENCE_NUMBER_OFFSET: usize = 0;
+
+/// Writes the sequence number into the header.
+///
+/// # Safety
+///
+///  * `header` must point to a payload header that is owned by the caller
+pub(crate) unsafe fn write_sequence_number(header: *mut u8, value: u64) {
+    unsafe {
+        header
+            .add(SEQUENCE_NUMBER_OFFSET)
+            .cast::<u64>()
+            .write(value)
+    }
+}
+
+/// Reads the sequence number from the header.
+///
+/// # Safety
+///
+///  * `header` must point to a valid payload header
+#[cfg(feature = "trace_points")]
+pub(crate) unsafe fn read_sequence_number(header: *const u8) -> u64 {
+    unsafe { header.add(SEQUENCE_NUMBER_OFFSET).cast::<u64>().read() }
+}
@@ -36,7 +36,7 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
 use crate::{
     identifiers::{UniqueNodeId, UniquePublisherId},
-    service::header::payload_header::PayloadHeader,
+    service::header::payload_header::{PayloadHeader, SEQUENCE_NUMBER_OFFSET},
 };
 
 /// Sample header used by
@@ -44,12 +44,15 @@ use crate::{
 #[derive(Debug, Copy, Clone, ZeroCopySend, PartialEq, Eq)]
 #[repr(C)]
 pub struct Header {
+    pub(crate) sequence_number: u64,
     node_id: UniqueNodeId,
     publisher_port_id: UniquePublisherId,
     pub(crate) number_of_elements: u64,
     pub(crate) payload_offset: u64,
 }
 
+const _: () = assert!(core::mem::offset_of!(Header, sequence_number) == SEQUENCE_NUMBER_OFFSET);
+
 impl PayloadHeader for Header {
     fn node_id(&self) -> UniqueNodeId {
         self.node_id
@@ -67,6 +70,7 @@ impl Header {
         number_of_elements: u64,
     ) -> Self {
         Self {
+            sequence_number: 0,
             node_id,
             publisher_port_id,
             number_of_elements,
@@ -10,27 +10,85 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::zero_copy_connection::ChannelId;
+use iceoryx2_log::fail;
 
 use crate::{
     active_request::RequestId,
     identifiers::{UniqueClientId, UniqueNodeId, UniqueServerId},
-    service::header::payload_header::PayloadHeader,
+    service::header::payload_header::{PayloadHeader, SEQUENCE_NUMBER_OFFSET},
 };
 
+pub(crate) const NO_DEADLINE: u64 = 0;
+
+/// Failures that can occur when the deadline of a request is set or evaluated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineError {
+    /// The monotonic system clock could not be read.
+    UnableToReadClock,
+}
+
+impl core::fmt::Display for DeadlineError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DeadlineError::{self:?}")
+    }
+}
+
+impl core::error::Error for DeadlineError {}
+
+// the monotonic clock is not affected by adjustments of the system time and is shared by all
+// processes of the system, therefore the deadline can be evaluated by the client and the server
+fn now_in_nanoseconds() -> Result<u64, DeadlineError> {
+    match Time::now_with_clock(ClockType::Monotonic) {
+        Ok(now) => Ok(now.as_duration().as_nanos() as u64),
+        Err(e) => {
+            fail!(from "RequestHeader::now_in_nanoseconds()", with DeadlineError::UnableToReadClock,
+                "Unable to acquire the current time of the monotonic clock ({e:?}).");
+        }
+    }
+}
+
+/// Converts a timeout that starts now into the deadline representation of the
+/// [`RequestHeader`].
+pub(crate) fn deadline_from_timeout(timeout: Duration) -> Result<u64, DeadlineError> {
+    Ok(now_in_nanoseconds()?
+        .saturating_add(timeout.as_nanos() as u64)
+        .max(NO_DEADLINE + 1))
+}
+
+/// Returns the time until the deadline is reached or [`None`] when there is no deadline.
+pub(crate) fn remaining_time(deadline: u64) -> Result<Option<Duration>, DeadlineError> {
+    match deadline {
+        NO_DEADLINE => Ok(None),
+        v => Ok(Some(Duration::from_nanos(
+            v.saturating_sub(now_in_nanoseconds()?),
+        ))),
+    }
+}
+
+/// Returns [`true`] when the deadline is set and was already reached.
+pub(crate) fn has_expired(deadline: u64) -> Result<bool, DeadlineError> {
+    Ok(remaining_time(deadline)? == Some(Duration::ZERO))
+}
+
 /// Request header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 #[derive(Debug, Copy, Clone, ZeroCopySend)]
 #[repr(C)]
 pub struct RequestHeader {
+    pub(crate) sequence_number: u64,
     pub(crate) node_id: UniqueNodeId,
     pub(crate) client_id: UniqueClientId,
     pub(crate) channel_id: ChannelId,
     pub(crate) request_id: RequestId,
     pub(crate) number_of_elements: u64,
     pub(crate) payload_offset: u64,
+    pub(crate) deadline: u64,
 }
 
 impl RequestHeader {
@@ -40,12 +98,25 @@ impl RequestHeader {
         self.client_id
     }
 
+    /// Returns the deadline of the [`RequestMut`](crate::request_mut::RequestMut) as
+    /// duration since the start of the monotonic system clock. If the
+    /// [`RequestMut`](crate::request_mut::RequestMut) has no deadline, it returns [`None`].
+    pub fn deadline(&self) -> Option<Duration> {
+        match self.deadline {
+            NO_DEADLINE => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
+
     /// Returns the payload offset.
     pub fn payload_offset(&self) -> u64 {
         self.payload_offset
     }
 }
 
+const _: () =
+    assert!(core::mem::offset_of!(RequestHeader, sequence_number) == SEQUENCE_NUMBER_OFFSET);
+
 impl PayloadHeader for RequestHeader {
     fn node_id(&self) -> UniqueNodeId {
         self.node_id
@@ -61,11 +132,13 @@ impl PayloadHeader for RequestHeader {
 #[derive(Debug, Copy, Clone, ZeroCopySend)]
 #[repr(C)]
 pub struct ResponseHeader {
+    pub(crate) sequence_number: u64,
     pub(crate) node_id: UniqueNodeId,
     pub(crate) server_id: UniqueServerId,
     pub(crate) request_id: RequestId,
     pub(crate) number_of_elements: u64,
     pub(crate) payload_offset: u64,
+    pub(crate) request_deadline: u64,
 }
 
 impl ResponseHeader {
@@ -81,6 +154,9 @@ impl ResponseHeader {
     }
 }
 
+const _: () =
+    assert!(core::mem::offset_of!(ResponseHeader, sequence_number) == SEQUENCE_NUMBER_OFFSET);
+
 impl PayloadHeader for ResponseHeader {
     fn number_of_elements(&self) -> u64 {
         self.number_of_elements
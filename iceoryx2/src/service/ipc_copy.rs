@@ -0,0 +1,88 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Every sender port, like a [`Publisher`](crate::port::publisher::Publisher), copies its
+//! payload into a receive area that it maintains for exactly one receiver port, like a
+//! [`Subscriber`](crate::port::subscriber::Subscriber). The receiver maps only its own receive
+//! area and never the data segment of the sender. A receiver that holds on to its samples or
+//! stops receiving exhausts only its own receive area, the sender can still loan and send to all
+//! other receivers. The same applies to requests and responses of the request-response
+//! messaging pattern.
+//!
+//! The isolation comes at the cost of one copy per receiver, therefore it is intended for
+//! mixed-criticality setups where a component of lower criticality must not be able to
+//! interfere with a component of higher criticality.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc_copy::Service>()?;
+//!
+//! // use `ipc_copy` as communication variant
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`Service`](crate::service) for more detailed examples.
+
+use core::fmt::Debug;
+use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::shm_allocator::buddy_allocator::BuddyAllocator;
+use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use crate::port::PayloadDelivery;
+
+/// Defines an inter-process communication setup based on posix mechanisms that delivers a copy
+/// of the payload to every receiver.
+#[derive(Debug, Clone)]
+pub struct Service {}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::recommended::Ipc;
+    type ConfigSerializer = serialize::recommended::Recommended;
+    type PersistentDynamicStorage<T: Debug + Send + Sync + ZeroCopySend + 'static> =
+        dynamic_storage::recommended::PersistentIpc<T>;
+    type DynamicStorage<T: Debug + Send + Sync + ZeroCopySend + 'static> =
+        dynamic_storage::recommended::Ipc<T>;
+    type ServiceNameHasher = hash::recommended::Recommended;
+    type SharedMemory = shared_memory::recommended::Ipc<PoolAllocator>;
+    type ResizableSharedMemory = resizable_shared_memory::recommended::Ipc<PoolAllocator>;
+    type BuddySharedMemory = shared_memory::recommended::Ipc<BuddyAllocator>;
+    type Connection = zero_copy_connection::recommended::Ipc;
+    type Event = event::recommended::Ipc;
+    type Monitoring = monitoring::recommended::Ipc;
+    type Reactor = reactor::recommended::Ipc;
+    type ArcThreadSafetyPolicy<T: Send + Debug + Abandonable> =
+        arc_sync_policy::single_threaded::SingleThreaded<T>;
+    type BlackboardMgmt<KeyType: Send + Sync + Debug + ZeroCopySend + 'static> =
+        dynamic_storage::recommended::Ipc<KeyType>;
+    type BlackboardPayload = shared_memory::recommended::Ipc<BumpAllocator>;
+}
+
+impl crate::service::internal::ServiceInternal<Service> for Service {
+    fn __internal_payload_delivery() -> PayloadDelivery {
+        PayloadDelivery::Copy
+    }
+}
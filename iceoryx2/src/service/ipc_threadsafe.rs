@@ -36,6 +36,7 @@ use core::fmt::Debug;
 
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::shm_allocator::buddy_allocator::BuddyAllocator;
 use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::*;
@@ -54,6 +55,7 @@ impl crate::service::Service for Service {
     type ServiceNameHasher = hash::recommended::Recommended;
     type SharedMemory = shared_memory::recommended::Ipc<PoolAllocator>;
     type ResizableSharedMemory = resizable_shared_memory::recommended::Ipc<PoolAllocator>;
+    type BuddySharedMemory = shared_memory::recommended::Ipc<BuddyAllocator>;
     type Connection = zero_copy_connection::recommended::Ipc;
     type Event = event::recommended::Ipc;
     type Monitoring = monitoring::recommended::Ipc;
@@ -199,6 +199,9 @@
 
 pub(crate) mod stale_resource_cleanup;
 
+/// Snapshots of the entries of a blackboard to restore them when the service is created again
+pub mod blackboard_snapshot;
+
 /// The builder to create or open [`Service`]s
 pub mod builder;
 
@@ -250,6 +253,10 @@ pub mod ipc;
 /// [`Send`] but at the cost of an additional internal mutex.
 pub mod ipc_threadsafe;
 
+/// A configuration when communicating between different processes using posix mechanisms where
+/// every receiver gets its own copy of the payload and never maps the memory of the sender.
+pub mod ipc_copy;
+
 /// Contains marker types to enable additional API features.
 pub mod marker;
 
@@ -310,7 +317,9 @@ use iceoryx2_cal::named_concept::*;
 use iceoryx2_cal::reactor::Reactor;
 use iceoryx2_cal::resizable_shared_memory::ResizableSharedMemoryForPoolAllocator;
 use iceoryx2_cal::serialize::Serialize;
-use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryForPoolAllocator};
+use iceoryx2_cal::shared_memory::{
+    SharedMemory, SharedMemoryForBuddyAllocator, SharedMemoryForPoolAllocator,
+};
 use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
 use iceoryx2_cal::static_storage::*;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
@@ -571,7 +580,8 @@ impl<S: Service, R: ServiceResource> Drop for ServiceState<S, R> {
 #[doc(hidden)]
 pub mod internal {
     use crate::{
-        port::port_name::PortName, service::resource::RemoveStaleResourcesError,
+        port::PayloadDelivery, port::port_name::PortName,
+        service::resource::RemoveStaleResourcesError,
         service::resource::remove_stale_service_resources,
         service::stale_resource_cleanup::ServiceRemoveTagError,
     };
@@ -608,7 +618,11 @@ pub mod internal {
             }
         };
 
-        let service = match node.service_builder(service_name).event().open() {
+        let service = match node
+            .service_builder_without_remapping(service_name)
+            .event()
+            .open()
+        {
             Ok(service) => service,
             Err(EventOpenError::DoesNotExist) => return,
             Err(e) => {
@@ -926,6 +940,13 @@ pub mod internal {
 
             remove_service_tag()
         }
+
+        /// Defines how the sender ports of the [`Service`] deliver their payload to the
+        /// receiver ports.
+        #[doc(hidden)]
+        fn __internal_payload_delivery() -> PayloadDelivery {
+            PayloadDelivery::ZeroCopy
+        }
     }
 }
 
@@ -961,6 +982,9 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone + Sen
     /// The dynamic memory used to store dynamic payload
     type ResizableSharedMemory: ResizableSharedMemoryForPoolAllocator<Self::SharedMemory>;
 
+    /// The memory used to store payload that is allocated in variable-sized chunks.
+    type BuddySharedMemory: SharedMemoryForBuddyAllocator;
+
     /// The connection used to exchange pointers to the payload
     type Connection: ZeroCopyConnection;
 
@@ -1239,7 +1263,9 @@ fn open_dynamic_config<S: Service>(
                 fail!(from origin, with ServiceDetailsError::VersionMismatch,
                     "{} since there is a version mismatch. Please use the same iceoryx2 version for the whole system.", msg);
             }
-            Err(DynamicStorageOpenError::InternalError) => {
+            Err(DynamicStorageOpenError::InternalError)
+            | Err(DynamicStorageOpenError::HugePagesNotSupported)
+            | Err(DynamicStorageOpenError::MemoryLockFailed) => {
                 fail!(from origin, with ServiceDetailsError::InternalError,
                     "{} due to an internal failure while opening the services dynamic config.", msg);
             }
@@ -76,6 +76,19 @@ pub(crate) fn data_segment_name(port_id_value: u128) -> FileName {
                  "{}", msg)
 }
 
+pub(crate) fn receive_area_name(sender_port_id: u128, receiver_port_id: u128) -> FileName {
+    connection_name(sender_port_id, receiver_port_id)
+}
+
+pub(crate) fn extract_port_ids_from_receive_area(receive_area: &FileName) -> Option<(u128, u128)> {
+    let name = core::str::from_utf8(receive_area.as_bytes()).ok()?;
+    let (sender_port_id, receiver_port_id) = name.split_once('_')?;
+    Some((
+        sender_port_id.parse::<u128>().ok()?,
+        receiver_port_id.parse::<u128>().ok()?,
+    ))
+}
+
 pub(crate) fn blackboard_name(service_id: UniqueServiceId) -> FileName {
     let msg = "The system does not support the required file name length for the blackboard's management segment.";
     let origin = "blackboard_name()";
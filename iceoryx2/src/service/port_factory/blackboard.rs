@@ -46,6 +46,7 @@ use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
 use crate::identifiers::UniqueServiceId;
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::blackboard_snapshot::BlackboardSnapshot;
 use crate::service::marker::CustomKeyMarker;
 use crate::service::resource::blackboard::{BlackboardResources, KeyMemory};
 use crate::service::service_hash::ServiceHash;
@@ -191,7 +192,8 @@ impl<
         PortFactoryReader::new(self)
     }
 
-    /// Iterates over all keys of the blackboard and calls the provided callback.
+    /// Iterates over all keys of the blackboard, including the keys of the entries that were
+    /// added at runtime, and calls the provided callback.
     ///
     /// # Example
     ///
@@ -216,11 +218,73 @@ impl<
     /// # }
     /// ```
     pub fn list_keys<F: FnMut(&KeyType) -> CallbackProgression>(&self, mut callback: F) {
-        self.service.additional_resource().mgmt.get().map.list_keys(
-            |key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
-                callback(unsafe { &*(key.data.as_ptr() as *const KeyType) })
-            },
-        );
+        list_key_memory(&self.service, |key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
+            callback(unsafe { &*(key.data.as_ptr() as *const KeyType) })
+        });
+    }
+
+    /// Returns a [`BlackboardSnapshot`] that contains a copy of the keys and values of all
+    /// entries, including the entries that were added at runtime. It can be used to create a
+    /// new blackboard with the same content with
+    /// [`Creator::from_snapshot()`](crate::service::builder::blackboard::Creator::from_snapshot()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard_creator::<u64>()
+    ///     .add::<i32>(0, 73)
+    ///     .create()?;
+    ///
+    /// let snapshot = blackboard.snapshot();
+    ///
+    /// let restored_blackboard = node.service_builder(&"My/Funk/RestoredServiceName".try_into()?)
+    ///     .blackboard_creator::<u64>()
+    ///     .from_snapshot(&snapshot)
+    ///     .create()?;
+    ///
+    /// let reader = restored_blackboard.reader_builder().create()?;
+    /// assert_eq!(*reader.entry::<i32>(&0)?.get(), 73);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> BlackboardSnapshot {
+        self.service
+            .additional_resource()
+            .snapshot(self.service.static_config().blackboard())
+    }
+}
+
+fn list_key_memory<
+    Service: service::Service,
+    F: FnMut(&KeyMemory<MAX_BLACKBOARD_KEY_SIZE>) -> CallbackProgression,
+>(
+    service: &SharedServiceState<Service, BlackboardResources<Service>>,
+    mut callback: F,
+) {
+    let mgmt = service.additional_resource().mgmt.get();
+    let mut progression = CallbackProgression::Continue;
+    mgmt.map
+        .list_keys(|key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
+            progression = callback(key);
+            progression
+        });
+
+    if progression == CallbackProgression::Stop {
+        return;
+    }
+
+    for entry in mgmt.dynamic_entries.iter() {
+        if let Some(snapshot) = entry.snapshot()
+            && callback(&snapshot.key) == CallbackProgression::Stop
+        {
+            return;
+        }
     }
 }
 
@@ -230,11 +294,8 @@ impl<Service: service::Service> PortFactory<Service, CustomKeyMarker> {
         &self,
         mut callback: F,
     ) {
-        self.service
-            .additional_resource()
-            .mgmt
-            .get()
-            .map
-            .list_keys(|key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| callback(key.data.as_ptr()));
+        list_key_memory(&self.service, |key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
+            callback(key.data.as_ptr())
+        });
     }
 }
@@ -33,13 +33,14 @@ use super::request_response::PortFactory;
 use crate::{
     port::{
         BackpressureFn, BackpressureHandler, DegradationAction, DegradationFn, DegradationHandler,
-        client::Client, port_name::PortName,
+        client::Client, port_name::PortName, request_dispatch_policy::RequestDispatchPolicy,
     },
     prelude::BackpressureStrategy,
     service::{self, marker::Flatbuffer},
 };
 use alloc::format;
 use core::fmt::Debug;
+use core::time::Duration;
 use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
 use iceoryx2_bb_elementary_traits::{iceoryx_send::IceoryxSend, zero_copy_send::ZeroCopySend};
 use iceoryx2_log::fail;
@@ -51,6 +52,12 @@ use tiny_fn::tiny_fn;
 pub enum ClientCreateError {
     /// The datasegment in which the payload of the [`Client`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The data segment of the [`Client`] shall be backed by huge pages but the system does
+    /// not support them.
+    DataSegmentHugePagesNotSupported,
+    /// The data segment of the [`Client`] could not be locked in memory, for instance since
+    /// the memory lock limit of the process is exceeded.
+    UnableToLockDataSegment,
     /// The maximum amount of [`Client`]s that can connect to a
     /// [`Service`](crate::service::Service) is
     /// defined in [`crate::config::Config`]. When this is exceeded no more [`Client`]s
@@ -100,6 +107,8 @@ impl<'a> Debug for PreallocatedRequestsOverride<'a> {
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct LocalClientConfig {
     pub(crate) backpressure_strategy: BackpressureStrategy,
+    pub(crate) request_dispatch_policy: RequestDispatchPolicy,
+    pub(crate) request_timeout: Option<Duration>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
     pub(crate) max_active_requests: Option<usize>,
@@ -195,12 +204,14 @@ impl<
             .service
             .shared_node()
             .config()
-            .defaults
+            .defaults_for(factory.service.static_config().name())
             .request_response;
 
         Self {
             config: LocalClientConfig {
                 backpressure_strategy: defs.client_backpressure_strategy,
+                request_dispatch_policy: RequestDispatchPolicy::default(),
+                request_timeout: None,
                 initial_max_slice_len: 1,
                 allocation_strategy: defs.client_allocation_strategy,
                 max_active_requests: None,
@@ -244,6 +255,25 @@ impl<
         self
     }
 
+    /// Sets the [`RequestDispatchPolicy`] which defines to which of the connected
+    /// [`Server`](crate::port::server::Server)s a [`RequestMut`](crate::request_mut::RequestMut)
+    /// is delivered. By default, every request is delivered to all
+    /// [`Server`](crate::port::server::Server)s.
+    pub fn request_dispatch_policy(mut self, value: RequestDispatchPolicy) -> Self {
+        self.config.request_dispatch_policy = value;
+        self
+    }
+
+    /// Sets the timeout after which every [`RequestMut`](crate::request_mut::RequestMut) of the
+    /// [`Client`] expires. The deadline of a [`RequestMut`](crate::request_mut::RequestMut) starts
+    /// when it is loaned and can be overridden with
+    /// [`RequestMut::set_deadline()`](crate::request_mut::RequestMut::set_deadline()).
+    /// By default, requests have no deadline.
+    pub fn request_timeout(mut self, value: Duration) -> Self {
+        self.config.request_timeout = Some(value);
+        self
+    }
+
     /// Sets the [`DegradationHandler`] for sending [`RequestMut`](crate::request_mut::RequestMut)
     /// from the [`Client`]. Whenever a request connection to a
     /// [`Server`](crate::port::server::Server) is corrupted or it seems to be dead, this handler
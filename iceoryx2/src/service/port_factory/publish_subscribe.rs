@@ -41,14 +41,19 @@
 //! ```
 extern crate alloc;
 use alloc::sync::Arc;
+use alloc::vec;
 use iceoryx2_bb_elementary_traits::iceoryx_send::IceoryxSend;
-use iceoryx2_cal::static_storage::StaticStorage;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageView};
+use iceoryx2_log::warn;
 
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
+use crate::dynamic_payload::TypeLayout;
 use crate::identifiers::UniqueServiceId;
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::marker::Flatbuffer;
 use crate::service::resource::publish_subscribe::PublishSubscribeResources;
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
@@ -60,6 +65,15 @@ use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
+/// The payload type of the process that opened or created the service. It can have a different
+/// version than the payload type of the service.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LocalPayloadType {
+    pub(crate) version: u32,
+    pub(crate) size: usize,
+    pub(crate) alignment: usize,
+}
+
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
 /// It can acquire dynamic and static service information and create
@@ -72,6 +86,7 @@ pub struct PortFactory<
     UserHeader: Debug + ZeroCopySend,
 > {
     pub(crate) service: SharedServiceState<Service, PublishSubscribeResources<Service>>,
+    pub(crate) local_payload_type: LocalPayloadType,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -155,11 +170,15 @@ impl<
     UserHeader: Debug + ZeroCopySend,
 > PortFactory<Service, Payload, UserHeader>
 {
-    pub(crate) fn new(service: ServiceState<Service, PublishSubscribeResources<Service>>) -> Self {
+    pub(crate) fn new(
+        service: ServiceState<Service, PublishSubscribeResources<Service>>,
+        local_payload_type: LocalPayloadType,
+    ) -> Self {
         Self {
             service: SharedServiceState {
                 state: Arc::new(service),
             },
+            local_payload_type,
             _payload: PhantomData,
             _user_header: PhantomData,
         }
@@ -214,7 +233,8 @@ impl<
         PortFactoryPublisher::new(self)
     }
 
-    /// Returns the [`StaticStorageView`](iceoryx2_cal::static_storage::StaticStorageView) that contains the type definition.
+    /// Returns the [`StaticStorageView`] that contains the type definition.
+    /// It is either the flatbuffer schema or the serialized [`TypeLayout`] of the payload.
     /// If the [`Service`](crate::service::Service) has no type definition it return [`None`].
     pub fn type_definition(&self) -> Option<&<Service::StaticStorage as StaticStorage>::View> {
         self.service
@@ -222,4 +242,65 @@ impl<
             .type_definition()
             .map(|v| v.view())
     }
+
+    /// Returns the [`TypeLayout`] of the payload when the payload type of the
+    /// [`Service`](crate::service::Service) was annotated with `#[type_layout]` in the
+    /// [`ZeroCopySend`] derive, otherwise [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, ZeroCopySend)]
+    /// #[type_layout]
+    /// #[repr(C)]
+    /// struct Position {
+    ///     x: f64,
+    ///     y: f64,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<Position>()
+    ///     .open_or_create()?;
+    ///
+    /// if let Some(layout) = pubsub.type_layout() {
+    ///     println!("payload layout: {layout:?}");
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn type_layout(&self) -> Option<TypeLayout> {
+        use iceoryx2_bb_container::string::String;
+        use iceoryx2_bb_elementary_traits::type_name::TypeName;
+
+        let payload_type_name = self
+            .service
+            .static_config()
+            .publish_subscribe()
+            .message_type_details()
+            .payload
+            .type_name();
+        if payload_type_name.as_str() == unsafe { Flatbuffer::<()>::type_name() } {
+            return None;
+        }
+
+        let type_definition = self.type_definition()?;
+        let mut content = vec![0u8; type_definition.len() as usize];
+        if let Err(e) = type_definition.read(&mut content) {
+            warn!(from self, "Unable to read the type layout of the payload. [{e:?}]");
+            return None;
+        }
+
+        match <Service::ConfigSerializer as Serialize>::deserialize(&content) {
+            Ok(type_layout) => Some(type_layout),
+            Err(e) => {
+                warn!(from self, "Unable to deserialize the type layout of the payload. [{e:?}]");
+                None
+            }
+        }
+    }
 }
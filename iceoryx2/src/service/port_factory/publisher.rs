@@ -50,13 +50,22 @@
 //!
 //! let sample = publisher.loan_slice(50)?;
 //!
+//! // samples require only the memory of their slice length
+//! let variable_size_publisher = pubsub.publisher_builder()
+//!                     .initial_max_slice_len(1024)
+//!                     .data_segment_allocator(DataSegmentAllocator::Buddy)
+//!                     .create()?;
+//!
+//! let sample = variable_size_publisher.loan_slice(12)?;
+//!
 //! # Ok(())
 //! # }
 //! ```
 
 use crate::{
     port::{
-        BackpressureFn, BackpressureHandler, DegradationAction, DegradationFn, DegradationHandler,
+        BackpressureFn, BackpressureHandler, DataSegmentAllocator, DegradationAction,
+        DegradationFn, DegradationHandler,
         backpressure_strategy::BackpressureStrategy,
         port_name::PortName,
         publisher::{Publisher, PublisherCreateError},
@@ -65,6 +74,7 @@ use crate::{
 };
 use alloc::format;
 use core::fmt::Debug;
+use core::time::Duration;
 use iceoryx2_bb_elementary::allocation_strategy::AllocationStrategy;
 use iceoryx2_bb_elementary_traits::{iceoryx_send::IceoryxSend, zero_copy_send::ZeroCopySend};
 use iceoryx2_log::fail;
@@ -100,6 +110,8 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) backpressure_strategy: BackpressureStrategy,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) segment_idle_timeout: Option<Duration>,
+    pub(crate) data_segment_allocator: DataSegmentAllocator,
     pub(crate) port_name: PortName,
 }
 
@@ -161,13 +173,15 @@ impl<
             .service
             .shared_node()
             .config()
-            .defaults
+            .defaults_for(factory.service.static_config().name())
             .publish_subscribe;
 
         Self {
             config: LocalPublisherConfig {
                 allocation_strategy: defaults.publisher_allocation_strategy,
                 initial_max_slice_len: 1,
+                segment_idle_timeout: None,
+                data_segment_allocator: DataSegmentAllocator::default(),
                 max_loaned_samples: defaults.publisher_max_loaned_samples,
                 backpressure_strategy: defaults.backpressure_strategy,
                 port_name: PortName::new_empty(),
@@ -268,6 +282,26 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
         self.config.allocation_strategy = value;
         self
     }
+
+    /// Defines how long a data segment that was acquired by a reallocation must be unused
+    /// before it is released and replaced by a data segment sized by
+    /// [`PortFactoryPublisher::initial_max_slice_len()`]. The timeout is evaluated when the next
+    /// sample is loaned. By default, reallocated data segments are kept until the [`Publisher`]
+    /// goes out of scope.
+    pub fn segment_idle_timeout(mut self, value: Duration) -> Self {
+        self.config.segment_idle_timeout = Some(value);
+        self
+    }
+
+    /// Defines the [`DataSegmentAllocator`] that manages the samples in the data segment. With
+    /// [`DataSegmentAllocator::Buddy`] every sample occupies only the memory its slice requires
+    /// instead of the memory of the largest slice. The data segment is then never reallocated,
+    /// therefore [`PortFactoryPublisher::allocation_strategy()`] is ignored and
+    /// [`PortFactoryPublisher::initial_max_slice_len()`] is the maximum slice length.
+    pub fn data_segment_allocator(mut self, value: DataSegmentAllocator) -> Self {
+        self.config.data_segment_allocator = value;
+        self
+    }
 }
 
 impl<Service: service::Service, Payload: Debug, UserHeader: Debug + ZeroCopySend>
@@ -286,4 +320,13 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug + ZeroCopySend
         self.config.allocation_strategy = value;
         self
     }
+
+    /// Defines how long a data segment that was acquired by a reallocation must be unused
+    /// before it is released and replaced by a data segment sized by
+    /// [`PortFactoryPublisher::initial_reserved_memory()`]. The timeout is evaluated when the
+    /// next sample is loaned.
+    pub fn segment_idle_timeout(mut self, value: Duration) -> Self {
+        self.config.segment_idle_timeout = Some(value);
+        self
+    }
 }
@@ -67,6 +67,12 @@ pub enum ServerCreateError {
     ExceedsMaxSupportedServers,
     /// The datasegment in which the payload of the [`Server`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The data segment of the [`Server`] shall be backed by huge pages but the system does
+    /// not support them.
+    DataSegmentHugePagesNotSupported,
+    /// The data segment of the [`Server`] could not be locked in memory, for instance since
+    /// the memory lock limit of the process is exceeded.
+    UnableToLockDataSegment,
     /// Caused by a failure when instantiating a
     /// [`ArcSyncPolicy`](iceoryx2_cal::arc_sync_policy::ArcSyncPolicy) defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
@@ -195,7 +201,7 @@ impl<
             .service
             .shared_node()
             .config()
-            .defaults
+            .defaults_for(factory.service.static_config().name())
             .request_response;
 
         Self {
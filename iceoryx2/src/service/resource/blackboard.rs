@@ -13,30 +13,41 @@
 extern crate alloc;
 
 use crate::config;
-use crate::constants::{MAX_BLACKBOARD_KEY_ALIGNMENT, MAX_BLACKBOARD_KEY_SIZE};
+use crate::constants::{
+    MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT, MAX_BLACKBOARD_KEY_ALIGNMENT, MAX_BLACKBOARD_KEY_SIZE,
+};
+use crate::service::blackboard_snapshot::{BlackboardSnapshot, BlackboardSnapshotEntry};
 use crate::service::builder::{self, ServiceCreateError};
 use crate::service::config_scheme::{blackboard_data_config, blackboard_mgmt_config};
 use crate::service::naming_scheme::blackboard_name;
 use crate::service::resource::RemoveStaleResourcesError;
-use crate::service::static_config::StaticConfig;
+use crate::service::static_config::{StaticConfig, blackboard};
 use crate::service::{
     self, resource::ServiceResource, static_config::message_type_details::TypeDetail,
 };
 use alloc::format;
 use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::mem::MaybeUninit;
 use core::{fmt::Debug, ptr::NonNull};
-use iceoryx2_bb_concurrency::atomic::AtomicU64;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering, fence};
+use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_container::string::String;
 use iceoryx2_bb_container::vector::Vector;
 use iceoryx2_bb_container::{flatmap::RelocatableFlatMap, vector::RelocatableVec};
 use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary::static_assert_align_of;
 use iceoryx2_bb_elementary_traits::{
     testing::abandonable::Abandonable, zero_copy_send::ZeroCopySend,
 };
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{
+    __internal_calculate_atomic_mgmt_and_payload_ptr, UnrestrictedAtomicMgmt,
+};
 use iceoryx2_bb_memory::bump_allocator::{Allocate, BumpAllocator};
 use iceoryx2_bb_posix::file::AccessMode;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
@@ -157,11 +168,177 @@ pub(crate) struct Entry {
     pub(crate) offset: AtomicU64,
 }
 
+/// Slot for an entry that can be added and removed at runtime by the writer. Key, type details
+/// and usage state are protected by a sequence lock: the writer increments the
+/// `sequence_number` before and after every modification so that readers can detect concurrent
+/// modifications and removed entries.
+#[repr(C)]
+#[derive(Debug, ZeroCopySend)]
+pub(crate) struct DynamicEntry {
+    sequence_number: AtomicU64,
+    is_used: UnsafeCell<bool>,
+    key: UnsafeCell<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>>,
+    type_details: UnsafeCell<TypeDetail>,
+    pub(crate) offset: u64,
+}
+
+// Safe since the cells are only modified by the single writer inside the sequence lock and
+// readers detect concurrent modifications via the sequence number.
+unsafe impl Send for DynamicEntry {}
+unsafe impl Sync for DynamicEntry {}
+
+/// Consistent snapshot of a used [`DynamicEntry`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicEntrySnapshot {
+    pub(crate) sequence_number: u64,
+    pub(crate) key: KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+    pub(crate) type_details: TypeDetail,
+}
+
+impl DynamicEntry {
+    fn new(offset: u64) -> Self {
+        Self {
+            sequence_number: AtomicU64::new(0),
+            is_used: UnsafeCell::new(false),
+            key: UnsafeCell::new(KeyMemory {
+                data: [0; MAX_BLACKBOARD_KEY_SIZE],
+            }),
+            type_details: UnsafeCell::new(TypeDetail::default()),
+            offset,
+        }
+    }
+
+    /// Returns a consistent snapshot of the entry or [`None`] when the slot is not used.
+    pub(crate) fn snapshot(&self) -> Option<DynamicEntrySnapshot> {
+        loop {
+            let sequence_number = self.sequence_number.load(Ordering::Acquire);
+            if sequence_number % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            let (is_used, key, type_details) = unsafe {
+                (
+                    core::ptr::read_volatile(self.is_used.get()),
+                    core::ptr::read_volatile(self.key.get()),
+                    core::ptr::read_volatile(self.type_details.get()),
+                )
+            };
+
+            if self.is_unchanged_since(sequence_number) {
+                return match is_used {
+                    true => Some(DynamicEntrySnapshot {
+                        sequence_number,
+                        key,
+                        type_details,
+                    }),
+                    false => None,
+                };
+            }
+        }
+    }
+
+    /// Returns true when the entry was not modified since the snapshot with the provided
+    /// `sequence_number` was acquired.
+    pub(crate) fn is_unchanged_since(&self, sequence_number: u64) -> bool {
+        fence(Ordering::Acquire);
+        self.sequence_number.load(Ordering::Relaxed) == sequence_number
+    }
+
+    /// # Safety
+    ///
+    ///  * must only be called by the single writer of the blackboard
+    pub(crate) unsafe fn is_used(&self) -> bool {
+        unsafe { *self.is_used.get() }
+    }
+
+    /// # Safety
+    ///
+    ///  * must only be called by the single writer of the blackboard
+    pub(crate) unsafe fn assign(
+        &self,
+        key: KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+        type_details: TypeDetail,
+    ) {
+        self.modify(|| unsafe {
+            self.key.get().write_volatile(key);
+            self.type_details.get().write_volatile(type_details);
+            self.is_used.get().write_volatile(true);
+        });
+    }
+
+    /// # Safety
+    ///
+    ///  * must only be called by the single writer of the blackboard
+    pub(crate) unsafe fn release(&self) {
+        self.modify(|| unsafe { self.is_used.get().write_volatile(false) });
+    }
+
+    fn modify<F: FnOnce()>(&self, modification: F) {
+        let sequence_number = self.sequence_number.load(Ordering::Relaxed);
+        self.sequence_number
+            .store(sequence_number + 1, Ordering::Relaxed);
+        fence(Ordering::Release);
+        modification();
+        self.sequence_number
+            .store(sequence_number + 2, Ordering::Release);
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, ZeroCopySend)]
 pub(crate) struct Mgmt {
     pub(crate) map: RelocatableFlatMap<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>,
     pub(crate) entries: RelocatableVec<Entry>,
+    pub(crate) dynamic_entries: RelocatableVec<DynamicEntry>,
+    pub(crate) key_set_event_id: usize,
+}
+
+impl Mgmt {
+    /// Returns the index and a snapshot of the dynamic entry with the provided key.
+    pub(crate) fn find_dynamic_entry(
+        &self,
+        key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+        key_eq_func: &(dyn Fn(*const u8, *const u8) -> bool + Send + Sync),
+    ) -> Option<(usize, DynamicEntrySnapshot)> {
+        for (index, entry) in self.dynamic_entries.iter().enumerate() {
+            if let Some(snapshot) = entry.snapshot()
+                && key_eq_func(
+                    (&snapshot.key as *const KeyMemory<MAX_BLACKBOARD_KEY_SIZE>).cast(),
+                    (key as *const KeyMemory<MAX_BLACKBOARD_KEY_SIZE>).cast(),
+                )
+            {
+                return Some((index, snapshot));
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns the size of a single cell of a dynamic entry value.
+pub(crate) fn dynamic_value_cell_size(max_dynamic_value_size: usize) -> usize {
+    align(
+        max_dynamic_value_size.max(1),
+        MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+    )
+}
+
+/// Returns the pointers to the [`UnrestrictedAtomicMgmt`] and the data cells of a dynamic entry
+/// value that is stored at the `offset` in the blackboard data segment.
+pub(crate) fn dynamic_value_ptrs(
+    payload_start_address: usize,
+    offset: u64,
+) -> (*const UnrestrictedAtomicMgmt, *mut u8) {
+    let atomic_mgmt_ptr = payload_start_address + offset as usize;
+    let data_ptr = align(
+        atomic_mgmt_ptr + core::mem::size_of::<UnrestrictedAtomicMgmt>(),
+        MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+    );
+    (
+        atomic_mgmt_ptr as *const UnrestrictedAtomicMgmt,
+        data_ptr as *mut u8,
+    )
 }
 
 pub(crate) struct BlackboardResources<ServiceType: service::Service> {
@@ -182,6 +359,89 @@ impl<ServiceType: service::Service> Debug for BlackboardResources<ServiceType> {
     }
 }
 
+impl<ServiceType: service::Service> BlackboardResources<ServiceType> {
+    /// Copies the keys and values of all entries into a [`BlackboardSnapshot`].
+    pub(crate) fn snapshot(&self, static_config: &blackboard::StaticConfig) -> BlackboardSnapshot {
+        let key_type_details = *static_config.type_details();
+        let cell_size = dynamic_value_cell_size(static_config.max_dynamic_value_size);
+        let payload_start_address = self.data.payload_start_address();
+        let mgmt = self.mgmt.get();
+        let mut entries = Vec::new();
+
+        mgmt.map.list_keys(|key| {
+            let index = match unsafe { mgmt.map.__internal_get(key, self.key_eq_func.as_ref()) } {
+                Some(index) => index,
+                None => return CallbackProgression::Continue,
+            };
+
+            let entry = &mgmt.entries[index];
+            let type_details = entry.type_details;
+            let atomic_mgmt_ptr = (payload_start_address
+                + entry.offset.load(Ordering::Relaxed) as usize)
+                as *const UnrestrictedAtomicMgmt;
+            let data_ptr = align(
+                atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>(),
+                type_details.alignment,
+            );
+
+            let mut value = vec![0u8; type_details.size];
+            unsafe {
+                (*atomic_mgmt_ptr).load(
+                    value.as_mut_ptr(),
+                    type_details.size,
+                    type_details.alignment,
+                    data_ptr as *const u8,
+                )
+            };
+
+            entries.push(BlackboardSnapshotEntry {
+                key: key.data[..key_type_details.size].to_vec(),
+                value_type_details: type_details,
+                value,
+                is_dynamic: false,
+            });
+            CallbackProgression::Continue
+        });
+
+        for entry in mgmt.dynamic_entries.iter() {
+            let snapshot = match entry.snapshot() {
+                Some(snapshot) => snapshot,
+                None => continue,
+            };
+
+            let (atomic_mgmt_ptr, data_ptr) =
+                dynamic_value_ptrs(payload_start_address, entry.offset);
+            let mut value = vec![0u8; snapshot.type_details.size];
+            unsafe {
+                (*atomic_mgmt_ptr).__internal_load_from_cell(
+                    value.as_mut_ptr(),
+                    snapshot.type_details.size,
+                    cell_size,
+                    MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+                    data_ptr,
+                )
+            };
+
+            // the cells may have been reused by another entry in the meantime
+            if !entry.is_unchanged_since(snapshot.sequence_number) {
+                continue;
+            }
+
+            entries.push(BlackboardSnapshotEntry {
+                key: snapshot.key.data[..key_type_details.size].to_vec(),
+                value_type_details: snapshot.type_details,
+                value,
+                is_dynamic: true,
+            });
+        }
+
+        BlackboardSnapshot {
+            key_type_details,
+            entries,
+        }
+    }
+}
+
 impl<ServiceType: service::Service> Abandonable for BlackboardResources<ServiceType> {
     unsafe fn abandon_in_place(mut this: NonNull<Self>) {
         let this = unsafe { this.as_mut() };
@@ -348,6 +608,7 @@ impl<ServiceType: service::Service> ServiceResource for BlackboardResources<Serv
         let blackboard_config = *resource_config.config_details();
         let key_eq_func = resource_config.key_eq_func.clone();
         let builder_internals = resource_config.internals.as_slice();
+        let restored_dynamic_entries = resource_config.restored_dynamic_entries.as_slice();
         let shared_node = &resource_config.base.shared_node;
         // create the payload data segment for the writer
         let name = blackboard_name(service_config.unique_service_id());
@@ -356,6 +617,21 @@ impl<ServiceType: service::Service> ServiceResource for BlackboardResources<Serv
         for i in builder_internals.iter() {
             payload_size += i.internal_value_size + i.internal_value_alignment - 1;
         }
+        let max_dynamic_entries = blackboard_config.max_dynamic_entries;
+        let dynamic_cell_size = dynamic_value_cell_size(blackboard_config.max_dynamic_value_size);
+        let dynamic_value_layout = unsafe {
+            Layout::from_size_align_unchecked(
+                UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+                    dynamic_cell_size,
+                    MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+                ),
+                UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(
+                    MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT,
+                ),
+            )
+        };
+        payload_size +=
+            max_dynamic_entries * (dynamic_value_layout.size() + dynamic_value_layout.align() - 1);
         let payload_shm = match <<ServiceType::BlackboardPayload as SharedMemory<
             iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator,
         >>::Builder as NamedConceptBuilder<ServiceType::BlackboardPayload>>::new(
@@ -374,8 +650,10 @@ impl<ServiceType: service::Service> ServiceResource for BlackboardResources<Serv
             }
         };
 
-        // create the management segment
-        let capacity = builder_internals.len();
+        // create the management segment; the containers require at least a capacity of one
+        // since zero-sized allocations are not supported
+        let capacity = builder_internals.len().max(1);
+        let dynamic_capacity = max_dynamic_entries.max(1);
 
         let mut mgmt_config = blackboard_mgmt_config::<ServiceType, Mgmt>(shared_node.config());
         let mgmt_name = blackboard_config.type_details.type_name.as_str();
@@ -393,17 +671,54 @@ impl<ServiceType: service::Service> ServiceResource for BlackboardResources<Serv
             >>::Builder::new(&name)
                 .config(&mgmt_config)
                 .has_ownership(true)
-                .supplementary_size(RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::const_memory_size(capacity)+RelocatableVec::<Entry>::const_memory_size(capacity))
+                .supplementary_size(RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::const_memory_size(capacity)
+                    + RelocatableVec::<Entry>::const_memory_size(capacity)
+                    + RelocatableVec::<DynamicEntry>::const_memory_size(dynamic_capacity))
                 .initializer(|mgmt: &mut MaybeUninit<Mgmt>, allocator: &mut BumpAllocator| {
                     mgmt.write(Mgmt {
                         map: unsafe { RelocatableFlatMap::<KeyMemory<MAX_BLACKBOARD_KEY_SIZE>, usize>::new_uninit(capacity) },
                         entries: unsafe { RelocatableVec::<Entry>::new_uninit(capacity) },
+                        dynamic_entries: unsafe { RelocatableVec::<DynamicEntry>::new_uninit(dynamic_capacity) },
+                        // every entry id is an offset inside the data segment, therefore the
+                        // size of the data segment can never collide with an entry id
+                        key_set_event_id: payload_size,
                     });
                     let mgmt = unsafe { mgmt.assume_init_mut() };
 
-                    if unsafe {mgmt.map.init(allocator)}.is_err() || unsafe {mgmt.entries.init(allocator).is_err()} {
+                    if unsafe {mgmt.map.init(allocator)}.is_err()
+                        || unsafe {mgmt.entries.init(allocator).is_err()}
+                        || unsafe {mgmt.dynamic_entries.init(allocator).is_err()} {
                         return false
                     }
+                    for _ in 0..max_dynamic_entries {
+                        let mem = match payload_shm.allocate(dynamic_value_layout) {
+                            Ok(m) => m,
+                            Err(_) => {
+                                error!(from origin, "Reserving the memory for a dynamic entry in the blackboard data segment failed.");
+                                return false
+                            }
+                        };
+                        unsafe { __internal_calculate_atomic_mgmt_and_payload_ptr(mem.data_ptr, MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT) };
+                        if mgmt.dynamic_entries.push(DynamicEntry::new(mem.offset.offset() as u64)).is_err() {
+                            error!(from origin, "Writing the dynamic entry to the blackboard management segment failed.");
+                            return false
+                        }
+                    }
+                    for (entry, restored) in mgmt.dynamic_entries.iter().zip(restored_dynamic_entries.iter()) {
+                        // restore the dynamic entries of a snapshot like Writer::add_entry()
+                        let (atomic_mgmt_ptr, data_ptr) = dynamic_value_ptrs(payload_shm.payload_start_address(), entry.offset);
+                        unsafe {
+                            if (*atomic_mgmt_ptr).__internal_acquire_producer().is_err() {
+                                error!(from origin, "Restoring the dynamic entry of the snapshot failed since the value is already in use.");
+                                return false
+                            }
+                            let write_cell = (*atomic_mgmt_ptr).__internal_get_ptr_to_write_cell(dynamic_cell_size, MAX_BLACKBOARD_DYNAMIC_VALUE_ALIGNMENT, data_ptr);
+                            core::ptr::copy_nonoverlapping(restored.value.as_ptr(), write_cell, restored.value.len());
+                            (*atomic_mgmt_ptr).__internal_update_write_cell();
+                            (*atomic_mgmt_ptr).__internal_release_producer();
+                            entry.assign(restored.key, restored.value_type_details);
+                        }
+                    }
                     for entry in builder_internals.iter() {
                         // write value passed to add() to payload_shm
                         let mem = match payload_shm.allocate(unsafe { Layout::from_size_align_unchecked(entry.internal_value_size, entry.internal_value_alignment) })
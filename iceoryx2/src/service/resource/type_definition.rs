@@ -84,6 +84,8 @@ pub struct TypeDefinition {
     pub schema_path: Option<FilePath>,
     pub type_name: TypeName,
     pub skip_type_definition_verification: bool,
+    pub type_layout: Option<Vec<u8>>,
+    pub open_type_layout: bool,
 }
 
 impl TypeDefinition {
@@ -93,16 +95,18 @@ impl TypeDefinition {
         config: &crate::config::Config,
         static_config: &crate::service::static_config::StaticConfig,
     ) -> Result<Option<TypeDefinitionStorage<S>>, ServiceCreateError> {
-        if !self.use_type_definition {
+        let schema_file_content = if self.use_type_definition {
+            self.read_schema_file(config)?
+        } else if let Some(type_layout) = &self.type_layout {
+            type_layout.clone()
+        } else {
             return Ok(None);
-        }
+        };
 
         let msg = "Unable to create type definition storage";
         let static_storage_config =
             Self::type_definition_static_storage_config::<S>(config, static_config);
 
-        let schema_file_content = self.read_schema_file(config)?;
-
         let static_storage = match
             <<S::StaticStorage as iceoryx2_cal::static_storage::StaticStorage>::Builder as NamedConceptBuilder::<S::StaticStorage>>::new(name).config(&static_storage_config).create(&schema_file_content) {
                     Ok(static_storage) => static_storage,
@@ -132,11 +136,8 @@ impl TypeDefinition {
         name: &FileName,
         config: &crate::config::Config,
         static_config: &crate::service::static_config::StaticConfig,
+        is_optional: bool,
     ) -> Result<Option<TypeDefinitionStorage<S>>, ServiceOpenError> {
-        if !self.use_type_definition {
-            return Ok(None);
-        }
-
         let msg = "Unable to open type definition storage";
         let static_storage_config =
             Self::type_definition_static_storage_config::<S>(config, static_config);
@@ -144,6 +145,7 @@ impl TypeDefinition {
         let static_storage = match
                 <<S::StaticStorage as iceoryx2_cal::static_storage::StaticStorage>::Builder as NamedConceptBuilder::<S::StaticStorage>>::new(name).config(&static_storage_config).open(Duration::ZERO) {
                     Ok(static_storage) => static_storage,
+                    Err(StaticStorageOpenError::DoesNotExist) if is_optional => return Ok(None),
                     Err(StaticStorageOpenError::InsufficientPermissions) => {
                         fail!(from self, with ServiceOpenError::InsufficientPermissions,
                             "{msg} since the type definition could not be opened.");
@@ -181,11 +183,14 @@ impl TypeDefinition {
         static_config: &crate::service::static_config::StaticConfig,
     ) -> Result<Option<TypeDefinitionStorage<S>>, ServiceOpenError> {
         if !self.use_type_definition {
+            if self.open_type_layout {
+                return self.open_storage(name, config, static_config, true);
+            }
             return Ok(None);
         }
 
         if self.skip_type_definition_verification {
-            return self.open_storage(name, config, static_config);
+            return self.open_storage(name, config, static_config, false);
         }
 
         let msg = "Unable to open type definition storage";
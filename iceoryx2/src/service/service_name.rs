@@ -119,6 +119,62 @@ impl ServiceName {
     pub fn max_len() -> usize {
         ServiceNameString::capacity()
     }
+
+    /// Returns true when the [`ServiceName`] matches the provided pattern, otherwise false.
+    /// In the pattern `*` matches any sequence of characters, including an empty one, and `?`
+    /// matches exactly one character.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let service_name = ServiceName::new("sensor/front/lidar")?;
+    ///
+    /// assert!(service_name.matches("sensor/*"));
+    /// assert!(service_name.matches("sensor/?????/lidar"));
+    /// assert!(!service_name.matches("sensor/*/radar"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches(&self, pattern: &str) -> bool {
+        let mut remaining_pattern = pattern;
+        let mut remaining_name = self.as_str();
+        // the pattern after the last '*' and the position in the name where it is retried
+        let mut last_wildcard: Option<(&str, &str)> = None;
+
+        loop {
+            let mut pattern_chars = remaining_pattern.chars();
+            let mut name_chars = remaining_name.chars();
+            match (pattern_chars.next(), name_chars.next()) {
+                (Some('*'), _) => {
+                    remaining_pattern = pattern_chars.as_str();
+                    last_wildcard = Some((remaining_pattern, remaining_name));
+                    continue;
+                }
+                (Some(p), Some(n)) if p == '?' || p == n => {
+                    remaining_pattern = pattern_chars.as_str();
+                    remaining_name = name_chars.as_str();
+                    continue;
+                }
+                (None, None) => return true,
+                _ => (),
+            }
+
+            // mismatch, let the last '*' consume one more character and retry
+            match last_wildcard {
+                Some((pattern, name)) => {
+                    let mut name_chars = name.chars();
+                    if name_chars.next().is_none() {
+                        return false;
+                    }
+                    remaining_pattern = pattern;
+                    remaining_name = name_chars.as_str();
+                    last_wildcard = Some((remaining_pattern, remaining_name));
+                }
+                None => return false,
+            }
+        }
+    }
 }
 
 impl core::fmt::Display for ServiceName {
@@ -29,8 +29,11 @@ use crate::service::Service;
 use crate::service::config_scheme::port_tag_config;
 use crate::service::config_scheme::service_tag_config;
 use crate::service::config_scheme::static_config_storage_config;
-use crate::service::config_scheme::{data_segment_config, resizable_data_segment_config};
+use crate::service::config_scheme::{
+    buddy_data_segment_config, data_segment_config, resizable_data_segment_config,
+};
 use crate::service::naming_scheme::data_segment_name;
+use crate::service::naming_scheme::extract_port_ids_from_receive_area;
 use crate::service::naming_scheme::static_config_name;
 use crate::service::service_hash::ServiceHash;
 
@@ -232,7 +235,59 @@ pub unsafe fn remove_data_segment_of_port<Service: service::Service>(
                 &resizable_data_segment_config::<Service>(config),
             ), "Unable to remove the ports ({port_id}) resizable data segment."
         );
+
+        fail!(from origin, when <Service::BuddySharedMemory as NamedConceptMgmt>::remove_cfg(
+                &data_segment_name(port_id),
+                &buddy_data_segment_config::<Service>(config),
+            ), "Unable to remove the ports ({port_id}) buddy allocated data segment."
+        );
+
+        fail!(from origin, when remove_receive_areas_of_port::<Service::SharedMemory>(
+                port_id,
+                &data_segment_config::<Service>(config),
+            ), "Unable to remove the ports ({port_id}) receive areas."
+        );
+
+        fail!(from origin, when remove_receive_areas_of_port::<Service::ResizableSharedMemory>(
+                port_id,
+                &resizable_data_segment_config::<Service>(config),
+            ), "Unable to remove the ports ({port_id}) resizable receive areas."
+        );
+
+        fail!(from origin, when remove_receive_areas_of_port::<Service::BuddySharedMemory>(
+                port_id,
+                &buddy_data_segment_config::<Service>(config),
+            ), "Unable to remove the ports ({port_id}) buddy allocated receive areas."
+        );
+    }
+    Ok(())
+}
+
+unsafe fn remove_receive_areas_of_port<Memory: NamedConceptMgmt>(
+    port_id: u128,
+    config: &Memory::Configuration,
+) -> Result<(), NamedConceptRemoveError> {
+    let receive_areas = match Memory::list_cfg(config) {
+        Ok(list) => list,
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            return Err(NamedConceptRemoveError::InsufficientPermissions);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            return Err(NamedConceptRemoveError::InternalError);
+        }
+    };
+
+    for receive_area in receive_areas {
+        // the receiver takes over the receive area when the sender is gone, therefore it
+        // belongs to both ports
+        if let Some((sender_port_id, receiver_port_id)) =
+            extract_port_ids_from_receive_area(&receive_area)
+            && (sender_port_id == port_id || receiver_port_id == port_id)
+        {
+            unsafe { Memory::remove_cfg(&receive_area, config) }?;
+        }
     }
+
     Ok(())
 }
 
@@ -46,15 +46,19 @@ pub struct StaticConfig {
     pub(crate) max_readers: usize,
     pub(crate) max_writers: usize,
     pub(crate) max_nodes: usize,
+    pub(crate) max_dynamic_entries: usize,
+    pub(crate) max_dynamic_value_size: usize,
     pub(crate) type_details: TypeDetail,
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_readers: config.defaults.blackboard.max_readers,
+            max_readers: defaults.blackboard.max_readers,
             max_writers: 1,
-            max_nodes: config.defaults.blackboard.max_nodes,
+            max_nodes: defaults.blackboard.max_nodes,
+            max_dynamic_entries: 0,
+            max_dynamic_value_size: 0,
             type_details: TypeDetail::default(),
         }
     }
@@ -70,6 +74,17 @@ impl StaticConfig {
         self.max_readers
     }
 
+    /// Returns the maximum number of entries that can be added at runtime with
+    /// [`crate::port::writer::Writer::add_entry()`].
+    pub fn max_dynamic_entries(&self) -> usize {
+        self.max_dynamic_entries
+    }
+
+    /// Returns the maximum size in bytes of a value of an entry that is added at runtime.
+    pub fn max_dynamic_value_size(&self) -> usize {
+        self.max_dynamic_value_size
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn type_details(&self) -> &TypeDetail {
         &self.type_details
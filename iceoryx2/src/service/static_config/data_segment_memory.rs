@@ -0,0 +1,56 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::shared_memory::MemoryProperties;
+use serde::{Deserialize, Serialize};
+
+/// Defines how the memory of the data segments of a [`Service`](crate::service::Service) is
+/// provided by the operating system. It is applied by every port that creates or maps a
+/// data segment.
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+#[repr(C)]
+pub struct DataSegmentMemory {
+    pub(crate) use_huge_pages: bool,
+    pub(crate) lock_memory: bool,
+    pub(crate) prefault_memory: bool,
+}
+
+impl DataSegmentMemory {
+    /// Returns true if the data segments are backed by huge pages.
+    pub fn use_huge_pages(&self) -> bool {
+        self.use_huge_pages
+    }
+
+    /// Returns true if the data segments are locked in memory so that they are never swapped
+    /// out.
+    pub fn lock_memory(&self) -> bool {
+        self.lock_memory
+    }
+
+    /// Returns true if every page of the data segments is touched when it is mapped so that
+    /// no page fault occurs later in the hot path.
+    pub fn prefault_memory(&self) -> bool {
+        self.prefault_memory
+    }
+
+    pub(crate) fn memory_properties(&self) -> MemoryProperties {
+        MemoryProperties {
+            use_huge_pages: self.use_huge_pages,
+            is_memory_locked: self.lock_memory,
+            prefault_memory: self.prefault_memory,
+        }
+    }
+}
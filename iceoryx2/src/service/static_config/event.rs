@@ -65,13 +65,12 @@ pub struct StaticConfig {
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_notifiers: config.defaults.event.max_notifiers,
-            max_listeners: config.defaults.event.max_listeners,
-            max_nodes: config.defaults.event.max_nodes,
-            deadline: config
-                .defaults
+            max_notifiers: defaults.event.max_notifiers,
+            max_listeners: defaults.event.max_listeners,
+            max_nodes: defaults.event.max_nodes,
+            deadline: defaults
                 .event
                 .deadline
                 .map(|v| Deadline {
@@ -79,10 +78,10 @@ impl StaticConfig {
                     value: v.into(),
                 })
                 .into(),
-            event_id_max_value: config.defaults.event.event_id_max_value,
-            notifier_created_event: config.defaults.event.notifier_created_event.into(),
-            notifier_dropped_event: config.defaults.event.notifier_dropped_event.into(),
-            notifier_dead_event: config.defaults.event.notifier_dead_event.into(),
+            event_id_max_value: defaults.event.event_id_max_value,
+            notifier_created_event: defaults.event.notifier_created_event.into(),
+            notifier_dropped_event: defaults.event.notifier_dropped_event.into(),
+            notifier_dead_event: defaults.event.notifier_dead_event.into(),
         }
     }
 
@@ -15,11 +15,14 @@ use core::alloc::Layout;
 use iceoryx2_bb_container::string::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary_traits::fingerprint::Fingerprint;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_log::fatal_panic;
 use serde::{Deserialize, Serialize};
 
-use crate::constants::MAX_TYPE_NAME_LENGTH;
+use crate::constants::{
+    MAX_FIELD_NAME_LENGTH, MAX_NUMBER_OF_RECORDED_FIELDS, MAX_TYPE_NAME_LENGTH,
+};
 
 /// Defines if the type is a slice with a runtime-size ([`TypeVariant::Dynamic`])
 /// or if its a type that satisfies [`Sized`] ([`TypeVariant::FixedSize`]).
@@ -129,6 +132,150 @@ impl TypeDetail {
     }
 }
 
+/// A fixed-size string type used to store field names.
+pub type FieldName = StaticString<MAX_FIELD_NAME_LENGTH>;
+
+/// The name and the structural fingerprint of a single field of a type. The fingerprint covers
+/// the offset and the structure of the field type.
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+#[repr(C)]
+pub struct FieldFingerprint {
+    name: FieldName,
+    fingerprint: u32,
+}
+
+impl FieldFingerprint {
+    /// The name of the field, fields of enum variants are named `Variant.field`.
+    pub fn name(&self) -> &FieldName {
+        &self.name
+    }
+
+    /// The structural fingerprint of the field.
+    pub fn fingerprint(&self) -> u32 {
+        self.fingerprint
+    }
+}
+
+/// Describes the structure of a type that implements
+/// [`ZeroCopySend`] with the derive macro. Its fingerprint covers the names, types and offsets
+/// of all fields recursively, so that two types with the same name and layout but, for
+/// instance, swapped fields are detected. The first [`MAX_NUMBER_OF_RECORDED_FIELDS`] fields
+/// are recorded individually to name the field that differs.
+///
+/// Types whose structure is not known, like types that are defined in another language, have
+/// an unknown [`TypeStructure`] which is compatible to every other structure.
+#[derive(Default, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+#[repr(C)]
+pub struct TypeStructure {
+    fingerprint: u32,
+    number_of_fields: usize,
+    fields: [FieldFingerprint; MAX_NUMBER_OF_RECORDED_FIELDS],
+}
+
+impl core::fmt::Debug for TypeStructure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypeStructure")
+            .field("fingerprint", &self.fingerprint)
+            .field("fields", &self.fields())
+            .finish()
+    }
+}
+
+impl TypeStructure {
+    /// Records the [`TypeStructure`] of `T`.
+    pub fn new<T: iceoryx2_bb_elementary_traits::type_name::TypeName>() -> Self {
+        let mut this = Self {
+            fingerprint:
+                <T as iceoryx2_bb_elementary_traits::type_name::TypeName>::__structural_fingerprint(
+                ),
+            ..Default::default()
+        };
+
+        if !this.is_known() {
+            return this;
+        }
+
+        <T as iceoryx2_bb_elementary_traits::type_name::TypeName>::__for_each_field(
+            &mut |name, offset, fingerprint| {
+                if this.number_of_fields < MAX_NUMBER_OF_RECORDED_FIELDS {
+                    this.fields[this.number_of_fields] = FieldFingerprint {
+                        name: FieldName::from_str_truncated(name).unwrap_or_default(),
+                        fingerprint: Fingerprint::new()
+                            .add_usize(offset)
+                            .add_u32(fingerprint)
+                            .value(),
+                    };
+                    this.number_of_fields += 1;
+                }
+            },
+        );
+
+        this
+    }
+
+    /// Returns true when the structure of the type is known.
+    pub fn is_known(&self) -> bool {
+        self.fingerprint != Fingerprint::UNKNOWN
+    }
+
+    /// The structural fingerprint of the whole type.
+    pub fn fingerprint(&self) -> u32 {
+        self.fingerprint
+    }
+
+    /// The individually recorded fields of the type.
+    pub fn fields(&self) -> &[FieldFingerprint] {
+        &self.fields[..self.number_of_fields]
+    }
+
+    /// Returns true when the recorded fields are the first fields of `rhs`, like the fields of
+    /// an older version of a type. Unknown structures are a prefix of every structure.
+    pub(crate) fn is_prefix_of(&self, rhs: &Self) -> bool {
+        !self.is_known() || !rhs.is_known() || rhs.fields().starts_with(self.fields())
+    }
+
+    /// Returns [`None`] when both structures are compatible, otherwise the
+    /// [`StructuralDifference`].
+    pub(crate) fn difference_to(&self, rhs: &Self) -> Option<StructuralDifference> {
+        if !self.is_known() || !rhs.is_known() || self.fingerprint == rhs.fingerprint {
+            return None;
+        }
+
+        let number_of_fields = self.number_of_fields.max(rhs.number_of_fields);
+        for n in 0..number_of_fields {
+            let lhs_field = self.fields().get(n);
+            let rhs_field = rhs.fields().get(n);
+            if lhs_field != rhs_field {
+                return Some(StructuralDifference {
+                    field: lhs_field.or(rhs_field).map(|f| f.name),
+                });
+            }
+        }
+
+        Some(StructuralDifference { field: None })
+    }
+}
+
+/// Names the field in which two [`TypeStructure`]s differ.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct StructuralDifference {
+    field: Option<FieldName>,
+}
+
+impl core::fmt::Display for StructuralDifference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.field {
+            Some(name) => write!(f, "field \"{name}\""),
+            None => write!(
+                f,
+                "size, alignment or a field beyond the first {MAX_NUMBER_OF_RECORDED_FIELDS} fields"
+            ),
+        }
+    }
+}
+
 /// Contains all type information to the header and payload type.
 #[derive(
     Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
@@ -142,6 +289,13 @@ pub struct MessageTypeDetails {
     pub user_header: TypeDetail,
     /// The [`TypeDetail`] of the payload of the message, the last part.
     pub payload: TypeDetail,
+    /// The [`TypeStructure`] of the user header.
+    pub user_header_structure: TypeStructure,
+    /// The [`TypeStructure`] of the payload.
+    pub payload_structure: TypeStructure,
+    /// The version of the payload type that is defined with `#[type_version(..)]` when
+    /// [`ZeroCopySend`] is derived. Unversioned types have the version 1.
+    pub payload_version: u32,
 }
 
 impl MessageTypeDetails {
@@ -156,6 +310,10 @@ impl MessageTypeDetails {
             header: TypeDetail::new::<Header>(TypeVariant::FixedSize),
             user_header: TypeDetail::new::<UserHeader>(TypeVariant::FixedSize),
             payload: TypeDetail::new::<Payload>(payload_variant),
+            user_header_structure: TypeStructure::new::<UserHeader>(),
+            payload_structure: TypeStructure::new::<Payload>(),
+            payload_version:
+                <Payload as iceoryx2_bb_elementary_traits::type_name::TypeName>::__type_version(),
         }
     }
 
@@ -210,6 +368,56 @@ impl MessageTypeDetails {
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
+            && self.payload_version == rhs.payload_version
+    }
+
+    /// Checks whether a payload of a different version is compatible. An older payload type
+    /// reads the beginning of the newer payload in place, therefore its fields must be the first
+    /// fields of the newer type. A newer payload type is initialized from the older payload,
+    /// whether it declares the compatibility to the older version is verified by the caller.
+    pub(crate) fn is_version_compatible_to(&self, rhs: &Self) -> bool {
+        let is_compatible = self.header == rhs.header
+            && self.user_header.type_name == rhs.user_header.type_name
+            && self.user_header.variant == rhs.user_header.variant
+            && self.user_header.size == rhs.user_header.size
+            && self.user_header.alignment <= rhs.user_header.alignment
+            && self
+                .user_header_structure
+                .difference_to(&rhs.user_header_structure)
+                .is_none()
+            && self.payload.type_name == rhs.payload.type_name
+            && self.payload.variant == TypeVariant::FixedSize
+            && rhs.payload.variant == TypeVariant::FixedSize;
+
+        if !is_compatible {
+            return false;
+        }
+
+        if self.payload_version < rhs.payload_version {
+            self.payload.size <= rhs.payload.size
+                && self.payload.alignment <= rhs.payload.alignment
+                && self.payload_structure.is_prefix_of(&rhs.payload_structure)
+        } else {
+            rhs.payload_structure.is_prefix_of(&self.payload_structure)
+        }
+    }
+
+    /// Returns the name of the part, `"user header"` or `"payload"`, and the
+    /// [`StructuralDifference`] of the first part whose structure differs from `rhs`.
+    pub(crate) fn structural_difference_to(
+        &self,
+        rhs: &Self,
+    ) -> Option<(&'static str, StructuralDifference)> {
+        if let Some(difference) = self
+            .user_header_structure
+            .difference_to(&rhs.user_header_structure)
+        {
+            return Some(("user header", difference));
+        }
+
+        self.payload_structure
+            .difference_to(&rhs.payload_structure)
+            .map(|difference| ("payload", difference))
     }
 }
 
@@ -249,6 +457,9 @@ mod tests {
             header: align_4,
             user_header: align_8,
             payload: align_16,
+            user_header_structure: TypeStructure::default(),
+            payload_structure: TypeStructure::default(),
+            payload_version: 1,
         };
         let layout = sut.chunk_layout(1);
         assert_that!(layout.align(), eq 16);
@@ -257,6 +468,9 @@ mod tests {
             header: align_4,
             user_header: align_8,
             payload: align_4,
+            user_header_structure: TypeStructure::default(),
+            payload_structure: TypeStructure::default(),
+            payload_version: 1,
         };
         let layout = sut.chunk_layout(1);
         assert_that!(layout.align(), eq 8);
@@ -265,6 +479,9 @@ mod tests {
             header: align_4,
             user_header: align_4,
             payload: align_4,
+            user_header_structure: TypeStructure::default(),
+            payload_structure: TypeStructure::default(),
+            payload_version: 1,
         };
         let layout = sut.chunk_layout(1);
         assert_that!(layout.align(), eq 4);
@@ -300,6 +517,9 @@ mod tests {
                 size: 16,
                 alignment: ALIGNMENT,
             },
+            user_header_structure: TypeStructure::new::<i64>(),
+            payload_structure: TypeStructure::new::<MyPayload>(),
+            payload_version: 1,
         };
         assert_that!(sut, eq expected);
 
@@ -323,6 +543,9 @@ mod tests {
                 size: 8,
                 alignment: ALIGNMENT,
             },
+            user_header_structure: TypeStructure::new::<bool>(),
+            payload_structure: TypeStructure::new::<i64>(),
+            payload_version: 1,
         };
         assert_that!(sut, eq expected);
     }
@@ -464,6 +687,9 @@ mod tests {
                 size: 8,
                 alignment: 2 * ALIGNMENT,
             },
+            user_header_structure: TypeStructure::new::<i64>(),
+            payload_structure: TypeStructure::new::<i64>(),
+            payload_version: 1,
         };
         // smaller to bigger is allowed.
         let sut = left.is_compatible_to(&right);
@@ -499,9 +725,154 @@ mod tests {
                 size: 8,
                 alignment: 2 * ALIGNMENT,
             },
+            user_header_structure: TypeStructure::new::<i64>(),
+            payload_structure: TypeStructure::new::<i64>(),
+            payload_version: 1,
         };
         // bigger to smaller is invalid.
         let sut = right.is_compatible_to(&left);
         assert_that!(sut, eq false);
     }
+
+    mod v1 {
+        use super::*;
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        pub struct Position {
+            pub x: u64,
+            pub y: u64,
+        }
+    }
+
+    mod v2 {
+        use super::*;
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        pub struct Position {
+            pub y: u64,
+            pub x: u64,
+        }
+    }
+
+    #[test]
+    fn test_type_structure_of_identical_types_has_no_difference() {
+        let left = MessageTypeDetails::from::<i64, u8, v1::Position>(TypeVariant::FixedSize);
+        let right = MessageTypeDetails::from::<i64, u8, v1::Position>(TypeVariant::FixedSize);
+
+        assert_that!(left.payload_structure.is_known(), eq true);
+        assert_that!(left.payload_structure.fields(), len 2);
+        assert_that!(left.structural_difference_to(&right), is_none);
+    }
+
+    #[test]
+    fn test_type_structure_names_the_swapped_field() {
+        let left = MessageTypeDetails::from::<i64, u8, v1::Position>(TypeVariant::FixedSize);
+        let right = MessageTypeDetails::from::<i64, u8, v2::Position>(TypeVariant::FixedSize);
+
+        let (part, difference) = left.structural_difference_to(&right).unwrap();
+        assert_that!(part, eq "payload");
+        assert_that!(difference.to_string(), eq "field \"x\"");
+    }
+
+    #[test]
+    fn test_unknown_type_structure_is_compatible_to_every_structure() {
+        let left = MessageTypeDetails::from::<i64, u8, v1::Position>(TypeVariant::FixedSize);
+        let mut right = MessageTypeDetails::from::<i64, u8, v2::Position>(TypeVariant::FixedSize);
+        right.payload_structure = TypeStructure::default();
+
+        assert_that!(right.payload_structure.is_known(), eq false);
+        assert_that!(left.structural_difference_to(&right), is_none);
+        assert_that!(right.structural_difference_to(&left), is_none);
+    }
+
+    mod versioned_v1 {
+        use super::*;
+
+        #[derive(ZeroCopySend, Default)]
+        #[type_name("Position")]
+        #[repr(C)]
+        pub struct Position {
+            pub x: u64,
+            pub y: u64,
+        }
+    }
+
+    mod versioned_v2 {
+        use super::*;
+
+        #[derive(ZeroCopySend, Default)]
+        #[type_name("Position")]
+        #[type_version(2)]
+        #[repr(C)]
+        pub struct Position {
+            pub x: u64,
+            pub y: u64,
+            #[since_version(2)]
+            pub z: u64,
+        }
+    }
+
+    mod versioned_v2_with_swapped_fields {
+        use super::*;
+
+        #[derive(ZeroCopySend, Default)]
+        #[type_name("Position")]
+        #[type_version(2)]
+        #[repr(C)]
+        pub struct Position {
+            pub y: u64,
+            pub x: u64,
+            #[since_version(2)]
+            pub z: u64,
+        }
+    }
+
+    #[test]
+    fn test_payload_version_is_recorded() {
+        let older =
+            MessageTypeDetails::from::<i64, u8, versioned_v1::Position>(TypeVariant::FixedSize);
+        let newer =
+            MessageTypeDetails::from::<i64, u8, versioned_v2::Position>(TypeVariant::FixedSize);
+
+        assert_that!(older.payload_version, eq 1);
+        assert_that!(newer.payload_version, eq 2);
+        assert_that!(older.is_compatible_to(&newer), eq false);
+        assert_that!(newer.is_compatible_to(&older), eq false);
+    }
+
+    #[test]
+    fn test_is_version_compatible_to_succeeds_for_appended_fields() {
+        let older =
+            MessageTypeDetails::from::<i64, u8, versioned_v1::Position>(TypeVariant::FixedSize);
+        let newer =
+            MessageTypeDetails::from::<i64, u8, versioned_v2::Position>(TypeVariant::FixedSize);
+
+        assert_that!(older.is_version_compatible_to(&newer), eq true);
+        assert_that!(newer.is_version_compatible_to(&older), eq true);
+    }
+
+    #[test]
+    fn test_is_version_compatible_to_fails_when_older_fields_are_not_a_prefix() {
+        let older =
+            MessageTypeDetails::from::<i64, u8, versioned_v1::Position>(TypeVariant::FixedSize);
+        let newer = MessageTypeDetails::from::<i64, u8, versioned_v2_with_swapped_fields::Position>(
+            TypeVariant::FixedSize,
+        );
+
+        assert_that!(older.is_version_compatible_to(&newer), eq false);
+        assert_that!(newer.is_version_compatible_to(&older), eq false);
+    }
+
+    #[test]
+    fn test_is_version_compatible_to_fails_for_dynamic_payloads() {
+        let older =
+            MessageTypeDetails::from::<i64, u8, versioned_v1::Position>(TypeVariant::Dynamic);
+        let newer =
+            MessageTypeDetails::from::<i64, u8, versioned_v2::Position>(TypeVariant::Dynamic);
+
+        assert_that!(older.is_version_compatible_to(&newer), eq false);
+        assert_that!(newer.is_version_compatible_to(&older), eq false);
+    }
 }
@@ -105,23 +105,27 @@ mod tests {
     #[test]
     fn test_is_same_pattern() {
         let cfg = config::Config::default();
-        let p1 = MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(&cfg));
-        let p2 = MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(&cfg));
+        let p1 =
+            MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(&cfg.defaults));
+        let p2 =
+            MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(&cfg.defaults));
         assert_that!(p1.is_same_pattern(&p2), eq true);
         assert_that!(p2.is_same_pattern(&p1), eq true);
 
-        let e1 = MessagingPattern::Event(event::StaticConfig::new(&cfg));
-        let e2 = MessagingPattern::Event(event::StaticConfig::new(&cfg));
+        let e1 = MessagingPattern::Event(event::StaticConfig::new(&cfg.defaults));
+        let e2 = MessagingPattern::Event(event::StaticConfig::new(&cfg.defaults));
         assert_that!(e1.is_same_pattern(&e2), eq true);
         assert_that!(e2.is_same_pattern(&e1), eq true);
 
-        let r1 = MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg));
-        let r2 = MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg));
+        let r1 =
+            MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg.defaults));
+        let r2 =
+            MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg.defaults));
         assert_that!(r1.is_same_pattern(&r2), eq true);
         assert_that!(r2.is_same_pattern(&r1), eq true);
 
-        let b1 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg));
-        let b2 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg));
+        let b1 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg.defaults));
+        let b2 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg.defaults));
         assert_that!(b1.is_same_pattern(&b2), eq true);
         assert_that!(b2.is_same_pattern(&b1), eq true);
 
@@ -139,24 +143,29 @@ mod tests {
         let cfg2 = config::Config {
             defaults: new_defaults,
             global: cfg.global.clone(),
+            services: cfg.services.clone(),
+            remappings: cfg.remappings.clone(),
         };
 
         // ensure the cfg and cfg2 are not equal
         assert_that!(cfg, ne cfg2);
 
-        let p3 = MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(&cfg2));
+        let p3 = MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(
+            &cfg2.defaults,
+        ));
         assert_that!(p1.is_same_pattern(&p3), eq true);
         assert_that!(p3.is_same_pattern(&p1), eq true);
 
-        let e3 = MessagingPattern::Event(event::StaticConfig::new(&cfg2));
+        let e3 = MessagingPattern::Event(event::StaticConfig::new(&cfg2.defaults));
         assert_that!(e1.is_same_pattern(&e3), eq true);
         assert_that!(e2.is_same_pattern(&e3), eq true);
 
-        let r3 = MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg));
+        let r3 =
+            MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg.defaults));
         assert_that!(r1.is_same_pattern(&r3), eq true);
         assert_that!(r2.is_same_pattern(&r3), eq true);
 
-        let b3 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg));
+        let b3 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg.defaults));
         assert_that!(b1.is_same_pattern(&b3), eq true);
         assert_that!(b2.is_same_pattern(&b3), eq true);
 
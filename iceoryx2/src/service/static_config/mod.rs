@@ -24,6 +24,10 @@ pub mod publish_subscribe;
 /// and the type variant
 pub mod message_type_details;
 
+/// Defines how the memory of the data segments of a service is provided by the
+/// operating system.
+pub mod data_segment_memory;
+
 pub mod request_response;
 
 pub mod messaging_pattern;
@@ -32,6 +36,7 @@ pub mod blackboard;
 
 use alloc::format;
 
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -53,6 +58,7 @@ pub struct StaticConfig {
     iceoryx2_version: PackageVersion,
     service_hash: ServiceHash,
     service_name: ServiceName,
+    original_service_name: RelocatableOption<ServiceName>,
     unique_service_id: UniqueServiceId,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
@@ -63,8 +69,9 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern =
-            MessagingPattern::RequestResponse(request_response::StaticConfig::new(config));
+        let messaging_pattern = MessagingPattern::RequestResponse(
+            request_response::StaticConfig::new(&config.defaults_for(service_name)),
+        );
         Self {
             iceoryx2_version: PackageVersion::get(),
             service_hash: ServiceHash::new::<Hasher>(
@@ -73,6 +80,7 @@ impl StaticConfig {
             ),
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
+            original_service_name: RelocatableOption::None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -82,7 +90,8 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern = MessagingPattern::Event(event::StaticConfig::new(config));
+        let messaging_pattern =
+            MessagingPattern::Event(event::StaticConfig::new(&config.defaults_for(service_name)));
         Self {
             iceoryx2_version: PackageVersion::get(),
             service_hash: ServiceHash::new::<Hasher>(
@@ -91,6 +100,7 @@ impl StaticConfig {
             ),
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
+            original_service_name: RelocatableOption::None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -100,8 +110,9 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern =
-            MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(config));
+        let messaging_pattern = MessagingPattern::PublishSubscribe(
+            publish_subscribe::StaticConfig::new(&config.defaults_for(service_name)),
+        );
         Self {
             iceoryx2_version: PackageVersion::get(),
             service_hash: ServiceHash::new::<Hasher>(
@@ -110,6 +121,7 @@ impl StaticConfig {
             ),
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
+            original_service_name: RelocatableOption::None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -119,7 +131,9 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(config));
+        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(
+            &config.defaults_for(service_name),
+        ));
         Self {
             iceoryx2_version: PackageVersion::get(),
             service_hash: ServiceHash::new::<Hasher>(
@@ -128,6 +142,7 @@ impl StaticConfig {
             ),
             unique_service_id: UniqueServiceId::new(),
             service_name: *service_name,
+            original_service_name: RelocatableOption::None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -158,6 +173,18 @@ impl StaticConfig {
         &self.service_name
     }
 
+    /// Returns the [`ServiceName`] that was used in the code of the creator of the
+    /// [`crate::service::Service`] when it differs from [`StaticConfig::name()`] due to a
+    /// [`ServiceNameRemapping`](crate::config::ServiceNameRemapping) or the namespace of the
+    /// [`Node`](crate::node::Node), otherwise [`None`].
+    pub fn original_name(&self) -> Option<&ServiceName> {
+        self.original_service_name.as_option_ref()
+    }
+
+    pub(crate) fn set_original_name(&mut self, value: Option<ServiceName>) {
+        self.original_service_name = value.into();
+    }
+
     /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
     pub fn messaging_pattern(&self) -> &MessagingPattern {
         &self.messaging_pattern
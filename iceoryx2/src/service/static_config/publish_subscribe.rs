@@ -33,6 +33,7 @@
 //! # }
 //! ```
 
+use super::data_segment_memory::DataSegmentMemory;
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -54,25 +55,27 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
     pub(crate) message_type_details: MessageTypeDetails,
+    pub(crate) data_segment_memory: DataSegmentMemory,
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_subscribers: config.defaults.publish_subscribe.max_subscribers,
-            max_publishers: config.defaults.publish_subscribe.max_publishers,
-            max_nodes: config.defaults.publish_subscribe.max_nodes,
-            history_size: config.defaults.publish_subscribe.publisher_history_size,
-            subscriber_max_buffer_size: config
-                .defaults
-                .publish_subscribe
-                .subscriber_max_buffer_size,
-            subscriber_max_borrowed_samples: config
-                .defaults
+            max_subscribers: defaults.publish_subscribe.max_subscribers,
+            max_publishers: defaults.publish_subscribe.max_publishers,
+            max_nodes: defaults.publish_subscribe.max_nodes,
+            history_size: defaults.publish_subscribe.publisher_history_size,
+            subscriber_max_buffer_size: defaults.publish_subscribe.subscriber_max_buffer_size,
+            subscriber_max_borrowed_samples: defaults
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
-            enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_safe_overflow: defaults.publish_subscribe.enable_safe_overflow,
             message_type_details: MessageTypeDetails::default(),
+            data_segment_memory: DataSegmentMemory {
+                use_huge_pages: defaults.publish_subscribe.data_segment_use_huge_pages,
+                lock_memory: defaults.publish_subscribe.data_segment_lock_memory,
+                prefault_memory: defaults.publish_subscribe.data_segment_prefault_memory,
+            },
         }
     }
 
@@ -130,4 +133,9 @@ impl StaticConfig {
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
     }
+
+    /// Returns how the memory of the data segments is provided by the operating system.
+    pub fn data_segment_memory(&self) -> &DataSegmentMemory {
+        &self.data_segment_memory
+    }
 }
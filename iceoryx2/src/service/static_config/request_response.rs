@@ -44,6 +44,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::config;
 
+use super::data_segment_memory::DataSegmentMemory;
 use super::message_type_details::MessageTypeDetails;
 
 /// The static configuration of an
@@ -65,38 +66,39 @@ pub struct StaticConfig {
     pub(crate) max_borrowed_responses_per_pending_response: usize,
     pub(crate) request_message_type_details: MessageTypeDetails,
     pub(crate) response_message_type_details: MessageTypeDetails,
+    pub(crate) data_segment_memory: DataSegmentMemory,
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            enable_safe_overflow_for_requests: config
-                .defaults
+            enable_safe_overflow_for_requests: defaults
                 .request_response
                 .enable_safe_overflow_for_requests,
-            enable_safe_overflow_for_responses: config
-                .defaults
+            enable_safe_overflow_for_responses: defaults
                 .request_response
                 .enable_safe_overflow_for_responses,
-            max_active_requests_per_client: config
-                .defaults
+            max_active_requests_per_client: defaults
                 .request_response
                 .max_active_requests_per_client,
-            max_response_buffer_size: config.defaults.request_response.max_response_buffer_size,
-            max_servers: config.defaults.request_response.max_servers,
-            max_clients: config.defaults.request_response.max_clients,
-            max_nodes: config.defaults.request_response.max_nodes,
-            max_borrowed_responses_per_pending_response: config
-                .defaults
+            max_response_buffer_size: defaults.request_response.max_response_buffer_size,
+            max_servers: defaults.request_response.max_servers,
+            max_clients: defaults.request_response.max_clients,
+            max_nodes: defaults.request_response.max_nodes,
+            max_borrowed_responses_per_pending_response: defaults
                 .request_response
                 .max_borrowed_responses_per_pending_response,
-            max_loaned_requests: config.defaults.request_response.max_loaned_requests,
-            enable_fire_and_forget_requests: config
-                .defaults
+            max_loaned_requests: defaults.request_response.max_loaned_requests,
+            enable_fire_and_forget_requests: defaults
                 .request_response
                 .enable_fire_and_forget_requests,
             request_message_type_details: MessageTypeDetails::default(),
             response_message_type_details: MessageTypeDetails::default(),
+            data_segment_memory: DataSegmentMemory {
+                use_huge_pages: defaults.request_response.data_segment_use_huge_pages,
+                lock_memory: defaults.request_response.data_segment_lock_memory,
+                prefault_memory: defaults.request_response.data_segment_prefault_memory,
+            },
         }
     }
 
@@ -153,6 +155,12 @@ impl StaticConfig {
         &self.response_message_type_details
     }
 
+    /// Returns how the memory of the client and server data segments is provided by the
+    /// operating system.
+    pub fn data_segment_memory(&self) -> &DataSegmentMemory {
+        &self.data_segment_memory
+    }
+
     /// Returns true if the request buffer of the [`crate::service::Service`] safely overflows,
     /// otherwise false. Safe overflow means that the [`crate::port::client::Client`] will
     /// recycle the oldest requests from the [`crate::port::server::Server`] when its buffer
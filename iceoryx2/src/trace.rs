@@ -0,0 +1,436 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Trace points of the communication hot path for performance analysis.
+//!
+//! When iceoryx2 is built with the `trace_points` feature, every loan, send, receive, release,
+//! notify and [`WaitSet`](crate::waitset::WaitSet) dispatch is reported as
+//! [`TraceEvent`](crate::trace::TraceEvent) to the [`TraceSink`](crate::trace::TraceSink) that
+//! was registered with [`set_trace_sink()`](crate::trace::set_trace_sink()). Without the
+//! feature, the trace points are not compiled in at all.
+//!
+//! The [`ChromeTraceWriter`](crate::trace::ChromeTraceWriter) is a
+//! [`TraceSink`](crate::trace::TraceSink) that writes the events in the Chrome trace event
+//! format which can be opened with `chrome://tracing` or <https://ui.perfetto.dev>. Multiple
+//! processes can write into the same file, the timestamps are taken from the monotonic system
+//! clock and are therefore comparable across processes. Every send is connected with the
+//! corresponding receive by a flow arrow, so that the latency of a single sample from one
+//! process to another becomes visible.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::LazyLock;
+//! use iceoryx2::trace::{ChromeTraceWriter, set_trace_sink};
+//!
+//! static TRACE_WRITER: LazyLock<ChromeTraceWriter> =
+//!     LazyLock::new(|| ChromeTraceWriter::create("iceoryx2_trace.json").unwrap());
+//!
+//! set_trace_sink(&*TRACE_WRITER);
+//!
+//! // communicate ...
+//!
+//! // write all buffered events before the process exits
+//! TRACE_WRITER.flush();
+//! ```
+
+use core::time::Duration;
+
+use alloc::string::String;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+#[cfg(feature = "trace_points")]
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_concurrency::cell::RefCell;
+#[cfg(feature = "trace_points")]
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_log::fail;
+
+/// Defines the operation a [`TraceEvent`] was recorded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracePointKind {
+    /// A sender port loaned a chunk. [`TraceEvent::value`] contains the offset of the chunk.
+    Loan,
+    /// A sender port delivered a chunk to all its receivers. [`TraceEvent::value`] contains the
+    /// sequence number that the sender assigned to the delivery.
+    Send,
+    /// A receiver port received a chunk. [`TraceEvent::value`] contains the sequence number that
+    /// the sender assigned to the delivery.
+    Receive,
+    /// A receiver port returned a received chunk to the sender. [`TraceEvent::value`] contains
+    /// the offset of the chunk.
+    Release,
+    /// A [`Notifier`](crate::port::notifier::Notifier) notified its listeners.
+    /// [`TraceEvent::value`] contains the [`EventId`](crate::port::event_id::EventId).
+    Notify,
+    /// A [`WaitSet`](crate::waitset::WaitSet) dispatched its triggered attachments to the
+    /// user callback. [`TraceEvent::value`] contains the number of triggered attachments.
+    WaitSetDispatch,
+}
+
+impl TracePointKind {
+    /// Returns the name of the trace point.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TracePointKind::Loan => "loan",
+            TracePointKind::Send => "send",
+            TracePointKind::Receive => "receive",
+            TracePointKind::Release => "release",
+            TracePointKind::Notify => "notify",
+            TracePointKind::WaitSetDispatch => "waitset_dispatch",
+        }
+    }
+}
+
+/// A single operation that was recorded at a trace point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// The operation that was recorded.
+    pub kind: TracePointKind,
+    /// The start of the operation, measured with the monotonic system clock.
+    pub timestamp: Duration,
+    /// The duration of the operation.
+    pub duration: Duration,
+    /// The id of the process that recorded the event.
+    pub process_id: u32,
+    /// A process local id of the thread that recorded the event.
+    pub thread_id: u64,
+    /// The id of the port that performed the operation. It is `0` for
+    /// [`TracePointKind::WaitSetDispatch`].
+    pub port_id: u128,
+    /// The id of the sender port that owns the chunk. For all operations without a chunk it is
+    /// `0`.
+    pub origin_port_id: u128,
+    /// The operation specific value, see [`TracePointKind`].
+    pub value: u64,
+}
+
+impl TraceEvent {
+    /// Returns an id that is equal for the [`TracePointKind::Send`] and all corresponding
+    /// [`TracePointKind::Receive`] events of a delivery. Since the sequence number is never
+    /// reused by a sender, the id stays unique even when the chunk is loaned and sent again.
+    pub fn flow_id(&self) -> u64 {
+        let origin = (self.origin_port_id as u64) ^ ((self.origin_port_id >> 64) as u64);
+        origin ^ self.value.wrapping_mul(0x9e37_79b9_7f4a_7c15)
+    }
+}
+
+/// The receiver of all [`TraceEvent`]s, registered with [`set_trace_sink()`].
+pub trait TraceSink: Send + Sync {
+    /// Called from the hot path for every recorded [`TraceEvent`]. The implementation shall
+    /// return as fast as possible.
+    fn record(&self, event: &TraceEvent);
+
+    /// Writes all buffered [`TraceEvent`]s.
+    fn flush(&self) {}
+}
+
+static TRACE_SINK: OnceLock<&'static dyn TraceSink> = OnceLock::new();
+
+#[cfg(feature = "trace_points")]
+static THREAD_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "trace_points")]
+std::thread_local! {
+    static THREAD_ID: u64 = THREAD_COUNTER.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Registers the [`TraceSink`] that receives all [`TraceEvent`]s of the process. The sink can be
+/// set only once, returns `false` when a sink was already registered.
+///
+/// The events are only recorded when iceoryx2 is built with the `trace_points` feature.
+pub fn set_trace_sink(sink: &'static dyn TraceSink) -> bool {
+    TRACE_SINK.set(sink).is_ok()
+}
+
+/// Returns the registered [`TraceSink`].
+pub fn trace_sink() -> Option<&'static dyn TraceSink> {
+    TRACE_SINK.get().copied()
+}
+
+#[cfg(feature = "trace_points")]
+fn now() -> Option<Duration> {
+    Time::now_with_clock(ClockType::Monotonic)
+        .ok()
+        .map(|time| time.as_duration())
+}
+
+/// Measures an operation on the hot path and reports it to the [`TraceSink`].
+#[cfg(feature = "trace_points")]
+pub(crate) struct Span {
+    sink: &'static dyn TraceSink,
+    start: Duration,
+}
+
+#[cfg(feature = "trace_points")]
+impl Span {
+    /// Starts the measurement, returns [`None`] when no [`TraceSink`] is registered.
+    #[inline(always)]
+    pub(crate) fn start() -> Option<Self> {
+        let sink = trace_sink()?;
+        Some(Self {
+            sink,
+            start: now()?,
+        })
+    }
+
+    pub(crate) fn finish(
+        self,
+        kind: TracePointKind,
+        port_id: u128,
+        origin_port_id: u128,
+        value: u64,
+    ) {
+        let end = now().unwrap_or(self.start);
+        self.sink.record(&TraceEvent {
+            kind,
+            timestamp: self.start,
+            duration: end.saturating_sub(self.start),
+            process_id: std::process::id(),
+            thread_id: THREAD_ID.with(|id| *id),
+            port_id,
+            origin_port_id,
+            value,
+        });
+    }
+}
+
+/// Failures that can occur when a [`ChromeTraceWriter`] is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChromeTraceWriterCreationError {
+    /// The trace file could neither be created nor opened.
+    UnableToOpenFile,
+    /// The header of the trace file could not be written.
+    UnableToWriteFile,
+}
+
+impl core::fmt::Display for ChromeTraceWriterCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ChromeTraceWriterCreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ChromeTraceWriterCreationError {}
+
+/// A [`TraceSink`] that writes all [`TraceEvent`]s in the Chrome trace event format into a
+/// file.
+///
+/// The file is written in the JSON array format where the closing bracket is optional. This
+/// allows multiple processes to append their events to the same file. The events are buffered
+/// and written when the buffer is full or [`ChromeTraceWriter::flush()`] is called.
+#[derive(Debug)]
+pub struct ChromeTraceWriter {
+    file: Mutex<BufWriter<File>>,
+}
+
+impl ChromeTraceWriter {
+    /// Creates a new [`ChromeTraceWriter`]. If the file already exists, the events are appended.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, ChromeTraceWriterCreationError> {
+        let path = path.as_ref();
+        let origin = "ChromeTraceWriter::create()";
+        let msg = "Unable to create chrome trace writer";
+
+        let file = match OpenOptions::new().append(true).create_new(true).open(path) {
+            Ok(mut file) => {
+                fail!(from origin, when file.write_all(b"[\n"),
+                    with ChromeTraceWriterCreationError::UnableToWriteFile,
+                    "{msg} since the header could not be written into {path:?}.");
+                file
+            }
+            Err(_) => {
+                fail!(from origin, when OpenOptions::new().append(true).open(path),
+                    with ChromeTraceWriterCreationError::UnableToOpenFile,
+                    "{msg} since the file {path:?} could not be opened.")
+            }
+        };
+
+        Ok(Self {
+            file: Mutex::new(BufWriter::new(file)),
+        })
+    }
+
+    /// Writes all buffered [`TraceEvent`]s into the file.
+    pub fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+impl Drop for ChromeTraceWriter {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+std::thread_local! {
+    static CHROME_TRACE_ENTRY: RefCell<String> = RefCell::new(String::with_capacity(512));
+}
+
+impl TraceSink for ChromeTraceWriter {
+    fn record(&self, event: &TraceEvent) {
+        // the entry is formatted into a thread local buffer outside of the lock, so that the hot
+        // path neither allocates nor blocks other threads while formatting
+        CHROME_TRACE_ENTRY.with(|entry| {
+            let mut entry = entry.borrow_mut();
+            entry.clear();
+            let _ = write_chrome_trace_event(&mut *entry, event);
+
+            // the events are written line by line and the buffer is flushed only between two
+            // lines so that the events of multiple processes do not interleave
+            if let Ok(mut file) = self.file.lock() {
+                let _ = file.write_all(entry.as_bytes());
+            }
+        });
+    }
+
+    fn flush(&self) {
+        ChromeTraceWriter::flush(self)
+    }
+}
+
+fn write_microseconds<W: core::fmt::Write>(w: &mut W, value: Duration) -> core::fmt::Result {
+    write!(
+        w,
+        "{}.{:03}",
+        value.as_micros(),
+        value.subsec_nanos() % 1000
+    )
+}
+
+/// Writes the [`TraceEvent`] as Chrome trace events, each terminated by `,\n`. Every event is
+/// written as complete event, [`TracePointKind::Send`] and [`TracePointKind::Receive`] are
+/// additionally connected with a flow event.
+pub fn write_chrome_trace_event<W: core::fmt::Write>(
+    w: &mut W,
+    event: &TraceEvent,
+) -> core::fmt::Result {
+    let name = event.kind.as_str();
+    let pid = event.process_id;
+    let tid = event.thread_id;
+
+    write!(
+        w,
+        "{{\"name\":\"{name}\",\"cat\":\"iceoryx2\",\"ph\":\"X\",\"ts\":"
+    )?;
+    write_microseconds(w, event.timestamp)?;
+    w.write_str(",\"dur\":")?;
+    write_microseconds(w, event.duration)?;
+    writeln!(
+        w,
+        ",\"pid\":{pid},\"tid\":{tid},\"args\":{{\"port_id\":\"{:#x}\",\"origin_port_id\":\"{:#x}\",\"value\":\"{:#x}\"}}}},",
+        event.port_id, event.origin_port_id, event.value
+    )?;
+
+    let flow_phase = match event.kind {
+        TracePointKind::Send => "\"ph\":\"s\"",
+        TracePointKind::Receive => "\"ph\":\"f\",\"bp\":\"e\"",
+        _ => return Ok(()),
+    };
+
+    write!(
+        w,
+        "{{\"name\":\"sample\",\"cat\":\"iceoryx2\",{flow_phase},\"id\":\"{:#x}\",\"ts\":",
+        event.flow_id()
+    )?;
+    write_microseconds(w, event.timestamp)?;
+    writeln!(w, ",\"pid\":{pid},\"tid\":{tid}}},")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn event(kind: TracePointKind) -> TraceEvent {
+        TraceEvent {
+            kind,
+            timestamp: Duration::from_nanos(1_234_567),
+            duration: Duration::from_nanos(89),
+            process_id: 12,
+            thread_id: 3,
+            port_id: 0xab,
+            origin_port_id: 0xcd,
+            value: 0x40,
+        }
+    }
+
+    #[test]
+    fn loan_is_written_as_complete_event() {
+        let mut output = String::new();
+        write_chrome_trace_event(&mut output, &event(TracePointKind::Loan)).unwrap();
+
+        assert_that!(
+            output,
+            eq "{\"name\":\"loan\",\"cat\":\"iceoryx2\",\"ph\":\"X\",\"ts\":1234.567,\"dur\":0.089,\"pid\":12,\"tid\":3,\"args\":{\"port_id\":\"0xab\",\"origin_port_id\":\"0xcd\",\"value\":\"0x40\"}},\n"
+        );
+    }
+
+    #[test]
+    fn send_and_receive_are_connected_by_flow_event() {
+        let send = event(TracePointKind::Send);
+        let mut receive = event(TracePointKind::Receive);
+        receive.port_id = 0xef;
+        receive.process_id = 13;
+
+        let mut output = String::new();
+        write_chrome_trace_event(&mut output, &send).unwrap();
+        write_chrome_trace_event(&mut output, &receive).unwrap();
+
+        let id = alloc::format!("\"id\":\"{:#x}\"", send.flow_id());
+        let lines: alloc::vec::Vec<&str> = output.lines().collect();
+        assert_that!(send.flow_id(), eq receive.flow_id());
+        assert_that!(lines, len 4);
+        assert_that!(lines[1].contains("\"ph\":\"s\""), eq true);
+        assert_that!(lines[1].contains(&id), eq true);
+        assert_that!(lines[3].contains("\"ph\":\"f\",\"bp\":\"e\""), eq true);
+        assert_that!(lines[3].contains(&id), eq true);
+    }
+
+    #[test]
+    fn different_deliveries_have_different_flow_ids() {
+        let send = event(TracePointKind::Send);
+        let mut other = send;
+        other.value = send.value + 1;
+
+        assert_that!(send.flow_id(), ne other.flow_id());
+    }
+
+    #[test]
+    fn chrome_trace_writer_appends_events_to_existing_file() {
+        let path = std::env::temp_dir().join(alloc::format!(
+            "iceoryx2_chrome_trace_writer_test_{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let sut = ChromeTraceWriter::create(&path).unwrap();
+            sut.record(&event(TracePointKind::Loan));
+        }
+        {
+            let sut = ChromeTraceWriter::create(&path).unwrap();
+            sut.record(&event(TracePointKind::Release));
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_that!(content.starts_with("[\n"), eq true);
+        assert_that!(content.matches('[').count(), eq 1);
+        assert_that!(content.contains("\"name\":\"loan\""), eq true);
+        assert_that!(content.contains("\"name\":\"release\""), eq true);
+    }
+}
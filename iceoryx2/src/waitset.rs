@@ -185,6 +185,55 @@
 //! # }
 //! ```
 //!
+//! ## Priorities And Callback Budget
+//!
+//! When multiple attachments are triggered at the same time, the events are dispatched in
+//! the order of their priority, see
+//! [`WaitSetGuard::set_priority()`](crate::waitset::WaitSetGuard::set_priority()).
+//! Additionally, the number of callbacks per wake up can be bounded with
+//! [`WaitSetBuilder::max_callbacks_per_cycle()`](crate::waitset::WaitSetBuilder::max_callbacks_per_cycle()).
+//! The events that exceed the budget are deferred to the next wake up, so that a flood of
+//! events cannot delay the processing of a safety-critical attachment indefinitely.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let event_1 = node.service_builder(&"MyEventName_1".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//! # let event_2 = node.service_builder(&"MyEventName_2".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! let emergency_stop = event_1.listener_builder().create()?;
+//! let camera = event_2.listener_builder().create()?;
+//!
+//! let waitset = WaitSetBuilder::new()
+//!                 .max_callbacks_per_cycle(4)
+//!                 .create::<ipc::Service>()?;
+//!
+//! let emergency_stop_guard = waitset.attach_notification(&emergency_stop)?;
+//! let camera_guard = waitset.attach_notification(&camera)?;
+//! // the emergency stop is always handled before the camera
+//! emergency_stop_guard.set_priority(255);
+//!
+//! let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+//!     if attachment_id.has_event_from(&emergency_stop_guard) {
+//!         emergency_stop.try_wait(|_| println!("emergency stop"));
+//!     } else if attachment_id.has_event_from(&camera_guard) {
+//!         camera.try_wait(|_| println!("new camera image"));
+//!     }
+//!     CallbackProgression::Continue
+//! };
+//!
+//! waitset.wait_and_process(on_event)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Using [`WaitSet`](crate::waitset::WaitSet) Without [`Signal`](iceoryx2_bb_posix::signal::Signal) Handling
 //!
 //! This example demonstrates how the [`WaitSet`](crate::waitset::WaitSet) can be used when
@@ -213,7 +262,7 @@
 //! # Ok(())
 //! # }
 
-use core::{fmt::Debug, hash::Hash, marker::PhantomData, time::Duration};
+use core::{cmp::Reverse, fmt::Debug, hash::Hash, marker::PhantomData, time::Duration};
 
 use alloc::collections::BTreeMap;
 use alloc::vec;
@@ -223,6 +272,7 @@ use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_concurrency::cell::RefCell;
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::{iceoryx_send::IceoryxSend, zero_copy_send::ZeroCopySend};
 use iceoryx2_bb_posix::{
     deadline_queue::{DeadlineQueue, DeadlineQueueBuilder, DeadlineQueueGuard, DeadlineQueueIndex},
     file_descriptor::FileDescriptor,
@@ -232,6 +282,7 @@ use iceoryx2_bb_posix::{
 use iceoryx2_cal::reactor::*;
 use iceoryx2_log::fail;
 
+use crate::pending_response::PendingResponse;
 use crate::signal_handling_mode::SignalHandlingMode;
 
 /// States why the [`WaitSet::wait_and_process()`] method returned.
@@ -248,7 +299,8 @@ pub enum WaitSetRunResult {
 }
 
 /// Defines the failures that can occur when attaching something with
-/// [`WaitSet::attach_notification()`], [`WaitSet::attach_interval()`] or [`WaitSet::attach_deadline()`].
+/// [`WaitSet::attach_notification()`], [`WaitSet::attach_interval()`],
+/// [`WaitSet::attach_deadline()`] or [`WaitSet::attach_request_deadline()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum WaitSetAttachmentError {
     /// The [`WaitSet`]s capacity is exceeded.
@@ -259,6 +311,8 @@ pub enum WaitSetAttachmentError {
     InternalError,
     /// Insufficient resources to add another attachment to the [`WaitSet`].
     InsufficientResources,
+    /// The attached [`PendingResponse`] belongs to a request without a deadline.
+    NoDeadline,
 }
 
 impl core::fmt::Display for WaitSetAttachmentError {
@@ -312,6 +366,28 @@ enum AttachmentIdType {
     Notification(u64, i32),
 }
 
+// The notification and the missed deadline of a deadline attachment share the same
+// reactor index and therefore the same priority.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+enum AttachmentKey {
+    Reactor(i32),
+    DeadlineQueue(DeadlineQueueIndex),
+}
+
+impl AttachmentIdType {
+    fn key(&self) -> AttachmentKey {
+        match self {
+            AttachmentIdType::Tick(_, deadline_queue_idx) => {
+                AttachmentKey::DeadlineQueue(*deadline_queue_idx)
+            }
+            AttachmentIdType::Deadline(_, reactor_idx, _)
+            | AttachmentIdType::Notification(_, reactor_idx) => {
+                AttachmentKey::Reactor(*reactor_idx)
+            }
+        }
+    }
+}
+
 /// Represents an attachment to the [`WaitSet`]
 #[derive(Clone, Copy)]
 pub struct WaitSetAttachmentId<Service: crate::service::Service> {
@@ -462,12 +538,36 @@ where
     guard_type: GuardType<'waitset, 'attachment, Service>,
 }
 
+impl<Service: crate::service::Service> WaitSetGuard<'_, '_, Service> {
+    /// Sets the priority of the attachment whereby `0` represents the lowest and `255` the
+    /// highest priority. When multiple attachments are triggered at the same time, the
+    /// [`WaitSet`] dispatches the events with a higher priority first. Attachments with the
+    /// same priority are dispatched in the order in which they were triggered.
+    /// By default, every attachment has the priority `0`.
+    pub fn set_priority(&self, value: u8) {
+        self.waitset
+            .priorities
+            .borrow_mut()
+            .insert(self.key(), value);
+    }
+
+    /// Returns the priority of the attachment, see [`WaitSetGuard::set_priority()`].
+    pub fn priority(&self) -> u8 {
+        self.waitset.priority_of(self.key())
+    }
+
+    fn key(&self) -> AttachmentKey {
+        WaitSetAttachmentId::from_guard(self).attachment_type.key()
+    }
+}
+
 impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
     fn drop(&mut self) {
         if let GuardType::Deadline(r, t) = &self.guard_type {
             self.waitset
                 .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
         }
+        self.waitset.remove_attachment_state(self.key());
         self.waitset.detach();
     }
 }
@@ -476,6 +576,7 @@ impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
 #[derive(Default, Debug, Clone)]
 pub struct WaitSetBuilder {
     signal_handling_mode: SignalHandlingMode,
+    max_callbacks_per_cycle: Option<usize>,
 }
 
 impl WaitSetBuilder {
@@ -493,6 +594,17 @@ impl WaitSetBuilder {
         self
     }
 
+    /// Defines the maximum number of callbacks that are called in one
+    /// [`WaitSet::wait_and_process_once()`] call or one iteration of
+    /// [`WaitSet::wait_and_process()`]. The events with the highest priority are dispatched
+    /// first, the remaining events are deferred to the next call where they are dispatched
+    /// before new events of the same priority. A value of `0` is treated as `1`.
+    /// By default, all events are dispatched in one call.
+    pub fn max_callbacks_per_cycle(mut self, value: usize) -> Self {
+        self.max_callbacks_per_cycle = Some(value.max(1));
+        self
+    }
+
     /// Creates the [`WaitSet`].
     pub fn create<Service: crate::service::Service>(
         self,
@@ -510,6 +622,9 @@ impl WaitSetBuilder {
                 deadline_to_attachment: RefCell::new(BTreeMap::new()),
                 attachment_counter: AtomicUsize::new(0),
                 signal_handling_mode: self.signal_handling_mode,
+                priorities: RefCell::new(BTreeMap::new()),
+                deferred_events: RefCell::new(vec![]),
+                max_callbacks_per_cycle: self.max_callbacks_per_cycle,
             }),
             Err(ReactorCreateError::InternalError) => {
                 fail!(from self, with WaitSetCreateError::InternalError,
@@ -542,6 +657,9 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_to_attachment: RefCell<BTreeMap<DeadlineQueueIndex, i32>>,
     attachment_counter: AtomicUsize,
     signal_handling_mode: SignalHandlingMode,
+    priorities: RefCell<BTreeMap<AttachmentKey, u8>>,
+    deferred_events: RefCell<Vec<AttachmentIdType>>,
+    max_callbacks_per_cycle: Option<usize>,
 }
 
 impl<Service: crate::service::Service> WaitSet<Service> {
@@ -559,6 +677,17 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         Ok(())
     }
 
+    fn priority_of(&self, key: AttachmentKey) -> u8 {
+        self.priorities.borrow().get(&key).copied().unwrap_or(0)
+    }
+
+    fn remove_attachment_state(&self, key: AttachmentKey) {
+        self.priorities.borrow_mut().remove(&key);
+        self.deferred_events
+            .borrow_mut()
+            .retain(|event| event.key() != key);
+    }
+
     fn remove_deadline(&self, reactor_idx: i32, deadline_queue_idx: DeadlineQueueIndex) {
         self.attachment_to_deadline
             .borrow_mut()
@@ -584,41 +713,35 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 
-    fn handle_deadlines<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+    fn collect_deadlines(
         &self,
-        fn_call: &mut F,
+        events: &mut Vec<AttachmentIdType>,
         error_msg: &str,
-    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+    ) -> Result<(), WaitSetRunError> {
         let deadline_to_attachment = self.deadline_to_attachment.borrow();
-        let mut result = WaitSetRunResult::AllEventsHandled;
-        let call = |idx: DeadlineQueueIndex| -> CallbackProgression {
-            let progression = if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
-                fn_call(WaitSetAttachmentId::deadline(self, *reactor_idx, idx))
+        let collect = |idx: DeadlineQueueIndex| -> CallbackProgression {
+            if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
+                events.push(WaitSetAttachmentId::deadline(self, *reactor_idx, idx).attachment_type);
             } else {
-                fn_call(WaitSetAttachmentId::tick(self, idx))
-            };
-
-            if let CallbackProgression::Stop = progression {
-                result = WaitSetRunResult::StopRequest;
+                events.push(WaitSetAttachmentId::tick(self, idx).attachment_type);
             }
-
-            progression
+            CallbackProgression::Continue
         };
 
         fail!(from self,
-                  when self.deadline_queue.missed_deadlines(call),
+                  when self.deadline_queue.missed_deadlines(collect),
                   with WaitSetRunError::InternalError,
                   "{error_msg} since the missed deadlines could not be acquired.");
 
-        Ok(result)
+        Ok(())
     }
 
-    fn handle_all_attachments<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+    fn collect_all_attachments(
         &self,
         triggered_file_descriptors: &Vec<i32>,
-        fn_call: &mut F,
+        events: &mut Vec<AttachmentIdType>,
         error_msg: &str,
-    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+    ) -> Result<(), WaitSetRunError> {
         // we need to reset the deadlines first, otherwise a long fn_call may extend the
         // deadline unintentionally
         let mut fd_and_deadline_queue_idx = Vec::with_capacity(triggered_file_descriptors.len());
@@ -629,20 +752,49 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
         // must be called after the deadlines have been reset, in the case that the
         // event has been received shortly before the deadline ended.
-
-        match self.handle_deadlines(fn_call, error_msg)? {
-            WaitSetRunResult::AllEventsHandled => (),
-            v => return Ok(v),
-        };
+        self.collect_deadlines(events, error_msg)?;
 
         for fd in triggered_file_descriptors {
-            if let CallbackProgression::Stop = fn_call(WaitSetAttachmentId::notification(self, *fd))
-            {
-                return Ok(WaitSetRunResult::StopRequest);
+            events.push(WaitSetAttachmentId::notification(self, *fd).attachment_type);
+        }
+
+        Ok(())
+    }
+
+    fn dispatch<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        new_events: Vec<AttachmentIdType>,
+        fn_call: &mut F,
+    ) -> WaitSetRunResult {
+        // deferred events precede the new events, the stable sort keeps this order for
+        // events with the same priority
+        let mut events = core::mem::take(&mut *self.deferred_events.borrow_mut());
+        for event in new_events {
+            if !events.contains(&event) {
+                events.push(event);
             }
         }
 
-        Ok(WaitSetRunResult::AllEventsHandled)
+        events.sort_by_key(|event| Reverse(self.priority_of(event.key())));
+
+        if let Some(max_callbacks_per_cycle) = self.max_callbacks_per_cycle
+            && max_callbacks_per_cycle < events.len()
+        {
+            *self.deferred_events.borrow_mut() = events.split_off(max_callbacks_per_cycle);
+        }
+
+        for attachment_type in events {
+            let attachment_id = WaitSetAttachmentId {
+                attachment_type,
+                _data: PhantomData,
+            };
+
+            if let CallbackProgression::Stop = fn_call(attachment_id) {
+                return WaitSetRunResult::StopRequest;
+            }
+        }
+
+        WaitSetRunResult::AllEventsHandled
     }
 
     /// Attaches an object as notification to the [`WaitSet`]. Whenever an event is received on the
@@ -698,10 +850,10 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
     /// Attaches a tick event to the [`WaitSet`]. Whenever the timeout is reached the [`WaitSet`]
     /// informs the user in [`WaitSet::wait_and_process()`].
-    pub fn attach_interval(
+    pub fn attach_interval<'attachment>(
         &self,
         interval: Duration,
-    ) -> Result<WaitSetGuard<'_, 'static, Service>, WaitSetAttachmentError> {
+    ) -> Result<WaitSetGuard<'_, 'attachment, Service>, WaitSetAttachmentError> {
         let deadline_queue_guard = self.attach_to_deadline_queue(interval)?;
         self.attach()?;
 
@@ -711,6 +863,54 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         })
     }
 
+    /// Attaches the deadline of the request of a [`PendingResponse`] to the [`WaitSet`]. When the
+    /// deadline is reached, the [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`]
+    /// and [`WaitSetAttachmentId::has_event_from()`] identifies the returned guard. The
+    /// [`PendingResponse`] must have a deadline, see
+    /// [`RequestMut::set_deadline()`](crate::request_mut::RequestMut::set_deadline()).
+    ///
+    /// The event fires again whenever the remaining time at attachment has passed once more,
+    /// therefore the guard shall be dropped after the expiry was handled.
+    pub fn attach_request_deadline<
+        'waitset,
+        'attachment,
+        RequestPayload: Debug + IceoryxSend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + IceoryxSend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    >(
+        &'waitset self,
+        pending_response: &'attachment PendingResponse<
+            Service,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        let remaining_time = match pending_response.remaining_time() {
+            Ok(Some(v)) => v,
+            Err(e) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "Unable to attach the request deadline of {pending_response:?} since the remaining time could not be acquired ({e}).");
+            }
+            Ok(None) => {
+                fail!(from self, with WaitSetAttachmentError::NoDeadline,
+                    "Unable to attach the request deadline of {pending_response:?} since the request has no deadline.");
+            }
+        };
+
+        // the deadline queue requires a non-zero period
+        let deadline_queue_guard =
+            self.attach_to_deadline_queue(remaining_time.max(Duration::from_nanos(1)))?;
+        self.attach()?;
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::Tick(deadline_queue_guard),
+        })
+    }
+
     /// Waits until an event arrives on the [`WaitSet`], then collects all events by calling the
     /// provided `fn_call` callback with the corresponding [`WaitSetAttachmentId`]. In contrast
     /// to [`WaitSet::wait_and_process_once()`] it will never return until the user explicitly
@@ -936,7 +1136,11 @@ impl<Service: crate::service::Service> WaitSet<Service> {
                                  when self.deadline_queue.duration_until_next_deadline(),
                                  with WaitSetRunError::InternalError,
                                  "{msg} since the next timeout could not be acquired.");
-        let next_timeout = next_timeout.min(timeout);
+        let next_timeout = if self.deferred_events.borrow().is_empty() {
+            next_timeout.min(timeout)
+        } else {
+            Duration::ZERO
+        };
 
         let mut triggered_file_descriptors = vec![];
         let collect_triggered_fds = |fd: &FileDescriptor| {
@@ -953,9 +1157,17 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             self.reactor.timed_wait(collect_triggered_fds, next_timeout)
         };
 
-        match reactor_wait_result {
-            Ok(0) => self.handle_deadlines(&mut fn_call, msg),
-            Ok(_) => self.handle_all_attachments(&triggered_file_descriptors, &mut fn_call, msg),
+        #[cfg(feature = "trace_points")]
+        let span = crate::trace::Span::start();
+
+        let mut events = vec![];
+        let result = match reactor_wait_result {
+            Ok(0) => self
+                .collect_deadlines(&mut events, msg)
+                .map(|_| self.dispatch(events, &mut fn_call)),
+            Ok(_) => self
+                .collect_all_attachments(&triggered_file_descriptors, &mut events, msg)
+                .map(|_| self.dispatch(events, &mut fn_call)),
             Err(ReactorWaitError::Interrupt) => Ok(WaitSetRunResult::Interrupt),
             Err(ReactorWaitError::InsufficientPermissions) => {
                 fail!(from self, with WaitSetRunError::InsufficientPermissions,
@@ -965,7 +1177,19 @@ impl<Service: crate::service::Service> WaitSet<Service> {
                 fail!(from self, with WaitSetRunError::InternalError,
                     "{msg} due to an internal error.");
             }
+        };
+
+        #[cfg(feature = "trace_points")]
+        if let Some(span) = span {
+            span.finish(
+                crate::trace::TracePointKind::WaitSetDispatch,
+                0,
+                0,
+                triggered_file_descriptors.len() as u64,
+            );
         }
+
+        result
     }
 
     /// Returns the capacity of the [`WaitSet`]
@@ -0,0 +1,577 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`WaitSetExecutor`](crate::waitset_executor::WaitSetExecutor) is built on top of a
+//! [`WaitSet`](crate::waitset::WaitSet) and dispatches the events of its attachments to
+//! callbacks that are registered per attachment. The callbacks are executed on a pool of
+//! worker threads whose priority, scheduler and CPU affinity can be configured with the
+//! [`WaitSetExecutorBuilder`](crate::waitset_executor::WaitSetExecutorBuilder).
+//!
+//! The [`WaitSetExecutor`](crate::waitset_executor::WaitSetExecutor) waits on the
+//! [`WaitSet`](crate::waitset::WaitSet) in the thread that calls
+//! [`WaitSetExecutor::run()`](crate::waitset_executor::WaitSetExecutor::run()). Whenever the
+//! [`WaitSet`](crate::waitset::WaitSet) wakes up, the callbacks of all triggered attachments
+//! are handed over to the worker threads where they run in parallel. The
+//! [`WaitSet`](crate::waitset::WaitSet) waits for the next events only after all callbacks
+//! were processed, therefore the callback of one attachment never runs concurrently with
+//! itself.
+//!
+//! The events of one wake up are handed over to the worker threads in the order of the
+//! priorities of their attachments, see
+//! [`WaitSetExecutor::set_priority()`](crate::waitset_executor::WaitSetExecutor::set_priority()).
+//!
+//! Since the callbacks are called from the worker threads, everything they capture must be
+//! [`Send`](core::marker::Send). Ports of a thread-safe service variant like
+//! [`ipc_threadsafe::Service`](crate::service::ipc_threadsafe::Service) can be shared with
+//! the callbacks.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset_executor::{AttachmentEvent, WaitSetExecutorBuilder};
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc_threadsafe::Service>()?;
+//! # let event = node.service_builder(&"MyEventName_1".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//! # let pubsub = node.service_builder(&"MyServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let publisher = pubsub.publisher_builder().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc_threadsafe::Service>()?;
+//! let mut executor = WaitSetExecutorBuilder::new()
+//!     .number_of_workers(2)
+//!     .worker_affinity(&[0, 1])
+//!     .create(&waitset)?;
+//!
+//! executor.attach_deadline(&listener, Duration::from_millis(100), |event| {
+//!     match event {
+//!         AttachmentEvent::MissedDeadline => println!("no event received within 100ms"),
+//!         _ => {
+//!             listener.try_wait(|event| println!("received notification {:?}", event.id));
+//!         }
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//!
+//! executor.attach_interval(Duration::from_millis(250), |_| {
+//!     publisher.send_copy(123);
+//!     CallbackProgression::Continue
+//! })?;
+//!
+//! executor.run()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_bb_posix::scheduler::Scheduler;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_posix::thread::{MAX_SCOPED_THREADS, ThreadScopeGuard, thread_scope};
+use iceoryx2_log::fail;
+
+use crate::waitset::{
+    WaitSet, WaitSetAttachmentError, WaitSetAttachmentId, WaitSetGuard, WaitSetRunError,
+    WaitSetRunResult,
+};
+
+/// The event with which the callback of an attachment is called by the [`WaitSetExecutor`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AttachmentEvent {
+    /// The attached notification or deadline has received an event.
+    Notification,
+    /// The attached deadline has passed without receiving an event.
+    MissedDeadline,
+    /// The attached interval has passed.
+    Tick,
+}
+
+/// Defines the failures that can occur when calling [`WaitSetExecutorBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetExecutorCreateError {
+    /// The number of workers is either zero or exceeds [`MAX_SCOPED_THREADS`].
+    InvalidNumberOfWorkers,
+}
+
+impl core::fmt::Display for WaitSetExecutorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitSetExecutorCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitSetExecutorCreateError {}
+
+/// Defines the failures that can occur when calling [`WaitSetExecutor::run()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetExecutorRunError {
+    /// The process has not sufficient permissions to wait on the attachments.
+    InsufficientPermissions,
+    /// An internal error has occurred.
+    InternalError,
+    /// Waiting on an empty [`WaitSetExecutor`] would lead to a deadlock therefore it causes
+    /// an error.
+    NoAttachments,
+    /// The worker threads could not be created with the configured settings.
+    WorkerThreadCreationFailed,
+}
+
+impl From<WaitSetRunError> for WaitSetExecutorRunError {
+    fn from(value: WaitSetRunError) -> Self {
+        match value {
+            WaitSetRunError::InsufficientPermissions => Self::InsufficientPermissions,
+            WaitSetRunError::InternalError => Self::InternalError,
+            WaitSetRunError::NoAttachments => Self::NoAttachments,
+        }
+    }
+}
+
+impl core::fmt::Display for WaitSetExecutorRunError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitSetExecutorRunError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitSetExecutorRunError {}
+
+type Callback<'callback> =
+    Box<dyn FnMut(AttachmentEvent) -> CallbackProgression + Send + 'callback>;
+
+#[derive(Debug, Clone, Copy)]
+struct Job {
+    attachment: usize,
+    event: AttachmentEvent,
+}
+
+#[derive(Debug, Default)]
+struct DispatchState {
+    jobs: VecDeque<Job>,
+    jobs_in_progress: usize,
+    stop_requested: bool,
+    shutdown: bool,
+}
+
+/// Hands the jobs of one [`WaitSet`] wake up over to the worker threads.
+#[derive(Debug, Default)]
+struct Dispatcher {
+    state: Mutex<DispatchState>,
+    job_available: Condvar,
+    jobs_finished: Condvar,
+}
+
+impl Dispatcher {
+    fn lock(&self) -> MutexGuard<'_, DispatchState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Processes all jobs on the worker threads and returns when they are finished. Returns
+    /// true when one of the callbacks requested a stop.
+    fn process(&self, jobs: &mut Vec<Job>) -> bool {
+        let mut state = self.lock();
+        state.jobs_in_progress += jobs.len();
+        state.jobs.extend(jobs.drain(..));
+        self.job_available.notify_all();
+
+        while state.jobs_in_progress != 0 {
+            state = self
+                .jobs_finished
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+
+        state.stop_requested
+    }
+
+    fn shutdown(&self) {
+        self.lock().shutdown = true;
+        self.job_available.notify_all();
+    }
+
+    fn run_worker(&self, callbacks: &[Mutex<Callback>]) {
+        loop {
+            let job = {
+                let mut state = self.lock();
+                loop {
+                    if let Some(job) = state.jobs.pop_front() {
+                        break job;
+                    }
+
+                    if state.shutdown {
+                        return;
+                    }
+
+                    state = self
+                        .job_available
+                        .wait(state)
+                        .unwrap_or_else(PoisonError::into_inner);
+                }
+            };
+
+            let progression = {
+                let mut callback = callbacks[job.attachment]
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                callback(job.event)
+            };
+
+            let mut state = self.lock();
+            state.jobs_in_progress -= 1;
+            if let CallbackProgression::Stop = progression {
+                state.stop_requested = true;
+            }
+
+            if state.jobs_in_progress == 0 {
+                self.jobs_finished.notify_all();
+            }
+        }
+    }
+}
+
+/// The builder for the [`WaitSetExecutor`].
+#[derive(Debug, Clone)]
+pub struct WaitSetExecutorBuilder {
+    number_of_workers: usize,
+    worker_priority: Option<u8>,
+    worker_scheduler: Option<Scheduler>,
+    worker_affinity: Vec<usize>,
+}
+
+impl Default for WaitSetExecutorBuilder {
+    fn default() -> Self {
+        Self {
+            number_of_workers: SystemInfo::NumberOfCpuCores
+                .value()
+                .clamp(1, MAX_SCOPED_THREADS),
+            worker_priority: None,
+            worker_scheduler: None,
+            worker_affinity: vec![],
+        }
+    }
+}
+
+impl WaitSetExecutorBuilder {
+    /// Creates a new [`WaitSetExecutorBuilder`]. By default, the executor uses one worker
+    /// thread per CPU core and the worker threads inherit the scheduling attributes of the
+    /// thread that calls [`WaitSetExecutor::run()`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the number of worker threads that process the callbacks. It must be at least
+    /// `1` and not exceed [`MAX_SCOPED_THREADS`].
+    pub fn number_of_workers(mut self, value: usize) -> Self {
+        self.number_of_workers = value;
+        self
+    }
+
+    /// Defines the priority of the worker threads whereby `0` represents the lowest and `255`
+    /// the highest priority. See
+    /// [`ThreadBuilder::priority()`](iceoryx2_bb_posix::thread::ThreadBuilder::priority()).
+    pub fn worker_priority(mut self, value: u8) -> Self {
+        self.worker_priority = Some(value);
+        self
+    }
+
+    /// Defines the [`Scheduler`] of the worker threads.
+    pub fn worker_scheduler(mut self, value: Scheduler) -> Self {
+        self.worker_scheduler = Some(value);
+        self
+    }
+
+    /// Pins the worker threads to the provided list of `cpu_core_id`s. The cpu cores must
+    /// exist otherwise [`WaitSetExecutor::run()`] fails with
+    /// [`WaitSetExecutorRunError::WorkerThreadCreationFailed`].
+    pub fn worker_affinity(mut self, cpu_core_ids: &[usize]) -> Self {
+        self.worker_affinity = cpu_core_ids.to_vec();
+        self
+    }
+
+    /// Creates a [`WaitSetExecutor`] that dispatches the events of the provided [`WaitSet`].
+    pub fn create<'waitset, 'attachment, 'callback, Service: crate::service::Service>(
+        self,
+        waitset: &'waitset WaitSet<Service>,
+    ) -> Result<
+        WaitSetExecutor<'waitset, 'attachment, 'callback, Service>,
+        WaitSetExecutorCreateError,
+    > {
+        if self.number_of_workers == 0 || MAX_SCOPED_THREADS < self.number_of_workers {
+            fail!(from self, with WaitSetExecutorCreateError::InvalidNumberOfWorkers,
+                "Unable to create WaitSetExecutor since the number of workers must be in the range [1, {MAX_SCOPED_THREADS}].");
+        }
+
+        Ok(WaitSetExecutor {
+            waitset,
+            attachments: vec![],
+            callbacks: vec![],
+            config: self,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AttachmentKind {
+    Notification,
+    Interval,
+}
+
+/// Dispatches the events of a [`WaitSet`] to the callbacks of the corresponding attachments
+/// on a pool of worker threads. Is created with the [`WaitSetExecutorBuilder`].
+pub struct WaitSetExecutor<'waitset, 'attachment, 'callback, Service: crate::service::Service>
+where
+    Service::Reactor: 'waitset,
+{
+    waitset: &'waitset WaitSet<Service>,
+    attachments: Vec<(WaitSetGuard<'waitset, 'attachment, Service>, AttachmentKind)>,
+    callbacks: Vec<Mutex<Callback<'callback>>>,
+    config: WaitSetExecutorBuilder,
+}
+
+impl<Service: crate::service::Service> Debug for WaitSetExecutor<'_, '_, '_, Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "WaitSetExecutor {{ number_of_attachments: {}, config: {:?} }}",
+            self.attachments.len(),
+            self.config
+        )
+    }
+}
+
+impl<'waitset, 'attachment, 'callback, Service: crate::service::Service>
+    WaitSetExecutor<'waitset, 'attachment, 'callback, Service>
+{
+    /// Attaches an object as notification to the underlying [`WaitSet`], see
+    /// [`WaitSet::attach_notification()`]. Whenever the object emits an event, the callback is
+    /// called with [`AttachmentEvent::Notification`].
+    pub fn attach_notification<
+        T: SynchronousMultiplexing + Debug + ?Sized,
+        F: FnMut(AttachmentEvent) -> CallbackProgression + Send + 'callback,
+    >(
+        &mut self,
+        attachment: &'attachment T,
+        callback: F,
+    ) -> Result<WaitSetAttachmentId<Service>, WaitSetAttachmentError> {
+        let guard = self.waitset.attach_notification(attachment)?;
+        Ok(self.register(guard, AttachmentKind::Notification, callback))
+    }
+
+    /// Attaches an object as deadline to the underlying [`WaitSet`], see
+    /// [`WaitSet::attach_deadline()`]. Whenever the object emits an event, the callback is
+    /// called with [`AttachmentEvent::Notification`] and when the deadline has passed without
+    /// an event, it is called with [`AttachmentEvent::MissedDeadline`].
+    pub fn attach_deadline<
+        T: SynchronousMultiplexing + Debug + ?Sized,
+        F: FnMut(AttachmentEvent) -> CallbackProgression + Send + 'callback,
+    >(
+        &mut self,
+        attachment: &'attachment T,
+        deadline: Duration,
+        callback: F,
+    ) -> Result<WaitSetAttachmentId<Service>, WaitSetAttachmentError> {
+        let guard = self.waitset.attach_deadline(attachment, deadline)?;
+        Ok(self.register(guard, AttachmentKind::Notification, callback))
+    }
+
+    /// Attaches an interval to the underlying [`WaitSet`], see [`WaitSet::attach_interval()`].
+    /// Whenever the interval has passed, the callback is called with
+    /// [`AttachmentEvent::Tick`].
+    pub fn attach_interval<F: FnMut(AttachmentEvent) -> CallbackProgression + Send + 'callback>(
+        &mut self,
+        interval: Duration,
+        callback: F,
+    ) -> Result<WaitSetAttachmentId<Service>, WaitSetAttachmentError> {
+        let guard = self.waitset.attach_interval(interval)?;
+        Ok(self.register(guard, AttachmentKind::Interval, callback))
+    }
+
+    /// Detaches the attachment with the provided [`WaitSetAttachmentId`] and removes its
+    /// callback. Returns false when no such attachment exists, otherwise true.
+    pub fn detach(&mut self, attachment_id: &WaitSetAttachmentId<Service>) -> bool {
+        match self
+            .attachments
+            .iter()
+            .position(|(guard, _)| WaitSetAttachmentId::from_guard(guard) == *attachment_id)
+        {
+            Some(index) => {
+                self.attachments.remove(index);
+                self.callbacks.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the priority of the attachment with the provided [`WaitSetAttachmentId`], see
+    /// [`WaitSetGuard::set_priority()`]. The events of attachments with a higher priority are
+    /// handed over to the worker threads first. Returns false when no such attachment exists,
+    /// otherwise true.
+    pub fn set_priority(&self, attachment_id: &WaitSetAttachmentId<Service>, value: u8) -> bool {
+        match self
+            .attachments
+            .iter()
+            .find(|(guard, _)| WaitSetAttachmentId::from_guard(guard) == *attachment_id)
+        {
+            Some((guard, _)) => {
+                guard.set_priority(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.attachments.len()
+    }
+
+    /// Returns true if the [`WaitSetExecutor`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.attachments.is_empty()
+    }
+
+    /// Starts the worker threads and dispatches the events of the [`WaitSet`] to the callbacks
+    /// until one of them returns [`CallbackProgression::Stop`] or a signal is received, see
+    /// [`WaitSet::wait_and_process()`]. When a callback requests a stop, the callbacks of the
+    /// same wake up are still completed before it returns [`WaitSetRunResult::StopRequest`].
+    ///
+    /// The worker threads are joined before the call returns.
+    pub fn run(&self) -> Result<WaitSetRunResult, WaitSetExecutorRunError> {
+        let dispatcher = Dispatcher::default();
+        let callbacks = &self.callbacks;
+        let mut result = Err(WaitSetExecutorRunError::WorkerThreadCreationFailed);
+
+        let spawn_result = thread_scope(|s| {
+            for _ in 0..self.config.number_of_workers {
+                if let Err(e) = self
+                    .worker_thread(s)
+                    .spawn(|| dispatcher.run_worker(callbacks))
+                {
+                    dispatcher.shutdown();
+                    return Err(e);
+                }
+            }
+
+            result = self.dispatch(&dispatcher);
+            dispatcher.shutdown();
+            Ok(())
+        });
+
+        if let Err(e) = spawn_result {
+            fail!(from self, with WaitSetExecutorRunError::WorkerThreadCreationFailed,
+                "Unable to run the WaitSetExecutor since the worker threads could not be created ({e:?}).");
+        }
+
+        result
+    }
+
+    fn worker_thread<'scope>(
+        &self,
+        scope: &'scope mut ThreadScopeGuard,
+    ) -> iceoryx2_bb_posix::thread::ScopedThreadBuilder<'scope> {
+        let mut builder = scope.thread_builder();
+
+        if let Some(priority) = self.config.worker_priority {
+            builder = builder
+                .inherit_scheduling_attributes(false)
+                .priority(priority);
+        }
+
+        if let Some(scheduler) = self.config.worker_scheduler {
+            builder = builder
+                .inherit_scheduling_attributes(false)
+                .scheduler(scheduler);
+        }
+
+        if !self.config.worker_affinity.is_empty() {
+            builder = builder.affinity(&self.config.worker_affinity);
+        }
+
+        builder
+    }
+
+    fn dispatch(
+        &self,
+        dispatcher: &Dispatcher,
+    ) -> Result<WaitSetRunResult, WaitSetExecutorRunError> {
+        let mut jobs = vec![];
+        loop {
+            let result = self.waitset.wait_and_process_once(|attachment_id| {
+                if let Some(job) = self.job_for(&attachment_id) {
+                    jobs.push(job);
+                }
+                CallbackProgression::Continue
+            });
+
+            let stop_requested = dispatcher.process(&mut jobs);
+
+            match result {
+                Ok(WaitSetRunResult::AllEventsHandled) if stop_requested => {
+                    return Ok(WaitSetRunResult::StopRequest);
+                }
+                Ok(WaitSetRunResult::AllEventsHandled) => (),
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    fail!(from self, with e.into(),
+                        "Unable to dispatch the events of the WaitSet since ({e:?}) has occurred.");
+                }
+            }
+        }
+    }
+
+    fn job_for(&self, attachment_id: &WaitSetAttachmentId<Service>) -> Option<Job> {
+        self.attachments
+            .iter()
+            .enumerate()
+            .find_map(|(attachment, (guard, kind))| {
+                let event = if attachment_id.has_missed_deadline(guard) {
+                    AttachmentEvent::MissedDeadline
+                } else if attachment_id.has_event_from(guard) {
+                    match kind {
+                        AttachmentKind::Notification => AttachmentEvent::Notification,
+                        AttachmentKind::Interval => AttachmentEvent::Tick,
+                    }
+                } else {
+                    return None;
+                };
+
+                Some(Job { attachment, event })
+            })
+    }
+
+    fn register<F: FnMut(AttachmentEvent) -> CallbackProgression + Send + 'callback>(
+        &mut self,
+        guard: WaitSetGuard<'waitset, 'attachment, Service>,
+        kind: AttachmentKind,
+        callback: F,
+    ) -> WaitSetAttachmentId<Service> {
+        let attachment_id = WaitSetAttachmentId::from_guard(&guard);
+        self.attachments.push((guard, kind));
+        self.callbacks.push(Mutex::new(Box::new(callback)));
+        attachment_id
+    }
+}
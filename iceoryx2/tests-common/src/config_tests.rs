@@ -0,0 +1,316 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::string::{String, ToString};
+
+use iceoryx2::config::{
+    Config, ConfigCreationError, ConfigValueOrigin, LayeredConfig, ServiceNameRemapping,
+    ServiceProfile,
+};
+use iceoryx2::port::backpressure_strategy::BackpressureStrategy;
+use iceoryx2::prelude::{AllocationStrategy, ServiceName};
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::file::{CreationMode, File, FileBuilder};
+use iceoryx2_bb_posix::testing::generate_file_path;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+struct ConfigFile {
+    path: FilePath,
+}
+
+impl ConfigFile {
+    fn new(content: &str) -> Self {
+        let path = generate_file_path();
+        let mut file = FileBuilder::new(&path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+            .unwrap();
+        file.write(content.as_bytes()).unwrap();
+        Self { path }
+    }
+}
+
+impl Drop for ConfigFile {
+    fn drop(&mut self) {
+        File::remove(&self.path).unwrap();
+    }
+}
+
+#[test]
+fn layered_config_without_layers_contains_default_values() {
+    let sut = LayeredConfig::new();
+
+    assert_that!(sut.config().unwrap(), eq Config::default());
+    assert_that!(sut.origin("global.prefix"), eq Some(&ConfigValueOrigin::Default));
+    assert_that!(sut.origin("defaults.publish-subscribe.max-subscribers"), eq Some(&ConfigValueOrigin::Default));
+    assert_that!(sut.origin("does.not.exist"), eq None);
+    assert_that!(sut.origins().all(|(_, origin)| *origin == ConfigValueOrigin::Default), eq true);
+}
+
+#[test]
+fn environment_variable_name_is_derived_from_key() {
+    assert_that!(LayeredConfig::environment_variable_name("global.prefix"), eq "IOX2_GLOBAL_PREFIX");
+    assert_that!(LayeredConfig::environment_variable_name("defaults.publish-subscribe.max-subscribers"),
+        eq "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS");
+}
+
+#[test]
+fn partial_config_file_overrides_only_contained_entries() {
+    let file = ConfigFile::new(
+        r#"
+[defaults.publish-subscribe]
+max-subscribers = 123
+"#,
+    );
+
+    let mut sut = LayeredConfig::new();
+    assert_that!(sut.add_file(&file.path), is_ok);
+    let config = sut.config().unwrap();
+
+    assert_that!(config.defaults.publish_subscribe.max_subscribers, eq 123);
+    assert_that!(config.defaults.publish_subscribe.max_publishers, eq Config::default().defaults.publish_subscribe.max_publishers);
+    assert_that!(config.global, eq Config::default().global);
+    assert_that!(sut.origin("defaults.publish-subscribe.max-subscribers"), eq Some(&ConfigValueOrigin::File(file.path)));
+    assert_that!(sut.origin("defaults.publish-subscribe.max-publishers"), eq Some(&ConfigValueOrigin::Default));
+}
+
+#[test]
+fn later_config_file_overrides_entries_of_earlier_config_file() {
+    let base = ConfigFile::new(
+        r#"
+[global]
+prefix = "base_"
+
+[defaults.event]
+max-listeners = 3
+"#,
+    );
+    let overrides = ConfigFile::new(
+        r#"
+[global]
+prefix = "override_"
+"#,
+    );
+
+    let mut sut = LayeredConfig::new();
+    assert_that!(sut.add_file(&base.path), is_ok);
+    assert_that!(sut.add_file(&overrides.path), is_ok);
+    let config = sut.config().unwrap();
+
+    assert_that!(config.global.prefix, eq FileName::new(b"override_").unwrap());
+    assert_that!(config.defaults.event.max_listeners, eq 3);
+    assert_that!(sut.origin("global.prefix"), eq Some(&ConfigValueOrigin::File(overrides.path)));
+    assert_that!(sut.origin("defaults.event.max-listeners"), eq Some(&ConfigValueOrigin::File(base.path)));
+}
+
+#[test]
+fn environment_overrides_have_priority_over_config_files() {
+    let file = ConfigFile::new(
+        r#"
+[global]
+prefix = "file_"
+
+[defaults.publish-subscribe]
+max-subscribers = 123
+"#,
+    );
+
+    let mut sut = LayeredConfig::new();
+    assert_that!(sut.add_file(&file.path), is_ok);
+    sut.add_environment_overrides_from(|name| match name {
+        "IOX2_GLOBAL_PREFIX" => Some("env_".to_string()),
+        "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_ENABLE_SAFE_OVERFLOW" => Some("false".to_string()),
+        _ => None,
+    });
+    let config = sut.config().unwrap();
+
+    assert_that!(config.global.prefix, eq FileName::new(b"env_").unwrap());
+    assert_that!(config.defaults.publish_subscribe.enable_safe_overflow, eq false);
+    assert_that!(config.defaults.publish_subscribe.max_subscribers, eq 123);
+    assert_that!(sut.origin("global.prefix"),
+        eq Some(&ConfigValueOrigin::EnvironmentVariable(String::from("IOX2_GLOBAL_PREFIX"))));
+    assert_that!(sut.origin("defaults.publish-subscribe.max-subscribers"), eq Some(&ConfigValueOrigin::File(file.path)));
+}
+
+#[test]
+fn invalid_environment_overrides_are_ignored() {
+    let mut sut = LayeredConfig::new();
+    sut.add_environment_overrides_from(|name| match name {
+        "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS" => Some("many".to_string()),
+        "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS" => Some("-1".to_string()),
+        "IOX2_GLOBAL_PREFIX" => Some("invalid/prefix".to_string()),
+        _ => None,
+    });
+
+    assert_that!(sut.config().unwrap(), eq Config::default());
+    assert_that!(sut.origins().all(|(_, origin)| *origin == ConfigValueOrigin::Default), eq true);
+}
+
+#[test]
+fn adding_non_existing_config_file_fails() {
+    let mut sut = LayeredConfig::new();
+
+    assert_that!(sut.add_file(&generate_file_path()).err(), eq Some(ConfigCreationError::ConfigFileDoesNotExist));
+    assert_that!(sut.config().unwrap(), eq Config::default());
+}
+
+#[test]
+fn adding_config_file_with_invalid_content_fails() {
+    let file = ConfigFile::new(
+        r#"
+[defaults.publish-subscribe]
+max-subscribers = "many"
+"#,
+    );
+
+    let mut sut = LayeredConfig::new();
+
+    assert_that!(sut.add_file(&file.path).err(), eq Some(ConfigCreationError::UnableToDeserializeContents));
+    assert_that!(sut.config().unwrap(), eq Config::default());
+}
+
+#[test]
+fn config_from_file_keeps_default_values_of_missing_entries() {
+    let file = ConfigFile::new(
+        r#"
+[defaults.request-response]
+max-servers = 7
+"#,
+    );
+
+    let config = Config::from_file(&file.path).unwrap();
+
+    assert_that!(config.defaults.request_response.max_servers, eq 7);
+    assert_that!(config.defaults.publish_subscribe, eq Config::default().defaults.publish_subscribe);
+}
+
+#[test]
+fn service_profiles_are_read_from_config_file() {
+    let file = ConfigFile::new(
+        r#"
+[[services]]
+name = "sensor/*"
+publish-subscribe.subscriber-max-buffer-size = 16
+publish-subscribe.backpressure-strategy = "DiscardData"
+
+[[services]]
+name = "sensor/lidar"
+event.max-listeners = 3
+"#,
+    );
+
+    let config = Config::from_file(&file.path).unwrap();
+
+    assert_that!(config.services, len 2);
+    assert_that!(config.services[0].name, eq "sensor/*");
+    assert_that!(config.services[0].publish_subscribe.subscriber_max_buffer_size, eq Some(16));
+    assert_that!(config.services[0].publish_subscribe.backpressure_strategy, eq Some(BackpressureStrategy::DiscardData));
+    assert_that!(config.services[0].publish_subscribe.max_publishers, eq None);
+    assert_that!(config.services[1].name, eq "sensor/lidar");
+    assert_that!(config.services[1].event.max_listeners, eq Some(3));
+}
+
+#[test]
+fn defaults_for_service_without_matching_profile_are_the_defaults() {
+    let mut config = Config::default();
+    let mut profile = ServiceProfile::new("sensor/*");
+    profile.publish_subscribe.max_publishers = Some(12);
+    config.services.push(profile);
+
+    let defaults = config.defaults_for(&ServiceName::new("actuator/brake").unwrap());
+
+    assert_that!(defaults, eq config.defaults);
+}
+
+#[test]
+fn defaults_for_applies_matching_profiles_in_order() {
+    let mut config = Config::default();
+    let mut profile = ServiceProfile::new("sensor/*");
+    profile.publish_subscribe.max_publishers = Some(12);
+    profile.publish_subscribe.subscriber_max_buffer_size = Some(16);
+    config.services.push(profile);
+    let mut profile = ServiceProfile::new("sensor/lidar");
+    profile.publish_subscribe.subscriber_max_buffer_size = Some(32);
+    profile.request_response.client_allocation_strategy = Some(AllocationStrategy::PowerOfTwo);
+    profile.blackboard.max_readers = Some(2);
+    config.services.push(profile);
+
+    let defaults = config.defaults_for(&ServiceName::new("sensor/lidar").unwrap());
+
+    assert_that!(defaults.publish_subscribe.max_publishers, eq 12);
+    assert_that!(defaults.publish_subscribe.subscriber_max_buffer_size, eq 32);
+    assert_that!(defaults.publish_subscribe.max_subscribers, eq config.defaults.publish_subscribe.max_subscribers);
+    assert_that!(defaults.request_response.client_allocation_strategy, eq AllocationStrategy::PowerOfTwo);
+    assert_that!(defaults.blackboard.max_readers, eq 2);
+    assert_that!(defaults.event, eq config.defaults.event);
+
+    let defaults = config.defaults_for(&ServiceName::new("sensor/radar").unwrap());
+
+    assert_that!(defaults.publish_subscribe.max_publishers, eq 12);
+    assert_that!(defaults.publish_subscribe.subscriber_max_buffer_size, eq 16);
+    assert_that!(defaults.blackboard, eq config.defaults.blackboard);
+}
+
+#[test]
+fn remappings_are_read_from_config_file() {
+    let file = ConfigFile::new(
+        r#"
+[[remappings]]
+from = "camera/image"
+to = "left_camera/image"
+"#,
+    );
+
+    let config = Config::from_file(&file.path).unwrap();
+
+    assert_that!(config.remappings, len 1);
+    assert_that!(config.remappings[0].from, eq "camera/image");
+    assert_that!(config.remappings[0].to, eq "left_camera/image");
+}
+
+#[test]
+fn remappings_can_be_overridden_with_environment_variable() {
+    let mut sut = LayeredConfig::new();
+    sut.add_environment_overrides_from(|name| match name {
+        "IOX2_REMAPPINGS" => {
+            Some(r#"[{ from = "camera/image", to = "right_camera/image" }]"#.to_string())
+        }
+        _ => None,
+    });
+    let config = sut.config().unwrap();
+
+    assert_that!(config.remappings, len 1);
+    assert_that!(config.remappings[0].from, eq "camera/image");
+    assert_that!(config.remappings[0].to, eq "right_camera/image");
+    assert_that!(sut.origin("remappings"),
+        eq Some(&ConfigValueOrigin::EnvironmentVariable(String::from("IOX2_REMAPPINGS"))));
+}
+
+#[test]
+fn remapped_service_name_is_provided_by_first_matching_remapping() {
+    let camera = ServiceName::new("camera/image").unwrap();
+    let left_camera = ServiceName::new("left_camera/image").unwrap();
+    let right_camera = ServiceName::new("right_camera/image").unwrap();
+    let mut config = Config::default();
+    config
+        .remappings
+        .push(ServiceNameRemapping::new(&camera, &left_camera));
+    config
+        .remappings
+        .push(ServiceNameRemapping::new(&camera, &right_camera));
+
+    assert_that!(config.remapped_service_name(&camera), eq Some(&left_camera));
+    assert_that!(config.remapped_service_name(&left_camera), eq None);
+}
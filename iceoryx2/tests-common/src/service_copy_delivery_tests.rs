@@ -0,0 +1,191 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2::port::PayloadDelivery;
+use iceoryx2::port::update_connections::UpdateConnections;
+use iceoryx2::prelude::*;
+use iceoryx2::testing::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+type ServiceType = ipc_copy::Service;
+
+#[test]
+fn publisher_announces_copy_delivery() {
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<u64>()
+        .create()
+        .unwrap();
+    let _publisher = service.publisher_builder().create().unwrap();
+
+    let mut payload_delivery = Vec::new();
+    service.dynamic_config().list_publishers(|details| {
+        payload_delivery.push(details.payload_delivery);
+        CallbackProgression::Continue
+    });
+
+    assert_that!(payload_delivery, eq vec![PayloadDelivery::Copy]);
+}
+
+#[test]
+fn samples_held_by_subscriber_do_not_occupy_publisher_data_segment() {
+    const NUMBER_OF_SAMPLES: usize = 4;
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<u64>()
+        .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+        .subscriber_max_borrowed_samples(NUMBER_OF_SAMPLES)
+        .create()
+        .unwrap();
+    let publisher = service
+        .publisher_builder()
+        .max_loaned_samples(1)
+        .override_sample_preallocation(|_| 1)
+        .create()
+        .unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    let mut received_samples = Vec::new();
+    for n in 0..NUMBER_OF_SAMPLES {
+        assert_that!(publisher.send_copy(n as u64), eq Ok(1));
+        received_samples.push(subscriber.receive().unwrap().unwrap());
+    }
+
+    for (n, sample) in received_samples.iter().enumerate() {
+        assert_that!(**sample, eq n as u64);
+    }
+}
+
+#[test]
+fn every_subscriber_receives_its_own_copy() {
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<u64>()
+        .create()
+        .unwrap();
+    let publisher = service.publisher_builder().create().unwrap();
+    let subscriber_1 = service.subscriber_builder().create().unwrap();
+    let subscriber_2 = service.subscriber_builder().create().unwrap();
+
+    assert_that!(publisher.send_copy(8127), eq Ok(2));
+
+    let sample_1 = subscriber_1.receive().unwrap().unwrap();
+    let sample_2 = subscriber_2.receive().unwrap().unwrap();
+    assert_that!(*sample_1, eq 8127);
+    assert_that!(*sample_2, eq 8127);
+    assert_that!(sample_1.payload() as *const u64, ne sample_2.payload() as *const u64);
+}
+
+#[test]
+fn zero_copy_subscriber_receives_from_copying_publisher() {
+    let config = generate_isolated_config();
+    let service_name = generate_service_name();
+    let copy_node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let zero_copy_node = NodeBuilder::new()
+        .config(&config)
+        .create::<ipc::Service>()
+        .unwrap();
+    let copy_service = copy_node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .create()
+        .unwrap();
+    let zero_copy_service = zero_copy_node
+        .service_builder(&service_name)
+        .publish_subscribe::<u64>()
+        .open()
+        .unwrap();
+
+    let publisher = copy_service.publisher_builder().create().unwrap();
+    let subscriber = zero_copy_service.subscriber_builder().create().unwrap();
+
+    assert_that!(publisher.send_copy(991), eq Ok(1));
+
+    let sample = subscriber.receive().unwrap().unwrap();
+    assert_that!(*sample, eq 991);
+}
+
+#[test]
+fn history_is_delivered_as_copy() {
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<u64>()
+        .history_size(2)
+        .subscriber_max_buffer_size(2)
+        .create()
+        .unwrap();
+    let publisher = service.publisher_builder().create().unwrap();
+
+    publisher.send_copy(12).unwrap();
+    publisher.send_copy(34).unwrap();
+
+    let subscriber = service
+        .subscriber_builder()
+        .buffer_size(2)
+        .create()
+        .unwrap();
+    publisher.update_connections().unwrap();
+
+    assert_that!(*subscriber.receive().unwrap().unwrap(), eq 12);
+    assert_that!(*subscriber.receive().unwrap().unwrap(), eq 34);
+}
+
+#[test]
+fn requests_and_responses_are_delivered_as_copies() {
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let service = node
+        .service_builder(&generate_service_name())
+        .request_response::<u64, u64>()
+        .create()
+        .unwrap();
+    let client = service.client_builder().create().unwrap();
+    let server = service.server_builder().create().unwrap();
+
+    let pending_response = client.send_copy(77).unwrap();
+    let active_request = server.receive().unwrap().unwrap();
+    assert_that!(*active_request, eq 77);
+
+    active_request.send_copy(*active_request + 1).unwrap();
+    let response = pending_response.receive().unwrap().unwrap();
+    assert_that!(*response, eq 78);
+}
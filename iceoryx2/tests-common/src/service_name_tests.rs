@@ -0,0 +1,58 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+fn matches_pattern_without_wildcards_only_when_equal() {
+    let sut = ServiceName::new("sensor/lidar").unwrap();
+
+    assert_that!(sut.matches("sensor/lidar"), eq true);
+    assert_that!(sut.matches("sensor/lida"), eq false);
+    assert_that!(sut.matches("sensor/lidar/front"), eq false);
+    assert_that!(sut.matches(""), eq false);
+}
+
+#[test]
+fn matches_pattern_with_asterisk_any_sequence() {
+    let sut = ServiceName::new("sensors/front/temperature").unwrap();
+
+    assert_that!(sut.matches("*"), eq true);
+    assert_that!(sut.matches("sensors/*"), eq true);
+    assert_that!(sut.matches("sensors/*/temperature"), eq true);
+    assert_that!(sut.matches("*temperature"), eq true);
+    assert_that!(sut.matches("sensors/front/temperature*"), eq true);
+    assert_that!(sut.matches("*/*/*"), eq true);
+    assert_that!(sut.matches("sensors/*/pressure"), eq false);
+    assert_that!(sut.matches("*/rear/*"), eq false);
+}
+
+#[test]
+fn matches_pattern_with_question_mark_exactly_one_character() {
+    let sut = ServiceName::new("sensor/7").unwrap();
+
+    assert_that!(sut.matches("sensor/?"), eq true);
+    assert_that!(sut.matches("sens?r/?"), eq true);
+    assert_that!(sut.matches("sensor/??"), eq false);
+    assert_that!(sut.matches("sensor/7?"), eq false);
+}
+
+#[test]
+fn matches_pattern_retries_asterisk_after_partial_match() {
+    let sut = ServiceName::new("a/b/a/b/c").unwrap();
+
+    assert_that!(sut.matches("*a/b/c"), eq true);
+    assert_that!(sut.matches("a*b*c"), eq true);
+    assert_that!(sut.matches("a*b"), eq false);
+}
@@ -0,0 +1,266 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2::port::listener::Listener;
+use iceoryx2::port::notifier::Notifier;
+use iceoryx2::prelude::*;
+use iceoryx2::testing::*;
+use iceoryx2::waitset::WaitSetRunResult;
+use iceoryx2::waitset_executor::{
+    AttachmentEvent, WaitSetExecutorBuilder, WaitSetExecutorCreateError, WaitSetExecutorRunError,
+};
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_concurrency::atomic::AtomicUsize;
+use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_posix::barrier::BarrierBuilder;
+use iceoryx2_bb_posix::barrier::BarrierHandle;
+use iceoryx2_bb_posix::barrier::Handle;
+use iceoryx2_bb_posix::clock::nanosleep;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::watchdog::Watchdog;
+use iceoryx2_bb_testing_macros::test;
+
+type ServiceType = ipc_threadsafe::Service;
+
+fn create_event(node: &Node<ServiceType>) -> (Listener<ServiceType>, Notifier<ServiceType>) {
+    let service = node
+        .service_builder(&generate_service_name())
+        .event()
+        .create()
+        .unwrap();
+    (
+        service.listener_builder().create().unwrap(),
+        service.notifier_builder().create().unwrap(),
+    )
+}
+
+#[test]
+fn creating_executor_with_invalid_number_of_workers_fails() {
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+
+    let sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(0)
+        .create(&waitset);
+    assert_that!(sut.err(), eq Some(WaitSetExecutorCreateError::InvalidNumberOfWorkers));
+}
+
+#[test]
+fn running_executor_without_attachments_fails() {
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(1)
+        .create(&waitset)
+        .unwrap();
+
+    assert_that!(sut.run().err(), eq Some(WaitSetExecutorRunError::NoAttachments));
+}
+
+#[test]
+fn running_executor_with_invalid_worker_affinity_fails() {
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let (listener, _notifier) = create_event(&node);
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(1)
+        .worker_affinity(&[SystemInfo::NumberOfCpuCores.value()])
+        .create(&waitset)
+        .unwrap();
+    sut.attach_notification(&listener, |_| CallbackProgression::Stop)
+        .unwrap();
+
+    assert_that!(sut.run().err(), eq Some(WaitSetExecutorRunError::WorkerThreadCreationFailed));
+}
+
+#[test]
+fn notification_callbacks_are_called_for_all_triggered_attachments() {
+    let _watchdog = Watchdog::new();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let (listener_1, notifier_1) = create_event(&node);
+    let (listener_2, notifier_2) = create_event(&node);
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let received_events = AtomicUsize::new(0);
+    let on_event = |listener: &Listener<ServiceType>, event: AttachmentEvent| {
+        assert_that!(event, eq AttachmentEvent::Notification);
+        listener.try_wait(|_| ()).unwrap();
+        if received_events.fetch_add(1, Ordering::Relaxed) + 1 == 2 {
+            CallbackProgression::Stop
+        } else {
+            CallbackProgression::Continue
+        }
+    };
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(2)
+        .create(&waitset)
+        .unwrap();
+
+    sut.attach_notification(&listener_1, |event| on_event(&listener_1, event))
+        .unwrap();
+    sut.attach_notification(&listener_2, |event| on_event(&listener_2, event))
+        .unwrap();
+
+    notifier_1.notify().unwrap();
+    notifier_2.notify().unwrap();
+
+    assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+    assert_that!(received_events.load(Ordering::Relaxed), eq 2);
+}
+
+#[test]
+fn callbacks_of_different_attachments_run_concurrently() {
+    let _watchdog = Watchdog::new();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let (listener_1, notifier_1) = create_event(&node);
+    let (listener_2, notifier_2) = create_event(&node);
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let barrier_handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new(2).create(&barrier_handle).unwrap();
+    let on_event = |listener: &Listener<ServiceType>| {
+        listener.try_wait(|_| ()).unwrap();
+        // blocks forever unless the other callback runs at the same time
+        barrier.wait();
+        CallbackProgression::Stop
+    };
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(2)
+        .create(&waitset)
+        .unwrap();
+
+    sut.attach_notification(&listener_1, |_| on_event(&listener_1))
+        .unwrap();
+    sut.attach_notification(&listener_2, |_| on_event(&listener_2))
+        .unwrap();
+
+    notifier_1.notify().unwrap();
+    notifier_2.notify().unwrap();
+
+    assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+}
+
+#[test]
+fn callback_of_one_attachment_never_runs_concurrently_with_itself() {
+    let _watchdog = Watchdog::new();
+    const NUMBER_OF_TICKS: usize = 20;
+    let is_running = AtomicBool::new(false);
+    let ticks = AtomicUsize::new(0);
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(4)
+        .create(&waitset)
+        .unwrap();
+
+    sut.attach_interval(Duration::from_micros(100), |event| {
+        assert_that!(event, eq AttachmentEvent::Tick);
+        assert_that!(is_running.swap(true, Ordering::Relaxed), eq false);
+        nanosleep(Duration::from_millis(1)).unwrap();
+        is_running.store(false, Ordering::Relaxed);
+
+        if ticks.fetch_add(1, Ordering::Relaxed) + 1 == NUMBER_OF_TICKS {
+            CallbackProgression::Stop
+        } else {
+            CallbackProgression::Continue
+        }
+    })
+    .unwrap();
+
+    assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+    assert_that!(ticks.load(Ordering::Relaxed), eq NUMBER_OF_TICKS);
+}
+
+#[test]
+fn missed_deadline_is_reported_to_the_callback() {
+    let _watchdog = Watchdog::new();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let (listener, _notifier) = create_event(&node);
+    let missed_deadlines = AtomicUsize::new(0);
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(1)
+        .create(&waitset)
+        .unwrap();
+
+    sut.attach_deadline(&listener, Duration::from_millis(1), |event| {
+        assert_that!(event, eq AttachmentEvent::MissedDeadline);
+        missed_deadlines.fetch_add(1, Ordering::Relaxed);
+        CallbackProgression::Stop
+    })
+    .unwrap();
+
+    assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+    assert_that!(missed_deadlines.load(Ordering::Relaxed), eq 1);
+}
+
+#[test]
+fn detached_attachments_are_no_longer_dispatched() {
+    let _watchdog = Watchdog::new();
+    let config = generate_isolated_config();
+    let node = NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap();
+    let (listener, notifier) = create_event(&node);
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(1)
+        .create(&waitset)
+        .unwrap();
+
+    let notification_id = sut
+        .attach_notification(&listener, |_| -> CallbackProgression {
+            unreachable!("the callback of a detached attachment shall not be called")
+        })
+        .unwrap();
+    sut.attach_interval(Duration::from_millis(1), |_| CallbackProgression::Stop)
+        .unwrap();
+    notifier.notify().unwrap();
+
+    assert_that!(sut.detach(&notification_id), eq true);
+    assert_that!(sut.len(), eq 1);
+    assert_that!(sut.detach(&notification_id), eq false);
+
+    assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+}
+
+#[test]
+fn priority_can_be_set_for_attached_attachments() {
+    let waitset = WaitSetBuilder::new().create::<ServiceType>().unwrap();
+    let mut sut = WaitSetExecutorBuilder::new()
+        .number_of_workers(1)
+        .create(&waitset)
+        .unwrap();
+
+    let attachment_id = sut
+        .attach_interval(Duration::from_millis(1), |_| CallbackProgression::Stop)
+        .unwrap();
+
+    assert_that!(sut.set_priority(&attachment_id, 10), eq true);
+    assert_that!(sut.detach(&attachment_id), eq true);
+    assert_that!(sut.set_priority(&attachment_id, 10), eq false);
+}